@@ -16,7 +16,7 @@ async fn main() -> Result<()> {
     let mut version_id = resp.version_id();
 
     let mut pls_bytes = client
-        .hit(DownloadVersionByID::new(dictionary_id, version_id))
+        .hit(DownloadDictionaryVersionPls::new(dictionary_id, version_id))
         .await?;
     let mut current_dictionary_state = unsafe { std::str::from_utf8_unchecked(&pls_bytes) };
     println!("--- Initial dictionary ---\n");
@@ -37,14 +37,14 @@ async fn main() -> Result<()> {
     let rules = vec![
         Rule::new_alias("TTS", "text to speech"),
         Rule::new_alias("API", "application programming interface"),
-        Rule::new_phoneme("via", "/ˈvaɪə/", "ipa"),
+        Rule::new_phoneme("via", "/ˈvaɪə/", PhonemeAlphabet::Ipa),
     ];
     let mut resp = client.hit(AddRules::new(dictionary_id, rules)).await?;
     dictionary_id = resp.id();
     version_id = resp.version_id();
 
     pls_bytes = client
-        .hit(DownloadVersionByID::new(dictionary_id, version_id))
+        .hit(DownloadDictionaryVersionPls::new(dictionary_id, version_id))
         .await?;
     current_dictionary_state = unsafe { std::str::from_utf8_unchecked(&pls_bytes) };
     println!("--- Dictionary with added rules ---\n");
@@ -62,7 +62,7 @@ async fn main() -> Result<()> {
     version_id = resp.version_id();
 
     pls_bytes = client
-        .hit(DownloadVersionByID::new(dictionary_id, version_id))
+        .hit(DownloadDictionaryVersionPls::new(dictionary_id, version_id))
         .await?;
     current_dictionary_state = unsafe { std::str::from_utf8_unchecked(&pls_bytes) };
     println!("--- Dictionary with removed rules ---\n");
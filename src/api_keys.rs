@@ -0,0 +1,222 @@
+//! Spreading requests over several api keys
+use crate::client::Result;
+use reqwest::{Response, StatusCode};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+const DEFAULT_COOLDOWN: Duration = Duration::from_secs(60);
+const CHARACTER_COST_HEADER: &str = "character-cost";
+
+/// How [ApiKeys] picks the key for a request
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum KeyRouting {
+    /// Each key in turn
+    #[default]
+    RoundRobin,
+    /// The key that was charged the fewest characters so far
+    LeastUsed,
+    /// The first key, the next ones only while the ones before them are cooling down
+    Failover,
+}
+
+/// Several api keys for one [ElevenLabsClient](crate::ElevenLabsClient), e.g. of different workspaces
+///
+/// A request answered with `401 Unauthorized`, e.g. for an exhausted quota, or
+/// `429 Too Many Requests` is sent again with the next key that was not tried yet,
+/// and the failed key is left out for a minute unless no other key is available.
+/// The requests, characters and failures of each key are counted, see
+/// [key_usage](crate::ElevenLabsClient::key_usage).
+///
+/// Websocket connections use a key picked the same way, but are not counted.
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let keys = ApiKeys::new([std::env::var("KEY_TEAM_A")?, std::env::var("KEY_TEAM_B")?])?
+///         .with_routing(KeyRouting::LeastUsed);
+///     let c = ElevenLabsClient::builder().api_keys(keys).build()?;
+///     let body = TextToSpeechBody::new("Shared between teams", Model::ElevenMultilingualV2);
+///     let speech = c.hit(TextToSpeech::new(PreMadeVoiceID::Sarah, body)).await?;
+///     for (i, usage) in c.key_usage().iter().enumerate() {
+///         println!("key {}: {} characters", i, usage.characters());
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct ApiKeys {
+    keys: Vec<String>,
+    routing: KeyRouting,
+    cooldown: Duration,
+    state: Mutex<State>,
+}
+
+#[derive(Debug)]
+struct State {
+    next: usize,
+    usage: Vec<KeyUsage>,
+    cooldowns: Vec<Cooldown>,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Cooldown {
+    None,
+    Until(Instant),
+    /// The cooldown reaches past what an `Instant` can hold
+    Indefinite,
+}
+
+impl Cooldown {
+    fn is_over(&self, now: Instant) -> bool {
+        match self {
+            Cooldown::None => true,
+            Cooldown::Until(until) => *until <= now,
+            Cooldown::Indefinite => false,
+        }
+    }
+}
+
+/// Counters of one key, since the client was created
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct KeyUsage {
+    requests: u64,
+    characters: u64,
+    failures: u64,
+}
+
+impl KeyUsage {
+    pub fn requests(&self) -> u64 {
+        self.requests
+    }
+    /// Characters charged, as reported by the API
+    pub fn characters(&self) -> u64 {
+        self.characters
+    }
+    /// Requests answered with `401` or `429`
+    pub fn failures(&self) -> u64 {
+        self.failures
+    }
+}
+
+impl ApiKeys {
+    /// Fails if no key is given
+    pub fn new<I>(keys: I) -> Result<Self>
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        let keys: Vec<String> = keys.into_iter().map(Into::into).collect();
+        if keys.is_empty() {
+            return Err("No api keys given".into());
+        }
+        Ok(ApiKeys {
+            state: Mutex::new(State {
+                next: 0,
+                usage: vec![KeyUsage::default(); keys.len()],
+                cooldowns: vec![Cooldown::None; keys.len()],
+            }),
+            keys,
+            routing: KeyRouting::default(),
+            cooldown: DEFAULT_COOLDOWN,
+        })
+    }
+    pub fn with_routing(mut self, routing: KeyRouting) -> Self {
+        self.routing = routing;
+        self
+    }
+    /// How long a key is left out after a `401` or `429`, defaults to a minute
+    ///
+    /// A cooldown too long to be added to the current time leaves the key out for good,
+    /// unless every other key is left out as well
+    pub fn with_cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+    /// The counters of each key, in the order the keys were given
+    pub fn usage(&self) -> Vec<KeyUsage> {
+        self.state.lock().expect("not poisoned").usage.clone()
+    }
+
+    pub(crate) fn single(key: String) -> Self {
+        ApiKeys::new([key]).expect("one key is given")
+    }
+
+    /// The index and the key to send a request with, leaving out the keys already tried for it
+    pub(crate) fn pick(&self, tried: &[usize]) -> (usize, &str) {
+        let now = Instant::now();
+        let mut state = self.state.lock().expect("not poisoned");
+        let untried: Vec<usize> = (0..self.keys.len())
+            .filter(|i| !tried.contains(i))
+            .collect();
+        let available: Vec<usize> = untried
+            .iter()
+            .copied()
+            .filter(|&i| state.cooldowns[i].is_over(now))
+            .collect();
+        let pool = if available.is_empty() {
+            untried
+        } else {
+            available
+        };
+
+        let index = match self.routing {
+            KeyRouting::RoundRobin => {
+                let index = pool
+                    .iter()
+                    .copied()
+                    .find(|&i| i >= state.next)
+                    .or(pool.first().copied());
+                state.next = index.map_or(0, |i| i + 1);
+                index
+            }
+            KeyRouting::LeastUsed => pool.iter().copied().min_by_key(|&i| {
+                let usage = &state.usage[i];
+                (usage.characters, usage.requests)
+            }),
+            KeyRouting::Failover => pool.first().copied(),
+        }
+        .unwrap_or(0);
+        (index, &self.keys[index])
+    }
+
+    /// Counts a request sent with the key, and starts its cooldown if it was refused
+    pub(crate) fn record(&self, index: usize, result: &Result<Response>) {
+        let mut state = self.state.lock().expect("not poisoned");
+        state.usage[index].requests += 1;
+        let Ok(resp) = result else {
+            return;
+        };
+        if let Some(characters) = resp
+            .headers()
+            .get(CHARACTER_COST_HEADER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.trim().parse::<u64>().ok())
+        {
+            state.usage[index].characters += characters;
+        }
+        if refused(resp.status()) {
+            state.usage[index].failures += 1;
+            state.cooldowns[index] = Instant::now()
+                .checked_add(self.cooldown)
+                .map_or(Cooldown::Indefinite, Cooldown::Until);
+        }
+    }
+
+    /// Whether the request should be sent again with another key
+    pub(crate) fn fails_over(&self, result: &Result<Response>, tried: &[usize]) -> bool {
+        matches!(result, Ok(resp) if refused(resp.status())) && tried.len() + 1 < self.keys.len()
+    }
+}
+
+fn refused(status: StatusCode) -> bool {
+    status == StatusCode::UNAUTHORIZED || status == StatusCode::TOO_MANY_REQUESTS
+}
@@ -0,0 +1,68 @@
+//! A client that blocks the current thread, for scripts and command line tools
+//!
+//! It drives the [async client](crate::ElevenLabsClient) on a runtime of its own
+//! and takes the same endpoints.
+//!
+//! # Example
+//! ```no_run
+//! use elevenlabs_rs::*;
+//! use elevenlabs_rs::blocking::BlockingElevenLabsClient;
+//!
+//! fn main() -> Result<()> {
+//!     let c = BlockingElevenLabsClient::default()?;
+//!     let body = TextToSpeechBody::new("No runtime required", Model::ElevenTurboV2Dot5);
+//!     let speech = c.hit(TextToSpeech::new(PreMadeVoiceID::Sarah, body))?;
+//!     std::fs::write("speech.mp3", speech)?;
+//!     Ok(())
+//! }
+//! ```
+//!
+//! # Panics
+//! Like `reqwest::blocking`, the client must not be used or dropped within an async runtime.
+use crate::client::{ElevenLabsClient, ResponseMeta, Result};
+use crate::endpoints::Endpoint;
+use std::sync::Arc;
+use tokio::runtime::{Builder, Runtime};
+
+/// A blocking [ElevenLabsClient], cheap to clone
+#[derive(Clone)]
+pub struct BlockingElevenLabsClient {
+    inner: ElevenLabsClient,
+    runtime: Arc<Runtime>,
+}
+
+impl BlockingElevenLabsClient {
+    /// Reads the api key from the `ELEVEN_API_KEY` environment variable
+    #[allow(clippy::should_implement_trait)]
+    pub fn default() -> Result<Self> {
+        Self::from_client(ElevenLabsClient::default()?)
+    }
+    pub fn new<T: Into<String>>(api_key: T) -> Result<Self> {
+        Self::from_client(ElevenLabsClient::new(api_key))
+    }
+    /// Wraps a configured client, e.g. one made with [ElevenLabsClient::builder]
+    pub fn from_client(client: ElevenLabsClient) -> Result<Self> {
+        let runtime = Builder::new_current_thread().enable_all().build()?;
+        Ok(BlockingElevenLabsClient {
+            inner: client,
+            runtime: Arc::new(runtime),
+        })
+    }
+    /// The wrapped async client
+    pub fn client(&self) -> &ElevenLabsClient {
+        &self.inner
+    }
+
+    /// See [ElevenLabsClient::hit]
+    pub fn hit<T: Endpoint>(&self, endpoint: T) -> Result<T::ResponseBody> {
+        self.runtime.block_on(self.inner.hit(endpoint))
+    }
+
+    /// See [ElevenLabsClient::hit_with_meta]
+    pub fn hit_with_meta<T: Endpoint>(
+        &self,
+        endpoint: T,
+    ) -> Result<(T::ResponseBody, ResponseMeta)> {
+        self.runtime.block_on(self.inner.hit_with_meta(endpoint))
+    }
+}
@@ -1,17 +1,36 @@
+use crate::api_keys::{ApiKeys, KeyUsage};
+use crate::config::ClientConfig;
 use crate::endpoints::tts::ws::{EOSMessage, Flush, TextChunk, WebSocketTTS, WebSocketTTSResponse};
-use crate::endpoints::{Endpoint, RequestBody};
-use crate::error::Error::HttpError;
-use crate::error::{ElevenLabsClientError, ElevenLabsServerError, WebSocketError};
+use crate::endpoints::{api_url, Endpoint, Paginated, RequestBody};
+use async_stream::try_stream;
+use crate::error::{retry_after, ApiError, Error, WebSocketError};
+use crate::middleware::{Middleware, Next, Sender};
+use crate::shared::extras;
+use crate::telemetry::{ws_path, Span};
+use crate::transport::{Request, Transport};
 use futures_util::{pin_mut, SinkExt, Stream, StreamExt};
 use reqwest;
-use reqwest::header::CONTENT_TYPE;
+use reqwest::header::{HeaderMap, CONTENT_TYPE};
 use reqwest::Method;
 use reqwest::Response;
+use reqwest::StatusCode;
+use reqwest::Url;
+use serde_json::Value;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use bytes::Bytes;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
 use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
 use tokio_tungstenite::{connect_async, tungstenite::protocol::Message};
+use tokio_util::sync::CancellationToken;
 
 pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
 
+/// A stream of byte chunks, e.g. audio as it is generated
+pub type ByteStream = Pin<Box<dyn Stream<Item = Result<Bytes>> + Send>>;
+
 pub const BASE_URL: &str = "https://api.elevenlabs.io";
 const XI_API_KEY_HEADER: &str = "xi-api-key";
 const APPLICATION_JSON: &str = "application/json";
@@ -20,44 +39,481 @@ const APPLICATION_JSON: &str = "application/json";
 #[derive(Clone)]
 pub struct ElevenLabsClient {
     inner: reqwest::Client,
-    api_key: String,
+    api_keys: Arc<ApiKeys>,
+    retry_policy: Option<RetryPolicy>,
+    base_url: Option<Url>,
+    transport: Option<Arc<dyn Transport>>,
+    middleware: Arc<[Arc<dyn Middleware>]>,
+    strict_responses: bool,
 }
 
 impl ElevenLabsClient {
     pub fn default() -> Result<Self> {
         Ok(Self {
             inner: reqwest::Client::new(),
-            api_key: std::env::var("ELEVEN_API_KEY")?,
+            api_keys: Arc::new(ApiKeys::single(std::env::var("ELEVEN_API_KEY")?)),
+            retry_policy: None,
+            base_url: None,
+            transport: None,
+            middleware: Arc::new([]),
+            strict_responses: false,
         })
     }
+    /// A client configured by the `ELEVENLABS_*` environment variables, see [ClientConfig]
+    pub fn from_env() -> Result<Self> {
+        ClientConfig::from_env()?.build()
+    }
     pub fn new<T: Into<String>>(api_key: T) -> Self {
         Self {
             inner: reqwest::Client::new(),
-            api_key: api_key.into(),
+            api_keys: Arc::new(ApiKeys::single(api_key.into())),
+            retry_policy: None,
+            base_url: None,
+            transport: None,
+            middleware: Arc::new([]),
+            strict_responses: false,
         }
     }
 
+    /// Send requests through the given transport instead of the network
+    ///
+    /// See the [transport](crate::transport) module for an example.
+    pub fn with_transport<T: Transport + 'static>(mut self, transport: T) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Pass every request through the given middleware, after any added before
+    ///
+    /// See the [middleware](crate::middleware) module for an example.
+    pub fn with_middleware<M: Middleware + 'static>(mut self, middleware: M) -> Self {
+        let mut chain = self.middleware.to_vec();
+        chain.push(Arc::new(middleware));
+        self.middleware = chain.into();
+        self
+    }
+
+    /// Configure the client, e.g. its base url or proxy
+    ///
+    /// # Example
+    /// ```no_run
+    /// use elevenlabs_rs::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     let c = ElevenLabsClient::builder()
+    ///         .api_key("some_api_key")
+    ///         .base_url("https://api.eu.residency.elevenlabs.io")?
+    ///         .proxy(Proxy::https("http://egress.internal:3128")?)
+    ///         .build()?;
+    ///     let voices = c.hit(GetVoices).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::default()
+    }
+
+    /// The base url requests are sent to
+    pub fn base_url(&self) -> &str {
+        self.base_url
+            .as_ref()
+            .map(|url| url.as_str().trim_end_matches('/'))
+            .unwrap_or(BASE_URL)
+    }
+
+    /// Moves an endpoint's url onto the configured base url, keeping its path and query
+    fn rebase(&self, url: Url) -> Url {
+        let Some(base) = &self.base_url else {
+            return url;
+        };
+        let mut rebased = base.clone();
+        let prefix = base.path().trim_end_matches('/');
+        rebased.set_path(&format!("{}{}", prefix, url.path()));
+        rebased.set_query(url.query());
+        rebased
+    }
+
+    pub(crate) fn rebase_ws(&self, url: String) -> Result<String> {
+        let Some(base) = &self.base_url else {
+            return Ok(url);
+        };
+        let url = url.parse::<Url>()?;
+        let mut rebased = self.rebase(url);
+        let scheme = if base.scheme() == "http" { "ws" } else { "wss" };
+        rebased
+            .set_scheme(scheme)
+            .map_err(|_| "Base url cannot be used for websockets")?;
+        Ok(rebased.to_string())
+    }
+
+    /// Retry failed requests according to the given policy
+    ///
+    /// # Example
+    /// ```no_run
+    /// use elevenlabs_rs::*;
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     let policy = RetryPolicy::default()
+    ///         .with_max_retries(5)
+    ///         .with_initial_backoff(Duration::from_secs(1))
+    ///         // TTS requests are POSTs, opt them in
+    ///         .with_non_idempotent_requests();
+    ///     let c = ElevenLabsClient::default()?.with_retry_policy(policy);
+    ///     let body = TextToSpeechBody::new("Try, try again", Model::ElevenTurboV2Dot5);
+    ///     let speech = c.hit(TextToSpeech::new(PreMadeVoiceID::Sarah, body)).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Spread requests over several api keys instead of the one the client was created with
+    pub fn with_api_keys(mut self, api_keys: ApiKeys) -> Self {
+        self.api_keys = Arc::new(api_keys);
+        self
+    }
+    /// The counters of each api key, see [ApiKeys]
+    pub fn key_usage(&self) -> Vec<KeyUsage> {
+        self.api_keys.usage()
+    }
+
+    /// Fail deserializing conversations and voices with fields unknown to the crate,
+    /// e.g. in CI to notice API changes, instead of keeping them as extra fields
+    pub fn with_strict_responses(mut self, strict_responses: bool) -> Self {
+        self.strict_responses = strict_responses;
+        self
+    }
+
+    pub(crate) fn has_retry_policy(&self) -> bool {
+        self.retry_policy.is_some()
+    }
+
     pub async fn hit<T: Endpoint>(&self, endpoint: T) -> Result<T::ResponseBody> {
+        let resp = self.send_with_retries(&endpoint).await?;
+        self.response_body(endpoint, resp).await
+    }
+
+    /// Like [`hit`](Self::hit), but also returns metadata read from the response headers
+    ///
+    /// # Example
+    /// ```no_run
+    /// use elevenlabs_rs::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     let c = ElevenLabsClient::default()?;
+    ///     let body = TextToSpeechBody::new("How much did that cost?", Model::ElevenTurboV2Dot5);
+    ///     let (speech, meta) = c
+    ///         .hit_with_meta(TextToSpeech::new(PreMadeVoiceID::Sarah, body))
+    ///         .await?;
+    ///     println!("characters: {:?}", meta.character_cost());
+    ///     println!("remaining: {:?}", meta.rate_limit_remaining());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn hit_with_meta<T: Endpoint>(
+        &self,
+        endpoint: T,
+    ) -> Result<(T::ResponseBody, ResponseMeta)> {
+        let resp = self.send_with_retries(&endpoint).await?;
+        let meta = ResponseMeta::from_response(&resp);
+        Ok((self.response_body(endpoint, resp).await?, meta))
+    }
+
+    async fn response_body<T: Endpoint>(
+        &self,
+        endpoint: T,
+        resp: Response,
+    ) -> Result<T::ResponseBody> {
+        extras::STRICT
+            .scope(self.strict_responses, endpoint.response_body(resp))
+            .await
+    }
+
+    /// Like [`hit`](Self::hit), but fails with [Error::Timeout] if no response body
+    /// was received within `timeout`, retries included
+    ///
+    /// # Example
+    /// ```no_run
+    /// use elevenlabs_rs::*;
+    /// use elevenlabs_rs::error::Error;
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     let c = ElevenLabsClient::default()?;
+    ///     let body = TextToSpeechBody::new("Quick, please", Model::ElevenFlashV2Dot5);
+    ///     let endpoint = TextToSpeech::new(PreMadeVoiceID::Sarah, body);
+    ///     match c.hit_with_timeout(endpoint, Duration::from_secs(10)).await {
+    ///         Ok(speech) => println!("{} bytes", speech.len()),
+    ///         Err(e) if matches!(e.downcast_ref(), Some(Error::Timeout(_))) => println!("too slow"),
+    ///         Err(e) => return Err(e),
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn hit_with_timeout<T: Endpoint>(
+        &self,
+        endpoint: T,
+        timeout: Duration,
+    ) -> Result<T::ResponseBody> {
+        match tokio::time::timeout(timeout, self.hit(endpoint)).await {
+            Ok(result) => result,
+            Err(_) => Err(Box::new(Error::Timeout(timeout))),
+        }
+    }
+
+    /// Like [`hit`](Self::hit), but fails with [Error::Cancelled] as soon as `token` is cancelled
+    ///
+    /// # Example
+    /// ```no_run
+    /// use elevenlabs_rs::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     let c = ElevenLabsClient::default()?;
+    ///     let token = CancellationToken::new();
+    ///     let chapter = std::fs::read_to_string("chapter_01.txt")?;
+    ///     let body = TextToSpeechBody::new(&chapter, Model::ElevenMultilingualV2);
+    ///     let endpoint = TextToSpeech::new(PreMadeVoiceID::George, body);
+    ///     let render = c.hit_with_cancellation(endpoint, &token);
+    ///     // e.g. when the user closes the window
+    ///     let cancel = token.clone();
+    ///     tokio::spawn(async move {
+    ///         tokio::signal::ctrl_c().await.ok();
+    ///         cancel.cancel();
+    ///     });
+    ///     let speech = render.await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn hit_with_cancellation<T: Endpoint>(
+        &self,
+        endpoint: T,
+        token: &CancellationToken,
+    ) -> Result<T::ResponseBody> {
+        tokio::select! {
+            result = self.hit(endpoint) => result,
+            _ = token.cancelled() => Err(Box::new(Error::Cancelled)),
+        }
+    }
+
+    /// Hits an endpoint and streams the raw response body instead of buffering it
+    ///
+    /// Useful for endpoints returning large audio or video files.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use elevenlabs_rs::*;
+    /// use tokio::io::AsyncWriteExt;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     let c = ElevenLabsClient::default()?;
+    ///     let chapter = std::fs::read_to_string("chapter_01.txt")?;
+    ///     let body = TextToSpeechBody::new(&chapter, Model::ElevenMultilingualV2);
+    ///     let stream = c.hit_stream(TextToSpeech::new(PreMadeVoiceID::George, body)).await?;
+    ///     pin_mut!(stream);
+    ///     let mut file = tokio::fs::File::create("chapter_01.mp3").await?;
+    ///     while let Some(chunk) = stream.next().await {
+    ///         file.write_all(&chunk?).await?;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn hit_stream<T: Endpoint>(&self, endpoint: T) -> Result<ByteStream> {
+        let resp = self.send_with_retries(&endpoint).await?;
+        let stream = resp.bytes_stream().map(|r| r.map_err(Into::into));
+        Ok(Box::pin(stream))
+    }
+
+    async fn send_with_retries<T: Endpoint>(&self, endpoint: &T) -> Result<Response> {
+        let span = Span::request(&endpoint.method(), endpoint.url().path());
+        let mut attempt = 0;
+        let mut tried = Vec::new();
+        let (mut key_index, api_key) = self.api_keys.pick(&tried);
+        let mut request = self.request(endpoint, api_key)?;
+        loop {
+            let result = span.instrument(self.send(request)).await;
+            self.api_keys.record(key_index, &result);
+            let delay = if self.api_keys.fails_over(&result, &tried) {
+                tried.push(key_index);
+                Some(Duration::ZERO)
+            } else {
+                let delay = self.retry_delay(&endpoint.method(), &result, attempt);
+                if delay.is_some() {
+                    attempt += 1;
+                    tried.clear();
+                }
+                delay
+            };
+            if let Some(delay) = delay {
+                let (next_key_index, api_key) = self.api_keys.pick(&tried);
+                match self.request(endpoint, api_key) {
+                    Ok(next_request) => {
+                        if !delay.is_zero() {
+                            tokio::time::sleep(delay).await;
+                        }
+                        key_index = next_key_index;
+                        request = next_request;
+                        continue;
+                    }
+                    // A body read from a reader can only be sent once, the response is returned as is
+                    Err(e) if matches!(e.downcast_ref(), Some(Error::ReaderAlreadyConsumed)) => {}
+                    Err(e) => return Err(e),
+                }
+            }
+            match &result {
+                Ok(resp) => span.record_response(resp.status(), resp.headers()),
+                Err(e) => span.record_error(e),
+            }
+            return handle_http_error(result?).await;
+        }
+    }
+
+    fn request<T: Endpoint>(&self, endpoint: &T, api_key: &str) -> Result<Request> {
         let init = self
             .inner
-            .request(endpoint.method(), endpoint.url())
-            .header(XI_API_KEY_HEADER, &self.api_key);
-
-        let resp = match endpoint.method() {
-            Method::GET | Method::DELETE => init.send().await?,
-            Method::POST => match endpoint.request_body()? {
-                RequestBody::Json(json) => {
-                    init.header(CONTENT_TYPE, APPLICATION_JSON)
-                        .json(&json)
-                        .send()
-                        .await?
-                }
-                RequestBody::Multipart(form) => init.multipart(form).send().await?,
-                RequestBody::Empty => return Err("Post request must have a body".into()),
+            .request(endpoint.method(), self.rebase(endpoint.url()))
+            .header(XI_API_KEY_HEADER, api_key);
+
+        let request = match endpoint.method() {
+            Method::GET => init.build()?,
+            Method::POST | Method::PATCH | Method::DELETE => match endpoint.request_body()? {
+                RequestBody::Json(json) => init
+                    .header(CONTENT_TYPE, APPLICATION_JSON)
+                    .json(&json)
+                    .build()?,
+                RequestBody::Multipart(form) => init.multipart(form).build()?,
+                // e.g. deletes, or actions on a resource like cancelling a batch call
+                RequestBody::Empty => init.build()?,
             },
             _ => return Err("Unsupported method for ElevenLabs API".into()),
         };
-        endpoint.response_body(handle_http_error(resp).await?).await
+        Ok(request)
+    }
+
+    async fn send(&self, request: Request) -> Result<Response> {
+        let sender = match &self.transport {
+            Some(transport) => Sender::Transport(Arc::clone(transport)),
+            None => Sender::Client(self.inner.clone()),
+        };
+        Next::new(Arc::clone(&self.middleware), sender)
+            .run(request)
+            .await
+    }
+
+    /// Returns how long to wait before retrying, or `None` if the request should not be retried
+    fn retry_delay(
+        &self,
+        method: &Method,
+        result: &Result<Response>,
+        attempt: u32,
+    ) -> Option<Duration> {
+        let policy = self.retry_policy.as_ref()?;
+        if attempt >= policy.max_retries || !policy.retries_method(method) {
+            return None;
+        }
+        match result {
+            Ok(resp) if policy.retries_status(resp.status()) => {
+                let delay = match retry_after(resp.headers()) {
+                    Some(retry_after) => retry_after.min(policy.max_backoff),
+                    None => policy.backoff(attempt),
+                };
+                Some(delay)
+            }
+            Ok(_) => None,
+            Err(e) => match e.downcast_ref::<reqwest::Error>() {
+                Some(e) if e.is_timeout() || e.is_connect() => Some(policy.backoff(attempt)),
+                _ => None,
+            },
+        }
+    }
+
+    /// Sends a `GET` request to any path of the API, e.g. one this crate does not cover yet
+    ///
+    /// The api key, base url, retries, middleware and error handling apply as for [`hit`](Self::hit),
+    /// the response is returned as is.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use elevenlabs_rs::*;
+    /// use serde_json::Value;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     let c = ElevenLabsClient::default()?;
+    ///     let resp = c.get_raw("/v1/voices", &[("show_legacy", "true")]).await?;
+    ///     let voices: Value = resp.json().await?;
+    ///     println!("{:#}", voices);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn get_raw(&self, path: &str, query: &[(&str, &str)]) -> Result<Response> {
+        let mut url = api_url(path);
+        if !query.is_empty() {
+            url.query_pairs_mut().extend_pairs(query);
+        }
+        self.hit(RawRequest::new(Method::GET, url, None)).await
+    }
+
+    /// Sends a `POST` request with a JSON body to any path of the API, see [`get_raw`](Self::get_raw)
+    pub async fn post_raw(&self, path: &str, body: Value) -> Result<Response> {
+        self.hit(RawRequest::new(Method::POST, api_url(path), Some(body)))
+            .await
+    }
+
+    /// Sends a `PATCH` request with a JSON body to any path of the API, see [`get_raw`](Self::get_raw)
+    pub async fn patch_raw(&self, path: &str, body: Value) -> Result<Response> {
+        self.hit(RawRequest::new(Method::PATCH, api_url(path), Some(body)))
+            .await
+    }
+
+    /// Sends a `DELETE` request to any path of the API, see [`get_raw`](Self::get_raw)
+    pub async fn delete_raw(&self, path: &str) -> Result<Response> {
+        self.hit(RawRequest::new(Method::DELETE, api_url(path), None))
+            .await
+    }
+
+    /// Hits a paginated endpoint page after page, yielding the items of each page
+    ///
+    /// # Example
+    /// ```no_run
+    /// use elevenlabs_rs::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     let c = ElevenLabsClient::default()?;
+    ///     let query = HistoryQuery::default().with_page_size(100);
+    ///     let items = c.paginate(GetGeneratedItems::new(query));
+    ///     pin_mut!(items);
+    ///     while let Some(item) = items.next().await {
+    ///         println!("{}", item?.text());
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn paginate<T>(&self, endpoint: T) -> impl Stream<Item = Result<T::Item>>
+    where
+        T: Paginated,
+    {
+        let client = self.clone();
+        try_stream! {
+            let mut next = Some(endpoint);
+            while let Some(endpoint) = next.take() {
+                let page = client.hit(endpoint.clone()).await?;
+                let (items, next_endpoint) = endpoint.next_page(page);
+                for item in items {
+                    yield item;
+                }
+                next = next_endpoint;
+            }
+        }
     }
 
     pub async fn hit_ws<S>(
@@ -65,9 +521,19 @@ impl ElevenLabsClient {
         mut endpoint: WebSocketTTS<S>,
     ) -> Result<impl Stream<Item = Result<WebSocketTTSResponse>>>
     where
-        S: Stream<Item = String> + Send + 'static,
+        S: Stream + Send + 'static,
+        S::Item: Into<TextChunk> + Send,
     {
-        let (ws_stream, _) = connect_async(endpoint.url()).await?;
+        let url = self.rebase_ws(endpoint.url())?;
+        let span = Span::websocket(&ws_path(&url));
+        let (ws_stream, resp) = match span.instrument(connect_async(url)).await {
+            Ok(connected) => connected,
+            Err(e) => {
+                span.record_error(&e);
+                return Err(e.into());
+            }
+        };
+        span.record_response(resp.status(), resp.headers());
         let (mut ws_writer, mut ws_reader) = ws_stream.split();
         let (tx, rx) = futures_channel::mpsc::unbounded::<Result<WebSocketTTSResponse>>();
 
@@ -102,7 +568,7 @@ impl ElevenLabsClient {
             Ok::<(), Box<dyn std::error::Error + Send + Sync>>(())
         });
 
-        let api_key = self.api_key.clone();
+        let api_key = self.api_keys.pick(&[]).1.to_string();
         tokio::spawn(async move {
             let mut bos_message = endpoint.bos_message().clone();
             bos_message = bos_message.with_api_key(&api_key);
@@ -110,6 +576,7 @@ impl ElevenLabsClient {
             ws_writer.send(Message::text(bos_message)).await?;
 
             let generation_triggers = endpoint.try_trigger_generation().unwrap_or_default();
+            let flush = endpoint.flush();
             let flush_streams = endpoint.streams_after_flush();
             let text_stream = endpoint.text_stream();
             let stream = text_stream.enumerate();
@@ -118,11 +585,15 @@ impl ElevenLabsClient {
             // TODO: add try_trigger_always?
             while let Some((i, chunk)) = stream.next().await {
                 let trigger_index = i + 1;
-                let trigger = generation_triggers.contains(&trigger_index);
+                let mut chunk: TextChunk = chunk.into();
+                if generation_triggers.contains(&trigger_index) {
+                    chunk = chunk.with_try_trigger_generation();
+                }
 
-                ws_writer
-                    .send(Message::text(TextChunk::new(chunk, trigger).json()?))
-                    .await?;
+                ws_writer.send(Message::text(chunk.json()?)).await?;
+            }
+            if flush && flush_streams.is_none() {
+                ws_writer.send(Message::text(Flush::new().json()?)).await?;
             }
             match flush_streams {
                 Some(streams) => {
@@ -153,18 +624,42 @@ impl ElevenLabsClient {
     }
 }
 
-async fn handle_http_error(resp: Response) -> Result<Response> {
-    if resp.status().is_server_error() {
-        let server_error = resp.json::<ElevenLabsServerError>().await?;
-        return Err(Box::new(server_error));
+/// A request built by the `*_raw` methods of [ElevenLabsClient]
+struct RawRequest {
+    method: Method,
+    url: Url,
+    body: Option<Value>,
+}
+
+impl RawRequest {
+    fn new(method: Method, url: Url, body: Option<Value>) -> Self {
+        RawRequest { method, url, body }
+    }
+}
+
+impl Endpoint for RawRequest {
+    type ResponseBody = Response;
+
+    fn method(&self) -> Method {
+        self.method.clone()
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(match &self.body {
+            Some(body) => RequestBody::Json(body.clone()),
+            None => RequestBody::Empty,
+        })
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp)
     }
-    if resp.status().is_client_error() {
-        let client_error = resp.json::<ElevenLabsClientError>().await?;
-        return Err(Box::new(client_error));
+    fn url(&self) -> Url {
+        self.url.clone()
     }
-    // TODO: improve this error handling
+}
+
+async fn handle_http_error(resp: Response) -> Result<Response> {
     if !resp.status().is_success() {
-        return Err(Box::new(HttpError(resp.json().await?)));
+        return Err(Box::new(ApiError::from_response(resp).await));
     }
     Ok(resp)
 }
@@ -173,7 +668,258 @@ impl From<(reqwest::Client, String)> for ElevenLabsClient {
     fn from((client, api_key): (reqwest::Client, String)) -> Self {
         Self {
             inner: client,
-            api_key,
+            api_keys: Arc::new(ApiKeys::single(api_key)),
+            retry_policy: None,
+            base_url: None,
+            transport: None,
+            middleware: Arc::new([]),
+            strict_responses: false,
+        }
+    }
+}
+
+/// Builder for [ElevenLabsClient]
+///
+/// If no api key is given, it is read from the `ELEVEN_API_KEY` environment variable.
+#[derive(Default)]
+pub struct ClientBuilder {
+    api_key: Option<String>,
+    api_keys: Option<ApiKeys>,
+    base_url: Option<Url>,
+    retry_policy: Option<RetryPolicy>,
+    proxies: Vec<reqwest::Proxy>,
+    timeout: Option<Duration>,
+    reqwest_client: Option<reqwest::Client>,
+    transport: Option<Arc<dyn Transport>>,
+    middleware: Vec<Arc<dyn Middleware>>,
+    strict_responses: bool,
+}
+
+impl ClientBuilder {
+    pub fn api_key<T: Into<String>>(mut self, api_key: T) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+    /// Spread requests over several api keys, taking precedence over [api_key](Self::api_key)
+    pub fn api_keys(mut self, api_keys: ApiKeys) -> Self {
+        self.api_keys = Some(api_keys);
+        self
+    }
+    /// Send requests to another host than `https://api.elevenlabs.io`,
+    /// e.g. a data residency endpoint, a mock server or a reverse proxy.
+    ///
+    /// A path in the url is used as a prefix for all endpoint paths.
+    pub fn base_url(mut self, base_url: &str) -> Result<Self> {
+        self.base_url = Some(base_url.parse::<Url>()?);
+        Ok(self)
+    }
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+    /// Route requests through a proxy, can be called more than once
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+    /// Use an already configured `reqwest::Client`, proxy and timeout settings are then ignored
+    pub fn reqwest_client(mut self, client: reqwest::Client) -> Self {
+        self.reqwest_client = Some(client);
+        self
+    }
+    /// Send requests through the given transport instead of the network
+    pub fn transport<T: Transport + 'static>(mut self, transport: T) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+    /// Pass every request through the given middleware, can be called more than once
+    ///
+    /// See the [middleware](crate::middleware) module for an example.
+    pub fn middleware<M: Middleware + 'static>(mut self, middleware: M) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+    /// See [ElevenLabsClient::with_strict_responses]
+    pub fn strict_responses(mut self, strict_responses: bool) -> Self {
+        self.strict_responses = strict_responses;
+        self
+    }
+    pub fn build(self) -> Result<ElevenLabsClient> {
+        let api_keys = match (self.api_keys, self.api_key) {
+            (Some(api_keys), _) => api_keys,
+            (None, Some(api_key)) => ApiKeys::single(api_key),
+            (None, None) => ApiKeys::single(std::env::var("ELEVEN_API_KEY")?),
+        };
+        let inner = match self.reqwest_client {
+            Some(client) => client,
+            None => {
+                let mut builder = reqwest::Client::builder();
+                for proxy in self.proxies {
+                    builder = builder.proxy(proxy);
+                }
+                if let Some(timeout) = self.timeout {
+                    builder = builder.timeout(timeout);
+                }
+                builder.build()?
+            }
+        };
+        Ok(ElevenLabsClient {
+            inner,
+            api_keys: Arc::new(api_keys),
+            retry_policy: self.retry_policy,
+            base_url: self.base_url,
+            transport: self.transport,
+            middleware: self.middleware.into(),
+            strict_responses: self.strict_responses,
+        })
+    }
+}
+
+/// Retry policy with exponential backoff and jitter
+///
+/// Retries on `429 Too Many Requests`, `5xx` responses, timeouts and connection errors.
+/// A `Retry-After` header sent by the server is honored over the computed backoff,
+/// up to the maximum backoff.
+///
+/// Only idempotent requests (`GET` and `DELETE`) are retried unless
+/// [`with_non_idempotent_requests`](RetryPolicy::with_non_idempotent_requests) is set.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    multiplier: u32,
+    jitter: bool,
+    non_idempotent_requests: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            multiplier: 2,
+            jitter: true,
+            non_idempotent_requests: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+    pub fn with_initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+    pub fn with_max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+    pub fn with_multiplier(mut self, multiplier: u32) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+    pub fn without_jitter(mut self) -> Self {
+        self.jitter = false;
+        self
+    }
+    /// Also retry `POST` requests, e.g. text to speech
+    pub fn with_non_idempotent_requests(mut self) -> Self {
+        self.non_idempotent_requests = true;
+        self
+    }
+    pub fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    fn retries_method(&self, method: &Method) -> bool {
+        self.non_idempotent_requests || matches!(*method, Method::GET | Method::DELETE)
+    }
+
+    fn retries_status(&self, status: StatusCode) -> bool {
+        status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+    }
+
+    fn backoff(&self, attempt: u32) -> Duration {
+        let factor = self.multiplier.saturating_pow(attempt);
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff);
+        if !self.jitter {
+            return backoff;
+        }
+        // Equal jitter: somewhere between half and all of the backoff
+        let random = RandomState::new().build_hasher().finish();
+        let half = backoff / 2;
+        half + half.mul_f64((random % 1000) as f64 / 1000.0)
+    }
+}
+
+/// Metadata of a response, returned by [`hit_with_meta`](ElevenLabsClient::hit_with_meta)
+///
+/// Headers the API did not send are `None`.
+#[derive(Clone, Debug)]
+pub struct ResponseMeta {
+    status: StatusCode,
+    headers: HeaderMap,
+}
+
+impl ResponseMeta {
+    fn from_response(resp: &Response) -> Self {
+        ResponseMeta {
+            status: resp.status(),
+            headers: resp.headers().clone(),
         }
     }
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+    /// All response headers
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+    /// A header's value, if it is valid text
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|v| v.to_str().ok())
+    }
+    pub fn request_id(&self) -> Option<&str> {
+        self.header("request-id")
+    }
+    /// The history item created by e.g. text to speech
+    pub fn history_item_id(&self) -> Option<&str> {
+        self.header("history-item-id")
+    }
+    /// Characters charged for the request
+    pub fn character_cost(&self) -> Option<u64> {
+        self.parsed("character-cost")
+    }
+    pub fn current_concurrent_requests(&self) -> Option<u32> {
+        self.parsed("current-concurrent-requests")
+    }
+    pub fn maximum_concurrent_requests(&self) -> Option<u32> {
+        self.parsed("maximum-concurrent-requests")
+    }
+    pub fn rate_limit_limit(&self) -> Option<u64> {
+        self.parsed("x-ratelimit-limit")
+    }
+    pub fn rate_limit_remaining(&self) -> Option<u64> {
+        self.parsed("x-ratelimit-remaining")
+    }
+    /// Seconds until the rate limit resets
+    pub fn rate_limit_reset(&self) -> Option<u64> {
+        self.parsed("x-ratelimit-reset")
+    }
+
+    fn parsed<V: std::str::FromStr>(&self, name: &str) -> Option<V> {
+        self.header(name)?.trim().parse().ok()
+    }
 }
@@ -0,0 +1,212 @@
+//! Client settings read from the environment or a file
+use crate::client::{ClientBuilder, ElevenLabsClient, Result, RetryPolicy};
+use crate::shared::identifiers::{Model, VoiceID};
+use serde::Deserialize;
+use std::env::{self, VarError};
+use std::str::FromStr;
+use std::time::Duration;
+
+const API_KEY_VAR: &str = "ELEVENLABS_API_KEY";
+/// Read by [ElevenLabsClient::default] since the first versions of the crate
+const LEGACY_API_KEY_VAR: &str = "ELEVEN_API_KEY";
+const BASE_URL_VAR: &str = "ELEVENLABS_BASE_URL";
+const TIMEOUT_VAR: &str = "ELEVENLABS_TIMEOUT_SECS";
+const MAX_RETRIES_VAR: &str = "ELEVENLABS_MAX_RETRIES";
+const VOICE_ID_VAR: &str = "ELEVENLABS_VOICE_ID";
+const MODEL_ID_VAR: &str = "ELEVENLABS_MODEL_ID";
+
+/// Settings for an [ElevenLabsClient], along with a default voice and model for the application
+///
+/// All settings are optional. Without an api key, the client reads it from `ELEVEN_API_KEY`.
+/// A number of retries sets a default [RetryPolicy] with it, which only retries idempotent requests.
+///
+/// | Setting | Environment variable | TOML key |
+/// |---|---|---|
+/// | [api key](Self::with_api_key) | `ELEVENLABS_API_KEY`, else `ELEVEN_API_KEY` | `api_key` |
+/// | [base url](Self::with_base_url) | `ELEVENLABS_BASE_URL` | `base_url` |
+/// | [timeout](Self::with_timeout) | `ELEVENLABS_TIMEOUT_SECS` | `timeout_secs` |
+/// | [retries](Self::with_max_retries) | `ELEVENLABS_MAX_RETRIES` | `max_retries` |
+/// | [voice](Self::with_voice_id) | `ELEVENLABS_VOICE_ID` | `voice_id` |
+/// | [model](Self::with_model) | `ELEVENLABS_MODEL_ID` | `model_id` |
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let config = ClientConfig::from_env()?;
+///     let c = config.build()?;
+///     let voice_id = config.voice_id().unwrap_or(PreMadeVoiceID::Sarah.into());
+///     let model = config.model().unwrap_or(Model::ElevenMultilingualV2);
+///     let body = TextToSpeechBody::new("Configured from the environment", model);
+///     let speech = c.hit(TextToSpeech::new(voice_id, body)).await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ClientConfig {
+    api_key: Option<String>,
+    base_url: Option<String>,
+    timeout_secs: Option<f64>,
+    max_retries: Option<u32>,
+    voice_id: Option<String>,
+    model_id: Option<String>,
+}
+
+impl ClientConfig {
+    pub fn new() -> Self {
+        ClientConfig::default()
+    }
+
+    /// Reads the `ELEVENLABS_*` environment variables, see the table above
+    ///
+    /// Fails if the timeout is not a finite number of seconds above zero,
+    /// or the number of retries is not a number.
+    pub fn from_env() -> Result<Self> {
+        let timeout_secs = parsed_var(TIMEOUT_VAR)?;
+        check_timeout(TIMEOUT_VAR, timeout_secs)?;
+        Ok(ClientConfig {
+            api_key: var(API_KEY_VAR)?.or(var(LEGACY_API_KEY_VAR)?),
+            base_url: var(BASE_URL_VAR)?,
+            timeout_secs,
+            max_retries: parsed_var(MAX_RETRIES_VAR)?,
+            voice_id: var(VOICE_ID_VAR)?,
+            model_id: var(MODEL_ID_VAR)?,
+        })
+    }
+
+    /// Parses a TOML document with the keys in the table above
+    ///
+    /// Fails on unknown keys, or if the timeout is not a finite number of seconds above zero.
+    ///
+    /// # Example
+    /// ```
+    /// use elevenlabs_rs::*;
+    /// use std::time::Duration;
+    ///
+    /// let config = ClientConfig::from_toml(
+    ///     r#"
+    ///     base_url = "https://api.eu.residency.elevenlabs.io"
+    ///     timeout_secs = 30
+    ///     model_id = "eleven_flash_v2_5"
+    ///     "#,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(config.timeout(), Some(Duration::from_secs(30)));
+    /// assert_eq!(config.model(), Some(Model::ElevenFlashV2Dot5));
+    /// ```
+    #[cfg(feature = "toml")]
+    pub fn from_toml(toml: &str) -> Result<Self> {
+        let config: ClientConfig = toml::from_str(toml)?;
+        check_timeout("timeout_secs", config.timeout_secs)?;
+        Ok(config)
+    }
+    /// Reads a TOML file, see [from_toml](Self::from_toml)
+    #[cfg(feature = "toml")]
+    pub fn from_toml_file<P: AsRef<std::path::Path>>(path: P) -> Result<Self> {
+        Self::from_toml(&std::fs::read_to_string(path)?)
+    }
+
+    pub fn with_api_key<T: Into<String>>(mut self, api_key: T) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+    /// See [ClientBuilder::base_url]
+    pub fn with_base_url<T: Into<String>>(mut self, base_url: T) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+    /// A zero timeout fails when building the client, as every request would time out
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout_secs = Some(timeout.as_secs_f64());
+        self
+    }
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = Some(max_retries);
+        self
+    }
+    pub fn with_voice_id<T: Into<String>>(mut self, voice_id: T) -> Self {
+        self.voice_id = Some(voice_id.into());
+        self
+    }
+    pub fn with_model<T: Into<Model>>(mut self, model: T) -> Self {
+        self.model_id = Some(model.into().to_string());
+        self
+    }
+
+    pub fn base_url(&self) -> Option<&str> {
+        self.base_url.as_deref()
+    }
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout_secs
+            .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+    }
+    pub fn max_retries(&self) -> Option<u32> {
+        self.max_retries
+    }
+    /// The voice the application speaks with unless told otherwise
+    pub fn voice_id(&self) -> Option<VoiceID> {
+        self.voice_id.as_deref().map(VoiceID::from)
+    }
+    /// The model the application generates with unless told otherwise
+    pub fn model(&self) -> Option<Model> {
+        self.model_id.as_deref().map(Model::from)
+    }
+
+    /// Fails if the base url is not a url, the timeout is zero,
+    /// or no api key is given nor in `ELEVEN_API_KEY`
+    pub fn build(&self) -> Result<ElevenLabsClient> {
+        self.builder()?.build()
+    }
+    /// A builder with the settings applied, e.g. to add middleware before building the client
+    pub fn builder(&self) -> Result<ClientBuilder> {
+        check_timeout("timeout", self.timeout_secs)?;
+        let mut builder = ElevenLabsClient::builder();
+        if let Some(api_key) = &self.api_key {
+            builder = builder.api_key(api_key);
+        }
+        if let Some(base_url) = &self.base_url {
+            builder = builder.base_url(base_url)?;
+        }
+        if let Some(timeout) = self.timeout() {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(max_retries) = self.max_retries {
+            builder = builder.retry_policy(RetryPolicy::default().with_max_retries(max_retries));
+        }
+        Ok(builder)
+    }
+}
+
+/// An unset or empty variable is `None`
+fn var(name: &str) -> Result<Option<String>> {
+    match env::var(name) {
+        Ok(value) if value.trim().is_empty() => Ok(None),
+        Ok(value) => Ok(Some(value.trim().to_string())),
+        Err(VarError::NotPresent) => Ok(None),
+        Err(e) => Err(format!("{}: {}", name, e).into()),
+    }
+}
+
+/// A timeout must be a finite number of seconds above zero
+fn check_timeout(name: &str, timeout_secs: Option<f64>) -> Result<()> {
+    match timeout_secs.map(Duration::try_from_secs_f64) {
+        Some(Err(e)) => Err(format!("{}: {}", name, e).into()),
+        Some(Ok(timeout)) if timeout.is_zero() => {
+            Err(format!("{}: timeout must be above zero", name).into())
+        }
+        _ => Ok(()),
+    }
+}
+
+fn parsed_var<T>(name: &str) -> Result<Option<T>>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    var(name)?
+        .map(|value| value.parse().map_err(|e| format!("{}: {}", name, e).into()))
+        .transpose()
+}
@@ -4,9 +4,7 @@
 //! # Pricing
 //!
 //!The API is charged at 1000 characters per minute of audio.
-use crate::error::Error;
 use crate::shared::path_segments::STREAM_PATH;
-use std::path::Path;
 //use base64::{engine::general_purpose, Engine as _};
 use futures_util::{Stream, StreamExt};
 use std::pin::Pin;
@@ -37,11 +35,11 @@ const AUDIO_ISOLATION_PATH: &str = "v1/audio-isolation";
 /// ```
 #[derive(Clone, Debug)]
 pub struct AudioIsolation {
-    pub audio_file: String,
+    pub audio_file: FileSource,
 }
 
 impl AudioIsolation {
-    pub fn new<T: Into<String> >(audio_file: T) -> Self {
+    pub fn new<T: Into<FileSource>>(audio_file: T) -> Self {
         Self { audio_file: audio_file.into() }
     }
 }
@@ -87,11 +85,11 @@ impl Endpoint for AudioIsolation {
 /// ```
 #[derive(Clone, Debug)]
 pub struct AudioIsolationStream {
-    pub audio_file: String,
+    pub audio_file: FileSource,
 }
 
 impl AudioIsolationStream {
-    pub fn new<T: Into<String> >(audio_file: T) -> Self {
+    pub fn new<T: Into<FileSource>>(audio_file: T) -> Self {
         Self { audio_file: audio_file.into() }
     }
 }
@@ -119,19 +117,10 @@ impl Endpoint for AudioIsolationStream {
 }
 
 
-fn to_form(audio_file: &str) -> Result<Form> {
+fn to_form(audio_file: &FileSource) -> Result<Form> {
     let mut form = Form::new();
-    let path = Path::new(audio_file);
-    let audio_bytes = std::fs::read(audio_file)?;
-    let mut part = Part::bytes(audio_bytes);
-    let file_path_str = path.to_str().ok_or(Box::new(Error::PathNotValidUTF8))?;
-    part = part.file_name(file_path_str.to_string());
-    let mime_subtype = path
-        .extension()
-        .ok_or(Box::new(Error::FileExtensionNotFound))?
-        .to_str()
-        .ok_or(Box::new(Error::FileExtensionNotValidUTF8))?;
-    let mime = format!("audio/{}", mime_subtype);
+    let mut part = audio_file.to_part()?;
+    let mime = format!("audio/{}", audio_file.extension()?);
     part = part.mime_str(&mime)?;
     form = form.part("audio", part);
     Ok(form)
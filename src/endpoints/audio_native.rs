@@ -1,10 +1,36 @@
-#![allow(dead_code)]
-//! The audio native endpoint
+//! The audio native endpoints
+//!
+//! Audio Native is an embeddable player that reads the content of a web page aloud.
+//!
+//! See the [ElevenLabs docs](https://elevenlabs.io/docs/api-reference/audio-native/create) for more information.
 use super::*;
 
 const AUDIO_NATIVE_PATH: &str = "/v1/audio-native";
+const CONTENT_PATH: &str = "/content";
+const SETTINGS_PATH: &str = "/settings";
 
-/// Audio Native endpoint
+/// Create an Audio Native project, returning the HTML snippet that embeds its player
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let body = AudioNativeBody::new("My blog post")
+///         .with_file("post.html")
+///         .with_title("My blog post")
+///         .with_author("Jane Doe")
+///         .with_text_color("#000000")
+///         .with_background_color("#ffffff")
+///         .with_voice_id(PreMadeVoiceID::George)
+///         .with_auto_convert();
+///     let resp = c.hit(AudioNative::new(body)).await?;
+///     println!("{}", resp.html_snippet());
+///     Ok(())
+/// }
+/// ```
 #[derive(Debug, Clone)]
 pub struct AudioNative(AudioNativeBody);
 
@@ -24,9 +50,9 @@ pub struct AudioNativeBody {
     text_color: Option<String>,
     background_color: Option<String>,
     sessionization: Option<u32>,
-    voice_id: Option<String>,
+    voice_id: Option<VoiceID>,
     model_id: Option<String>,
-    file: Option<String>,
+    file: Option<FileSource>,
     auto_convert: Option<bool>,
 }
 
@@ -61,20 +87,22 @@ impl AudioNativeBody {
         self.background_color = Some(background_color.to_string());
         self
     }
+    /// The number of minutes a listening session lasts, for the analytics
     pub fn with_sessionization(mut self, sessionization: u32) -> Self {
         self.sessionization = Some(sessionization);
         self
     }
-    pub fn with_voice_id(mut self, voice_id: &str) -> Self {
-        self.voice_id = Some(voice_id.to_string());
+    pub fn with_voice_id<T: Into<VoiceID>>(mut self, voice_id: T) -> Self {
+        self.voice_id = Some(voice_id.into());
         self
     }
-    pub fn with_model_id(mut self, model_id: &str) -> Self {
-        self.model_id = Some(model_id.to_string());
+    pub fn with_model_id<T: Into<String>>(mut self, model_id: T) -> Self {
+        self.model_id = Some(model_id.into());
         self
     }
-    pub fn with_file(mut self, file: &str) -> Self {
-        self.file = Some(file.to_string());
+    /// An HTML or text file with the content to read aloud
+    pub fn with_file<F: Into<FileSource>>(mut self, file: F) -> Self {
+        self.file = Some(file.into());
         self
     }
     pub fn with_auto_convert(mut self) -> Self {
@@ -106,13 +134,13 @@ impl AudioNativeBody {
             form = form.text("sessionization", sessionization.to_string());
         }
         if let Some(voice_id) = self.voice_id {
-            form = form.text("voice_id", voice_id);
+            form = form.text("voice_id", voice_id.0);
         }
         if let Some(model_id) = self.model_id {
             form = form.text("model_id", model_id);
         }
         if let Some(file) = self.file {
-            form = form.text("file", file);
+            form = form.part("file", file_part(&file)?);
         }
         if let Some(auto_convert) = self.auto_convert {
             form = form.text("auto_convert", auto_convert.to_string());
@@ -148,3 +176,221 @@ pub struct AudioNativeResponseBody {
     converting: bool,
     html_snippet: String,
 }
+
+impl AudioNativeResponseBody {
+    pub fn project_id(&self) -> &str {
+        &self.project_id
+    }
+    pub fn converting(&self) -> bool {
+        self.converting
+    }
+    /// The HTML to embed the player in a web page
+    pub fn html_snippet(&self) -> &str {
+        &self.html_snippet
+    }
+}
+
+/// Replace the content of an Audio Native project with an HTML or text file
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let endpoint = UpdateAudioNativeContent::new("project_id", "post.html")
+///         .with_auto_convert(true)
+///         .with_auto_publish(true);
+///     let resp = c.hit(endpoint).await?;
+///     println!("converting: {}", resp.converting());
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct UpdateAudioNativeContent {
+    project_id: String,
+    file: FileSource,
+    auto_convert: Option<bool>,
+    auto_publish: Option<bool>,
+}
+
+impl UpdateAudioNativeContent {
+    pub fn new<F: Into<FileSource>>(project_id: &str, file: F) -> Self {
+        UpdateAudioNativeContent {
+            project_id: project_id.to_string(),
+            file: file.into(),
+            auto_convert: None,
+            auto_publish: None,
+        }
+    }
+    /// Convert the new content to audio
+    pub fn with_auto_convert(mut self, auto_convert: bool) -> Self {
+        self.auto_convert = Some(auto_convert);
+        self
+    }
+    /// Publish the converted audio to the player, requires auto convert
+    pub fn with_auto_publish(mut self, auto_publish: bool) -> Self {
+        self.auto_publish = Some(auto_publish);
+        self
+    }
+}
+
+impl Endpoint for UpdateAudioNativeContent {
+    type ResponseBody = UpdateAudioNativeContentResponse;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        let mut form = Form::new().part("file", file_part(&self.file)?);
+        if let Some(auto_convert) = self.auto_convert {
+            form = form.text("auto_convert", auto_convert.to_string());
+        }
+        if let Some(auto_publish) = self.auto_publish {
+            form = form.text("auto_publish", auto_publish.to_string());
+        }
+        Ok(RequestBody::Multipart(form))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        audio_native_url(&self.project_id, CONTENT_PATH)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct UpdateAudioNativeContentResponse {
+    project_id: String,
+    converting: bool,
+    publishing: bool,
+    html_snippet: String,
+}
+
+impl UpdateAudioNativeContentResponse {
+    pub fn project_id(&self) -> &str {
+        &self.project_id
+    }
+    pub fn converting(&self) -> bool {
+        self.converting
+    }
+    pub fn publishing(&self) -> bool {
+        self.publishing
+    }
+    /// The HTML to embed the player in a web page
+    pub fn html_snippet(&self) -> &str {
+        &self.html_snippet
+    }
+}
+
+/// Get the player settings of an Audio Native project
+#[derive(Clone, Debug)]
+pub struct GetAudioNativeSettings {
+    project_id: String,
+}
+
+impl GetAudioNativeSettings {
+    pub fn new(project_id: &str) -> Self {
+        GetAudioNativeSettings {
+            project_id: project_id.to_string(),
+        }
+    }
+}
+
+impl Endpoint for GetAudioNativeSettings {
+    type ResponseBody = AudioNativeSettingsResponse;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        audio_native_url(&self.project_id, SETTINGS_PATH)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AudioNativeSettingsResponse {
+    enabled: bool,
+    snapshot_id: Option<String>,
+    settings: Option<AudioNativeSettings>,
+}
+
+impl AudioNativeSettingsResponse {
+    /// Whether the player is enabled
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+    /// The published snapshot of the project
+    pub fn snapshot_id(&self) -> Option<&str> {
+        self.snapshot_id.as_deref()
+    }
+    pub fn settings(&self) -> Option<&AudioNativeSettings> {
+        self.settings.as_ref()
+    }
+}
+
+/// The customization of an Audio Native player
+#[derive(Clone, Debug, Deserialize)]
+pub struct AudioNativeSettings {
+    title: Option<String>,
+    image: Option<String>,
+    author: Option<String>,
+    #[serde(default)]
+    small: bool,
+    text_color: Option<String>,
+    background_color: Option<String>,
+    sessionization: Option<u32>,
+    audio_url: Option<String>,
+    status: Option<String>,
+}
+
+impl AudioNativeSettings {
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+    pub fn image(&self) -> Option<&str> {
+        self.image.as_deref()
+    }
+    pub fn author(&self) -> Option<&str> {
+        self.author.as_deref()
+    }
+    pub fn small(&self) -> bool {
+        self.small
+    }
+    pub fn text_color(&self) -> Option<&str> {
+        self.text_color.as_deref()
+    }
+    pub fn background_color(&self) -> Option<&str> {
+        self.background_color.as_deref()
+    }
+    pub fn sessionization(&self) -> Option<u32> {
+        self.sessionization
+    }
+    /// The URL of the published audio
+    pub fn audio_url(&self) -> Option<&str> {
+        self.audio_url.as_deref()
+    }
+    /// e.g. `processing` or `ready`
+    pub fn status(&self) -> Option<&str> {
+        self.status.as_deref()
+    }
+}
+
+/// HTML files are sent as `text/html`, anything else as `text/plain`
+fn file_part(file: &FileSource) -> Result<Part> {
+    let mime = match file.extension()? {
+        "html" | "htm" => "text/html",
+        _ => "text/plain",
+    };
+    Ok(file.to_part()?.mime_str(mime)?)
+}
+
+fn audio_native_url(project_id: &str, path: &str) -> Url {
+    let mut url = BASE_URL.parse::<Url>().unwrap();
+    url.set_path(&format!("{}/{}{}", AUDIO_NATIVE_PATH, project_id, path));
+    url
+}
@@ -0,0 +1,1624 @@
+//! The agents endpoints
+//!
+//! An agent is configured by its [ConversationConfig], i.e. how it listens, thinks and speaks,
+//! and by its [PlatformSettings], e.g. how its conversations are evaluated.
+//!
+//! See the [ElevenLabs docs](https://elevenlabs.io/docs/api-reference/agents/create) for more information.
+use super::knowledge_base::DocumentType;
+use super::tools::ToolConfig;
+use super::*;
+
+const AGENTS_PATH: &str = "/agents";
+const CREATE_PATH: &str = "/create";
+const DUPLICATE_PATH: &str = "/duplicate";
+const LINK_PATH: &str = "/link";
+const WIDGET_PATH: &str = "/widget";
+const AVATAR_PATH: &str = "/avatar";
+
+const SEARCH_QUERY: &str = "search";
+const CONVERSATION_SIGNATURE_QUERY: &str = "conversation_signature";
+
+/// Create an agent
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::agents::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let prompt = PromptConfig::new("You are a friendly support agent for Acme. Be brief.")
+///         .with_llm(Llm::Gemini2_0Flash)
+///         .with_temperature(0.3)
+///         .with_tool_id("tool_id")
+///         .with_knowledge_base(KnowledgeBaseLocator::url("doc_id", "Pricing"));
+///     let agent = AgentConfig::new(prompt)
+///         .with_first_message("Hi, this is Acme support, how can I help?")
+///         .with_language("en");
+///     let tts = TtsConfig::default()
+///         .with_voice_id(PreMadeVoiceID::Sarah)
+///         .with_model_id(Model::ElevenFlashV2)
+///         .with_agent_output_audio_format(OutputFormat::MuLaw8000Hz)
+///         .with_optimize_streaming_latency(3);
+///     let asr = AsrConfig::default()
+///         .with_user_input_audio_format(OutputFormat::MuLaw8000Hz)
+///         .with_keywords(vec!["Acme".to_string()]);
+///     let config = ConversationConfig::new(agent)
+///         .with_tts(tts)
+///         .with_asr(asr)
+///         .with_turn(TurnConfig::default().with_turn_timeout(7.0));
+///     let evaluation = EvaluationSettings::default().with_criterion(EvaluationCriterion::new(
+///         "solved",
+///         "Solved",
+///         "The user's problem was solved",
+///     ));
+///     let body = CreateAgentBody::new(config)
+///         .with_name("Acme support")
+///         .with_platform_settings(PlatformSettings::default().with_evaluation(evaluation));
+///     let resp = c.hit(CreateAgent::new(body)).await?;
+///     println!("{}", resp.agent_id());
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct CreateAgent(CreateAgentBody);
+
+impl CreateAgent {
+    pub fn new(body: CreateAgentBody) -> Self {
+        Self(body)
+    }
+}
+
+/// Create agent body
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateAgentBody {
+    conversation_config: ConversationConfig,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    platform_settings: Option<PlatformSettings>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<String>,
+}
+
+impl CreateAgentBody {
+    pub fn new(conversation_config: ConversationConfig) -> Self {
+        Self {
+            conversation_config,
+            platform_settings: None,
+            name: None,
+            tags: Vec::new(),
+        }
+    }
+    pub fn with_platform_settings(mut self, platform_settings: PlatformSettings) -> Self {
+        self.platform_settings = Some(platform_settings);
+        self
+    }
+    pub fn with_name<T: Into<String>>(mut self, name: T) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+    /// Tags to find the agent by, e.g. its environment
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+}
+
+impl Endpoint for CreateAgent {
+    type ResponseBody = CreateAgentResponse;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(serde_json::to_value(&self.0)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        convai_url(&format!("{}{}", AGENTS_PATH, CREATE_PATH))
+    }
+}
+
+/// Create agent response
+#[derive(Clone, Debug, Deserialize)]
+pub struct CreateAgentResponse {
+    agent_id: String,
+}
+
+impl CreateAgentResponse {
+    pub fn agent_id(&self) -> &str {
+        &self.agent_id
+    }
+}
+
+/// List the agents
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::agents::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let query = AgentsQuery::default().with_search("support");
+///     let agents = c.paginate(ListAgents::with_query(query));
+///     pin_mut!(agents);
+///     while let Some(agent) = agents.next().await {
+///         let agent = agent?;
+///         println!("{}: {}", agent.agent_id(), agent.name());
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ListAgents(AgentsQuery);
+
+impl ListAgents {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn with_query(query: AgentsQuery) -> Self {
+        Self(query)
+    }
+}
+
+/// List agents query
+#[derive(Clone, Debug, Default)]
+pub struct AgentsQuery {
+    page: PageQuery,
+    search: Option<String>,
+}
+
+impl AgentsQuery {
+    pub fn with_cursor<T: Into<String>>(mut self, cursor: T) -> Self {
+        self.page = self.page.with_cursor(cursor);
+        self
+    }
+    /// Defaults to 30, the maximum is 100
+    pub fn with_page_size(mut self, page_size: u32) -> Self {
+        self.page = self.page.with_page_size(page_size);
+        self
+    }
+    /// Only agents whose name contains `search`
+    pub fn with_search<T: Into<String>>(mut self, search: T) -> Self {
+        self.search = Some(search.into());
+        self
+    }
+}
+
+impl Endpoint for ListAgents {
+    type ResponseBody = ListAgentsResponse;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        let mut url = convai_url(AGENTS_PATH);
+        self.0.page.append_to(&mut url);
+        if let Some(search) = &self.0.search {
+            url.query_pairs_mut().append_pair(SEARCH_QUERY, search);
+        }
+        trim_query(url)
+    }
+}
+
+impl Paginated for ListAgents {
+    type Item = AgentSummary;
+
+    fn next_page(self, page: Self::ResponseBody) -> (Vec<Self::Item>, Option<Self>) {
+        let next = match (page.has_more, &page.next_cursor) {
+            (true, Some(cursor)) => Some(Self(self.0.with_cursor(cursor))),
+            _ => None,
+        };
+        (page.agents, next)
+    }
+}
+
+/// List agents response
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListAgentsResponse {
+    agents: Vec<AgentSummary>,
+    next_cursor: Option<String>,
+    has_more: bool,
+}
+
+impl ListAgentsResponse {
+    pub fn agents(&self) -> &[AgentSummary] {
+        &self.agents
+    }
+    pub fn next_cursor(&self) -> Option<&str> {
+        self.next_cursor.as_deref()
+    }
+    pub fn has_more(&self) -> bool {
+        self.has_more
+    }
+}
+
+/// An agent as listed by [ListAgents]
+#[derive(Clone, Debug, Deserialize)]
+pub struct AgentSummary {
+    agent_id: String,
+    name: String,
+    #[serde(default)]
+    tags: Vec<String>,
+    created_at_unix_secs: i64,
+    access_info: Option<AccessInfo>,
+}
+
+impl AgentSummary {
+    pub fn agent_id(&self) -> &str {
+        &self.agent_id
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+    pub fn created_at_unix_secs(&self) -> i64 {
+        self.created_at_unix_secs
+    }
+    /// [created_at_unix_secs](Self::created_at_unix_secs) as a date
+    #[cfg(feature = "chrono")]
+    pub fn created_at(&self) -> DateTime<Utc> {
+        utc(self.created_at_unix_secs())
+    }
+    pub fn access_info(&self) -> Option<&AccessInfo> {
+        self.access_info.as_ref()
+    }
+}
+
+/// Get an agent
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::agents::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let agent = c.hit(GetAgent::new("agent_id")).await?;
+///     println!("{:?}", agent.conversation_config().agent().and_then(|a| a.first_message()));
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct GetAgent(AgentID);
+
+impl GetAgent {
+    pub fn new<T: Into<AgentID>>(agent_id: T) -> Self {
+        Self(agent_id.into())
+    }
+}
+
+impl Endpoint for GetAgent {
+    type ResponseBody = Agent;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        convai_url(&format!("{}/{}", AGENTS_PATH, self.0 .0))
+    }
+}
+
+/// Update an agent, only the given parts of the config are changed
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::agents::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let agent = c.hit(GetAgent::new("agent_id")).await?;
+///     let tts = agent.conversation_config().tts().cloned().unwrap_or_default().with_speed(1.1);
+///     let config = agent.conversation_config().clone().with_tts(tts);
+///     let body = UpdateAgentBody::default().with_conversation_config(config);
+///     c.hit(UpdateAgent::new(agent.agent_id(), body)).await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct UpdateAgent {
+    agent_id: AgentID,
+    body: UpdateAgentBody,
+}
+
+impl UpdateAgent {
+    pub fn new<T: Into<AgentID>>(agent_id: T, body: UpdateAgentBody) -> Self {
+        Self {
+            agent_id: agent_id.into(),
+            body,
+        }
+    }
+}
+
+/// Update agent body
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct UpdateAgentBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    conversation_config: Option<ConversationConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    platform_settings: Option<PlatformSettings>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tags: Option<Vec<String>>,
+}
+
+impl UpdateAgentBody {
+    pub fn with_conversation_config(mut self, conversation_config: ConversationConfig) -> Self {
+        self.conversation_config = Some(conversation_config);
+        self
+    }
+    pub fn with_platform_settings(mut self, platform_settings: PlatformSettings) -> Self {
+        self.platform_settings = Some(platform_settings);
+        self
+    }
+    pub fn with_name<T: Into<String>>(mut self, name: T) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = Some(tags);
+        self
+    }
+}
+
+impl From<&Agent> for UpdateAgentBody {
+    fn from(agent: &Agent) -> Self {
+        Self {
+            conversation_config: Some(agent.conversation_config.clone()),
+            platform_settings: Some(agent.platform_settings.clone()),
+            name: Some(agent.name.clone()),
+            tags: Some(agent.tags.clone()),
+        }
+    }
+}
+
+impl Endpoint for UpdateAgent {
+    type ResponseBody = Agent;
+
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(serde_json::to_value(&self.body)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        convai_url(&format!("{}/{}", AGENTS_PATH, self.agent_id.0))
+    }
+}
+
+/// Delete an agent
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::agents::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     c.hit(DeleteAgent::new("agent_id")).await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct DeleteAgent(AgentID);
+
+impl DeleteAgent {
+    pub fn new<T: Into<AgentID>>(agent_id: T) -> Self {
+        Self(agent_id.into())
+    }
+}
+
+impl Endpoint for DeleteAgent {
+    type ResponseBody = ();
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+    async fn response_body(self, _resp: Response) -> Result<Self::ResponseBody> {
+        Ok(())
+    }
+    fn url(&self) -> Url {
+        convai_url(&format!("{}/{}", AGENTS_PATH, self.0 .0))
+    }
+}
+
+/// Duplicate an agent, e.g. to create one per customer from a template agent
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::agents::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let resp = c
+///         .hit(DuplicateAgent::new("template_agent_id").with_name("Acme support"))
+///         .await?;
+///     let link = c.hit(GetAgentLink::new(resp.agent_id())).await?;
+///     println!("{:?}", link.token().map(|t| t.conversation_token()));
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct DuplicateAgent {
+    agent_id: AgentID,
+    body: DuplicateAgentBody,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+struct DuplicateAgentBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+}
+
+impl DuplicateAgent {
+    pub fn new<T: Into<AgentID>>(agent_id: T) -> Self {
+        Self {
+            agent_id: agent_id.into(),
+            body: DuplicateAgentBody::default(),
+        }
+    }
+    /// The name of the copy, defaults to the name of the agent
+    pub fn with_name<T: Into<String>>(mut self, name: T) -> Self {
+        self.body.name = Some(name.into());
+        self
+    }
+}
+
+impl Endpoint for DuplicateAgent {
+    type ResponseBody = CreateAgentResponse;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(serde_json::to_value(&self.body)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        convai_url(&format!(
+            "{}/{}{}",
+            AGENTS_PATH, self.agent_id.0, DUPLICATE_PATH
+        ))
+    }
+}
+
+/// Get the link to talk to an agent, with a token if the agent requires authentication
+#[derive(Clone, Debug)]
+pub struct GetAgentLink(AgentID);
+
+impl GetAgentLink {
+    pub fn new<T: Into<AgentID>>(agent_id: T) -> Self {
+        Self(agent_id.into())
+    }
+}
+
+impl Endpoint for GetAgentLink {
+    type ResponseBody = AgentLinkResponse;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        convai_url(&format!("{}/{}{}", AGENTS_PATH, self.0 .0, LINK_PATH))
+    }
+}
+
+/// Agent link response
+#[derive(Clone, Debug, Deserialize)]
+pub struct AgentLinkResponse {
+    agent_id: String,
+    token: Option<AgentLinkToken>,
+}
+
+impl AgentLinkResponse {
+    pub fn agent_id(&self) -> &str {
+        &self.agent_id
+    }
+    /// Not set for public agents
+    pub fn token(&self) -> Option<&AgentLinkToken> {
+        self.token.as_ref()
+    }
+}
+
+/// A token that allows conversations with an agent that requires authentication
+#[derive(Clone, Debug, Deserialize)]
+pub struct AgentLinkToken {
+    agent_id: String,
+    conversation_token: String,
+    expiration_time_unix_secs: Option<i64>,
+    purpose: Option<String>,
+}
+
+impl AgentLinkToken {
+    pub fn agent_id(&self) -> &str {
+        &self.agent_id
+    }
+    pub fn conversation_token(&self) -> &str {
+        &self.conversation_token
+    }
+    /// Not set for tokens that do not expire
+    pub fn expiration_time_unix_secs(&self) -> Option<i64> {
+        self.expiration_time_unix_secs
+    }
+    /// [expiration_time_unix_secs](Self::expiration_time_unix_secs) as a date
+    #[cfg(feature = "chrono")]
+    pub fn expiration_time(&self) -> Option<DateTime<Utc>> {
+        self.expiration_time_unix_secs().map(utc)
+    }
+    /// e.g. `signed_url` or `shareable_link`
+    pub fn purpose(&self) -> Option<&str> {
+        self.purpose.as_deref()
+    }
+}
+
+/// Get how an agent's embedded widget looks
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::agents::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let resp = c.hit(GetAgentWidgetConfig::new("agent_id")).await?;
+///     let widget = resp.widget_config();
+///     println!("{:?} {:?}", widget.variant(), widget.avatar());
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct GetAgentWidgetConfig {
+    agent_id: AgentID,
+    conversation_signature: Option<String>,
+}
+
+impl GetAgentWidgetConfig {
+    pub fn new<T: Into<AgentID>>(agent_id: T) -> Self {
+        Self {
+            agent_id: agent_id.into(),
+            conversation_signature: None,
+        }
+    }
+    /// A signature from a [signed url](super::client::GetSignedUrl), required for private agents
+    pub fn with_conversation_signature<T: Into<String>>(mut self, signature: T) -> Self {
+        self.conversation_signature = Some(signature.into());
+        self
+    }
+}
+
+impl Endpoint for GetAgentWidgetConfig {
+    type ResponseBody = AgentWidgetResponse;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        let mut url = convai_url(&format!(
+            "{}/{}{}",
+            AGENTS_PATH, self.agent_id.0, WIDGET_PATH
+        ));
+        if let Some(signature) = &self.conversation_signature {
+            url.query_pairs_mut()
+                .append_pair(CONVERSATION_SIGNATURE_QUERY, signature);
+        }
+        url
+    }
+}
+
+/// Agent widget response
+#[derive(Clone, Debug, Deserialize)]
+pub struct AgentWidgetResponse {
+    agent_id: String,
+    widget_config: WidgetConfig,
+}
+
+impl AgentWidgetResponse {
+    pub fn agent_id(&self) -> &str {
+        &self.agent_id
+    }
+    pub fn widget_config(&self) -> &WidgetConfig {
+        &self.widget_config
+    }
+}
+
+/// The look and texts of an agent's embedded widget
+#[derive(Clone, Debug, Deserialize)]
+pub struct WidgetConfig {
+    variant: Option<WidgetVariant>,
+    placement: Option<String>,
+    avatar: Option<WidgetAvatar>,
+    bg_color: Option<String>,
+    text_color: Option<String>,
+    btn_color: Option<String>,
+    btn_text_color: Option<String>,
+    border_color: Option<String>,
+    focus_color: Option<String>,
+    border_radius: Option<u32>,
+    btn_radius: Option<u32>,
+    action_text: Option<String>,
+    start_call_text: Option<String>,
+    end_call_text: Option<String>,
+    expand_text: Option<String>,
+    listening_text: Option<String>,
+    speaking_text: Option<String>,
+    terms_html: Option<String>,
+    #[serde(default)]
+    disable_banner: bool,
+    #[serde(default)]
+    text_input_enabled: bool,
+    language: Option<String>,
+    first_message: Option<String>,
+}
+
+impl WidgetConfig {
+    pub fn variant(&self) -> Option<&WidgetVariant> {
+        self.variant.as_ref()
+    }
+    /// e.g. `bottom-right`
+    pub fn placement(&self) -> Option<&str> {
+        self.placement.as_deref()
+    }
+    pub fn avatar(&self) -> Option<&WidgetAvatar> {
+        self.avatar.as_ref()
+    }
+    /// The colors are CSS hex colors, e.g. `#ffffff`
+    pub fn bg_color(&self) -> Option<&str> {
+        self.bg_color.as_deref()
+    }
+    pub fn text_color(&self) -> Option<&str> {
+        self.text_color.as_deref()
+    }
+    pub fn btn_color(&self) -> Option<&str> {
+        self.btn_color.as_deref()
+    }
+    pub fn btn_text_color(&self) -> Option<&str> {
+        self.btn_text_color.as_deref()
+    }
+    pub fn border_color(&self) -> Option<&str> {
+        self.border_color.as_deref()
+    }
+    pub fn focus_color(&self) -> Option<&str> {
+        self.focus_color.as_deref()
+    }
+    /// In pixels
+    pub fn border_radius(&self) -> Option<u32> {
+        self.border_radius
+    }
+    /// In pixels
+    pub fn btn_radius(&self) -> Option<u32> {
+        self.btn_radius
+    }
+    pub fn action_text(&self) -> Option<&str> {
+        self.action_text.as_deref()
+    }
+    pub fn start_call_text(&self) -> Option<&str> {
+        self.start_call_text.as_deref()
+    }
+    pub fn end_call_text(&self) -> Option<&str> {
+        self.end_call_text.as_deref()
+    }
+    pub fn expand_text(&self) -> Option<&str> {
+        self.expand_text.as_deref()
+    }
+    pub fn listening_text(&self) -> Option<&str> {
+        self.listening_text.as_deref()
+    }
+    pub fn speaking_text(&self) -> Option<&str> {
+        self.speaking_text.as_deref()
+    }
+    /// Terms the user has to accept before the first conversation
+    pub fn terms_html(&self) -> Option<&str> {
+        self.terms_html.as_deref()
+    }
+    /// Whether the "Powered by ElevenLabs" banner is hidden
+    pub fn disable_banner(&self) -> bool {
+        self.disable_banner
+    }
+    pub fn text_input_enabled(&self) -> bool {
+        self.text_input_enabled
+    }
+    pub fn language(&self) -> Option<&str> {
+        self.language.as_deref()
+    }
+    pub fn first_message(&self) -> Option<&str> {
+        self.first_message.as_deref()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WidgetVariant {
+    Tiny,
+    Compact,
+    Full,
+    Expandable,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// The avatar shown in the widget
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum WidgetAvatar {
+    /// An animated orb in two colors
+    Orb {
+        color_1: Option<String>,
+        color_2: Option<String>,
+    },
+    /// An image hosted elsewhere
+    Url { custom_url: Option<String> },
+    /// An image uploaded with [CreateAgentAvatar]
+    Image { url: Option<String> },
+    #[serde(other)]
+    Unknown,
+}
+
+/// Upload the image shown as an agent's avatar in its widget
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::agents::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let resp = c
+///         .hit(CreateAgentAvatar::new("agent_id", FileSource::from_path("logo.png")))
+///         .await?;
+///     println!("{}", resp.avatar_url());
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct CreateAgentAvatar {
+    agent_id: AgentID,
+    avatar_file: FileSource,
+}
+
+impl CreateAgentAvatar {
+    /// Supported image types are png, jpeg, gif and webp
+    pub fn new<T: Into<AgentID>, F: Into<FileSource>>(agent_id: T, avatar_file: F) -> Self {
+        Self {
+            agent_id: agent_id.into(),
+            avatar_file: avatar_file.into(),
+        }
+    }
+}
+
+impl Endpoint for CreateAgentAvatar {
+    type ResponseBody = AgentAvatarResponse;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Multipart(
+            Form::new().part("avatar_file", self.avatar_file.to_part()?),
+        ))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        convai_url(&format!(
+            "{}/{}{}",
+            AGENTS_PATH, self.agent_id.0, AVATAR_PATH
+        ))
+    }
+}
+
+/// Agent avatar response
+#[derive(Clone, Debug, Deserialize)]
+pub struct AgentAvatarResponse {
+    agent_id: String,
+    avatar_url: String,
+}
+
+impl AgentAvatarResponse {
+    pub fn agent_id(&self) -> &str {
+        &self.agent_id
+    }
+    pub fn avatar_url(&self) -> &str {
+        &self.avatar_url
+    }
+}
+
+/// An agent
+#[derive(Clone, Debug, Deserialize)]
+pub struct Agent {
+    agent_id: String,
+    name: String,
+    conversation_config: ConversationConfig,
+    #[serde(default)]
+    platform_settings: PlatformSettings,
+    #[serde(default)]
+    tags: Vec<String>,
+    access_info: Option<AccessInfo>,
+}
+
+impl Agent {
+    pub fn agent_id(&self) -> &str {
+        &self.agent_id
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn conversation_config(&self) -> &ConversationConfig {
+        &self.conversation_config
+    }
+    pub fn platform_settings(&self) -> &PlatformSettings {
+        &self.platform_settings
+    }
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+    pub fn access_info(&self) -> Option<&AccessInfo> {
+        self.access_info.as_ref()
+    }
+}
+
+/// How an agent listens, thinks and speaks
+///
+/// Parts that are not set keep the server's defaults, or their current value on update.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ConversationConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    agent: Option<AgentConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    asr: Option<AsrConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    tts: Option<TtsConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    turn: Option<TurnConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    conversation: Option<ConversationSettings>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    language_presets: HashMap<String, LanguagePreset>,
+}
+
+impl ConversationConfig {
+    pub fn new(agent: AgentConfig) -> Self {
+        Self {
+            agent: Some(agent),
+            ..Default::default()
+        }
+    }
+    pub fn with_agent(mut self, agent: AgentConfig) -> Self {
+        self.agent = Some(agent);
+        self
+    }
+    pub fn with_asr(mut self, asr: AsrConfig) -> Self {
+        self.asr = Some(asr);
+        self
+    }
+    pub fn with_tts(mut self, tts: TtsConfig) -> Self {
+        self.tts = Some(tts);
+        self
+    }
+    pub fn with_turn(mut self, turn: TurnConfig) -> Self {
+        self.turn = Some(turn);
+        self
+    }
+    pub fn with_conversation(mut self, conversation: ConversationSettings) -> Self {
+        self.conversation = Some(conversation);
+        self
+    }
+    /// Overrides used when the conversation is in another language, keyed by its ISO 639-1 code
+    pub fn with_language_preset<T: Into<String>>(
+        mut self,
+        language: T,
+        preset: LanguagePreset,
+    ) -> Self {
+        self.language_presets.insert(language.into(), preset);
+        self
+    }
+    pub fn agent(&self) -> Option<&AgentConfig> {
+        self.agent.as_ref()
+    }
+    pub fn asr(&self) -> Option<&AsrConfig> {
+        self.asr.as_ref()
+    }
+    pub fn tts(&self) -> Option<&TtsConfig> {
+        self.tts.as_ref()
+    }
+    pub fn turn(&self) -> Option<&TurnConfig> {
+        self.turn.as_ref()
+    }
+    pub fn conversation(&self) -> Option<&ConversationSettings> {
+        self.conversation.as_ref()
+    }
+    pub fn language_presets(&self) -> &HashMap<String, LanguagePreset> {
+        &self.language_presets
+    }
+}
+
+/// What the agent says and how it decides what to say
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct AgentConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    first_message: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    prompt: Option<PromptConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    dynamic_variables: Option<DynamicVariablesConfig>,
+}
+
+impl AgentConfig {
+    pub fn new(prompt: PromptConfig) -> Self {
+        Self {
+            prompt: Some(prompt),
+            ..Default::default()
+        }
+    }
+    /// What the agent says when the conversation starts, it waits for the user if not set
+    pub fn with_first_message<T: Into<String>>(mut self, first_message: T) -> Self {
+        self.first_message = Some(first_message.into());
+        self
+    }
+    /// An ISO 639-1 language code, defaults to `en`
+    pub fn with_language<T: Into<String>>(mut self, language: T) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+    pub fn with_prompt(mut self, prompt: PromptConfig) -> Self {
+        self.prompt = Some(prompt);
+        self
+    }
+    /// A value used for a `{{name}}` placeholder when the conversation does not set it
+    pub fn with_dynamic_variable_placeholder<K, V>(mut self, name: K, value: V) -> Self
+    where
+        K: Into<String>,
+        V: Into<DynamicVariable>,
+    {
+        self.dynamic_variables
+            .get_or_insert_with(DynamicVariablesConfig::default)
+            .dynamic_variable_placeholders
+            .insert(name.into(), value.into());
+        self
+    }
+    pub fn first_message(&self) -> Option<&str> {
+        self.first_message.as_deref()
+    }
+    pub fn language(&self) -> Option<&str> {
+        self.language.as_deref()
+    }
+    pub fn prompt(&self) -> Option<&PromptConfig> {
+        self.prompt.as_ref()
+    }
+    pub fn dynamic_variable_placeholders(&self) -> Option<&HashMap<String, DynamicVariable>> {
+        Some(
+            &self
+                .dynamic_variables
+                .as_ref()?
+                .dynamic_variable_placeholders,
+        )
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+struct DynamicVariablesConfig {
+    #[serde(default, deserialize_with = "deserialize_dynamic_variables")]
+    dynamic_variable_placeholders: HashMap<String, DynamicVariable>,
+}
+
+/// The LLM of the agent and what it knows
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct PromptConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    prompt: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    llm: Option<Llm>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    temperature: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    max_tokens: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    tool_ids: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    tools: Option<Vec<ToolConfig>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    mcp_server_ids: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    knowledge_base: Option<Vec<KnowledgeBaseLocator>>,
+}
+
+impl PromptConfig {
+    /// The system prompt, may contain `{{name}}` dynamic variable placeholders
+    pub fn new<T: Into<String>>(prompt: T) -> Self {
+        Self {
+            prompt: Some(prompt.into()),
+            ..Default::default()
+        }
+    }
+    pub fn with_llm<T: Into<Llm>>(mut self, llm: T) -> Self {
+        self.llm = Some(llm.into());
+        self
+    }
+    /// 0.0 to 1.0, higher is more creative
+    pub fn with_temperature(mut self, temperature: f32) -> Self {
+        self.temperature = Some(temperature);
+        self
+    }
+    /// The maximum length of a reply, -1 for no limit
+    pub fn with_max_tokens(mut self, max_tokens: i32) -> Self {
+        self.max_tokens = Some(max_tokens);
+        self
+    }
+    /// A [workspace tool](super::tools) the agent can call
+    pub fn with_tool_id<T: Into<String>>(mut self, tool_id: T) -> Self {
+        self.tool_ids
+            .get_or_insert_with(Vec::new)
+            .push(tool_id.into());
+        self
+    }
+    /// A tool defined only for this agent, prefer [workspace tools](Self::with_tool_id)
+    pub fn with_tool<T: Into<ToolConfig>>(mut self, tool: T) -> Self {
+        self.tools.get_or_insert_with(Vec::new).push(tool.into());
+        self
+    }
+    /// An [MCP server](super::mcp_servers) whose tools the agent can call
+    pub fn with_mcp_server_id<T: Into<String>>(mut self, mcp_server_id: T) -> Self {
+        self.mcp_server_ids
+            .get_or_insert_with(Vec::new)
+            .push(mcp_server_id.into());
+        self
+    }
+    pub fn with_knowledge_base(mut self, locator: KnowledgeBaseLocator) -> Self {
+        self.knowledge_base
+            .get_or_insert_with(Vec::new)
+            .push(locator);
+        self
+    }
+    pub fn prompt(&self) -> Option<&str> {
+        self.prompt.as_deref()
+    }
+    pub fn llm(&self) -> Option<&Llm> {
+        self.llm.as_ref()
+    }
+    pub fn temperature(&self) -> Option<f32> {
+        self.temperature
+    }
+    pub fn max_tokens(&self) -> Option<i32> {
+        self.max_tokens
+    }
+    pub fn tool_ids(&self) -> &[String] {
+        self.tool_ids.as_deref().unwrap_or_default()
+    }
+    pub fn tools(&self) -> &[ToolConfig] {
+        self.tools.as_deref().unwrap_or_default()
+    }
+    pub fn mcp_server_ids(&self) -> &[String] {
+        self.mcp_server_ids.as_deref().unwrap_or_default()
+    }
+    pub fn knowledge_base(&self) -> &[KnowledgeBaseLocator] {
+        self.knowledge_base.as_deref().unwrap_or_default()
+    }
+}
+
+/// The LLM that generates the agent's replies
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Llm {
+    #[serde(rename = "gpt-4o")]
+    Gpt4o,
+    #[serde(rename = "gpt-4o-mini")]
+    Gpt4oMini,
+    #[serde(rename = "gpt-4.1")]
+    Gpt4_1,
+    #[serde(rename = "gpt-4.1-mini")]
+    Gpt4_1Mini,
+    #[serde(rename = "gpt-4.1-nano")]
+    Gpt4_1Nano,
+    #[serde(rename = "gemini-2.0-flash")]
+    Gemini2_0Flash,
+    #[serde(rename = "gemini-2.5-flash")]
+    Gemini2_5Flash,
+    #[serde(rename = "claude-sonnet-4")]
+    ClaudeSonnet4,
+    #[serde(rename = "claude-3-5-sonnet")]
+    Claude3_5Sonnet,
+    /// The agent's custom LLM server
+    #[serde(rename = "custom-llm")]
+    CustomLlm,
+    /// A model this version of the crate does not know about
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl From<&str> for Llm {
+    fn from(llm: &str) -> Self {
+        serde_json::from_value(Value::String(llm.to_string()))
+            .unwrap_or_else(|_| Llm::Other(llm.to_string()))
+    }
+}
+
+impl From<String> for Llm {
+    fn from(llm: String) -> Self {
+        Llm::from(llm.as_str())
+    }
+}
+
+/// A knowledge base document the agent can use
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct KnowledgeBaseLocator {
+    r#type: DocumentType,
+    name: String,
+    id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    usage_mode: Option<KnowledgeBaseUsageMode>,
+}
+
+impl KnowledgeBaseLocator {
+    pub fn new<I: Into<String>, N: Into<String>>(r#type: DocumentType, id: I, name: N) -> Self {
+        Self {
+            r#type,
+            name: name.into(),
+            id: id.into(),
+            usage_mode: None,
+        }
+    }
+    pub fn file<I: Into<String>, N: Into<String>>(id: I, name: N) -> Self {
+        Self::new(DocumentType::File, id, name)
+    }
+    pub fn url<I: Into<String>, N: Into<String>>(id: I, name: N) -> Self {
+        Self::new(DocumentType::Url, id, name)
+    }
+    pub fn text<I: Into<String>, N: Into<String>>(id: I, name: N) -> Self {
+        Self::new(DocumentType::Text, id, name)
+    }
+    pub fn with_usage_mode(mut self, usage_mode: KnowledgeBaseUsageMode) -> Self {
+        self.usage_mode = Some(usage_mode);
+        self
+    }
+    pub fn r#type(&self) -> &DocumentType {
+        &self.r#type
+    }
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn usage_mode(&self) -> Option<&KnowledgeBaseUsageMode> {
+        self.usage_mode.as_ref()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KnowledgeBaseUsageMode {
+    /// The whole document is added to the prompt
+    Prompt,
+    /// Relevant parts are retrieved, the document must be [indexed](super::knowledge_base::ComputeRagIndex)
+    Auto,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// How the user's speech is transcribed
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct AsrConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    quality: Option<AsrQuality>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    provider: Option<AsrProvider>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    user_input_audio_format: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    keywords: Option<Vec<String>>,
+}
+
+impl AsrConfig {
+    pub fn with_quality(mut self, quality: AsrQuality) -> Self {
+        self.quality = Some(quality);
+        self
+    }
+    pub fn with_provider(mut self, provider: AsrProvider) -> Self {
+        self.provider = Some(provider);
+        self
+    }
+    /// The format the client sends audio in, MP3 and Opus are not supported
+    pub fn with_user_input_audio_format(mut self, format: OutputFormat) -> Self {
+        self.user_input_audio_format = Some(format.to_string());
+        self
+    }
+    /// Words that are hard to recognize, e.g. product names
+    pub fn with_keywords(mut self, keywords: Vec<String>) -> Self {
+        self.keywords = Some(keywords);
+        self
+    }
+    pub fn quality(&self) -> Option<&AsrQuality> {
+        self.quality.as_ref()
+    }
+    pub fn provider(&self) -> Option<&AsrProvider> {
+        self.provider.as_ref()
+    }
+    /// The [user input format](Self::user_input_audio_format), if it is a known format
+    pub fn user_input_format(&self) -> Option<OutputFormat> {
+        self.user_input_audio_format.as_deref()?.parse().ok()
+    }
+    /// e.g. `pcm_16000` or `ulaw_8000`
+    pub fn user_input_audio_format(&self) -> Option<&str> {
+        self.user_input_audio_format.as_deref()
+    }
+    pub fn keywords(&self) -> &[String] {
+        self.keywords.as_deref().unwrap_or_default()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AsrQuality {
+    High,
+    #[serde(untagged)]
+    Other(String),
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AsrProvider {
+    Elevenlabs,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// How the agent's replies are spoken
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct TtsConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    model_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    voice_id: Option<VoiceID>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    agent_output_audio_format: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    optimize_streaming_latency: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    stability: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    speed: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    similarity_boost: Option<f32>,
+}
+
+impl TtsConfig {
+    /// One of the turbo or flash models, e.g. [Model::ElevenFlashV2](crate::Model::ElevenFlashV2)
+    pub fn with_model_id<T: Into<String>>(mut self, model_id: T) -> Self {
+        self.model_id = Some(model_id.into());
+        self
+    }
+    pub fn with_voice_id<T: Into<VoiceID>>(mut self, voice_id: T) -> Self {
+        self.voice_id = Some(voice_id.into());
+        self
+    }
+    /// MP3 and Opus are not supported
+    pub fn with_agent_output_audio_format(mut self, format: OutputFormat) -> Self {
+        self.agent_output_audio_format = Some(format.to_string());
+        self
+    }
+    /// 0 to 4, higher trades quality for latency
+    pub fn with_optimize_streaming_latency(mut self, optimize_streaming_latency: u32) -> Self {
+        self.optimize_streaming_latency = Some(optimize_streaming_latency);
+        self
+    }
+    /// 0.0 to 1.0, lower is more expressive
+    pub fn with_stability(mut self, stability: f32) -> Self {
+        self.stability = Some(stability);
+        self
+    }
+    /// 0.7 to 1.2, 1.0 is the voice's normal pace
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = Some(speed);
+        self
+    }
+    /// 0.0 to 1.0, how closely the voice should match the original
+    pub fn with_similarity_boost(mut self, similarity_boost: f32) -> Self {
+        self.similarity_boost = Some(similarity_boost);
+        self
+    }
+    pub fn model_id(&self) -> Option<&str> {
+        self.model_id.as_deref()
+    }
+    pub fn voice_id(&self) -> Option<&str> {
+        self.voice_id.as_ref().map(VoiceID::as_str)
+    }
+    /// e.g. `pcm_16000` or `ulaw_8000`
+    pub fn agent_output_audio_format(&self) -> Option<&str> {
+        self.agent_output_audio_format.as_deref()
+    }
+    /// The [agent output format](Self::agent_output_audio_format), if it is a known format
+    pub fn agent_output_format(&self) -> Option<OutputFormat> {
+        self.agent_output_audio_format.as_deref()?.parse().ok()
+    }
+    pub fn optimize_streaming_latency(&self) -> Option<u32> {
+        self.optimize_streaming_latency
+    }
+    pub fn stability(&self) -> Option<f32> {
+        self.stability
+    }
+    pub fn speed(&self) -> Option<f32> {
+        self.speed
+    }
+    pub fn similarity_boost(&self) -> Option<f32> {
+        self.similarity_boost
+    }
+}
+
+/// When the agent takes its turn
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct TurnConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    turn_timeout: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    silence_end_call_timeout: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    mode: Option<TurnMode>,
+}
+
+impl TurnConfig {
+    /// Seconds of silence before the agent prompts the user
+    pub fn with_turn_timeout(mut self, turn_timeout: f32) -> Self {
+        self.turn_timeout = Some(turn_timeout);
+        self
+    }
+    /// Seconds of silence before the call is ended, -1 to never end it
+    pub fn with_silence_end_call_timeout(mut self, silence_end_call_timeout: f32) -> Self {
+        self.silence_end_call_timeout = Some(silence_end_call_timeout);
+        self
+    }
+    pub fn with_mode(mut self, mode: TurnMode) -> Self {
+        self.mode = Some(mode);
+        self
+    }
+    pub fn turn_timeout(&self) -> Option<f32> {
+        self.turn_timeout
+    }
+    pub fn silence_end_call_timeout(&self) -> Option<f32> {
+        self.silence_end_call_timeout
+    }
+    pub fn mode(&self) -> Option<&TurnMode> {
+        self.mode.as_ref()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TurnMode {
+    /// The agent replies after the user has been silent for a moment
+    Silence,
+    /// The agent replies when a model decides the user has finished their turn
+    Turn,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// Settings of the conversation itself
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ConversationSettings {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    text_only: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    max_duration_seconds: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    client_events: Option<Vec<String>>,
+}
+
+impl ConversationSettings {
+    /// Only exchange text messages, without audio
+    pub fn with_text_only(mut self, text_only: bool) -> Self {
+        self.text_only = Some(text_only);
+        self
+    }
+    pub fn with_max_duration_seconds(mut self, max_duration_seconds: u32) -> Self {
+        self.max_duration_seconds = Some(max_duration_seconds);
+        self
+    }
+    /// The [server messages](super::server_messages) sent to the client, e.g. `audio` or `vad_score`
+    pub fn with_client_events(mut self, client_events: Vec<String>) -> Self {
+        self.client_events = Some(client_events);
+        self
+    }
+    pub fn text_only(&self) -> Option<bool> {
+        self.text_only
+    }
+    pub fn max_duration_seconds(&self) -> Option<u32> {
+        self.max_duration_seconds
+    }
+    pub fn client_events(&self) -> &[String] {
+        self.client_events.as_deref().unwrap_or_default()
+    }
+}
+
+/// The config used when a conversation is held in another language
+///
+/// # Example
+/// ```
+/// use elevenlabs_rs::endpoints::convai::ConversationConfigOverride;
+/// use elevenlabs_rs::endpoints::convai::agents::LanguagePreset;
+///
+/// let preset = LanguagePreset::new(
+///     ConversationConfigOverride::default()
+///         .with_first_message("Hola, ¿en qué puedo ayudarte?")
+///         .with_voice_id("voice_id"),
+/// );
+/// ```
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct LanguagePreset {
+    overrides: ConversationConfigOverride,
+}
+
+impl LanguagePreset {
+    pub fn new(overrides: ConversationConfigOverride) -> Self {
+        Self { overrides }
+    }
+    pub fn overrides(&self) -> &ConversationConfigOverride {
+        &self.overrides
+    }
+}
+
+/// Settings of the agent outside of its conversations
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct PlatformSettings {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    evaluation: Option<EvaluationSettings>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    auth: Option<AuthSettings>,
+}
+
+impl PlatformSettings {
+    pub fn with_evaluation(mut self, evaluation: EvaluationSettings) -> Self {
+        self.evaluation = Some(evaluation);
+        self
+    }
+    pub fn with_auth(mut self, auth: AuthSettings) -> Self {
+        self.auth = Some(auth);
+        self
+    }
+    pub fn evaluation(&self) -> Option<&EvaluationSettings> {
+        self.evaluation.as_ref()
+    }
+    pub fn auth(&self) -> Option<&AuthSettings> {
+        self.auth.as_ref()
+    }
+    /// Whether conversations need a [signed url](super::client::GetSignedUrl)
+    pub fn requires_auth(&self) -> bool {
+        self.auth.as_ref().is_some_and(|auth| auth.enable_auth)
+    }
+}
+
+/// Who may start conversations with the agent
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct AuthSettings {
+    #[serde(default)]
+    enable_auth: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    allowlist: Vec<AllowedHost>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct AllowedHost {
+    hostname: String,
+}
+
+impl AuthSettings {
+    /// Conversations can only be started with a [signed url](super::client::GetSignedUrl)
+    pub fn with_enable_auth(mut self, enable_auth: bool) -> Self {
+        self.enable_auth = enable_auth;
+        self
+    }
+    /// A host the agent's widget may be embedded on, all hosts are allowed if none is set
+    pub fn with_allowed_host<T: Into<String>>(mut self, hostname: T) -> Self {
+        self.allowlist.push(AllowedHost {
+            hostname: hostname.into(),
+        });
+        self
+    }
+    pub fn enable_auth(&self) -> bool {
+        self.enable_auth
+    }
+    pub fn allowed_hosts(&self) -> impl Iterator<Item = &str> {
+        self.allowlist.iter().map(|host| host.hostname.as_str())
+    }
+}
+
+/// How the agent's conversations are judged once they end
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct EvaluationSettings {
+    #[serde(default)]
+    criteria: Vec<EvaluationCriterion>,
+}
+
+impl EvaluationSettings {
+    pub fn with_criterion(mut self, criterion: EvaluationCriterion) -> Self {
+        self.criteria.push(criterion);
+        self
+    }
+    pub fn criteria(&self) -> &[EvaluationCriterion] {
+        &self.criteria
+    }
+}
+
+/// A goal an LLM checks every conversation against
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EvaluationCriterion {
+    id: String,
+    name: String,
+    #[serde(default = "EvaluationCriterion::prompt_type")]
+    r#type: String,
+    conversation_goal_prompt: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    use_knowledge_base: Option<bool>,
+}
+
+impl EvaluationCriterion {
+    /// The `conversation_goal_prompt` describes what counts as a success
+    pub fn new<I, N, P>(id: I, name: N, conversation_goal_prompt: P) -> Self
+    where
+        I: Into<String>,
+        N: Into<String>,
+        P: Into<String>,
+    {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            r#type: Self::prompt_type(),
+            conversation_goal_prompt: conversation_goal_prompt.into(),
+            use_knowledge_base: None,
+        }
+    }
+    pub fn with_use_knowledge_base(mut self, use_knowledge_base: bool) -> Self {
+        self.use_knowledge_base = Some(use_knowledge_base);
+        self
+    }
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn conversation_goal_prompt(&self) -> &str {
+        &self.conversation_goal_prompt
+    }
+    pub fn use_knowledge_base(&self) -> Option<bool> {
+        self.use_knowledge_base
+    }
+
+    fn prompt_type() -> String {
+        "prompt".to_string()
+    }
+}
@@ -0,0 +1,291 @@
+//! Microphone and speaker audio for a [Conversation](super::client::Conversation)
+//!
+//! Requires the `audio-interface` feature.
+//!
+//! The [AudioInterface] captures the default input device, converts it to the agent's
+//! input format and sends it to the conversation, and plays the agent's audio
+//! on the default output device.
+//!
+//! # Example
+//! ```no_run
+//! use elevenlabs_rs::*;
+//! use elevenlabs_rs::endpoints::convai::audio_interface::AudioInterface;
+//! use elevenlabs_rs::endpoints::convai::client::Conversation;
+//! use elevenlabs_rs::endpoints::convai::server_messages::ServerMessage;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<()> {
+//!     let c = ElevenLabsClient::default()?;
+//!     let mut conversation = Conversation::builder("agent_id").connect(&c).await?;
+//!     let mut audio = None;
+//!
+//!     while let Some(msg) = conversation.next().await {
+//!         let msg = msg?;
+//!         if let ServerMessage::ConversationInitiationMetadata(metadata) = &msg {
+//!             audio = Some(AudioInterface::start(conversation.handle(), metadata)?);
+//!         }
+//!         if let Some(audio) = &audio {
+//!             audio.handle_message(&msg)?;
+//!         }
+//!     }
+//!     Ok(())
+//! }
+//! ```
+use super::client::ConversationHandle;
+use super::server_messages::{Audio, ConversationInitiationMetadata, ServerMessage};
+use crate::client::Result;
+use crate::error::AudioInterfaceError;
+use crate::shared::query_params::{AudioCodec, OutputFormat};
+use crate::utils::Resampler;
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use cpal::{Device, FromSample, Sample, SampleFormat, SizedSample, Stream, StreamConfig};
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread::{self, JoinHandle};
+
+const DEFAULT_PCM_FORMAT: &str = "pcm_16000";
+/// Microphone audio is sent in chunks of this length
+const INPUT_CHUNK_MS: u32 = 250;
+
+type PlaybackQueue = Arc<Mutex<VecDeque<f32>>>;
+
+/// Full duplex audio for a conversation
+///
+/// The audio devices live on their own thread, which is stopped when the interface is dropped.
+pub struct AudioInterface {
+    queue: PlaybackQueue,
+    resampler: Mutex<Resampler>,
+    stop_tx: Option<mpsc::Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl AudioInterface {
+    /// Starts sending the microphone to the conversation,
+    /// in the formats the agent announced in its first message
+    pub fn start(
+        handle: ConversationHandle,
+        metadata: &ConversationInitiationMetadata,
+    ) -> Result<Self> {
+        let input_rate = pcm_sample_rate(
+            metadata
+                .user_input_audio_format()
+                .unwrap_or(DEFAULT_PCM_FORMAT),
+        )?;
+        let output_rate = pcm_sample_rate(metadata.agent_output_audio_format())?;
+        Self::start_with_sample_rates(handle, input_rate, output_rate)
+    }
+
+    /// Starts sending the microphone to the conversation as 16-bit mono PCM at `input_rate`,
+    /// agent audio is expected as 16-bit mono PCM at `output_rate`
+    pub fn start_with_sample_rates(
+        handle: ConversationHandle,
+        input_rate: u32,
+        output_rate: u32,
+    ) -> Result<Self> {
+        let queue = PlaybackQueue::default();
+        let (ready_tx, ready_rx) = mpsc::channel::<Result<u32>>();
+        let (stop_tx, stop_rx) = mpsc::channel::<()>();
+
+        let playback_queue = queue.clone();
+        let thread = thread::spawn(move || {
+            match open_streams(handle, input_rate, playback_queue) {
+                Ok((streams, device_rate)) => {
+                    let _ = ready_tx.send(Ok(device_rate));
+                    // Returns once the interface is dropped
+                    let _ = stop_rx.recv();
+                    drop(streams);
+                }
+                Err(e) => {
+                    let _ = ready_tx.send(Err(e));
+                }
+            }
+        });
+
+        let device_rate = ready_rx.recv()??;
+        Ok(Self {
+            queue,
+            resampler: Mutex::new(Resampler::new(output_rate, device_rate)),
+            stop_tx: Some(stop_tx),
+            thread: Some(thread),
+        })
+    }
+
+    /// Plays [Audio] and clears the queued audio on an
+    /// [Interruption](super::server_messages::Interruption), other messages are ignored
+    pub fn handle_message(&self, msg: &ServerMessage) -> Result<()> {
+        match msg {
+            ServerMessage::Audio(audio) => self.play(audio)?,
+            ServerMessage::Interruption(_) => self.interrupt(),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Queues the agent's audio for playback
+    pub fn play(&self, audio: &Audio) -> Result<()> {
+        self.play_pcm(&audio.audio_as_bytes()?);
+        Ok(())
+    }
+
+    /// Queues 16-bit little endian mono PCM for playback
+    pub fn play_pcm(&self, pcm: &[u8]) {
+        let samples = pcm
+            .chunks_exact(2)
+            .map(|b| f32::from_sample(i16::from_le_bytes([b[0], b[1]])))
+            .collect::<Vec<f32>>();
+        let mut resampled = Vec::new();
+        if let Ok(mut resampler) = self.resampler.lock() {
+            resampler.process(&samples, &mut resampled);
+        }
+        if let Ok(mut queue) = self.queue.lock() {
+            queue.extend(resampled);
+        }
+    }
+
+    /// Stops the audio that is playing
+    pub fn interrupt(&self) {
+        if let Ok(mut queue) = self.queue.lock() {
+            queue.clear();
+        }
+    }
+
+    /// Closes the audio devices, the same as dropping the interface
+    pub fn stop(self) {}
+}
+
+impl Drop for AudioInterface {
+    fn drop(&mut self) {
+        self.stop_tx.take();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl fmt::Debug for AudioInterface {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AudioInterface").finish_non_exhaustive()
+    }
+}
+
+/// Parses the sample rate of a format such as `pcm_16000`
+fn pcm_sample_rate(format: &str) -> Result<u32> {
+    match format.parse::<OutputFormat>() {
+        Ok(output_format) if output_format.codec() == AudioCodec::Pcm => {
+            Ok(output_format.sample_rate())
+        }
+        _ => Err(AudioInterfaceError::UnsupportedAudioFormat(format.to_string()).into()),
+    }
+}
+
+/// Opens the default devices, returning the streams and the output device's sample rate
+fn open_streams(
+    handle: ConversationHandle,
+    input_rate: u32,
+    queue: PlaybackQueue,
+) -> Result<([Stream; 2], u32)> {
+    let host = cpal::default_host();
+    let input_device = host
+        .default_input_device()
+        .ok_or(AudioInterfaceError::NoInputDevice)?;
+    let output_device = host
+        .default_output_device()
+        .ok_or(AudioInterfaceError::NoOutputDevice)?;
+
+    let input_config = input_device.default_input_config()?;
+    let output_config = output_device.default_output_config()?;
+    let device_rate = output_config.sample_rate().0;
+
+    let input = match input_config.sample_format() {
+        SampleFormat::F32 => {
+            input_stream::<f32>(&input_device, &input_config.into(), handle, input_rate)?
+        }
+        SampleFormat::I16 => {
+            input_stream::<i16>(&input_device, &input_config.into(), handle, input_rate)?
+        }
+        SampleFormat::U16 => {
+            input_stream::<u16>(&input_device, &input_config.into(), handle, input_rate)?
+        }
+        format => {
+            return Err(AudioInterfaceError::UnsupportedSampleFormat(format.to_string()).into())
+        }
+    };
+    let output = match output_config.sample_format() {
+        SampleFormat::F32 => output_stream::<f32>(&output_device, &output_config.into(), queue)?,
+        SampleFormat::I16 => output_stream::<i16>(&output_device, &output_config.into(), queue)?,
+        SampleFormat::U16 => output_stream::<u16>(&output_device, &output_config.into(), queue)?,
+        format => {
+            return Err(AudioInterfaceError::UnsupportedSampleFormat(format.to_string()).into())
+        }
+    };
+
+    input.play()?;
+    output.play()?;
+    Ok(([input, output], device_rate))
+}
+
+fn input_stream<T>(
+    device: &Device,
+    config: &StreamConfig,
+    handle: ConversationHandle,
+    input_rate: u32,
+) -> Result<Stream>
+where
+    T: SizedSample,
+    f32: FromSample<T>,
+{
+    let channels = config.channels as usize;
+    let chunk_len = (input_rate * INPUT_CHUNK_MS / 1000) as usize * 2;
+    let mut resampler = Resampler::new(config.sample_rate.0, input_rate);
+    let mut mono = Vec::new();
+    let mut resampled = Vec::new();
+    let mut chunk = Vec::with_capacity(chunk_len);
+
+    let stream = device.build_input_stream(
+        config,
+        move |data: &[T], _| {
+            mono.clear();
+            mono.extend(data.chunks(channels).map(|frame| {
+                frame.iter().map(|s| s.to_sample::<f32>()).sum::<f32>() / channels as f32
+            }));
+            resampled.clear();
+            resampler.process(&mono, &mut resampled);
+            for sample in &resampled {
+                chunk.extend_from_slice(&i16::from_sample(*sample).to_le_bytes());
+            }
+            while chunk.len() >= chunk_len {
+                // Fails once the conversation has ended, the interface is stopped by its owner
+                let _ = handle.send_audio(&chunk[..chunk_len]);
+                chunk.drain(..chunk_len);
+            }
+        },
+        |_| {},
+        None,
+    )?;
+    Ok(stream)
+}
+
+fn output_stream<T>(device: &Device, config: &StreamConfig, queue: PlaybackQueue) -> Result<Stream>
+where
+    T: SizedSample + FromSample<f32>,
+{
+    let channels = config.channels as usize;
+
+    let stream = device.build_output_stream(
+        config,
+        move |data: &mut [T], _| {
+            let mut queue = queue.lock().ok();
+            for frame in data.chunks_mut(channels) {
+                let sample = queue
+                    .as_mut()
+                    .and_then(|queue| queue.pop_front())
+                    .unwrap_or(0.0);
+                frame.fill(T::from_sample(sample));
+            }
+        },
+        |_| {},
+        None,
+    )?;
+    Ok(stream)
+}
@@ -0,0 +1,559 @@
+//! The batch calling endpoints
+//!
+//! A batch call places an outbound call from an agent to each of its recipients.
+//!
+//! See the [ElevenLabs docs](https://elevenlabs.io/docs/api-reference/batch-calling/create) for more information.
+use super::*;
+
+const BATCH_CALLING_PATH: &str = "/batch-calling";
+const SUBMIT_PATH: &str = "/submit";
+const WORKSPACE_PATH: &str = "/workspace";
+const CANCEL_PATH: &str = "/cancel";
+const RETRY_PATH: &str = "/retry";
+
+const LIMIT_QUERY: &str = "limit";
+const LAST_DOC_QUERY: &str = "last_doc";
+
+/// Submit a batch call
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::batch_calling::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let customers = vec![
+///         ("+15551234567", vec![("name", "Ada")]),
+///         ("+15557654321", vec![("name", "Grace")]),
+///     ];
+///     let body = SubmitBatchCallBody::new("Nightly reminders", "agent_id", "agent_phone_number_id")
+///         .with_recipients(recipients(customers));
+///     let batch = c.hit(SubmitBatchCall::new(body)).await?;
+///     println!("{} {:?}", batch.id(), batch.status());
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SubmitBatchCall(SubmitBatchCallBody);
+
+impl SubmitBatchCall {
+    pub fn new(body: SubmitBatchCallBody) -> Self {
+        Self(body)
+    }
+}
+
+/// Submit batch call body
+#[derive(Clone, Debug, Serialize)]
+pub struct SubmitBatchCallBody {
+    call_name: String,
+    agent_id: AgentID,
+    agent_phone_number_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scheduled_time_unix: Option<i64>,
+    recipients: Vec<Recipient>,
+}
+
+impl SubmitBatchCallBody {
+    pub fn new<N, A, P>(call_name: N, agent_id: A, agent_phone_number_id: P) -> Self
+    where
+        N: Into<String>,
+        A: Into<AgentID>,
+        P: Into<String>,
+    {
+        Self {
+            call_name: call_name.into(),
+            agent_id: agent_id.into(),
+            agent_phone_number_id: agent_phone_number_id.into(),
+            scheduled_time_unix: None,
+            recipients: Vec::new(),
+        }
+    }
+    /// Calls start immediately when not set
+    pub fn with_scheduled_time_unix(mut self, scheduled_time_unix: i64) -> Self {
+        self.scheduled_time_unix = Some(scheduled_time_unix);
+        self
+    }
+    pub fn with_recipient(mut self, recipient: Recipient) -> Self {
+        self.recipients.push(recipient);
+        self
+    }
+    pub fn with_recipients<I: IntoIterator<Item = Recipient>>(mut self, recipients: I) -> Self {
+        self.recipients.extend(recipients);
+        self
+    }
+    pub fn recipients(&self) -> &[Recipient] {
+        &self.recipients
+    }
+}
+
+/// A phone number to call in a batch
+#[derive(Clone, Debug, Serialize)]
+pub struct Recipient {
+    phone_number: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    conversation_initiation_client_data: Option<ConversationInitiationClientData>,
+}
+
+impl Recipient {
+    pub fn new<T: Into<String>>(phone_number: T) -> Self {
+        Self {
+            phone_number: phone_number.into(),
+            conversation_initiation_client_data: None,
+        }
+    }
+    pub fn with_conversation_initiation_client_data(
+        mut self,
+        data: ConversationInitiationClientData,
+    ) -> Self {
+        self.conversation_initiation_client_data = Some(data);
+        self
+    }
+    pub fn with_dynamic_variable<K, V>(mut self, name: K, value: V) -> Self
+    where
+        K: Into<String>,
+        V: Into<DynamicVariable>,
+    {
+        let data = self
+            .conversation_initiation_client_data
+            .take()
+            .unwrap_or_default();
+        self.conversation_initiation_client_data = Some(data.with_dynamic_variable(name, value));
+        self
+    }
+    pub fn phone_number(&self) -> &str {
+        &self.phone_number
+    }
+}
+
+/// Builds recipients from phone numbers and their dynamic variables
+///
+/// # Example
+/// ```
+/// use elevenlabs_rs::endpoints::convai::batch_calling::recipients;
+/// use std::collections::HashMap;
+///
+/// let mut vars = HashMap::new();
+/// vars.insert("name", "Ada");
+/// let recipients = recipients([("+15551234567", vars)]);
+/// assert_eq!(recipients[0].phone_number(), "+15551234567");
+/// ```
+pub fn recipients<I, P, D, K, V>(numbers: I) -> Vec<Recipient>
+where
+    I: IntoIterator<Item = (P, D)>,
+    P: Into<String>,
+    D: IntoIterator<Item = (K, V)>,
+    K: Into<String>,
+    V: Into<DynamicVariable>,
+{
+    numbers
+        .into_iter()
+        .map(|(phone_number, variables)| {
+            variables
+                .into_iter()
+                .fold(Recipient::new(phone_number), |r, (k, v)| {
+                    r.with_dynamic_variable(k, v)
+                })
+        })
+        .collect()
+}
+
+impl Endpoint for SubmitBatchCall {
+    type ResponseBody = BatchCall;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(serde_json::to_value(&self.0)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        convai_url(&format!("{}{}", BATCH_CALLING_PATH, SUBMIT_PATH))
+    }
+}
+
+/// List the batch calls of the workspace
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::batch_calling::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let batches = c.paginate(ListBatchCalls::new().with_limit(50));
+///     pin_mut!(batches);
+///     while let Some(batch) = batches.next().await {
+///         let batch = batch?;
+///         println!("{} {:?}", batch.name(), batch.status());
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ListBatchCalls {
+    limit: Option<u32>,
+    last_doc: Option<String>,
+}
+
+impl ListBatchCalls {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Defaults to 100
+    pub fn with_limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+    /// The cursor of the next page, as returned in [ListBatchCallsResponse::next_doc]
+    pub fn with_last_doc<T: Into<String>>(mut self, last_doc: T) -> Self {
+        self.last_doc = Some(last_doc.into());
+        self
+    }
+}
+
+impl Endpoint for ListBatchCalls {
+    type ResponseBody = ListBatchCallsResponse;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        let mut url = convai_url(&format!("{}{}", BATCH_CALLING_PATH, WORKSPACE_PATH));
+        {
+            let mut pairs = url.query_pairs_mut();
+            if let Some(limit) = self.limit {
+                pairs.append_pair(LIMIT_QUERY, &limit.to_string());
+            }
+            if let Some(last_doc) = &self.last_doc {
+                pairs.append_pair(LAST_DOC_QUERY, last_doc);
+            }
+        }
+        trim_query(url)
+    }
+}
+
+impl Paginated for ListBatchCalls {
+    type Item = BatchCall;
+
+    fn next_page(self, page: Self::ResponseBody) -> (Vec<Self::Item>, Option<Self>) {
+        let next = match (page.has_more, page.next_doc) {
+            (true, Some(next_doc)) => Some(self.with_last_doc(next_doc)),
+            _ => None,
+        };
+        (page.batch_calls, next)
+    }
+}
+
+/// List batch calls response
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListBatchCallsResponse {
+    batch_calls: Vec<BatchCall>,
+    next_doc: Option<String>,
+    #[serde(default)]
+    has_more: bool,
+}
+
+impl ListBatchCallsResponse {
+    pub fn batch_calls(&self) -> &[BatchCall] {
+        &self.batch_calls
+    }
+    pub fn next_doc(&self) -> Option<&str> {
+        self.next_doc.as_deref()
+    }
+    pub fn has_more(&self) -> bool {
+        self.has_more
+    }
+}
+
+/// Get a batch call and the status of each of its recipients
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::batch_calling::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let detail = c.hit(GetBatchCall::new("batch_id")).await?;
+///     for r in detail.recipients() {
+///         println!("{} {:?} {:?}", r.phone_number(), r.status(), r.conversation_id());
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct GetBatchCall(BatchID);
+
+impl GetBatchCall {
+    pub fn new<T: Into<String>>(batch_id: T) -> Self {
+        Self(BatchID(batch_id.into()))
+    }
+}
+
+#[derive(Clone, Debug)]
+struct BatchID(String);
+
+impl Endpoint for GetBatchCall {
+    type ResponseBody = BatchCallDetail;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        convai_url(&format!("{}/{}", BATCH_CALLING_PATH, self.0 .0))
+    }
+}
+
+/// Cancel a batch call, recipients that were not called yet are skipped
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::batch_calling::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let batch = c.hit(CancelBatchCall::new("batch_id")).await?;
+///     assert_eq!(batch.status(), &BatchCallStatus::Cancelled);
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct CancelBatchCall(BatchID);
+
+impl CancelBatchCall {
+    pub fn new<T: Into<String>>(batch_id: T) -> Self {
+        Self(BatchID(batch_id.into()))
+    }
+}
+
+impl Endpoint for CancelBatchCall {
+    type ResponseBody = BatchCall;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        convai_url(&format!(
+            "{}/{}{}",
+            BATCH_CALLING_PATH, self.0 .0, CANCEL_PATH
+        ))
+    }
+}
+
+/// Retry the failed and unanswered calls of a batch call
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::batch_calling::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let batch = c.hit(RetryBatchCall::new("batch_id")).await?;
+///     println!("{:?}", batch.status());
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct RetryBatchCall(BatchID);
+
+impl RetryBatchCall {
+    pub fn new<T: Into<String>>(batch_id: T) -> Self {
+        Self(BatchID(batch_id.into()))
+    }
+}
+
+impl Endpoint for RetryBatchCall {
+    type ResponseBody = BatchCall;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        convai_url(&format!(
+            "{}/{}{}",
+            BATCH_CALLING_PATH, self.0 .0, RETRY_PATH
+        ))
+    }
+}
+
+/// A batch call
+#[derive(Clone, Debug, Deserialize)]
+pub struct BatchCall {
+    id: String,
+    name: String,
+    agent_id: String,
+    agent_name: Option<String>,
+    phone_number_id: Option<String>,
+    status: BatchCallStatus,
+    created_at_unix: i64,
+    scheduled_time_unix: Option<i64>,
+    last_updated_at_unix: Option<i64>,
+    #[serde(default)]
+    total_calls_dispatched: u32,
+    #[serde(default)]
+    total_calls_scheduled: u32,
+}
+
+impl BatchCall {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn agent_id(&self) -> &str {
+        &self.agent_id
+    }
+    pub fn agent_name(&self) -> Option<&str> {
+        self.agent_name.as_deref()
+    }
+    pub fn phone_number_id(&self) -> Option<&str> {
+        self.phone_number_id.as_deref()
+    }
+    pub fn status(&self) -> &BatchCallStatus {
+        &self.status
+    }
+    pub fn created_at_unix(&self) -> i64 {
+        self.created_at_unix
+    }
+    /// [created_at_unix](Self::created_at_unix) as a date
+    #[cfg(feature = "chrono")]
+    pub fn created_at(&self) -> DateTime<Utc> {
+        utc(self.created_at_unix())
+    }
+    pub fn scheduled_time_unix(&self) -> Option<i64> {
+        self.scheduled_time_unix
+    }
+    /// [scheduled_time_unix](Self::scheduled_time_unix) as a date
+    #[cfg(feature = "chrono")]
+    pub fn scheduled_time(&self) -> Option<DateTime<Utc>> {
+        self.scheduled_time_unix().map(utc)
+    }
+    pub fn last_updated_at_unix(&self) -> Option<i64> {
+        self.last_updated_at_unix
+    }
+    /// [last_updated_at_unix](Self::last_updated_at_unix) as a date
+    #[cfg(feature = "chrono")]
+    pub fn last_updated_at(&self) -> Option<DateTime<Utc>> {
+        self.last_updated_at_unix().map(utc)
+    }
+    pub fn total_calls_dispatched(&self) -> u32 {
+        self.total_calls_dispatched
+    }
+    pub fn total_calls_scheduled(&self) -> u32 {
+        self.total_calls_scheduled
+    }
+}
+
+/// A batch call with its recipients
+#[derive(Clone, Debug, Deserialize)]
+pub struct BatchCallDetail {
+    #[serde(flatten)]
+    batch_call: BatchCall,
+    recipients: Vec<RecipientStatus>,
+}
+
+impl BatchCallDetail {
+    pub fn batch_call(&self) -> &BatchCall {
+        &self.batch_call
+    }
+    pub fn recipients(&self) -> &[RecipientStatus] {
+        &self.recipients
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchCallStatus {
+    Pending,
+    InProgress,
+    Completed,
+    Failed,
+    Cancelled,
+    #[serde(other)]
+    Unknown,
+}
+
+/// The call to one recipient of a batch call
+#[derive(Clone, Debug, Deserialize)]
+pub struct RecipientStatus {
+    id: String,
+    phone_number: String,
+    status: RecipientCallStatus,
+    created_at_unix: i64,
+    updated_at_unix: i64,
+    conversation_id: Option<String>,
+    conversation_initiation_client_data: Option<ConversationInitiationClientData>,
+}
+
+impl RecipientStatus {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+    pub fn phone_number(&self) -> &str {
+        &self.phone_number
+    }
+    pub fn status(&self) -> &RecipientCallStatus {
+        &self.status
+    }
+    pub fn created_at_unix(&self) -> i64 {
+        self.created_at_unix
+    }
+    /// [created_at_unix](Self::created_at_unix) as a date
+    #[cfg(feature = "chrono")]
+    pub fn created_at(&self) -> DateTime<Utc> {
+        utc(self.created_at_unix())
+    }
+    pub fn updated_at_unix(&self) -> i64 {
+        self.updated_at_unix
+    }
+    /// [updated_at_unix](Self::updated_at_unix) as a date
+    #[cfg(feature = "chrono")]
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        utc(self.updated_at_unix())
+    }
+    /// Set once the call was placed
+    pub fn conversation_id(&self) -> Option<&str> {
+        self.conversation_id.as_deref()
+    }
+    pub fn conversation_initiation_client_data(&self) -> Option<&ConversationInitiationClientData> {
+        self.conversation_initiation_client_data.as_ref()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecipientCallStatus {
+    Pending,
+    Initiated,
+    InProgress,
+    Completed,
+    Failed,
+    Cancelled,
+    Voicemail,
+    #[serde(other)]
+    Unknown,
+}
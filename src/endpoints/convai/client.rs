@@ -0,0 +1,1002 @@
+//! A websocket client for conversational AI agents
+//!
+//! # Example
+//! ```no_run
+//! use elevenlabs_rs::*;
+//! use elevenlabs_rs::endpoints::convai::client::Conversation;
+//! use elevenlabs_rs::endpoints::convai::server_messages::ServerMessage;
+//! use serde_json::{json, Value};
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<()> {
+//!     let c = ElevenLabsClient::default()?;
+//!     let mut conversation = Conversation::builder("agent_id")
+//!         .with_client_tool("get_weather", |params: Value| async move {
+//!             let city = params["city"].as_str().unwrap_or("London").to_string();
+//!             Ok(json!({ "city": city, "forecast": "sunny" }))
+//!         })
+//!         .connect(&c)
+//!         .await?;
+//!
+//!     while let Some(msg) = conversation.next().await {
+//!         match msg? {
+//!             ServerMessage::AgentResponse(r) => println!("agent: {}", r.agent_response()),
+//!             ServerMessage::UserTranscript(t) => println!("user: {}", t.user_transcript()),
+//!             _ => {}
+//!         }
+//!     }
+//!     Ok(())
+//! }
+//! ```
+use super::agents::GetAgent;
+use super::client_messages::ClientMessage;
+use super::server_messages::{
+    AgentResponse, AgentResponseCorrection, Audio, ClientToolCall, ConversationInitiationMetadata,
+    Interruption, Reconnected, ServerMessage, TentativeAgentResponse, UserTranscript, VadScore,
+};
+use super::*;
+use crate::client::ElevenLabsClient;
+use crate::error::{ConvAIError, Error, WebSocketError};
+use crate::telemetry::{ws_path, Span};
+use futures_channel::mpsc::{unbounded, TryRecvError, UnboundedReceiver, UnboundedSender};
+use futures_util::{SinkExt, Stream, StreamExt};
+use std::collections::VecDeque;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::net::TcpStream;
+use tokio::task::JoinSet;
+use tokio::time::{Instant, Interval, MissedTickBehavior};
+use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+use tokio_tungstenite::tungstenite::protocol::{CloseFrame, Message};
+use tokio_tungstenite::{connect_async, MaybeTlsStream, WebSocketStream};
+use tokio_util::sync::CancellationToken;
+
+const CONVERSATION_PATH: &str = "/conversation";
+const GET_SIGNED_URL_PATH: &str = "/get-signed-url";
+const TOKEN_PATH: &str = "/token";
+const AGENT_ID_QUERY: &str = "agent_id";
+const PARTICIPANT_NAME_QUERY: &str = "participant_name";
+/// How long to wait for the server to acknowledge a close frame
+const CLOSE_TIMEOUT: Duration = Duration::from_secs(5);
+const MIN_KEEPALIVE: Duration = Duration::from_secs(1);
+const MAX_KEEPALIVE: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Get a signed url to start a conversation with an agent
+///
+/// The url is valid for 15 minutes and can be handed to clients that must not see the api key.
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::client::GetSignedUrl;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let resp = c.hit(GetSignedUrl::new("agent_id")).await?;
+///     println!("{}", resp.signed_url());
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct GetSignedUrl {
+    agent_id: AgentID,
+}
+
+impl GetSignedUrl {
+    pub fn new<T: Into<AgentID>>(agent_id: T) -> Self {
+        Self {
+            agent_id: agent_id.into(),
+        }
+    }
+}
+
+impl Endpoint for GetSignedUrl {
+    type ResponseBody = SignedUrlResponse;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        let mut url = convai_url(&format!("{}{}", CONVERSATION_PATH, GET_SIGNED_URL_PATH));
+        url.query_pairs_mut()
+            .append_pair(AGENT_ID_QUERY, self.agent_id.as_str());
+        url
+    }
+}
+
+/// Get signed url response
+#[derive(Clone, Debug, Deserialize)]
+pub struct SignedUrlResponse {
+    signed_url: String,
+}
+
+impl SignedUrlResponse {
+    pub fn signed_url(&self) -> &str {
+        &self.signed_url
+    }
+}
+
+/// Get a token for a WebRTC conversation with an agent
+///
+/// Only the token endpoint is covered. The crate has no WebRTC transport yet, since WebRTC
+/// conversations are held through a LiveKit client; from Rust, talk to agents over websockets
+/// with [Conversation]. The token can be handed to browser or native clients that must not see the api key.
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::client::GetConversationToken;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let endpoint = GetConversationToken::new("agent_id").with_participant_name("Ada");
+///     let resp = c.hit(endpoint).await?;
+///     println!("{}", resp.token());
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct GetConversationToken {
+    agent_id: AgentID,
+    participant_name: Option<String>,
+}
+
+impl GetConversationToken {
+    pub fn new<T: Into<AgentID>>(agent_id: T) -> Self {
+        Self {
+            agent_id: agent_id.into(),
+            participant_name: None,
+        }
+    }
+    /// The name the user joins the WebRTC room with
+    pub fn with_participant_name<T: Into<String>>(mut self, participant_name: T) -> Self {
+        self.participant_name = Some(participant_name.into());
+        self
+    }
+}
+
+impl Endpoint for GetConversationToken {
+    type ResponseBody = ConversationTokenResponse;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        let mut url = convai_url(&format!("{}{}", CONVERSATION_PATH, TOKEN_PATH));
+        url.query_pairs_mut()
+            .append_pair(AGENT_ID_QUERY, self.agent_id.as_str());
+        if let Some(participant_name) = &self.participant_name {
+            url.query_pairs_mut()
+                .append_pair(PARTICIPANT_NAME_QUERY, participant_name);
+        }
+        url
+    }
+}
+
+/// Get conversation token response
+#[derive(Clone, Debug, Deserialize)]
+pub struct ConversationTokenResponse {
+    token: String,
+}
+
+impl ConversationTokenResponse {
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+}
+
+type ToolFuture = Pin<Box<dyn Future<Output = Result<Value>> + Send>>;
+type ToolFn = Arc<dyn Fn(Value) -> ToolFuture + Send + Sync>;
+
+/// Client tools run locally when the agent calls them
+///
+/// The value a tool returns is sent back to the agent as its result,
+/// an error is sent back as an error result.
+/// Calls to tools that are not registered are left to the caller to answer.
+#[derive(Clone, Default)]
+pub struct ClientTools {
+    tools: HashMap<String, ToolFn>,
+}
+
+impl ClientTools {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Registers a tool under the name it has in the agent's config
+    pub fn register<N, F, Fut, R>(mut self, name: N, tool: F) -> Self
+    where
+        N: Into<String>,
+        F: Fn(Value) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<R>> + Send + 'static,
+        R: Serialize,
+    {
+        let tool: ToolFn = Arc::new(move |parameters| {
+            let fut = tool(parameters);
+            Box::pin(async move { Ok(serde_json::to_value(fut.await?)?) })
+        });
+        self.tools.insert(name.into(), tool);
+        self
+    }
+    pub fn contains(&self, name: &str) -> bool {
+        self.tools.contains_key(name)
+    }
+    fn call(&self, call: &ClientToolCall) -> Option<ToolFuture> {
+        let tool = self.tools.get(call.tool_name())?;
+        Some(tool(call.parameters().clone()))
+    }
+}
+
+impl fmt::Debug for ClientTools {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.tools.keys()).finish()
+    }
+}
+
+/// Builder for a [Conversation]
+#[derive(Debug)]
+pub struct ConversationBuilder {
+    agent_id: AgentID,
+    initiation_data: ConversationInitiationClientData,
+    tools: ClientTools,
+    reconnect_policy: Option<ReconnectPolicy>,
+    public: bool,
+    auto_pong: bool,
+    keepalive: Option<Duration>,
+    cancellation: Option<CancellationToken>,
+}
+
+impl ConversationBuilder {
+    /// Overrides and dynamic variables sent when the conversation starts
+    pub fn with_initiation_data(mut self, data: ConversationInitiationClientData) -> Self {
+        self.initiation_data = data;
+        self
+    }
+    pub fn with_client_tools(mut self, tools: ClientTools) -> Self {
+        self.tools = tools;
+        self
+    }
+    /// See [ClientTools::register]
+    pub fn with_client_tool<N, F, Fut, R>(mut self, name: N, tool: F) -> Self
+    where
+        N: Into<String>,
+        F: Fn(Value) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<R>> + Send + 'static,
+        R: Serialize,
+    {
+        self.tools = self.tools.register(name, tool);
+        self
+    }
+    /// Reconnect when the connection drops, see [ReconnectPolicy]
+    pub fn with_reconnect_policy(mut self, policy: ReconnectPolicy) -> Self {
+        self.reconnect_policy = Some(policy);
+        self
+    }
+    /// Whether pings are answered automatically, defaults to `true`
+    ///
+    /// When disabled, pings must be answered with a [pong](ClientMessage::pong)
+    /// or the server ends the conversation.
+    pub fn with_auto_pong(mut self, auto_pong: bool) -> Self {
+        self.auto_pong = auto_pong;
+        self
+    }
+    /// Sends a [user activity](ClientMessage::UserActivity) message whenever nothing was sent
+    /// for `interval`, so the conversation is not ended during long silences of the user
+    ///
+    /// The interval is clamped between a second and a day.
+    pub fn with_keepalive(mut self, interval: Duration) -> Self {
+        self.keepalive = Some(interval.clamp(MIN_KEEPALIVE, MAX_KEEPALIVE));
+        self
+    }
+    /// Ends the conversation when the token is cancelled, e.g. from another task
+    ///
+    /// The conversation then yields an [Error::Cancelled](crate::error::Error::Cancelled).
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+    /// Connect to the agent's public url instead of getting a signed url,
+    /// only possible for agents that do not [require auth](super::agents::PlatformSettings::requires_auth)
+    pub fn with_public_url(mut self) -> Self {
+        self.public = true;
+        self
+    }
+    /// Gets a signed url for the agent, unless the [public url](Self::with_public_url) is used,
+    /// and starts the conversation
+    pub async fn connect(self, client: &ElevenLabsClient) -> Result<Conversation> {
+        let url = conversation_url(client, self.agent_id.as_str(), self.public).await?;
+        let ws = open(&url, &self.initiation_data).await?;
+
+        let (out_tx, out_rx) = unbounded::<ClientMessage>();
+        let (msg_tx, messages) = unbounded::<Result<ServerMessage>>();
+        let handle = ConversationHandle { tx: out_tx.clone() };
+        let session = Session {
+            initiation_data: self.initiation_data,
+            tools: self.tools,
+            reconnect: self.reconnect_policy.map(|policy| Reconnect {
+                client: client.clone(),
+                agent_id: self.agent_id,
+                public: self.public,
+                policy,
+            }),
+            auto_pong: self.auto_pong,
+            keepalive: self.keepalive,
+            cancellation: self.cancellation,
+            out_tx,
+            out_rx,
+            msg_tx,
+            tool_tasks: JoinSet::new(),
+        };
+        tokio::spawn(session.run(ws));
+        Ok(Conversation {
+            handle,
+            messages,
+            pending: VecDeque::new(),
+            ended: false,
+        })
+    }
+}
+
+/// A conversation with an agent
+///
+/// Yields the messages of the agent until the conversation ends.
+/// Pings are answered, unless [disabled](ConversationBuilder::with_auto_pong),
+/// and registered [ClientTools] are run, without the caller's involvement.
+/// Dropping the conversation ends it, [end](Conversation::end) it to wait until it has.
+///
+/// Agent audio that was received but not yet yielded is discarded when the agent is
+/// [interrupted](ServerMessage::Interruption), so it is never played.
+pub struct Conversation {
+    handle: ConversationHandle,
+    messages: UnboundedReceiver<Result<ServerMessage>>,
+    /// Messages received but not yet yielded
+    pending: VecDeque<Result<ServerMessage>>,
+    ended: bool,
+}
+
+impl Conversation {
+    pub fn builder<T: Into<AgentID>>(agent_id: T) -> ConversationBuilder {
+        ConversationBuilder {
+            agent_id: agent_id.into(),
+            initiation_data: ConversationInitiationClientData::default(),
+            tools: ClientTools::default(),
+            reconnect_policy: None,
+            public: false,
+            auto_pong: true,
+            keepalive: None,
+            cancellation: None,
+        }
+    }
+    /// Starts a conversation, with a signed url if the agent requires auth and
+    /// with its public url otherwise
+    ///
+    /// # Example
+    /// ```no_run
+    /// use elevenlabs_rs::*;
+    /// use elevenlabs_rs::endpoints::convai::client::Conversation;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     let c = ElevenLabsClient::default()?;
+    ///     let mut conversation = Conversation::from_agent_id(&c, "agent_id").await?;
+    ///     conversation.send_user_message("Hi!")?;
+    ///     while let Some(msg) = conversation.next().await {
+    ///         println!("{:?}", msg?);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn from_agent_id<T: Into<AgentID>>(
+        client: &ElevenLabsClient,
+        agent_id: T,
+    ) -> Result<Conversation> {
+        let agent_id = agent_id.into();
+        let agent = client.hit(GetAgent::new(&agent_id)).await?;
+        let mut builder = Self::builder(agent_id);
+        if !agent.platform_settings().requires_auth() {
+            builder = builder.with_public_url();
+        }
+        builder.connect(client).await
+    }
+    /// A handle to send messages from another task
+    pub fn handle(&self) -> ConversationHandle {
+        self.handle.clone()
+    }
+    /// See [ConversationHandle::send_audio]
+    pub fn send_audio(&self, audio: &[u8]) -> Result<()> {
+        self.handle.send_audio(audio)
+    }
+    pub fn send(&self, message: ClientMessage) -> Result<()> {
+        self.handle.send(message)
+    }
+    /// See [ConversationHandle::send_contextual_update]
+    pub fn send_contextual_update<T: Into<String>>(&self, text: T) -> Result<()> {
+        self.handle.send_contextual_update(text)
+    }
+    /// See [ConversationHandle::send_user_message]
+    pub fn send_user_message<T: Into<String>>(&self, text: T) -> Result<()> {
+        self.handle.send_user_message(text)
+    }
+    /// Ends the conversation, without waiting for it to end
+    pub fn close(&self) {
+        self.handle.close()
+    }
+    /// Ends the conversation and waits until the connection is closed
+    ///
+    /// A normal close frame is sent and the messages still on their way are discarded,
+    /// including the agent's audio. Running [client tools](ClientTools) are cancelled.
+    ///
+    /// Returns the first error that occurred while closing, e.g. if the server did not
+    /// acknowledge the close frame in time.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use elevenlabs_rs::*;
+    /// use elevenlabs_rs::endpoints::convai::client::Conversation;
+    /// use elevenlabs_rs::endpoints::convai::server_messages::ServerMessage;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     let c = ElevenLabsClient::default()?;
+    ///     let mut conversation = Conversation::builder("agent_id").connect(&c).await?;
+    ///     while let Some(msg) = conversation.next().await {
+    ///         if let ServerMessage::UserTranscript(t) = msg? {
+    ///             if t.user_transcript().to_lowercase().contains("goodbye") {
+    ///                 break;
+    ///             }
+    ///         }
+    ///     }
+    ///     conversation.end().await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn end(mut self) -> Result<()> {
+        self.close();
+        self.pending.clear();
+        while let Some(msg) = self.messages.next().await {
+            msg?;
+        }
+        Ok(())
+    }
+    /// Discards the agent's audio that was received but not yet yielded,
+    /// returning the number of discarded chunks
+    ///
+    /// Done automatically when the agent is [interrupted](ServerMessage::Interruption),
+    /// call it when the user barges in before the server noticed, e.g. on local voice activity.
+    pub fn interrupt(&mut self) -> usize {
+        self.receive_ready();
+        let before = self.pending.len();
+        self.pending
+            .retain(|msg| !matches!(msg, Ok(ServerMessage::Audio(_))));
+        before - self.pending.len()
+    }
+    /// Moves the messages that already arrived to the pending queue, dropping the audio
+    /// that an interruption made obsolete
+    fn receive_ready(&mut self) {
+        while !self.ended {
+            match self.messages.try_recv() {
+                Ok(msg) => self.queue(msg),
+                Err(TryRecvError::Closed) => self.ended = true,
+                Err(TryRecvError::Empty) => break,
+            }
+        }
+    }
+    fn queue(&mut self, msg: Result<ServerMessage>) {
+        if let Ok(ServerMessage::Interruption(interruption)) = &msg {
+            let event_id = interruption.event_id();
+            self.pending.retain(|pending| match pending {
+                Ok(ServerMessage::Audio(audio)) => audio.event_id() > event_id,
+                _ => true,
+            });
+        }
+        self.pending.push_back(msg);
+    }
+    /// Dispatches the messages of the conversation to the handler until it ends
+    ///
+    /// Returns the first error, which also ends the conversation.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use elevenlabs_rs::*;
+    /// use elevenlabs_rs::endpoints::convai::client::{Conversation, ConversationHandler};
+    /// use elevenlabs_rs::endpoints::convai::server_messages::{AgentResponse, UserTranscript};
+    ///
+    /// #[derive(Default)]
+    /// struct Transcript(Vec<String>);
+    ///
+    /// impl ConversationHandler for Transcript {
+    ///     fn on_agent_response(&mut self, response: &AgentResponse) {
+    ///         self.0.push(format!("agent: {}", response.agent_response()));
+    ///     }
+    ///     fn on_user_transcript(&mut self, transcript: &UserTranscript) {
+    ///         self.0.push(format!("user: {}", transcript.user_transcript()));
+    ///     }
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     let c = ElevenLabsClient::default()?;
+    ///     let conversation = Conversation::builder("agent_id").connect(&c).await?;
+    ///     let mut transcript = Transcript::default();
+    ///     conversation.run(&mut transcript).await?;
+    ///     println!("{}", transcript.0.join("\n"));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn run<H: ConversationHandler>(mut self, handler: &mut H) -> Result<()> {
+        while let Some(msg) = self.next().await {
+            dispatch(handler, &msg?);
+        }
+        Ok(())
+    }
+}
+
+/// Handles the messages of a [Conversation], see [Conversation::run]
+///
+/// Every method does nothing by default.
+/// Pings are answered by the client, unless [disabled](ConversationBuilder::with_auto_pong),
+/// and are not passed on.
+pub trait ConversationHandler {
+    fn on_conversation_initiation_metadata(&mut self, _metadata: &ConversationInitiationMetadata) {}
+    fn on_user_transcript(&mut self, _transcript: &UserTranscript) {}
+    fn on_agent_response(&mut self, _response: &AgentResponse) {}
+    fn on_agent_response_correction(&mut self, _correction: &AgentResponseCorrection) {}
+    fn on_audio(&mut self, _audio: &Audio) {}
+    fn on_interruption(&mut self, _interruption: &Interruption) {}
+    /// Called for every tool call, including those of registered [ClientTools]
+    /// which are answered by the client
+    fn on_tool_call(&mut self, _call: &ClientToolCall) {}
+    fn on_vad_score(&mut self, _score: &VadScore) {}
+    fn on_tentative_agent_response(&mut self, _response: &TentativeAgentResponse) {}
+    fn on_reconnected(&mut self, _reconnected: &Reconnected) {}
+}
+
+/// Does nothing, for callers that are not interested in the messages
+impl ConversationHandler for () {}
+
+/// Passes a message to the matching method of the handler
+pub(crate) fn dispatch<H: ConversationHandler>(handler: &mut H, msg: &ServerMessage) {
+    match msg {
+        ServerMessage::ConversationInitiationMetadata(metadata) => {
+            handler.on_conversation_initiation_metadata(metadata)
+        }
+        ServerMessage::UserTranscript(transcript) => handler.on_user_transcript(transcript),
+        ServerMessage::AgentResponse(response) => handler.on_agent_response(response),
+        ServerMessage::AgentResponseCorrection(correction) => {
+            handler.on_agent_response_correction(correction)
+        }
+        ServerMessage::Audio(audio) => handler.on_audio(audio),
+        ServerMessage::Interruption(interruption) => handler.on_interruption(interruption),
+        ServerMessage::ClientToolCall(call) => handler.on_tool_call(call),
+        ServerMessage::VadScore(score) => handler.on_vad_score(score),
+        ServerMessage::InternalTentativeAgentResponse(response) => {
+            handler.on_tentative_agent_response(response)
+        }
+        ServerMessage::Reconnected(reconnected) => handler.on_reconnected(reconnected),
+        ServerMessage::Ping(_) | ServerMessage::Unknown => {}
+    }
+}
+
+impl Stream for Conversation {
+    type Item = Result<ServerMessage>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receive_ready();
+        if let Some(msg) = self.pending.pop_front() {
+            return Poll::Ready(Some(msg));
+        }
+        if self.ended {
+            return Poll::Ready(None);
+        }
+        // Registers the waker, the message it may return is queued like any other
+        match self.messages.poll_next_unpin(cx) {
+            Poll::Ready(Some(msg)) => {
+                self.queue(msg);
+                self.receive_ready();
+                Poll::Ready(self.pending.pop_front())
+            }
+            Poll::Ready(None) => {
+                self.ended = true;
+                Poll::Ready(None)
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+impl fmt::Debug for Conversation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Conversation").finish_non_exhaustive()
+    }
+}
+
+/// Sends messages to an ongoing [Conversation]
+#[derive(Clone, Debug)]
+pub struct ConversationHandle {
+    tx: UnboundedSender<ClientMessage>,
+}
+
+impl ConversationHandle {
+    pub fn send(&self, message: ClientMessage) -> Result<()> {
+        self.tx
+            .unbounded_send(message)
+            .map_err(|_| ConvAIError::WebSocketError("conversation has ended".to_string()))?;
+        Ok(())
+    }
+    /// Sends the user's audio, in the conversation's
+    /// [input format](super::server_messages::ConversationInitiationMetadata::user_input_audio_format)
+    pub fn send_audio(&self, audio: &[u8]) -> Result<()> {
+        self.send(ClientMessage::user_audio_chunk(audio))
+    }
+    /// Tells the agent something without interrupting it
+    ///
+    /// # Example
+    /// ```no_run
+    /// use elevenlabs_rs::*;
+    /// use elevenlabs_rs::endpoints::convai::client::Conversation;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     let c = ElevenLabsClient::default()?;
+    ///     let conversation = Conversation::builder("agent_id").connect(&c).await?;
+    ///     let handle = conversation.handle();
+    ///     handle.send_contextual_update("The user opened the pricing page")?;
+    ///     handle.send_user_message("How much is the pro plan?")?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn send_contextual_update<T: Into<String>>(&self, text: T) -> Result<()> {
+        self.send(ClientMessage::contextual_update(text))
+    }
+    /// Sends a user turn as text, the agent replies as it would to speech
+    pub fn send_user_message<T: Into<String>>(&self, text: T) -> Result<()> {
+        self.send(ClientMessage::user_message(text))
+    }
+    /// Ends the conversation
+    pub fn close(&self) {
+        self.tx.close_channel();
+    }
+    pub fn is_closed(&self) -> bool {
+        self.tx.is_closed()
+    }
+}
+
+/// Reconnects a conversation whose connection dropped
+///
+/// A new signed url is fetched and the
+/// [initiation data](ConversationBuilder::with_initiation_data) is sent again,
+/// then a [Reconnected](ServerMessage::Reconnected) message is yielded.
+/// Messages sent while reconnecting are delivered once the new connection is open.
+///
+/// Conversations the server ends, normally or with an error, are not reconnected.
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::client::{Conversation, ReconnectPolicy};
+/// use elevenlabs_rs::endpoints::convai::server_messages::ServerMessage;
+/// use std::time::Duration;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let policy = ReconnectPolicy::default()
+///         .with_max_attempts(10)
+///         .with_max_backoff(Duration::from_secs(5));
+///     let mut conversation = Conversation::builder("agent_id")
+///         .with_reconnect_policy(policy)
+///         .connect(&c)
+///         .await?;
+///
+///     while let Some(msg) = conversation.next().await {
+///         if let ServerMessage::Reconnected(r) = msg? {
+///             println!("reconnected after {} attempts", r.attempts());
+///         }
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ReconnectPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        ReconnectPolicy {
+            max_attempts: 5,
+            initial_backoff: Duration::from_millis(250),
+            max_backoff: Duration::from_secs(10),
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+    pub fn with_initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+    pub fn with_max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    fn backoff(&self, attempt: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_backoff)
+    }
+}
+
+type WebSocket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// How a connection ended
+enum Disconnect {
+    /// Closed normally, by either side, or the conversation was dropped
+    Closed,
+    /// Closed by the server with an error
+    Failed(Box<dyn std::error::Error + Send + Sync>),
+    /// Lost without a close frame
+    Dropped(Box<dyn std::error::Error + Send + Sync>),
+}
+
+/// What is needed to open a new connection
+struct Reconnect {
+    client: ElevenLabsClient,
+    agent_id: AgentID,
+    public: bool,
+    policy: ReconnectPolicy,
+}
+
+struct Session {
+    initiation_data: ConversationInitiationClientData,
+    tools: ClientTools,
+    reconnect: Option<Reconnect>,
+    auto_pong: bool,
+    keepalive: Option<Duration>,
+    cancellation: Option<CancellationToken>,
+    out_tx: UnboundedSender<ClientMessage>,
+    out_rx: UnboundedReceiver<ClientMessage>,
+    msg_tx: UnboundedSender<Result<ServerMessage>>,
+    /// Dropped with the session, which cancels the tools still running
+    tool_tasks: JoinSet<()>,
+}
+
+impl Session {
+    async fn run(mut self, mut ws: WebSocket) {
+        loop {
+            let error = match self.serve(&mut ws).await {
+                Disconnect::Closed => break,
+                Disconnect::Failed(e) => {
+                    let _ = self.msg_tx.unbounded_send(Err(e));
+                    break;
+                }
+                Disconnect::Dropped(e) => e,
+            };
+            let reconnected = tokio::select! {
+                reconnected = self.reconnect() => reconnected,
+                _ = cancelled(&self.cancellation) => {
+                    let _ = self.msg_tx.unbounded_send(Err(Box::new(Error::Cancelled)));
+                    break;
+                }
+            };
+            match reconnected {
+                Some(Ok((new_ws, attempts))) => {
+                    ws = new_ws;
+                    let reconnected = ServerMessage::Reconnected(Reconnected { attempts });
+                    if self.msg_tx.unbounded_send(Ok(reconnected)).is_err() {
+                        break;
+                    }
+                }
+                Some(Err(reconnect_error)) => {
+                    let _ = self.msg_tx.unbounded_send(Err(error));
+                    let _ = self.msg_tx.unbounded_send(Err(reconnect_error));
+                    break;
+                }
+                None => {
+                    let _ = self.msg_tx.unbounded_send(Err(error));
+                    break;
+                }
+            }
+        }
+        self.out_tx.close_channel();
+    }
+
+    /// Forwards messages both ways until the connection ends
+    async fn serve(&mut self, ws: &mut WebSocket) -> Disconnect {
+        let mut keepalive = self.keepalive.map(|period| {
+            let mut interval = tokio::time::interval_at(Instant::now() + period, period);
+            interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            interval
+        });
+        loop {
+            tokio::select! {
+                msg = self.out_rx.next() => {
+                    let Some(msg) = msg else {
+                        // The conversation was closed
+                        return close(ws).await;
+                    };
+                    if let Some(keepalive) = &mut keepalive {
+                        keepalive.reset();
+                    }
+                    let Ok(json) = msg.json() else {
+                        continue;
+                    };
+                    if let Err(e) = ws.send(Message::text(json)).await {
+                        return Disconnect::Dropped(e.into());
+                    }
+                }
+                msg = ws.next() => match msg {
+                    Some(Ok(Message::Text(text))) => {
+                        let server_msg = ServerMessage::from_json(&text);
+                        match &server_msg {
+                            Ok(ServerMessage::Ping(ping)) if self.auto_pong => {
+                                let _ = self.out_tx.unbounded_send(ClientMessage::pong(ping.event_id()));
+                            }
+                            Ok(ServerMessage::ClientToolCall(call)) => self.call_tool(call),
+                            _ => {}
+                        }
+                        if self.msg_tx.unbounded_send(server_msg).is_err() {
+                            // The conversation was dropped
+                            return close(ws).await;
+                        }
+                    }
+                    Some(Ok(Message::Close(close_frame))) => {
+                        return match close_frame {
+                            Some(frame) if frame.code == CloseCode::Normal => Disconnect::Closed,
+                            Some(frame) => Disconnect::Failed(Box::new(
+                                WebSocketError::NonNormalCloseCode(frame.reason.to_string()),
+                            )),
+                            None => Disconnect::Dropped(Box::new(
+                                WebSocketError::ClosedWithoutCloseFrame,
+                            )),
+                        };
+                    }
+                    Some(Ok(_)) => {}
+                    Some(Err(e)) => return Disconnect::Dropped(e.into()),
+                    None => {
+                        return Disconnect::Dropped(Box::new(WebSocketError::ClosedWithoutCloseFrame))
+                    }
+                },
+                Some(_) = self.tool_tasks.join_next(), if !self.tool_tasks.is_empty() => {}
+                _ = tick(&mut keepalive) => {
+                    let _ = self.out_tx.unbounded_send(ClientMessage::UserActivity);
+                }
+                _ = cancelled(&self.cancellation) => {
+                    close(ws).await;
+                    return Disconnect::Failed(Box::new(Error::Cancelled));
+                }
+            }
+        }
+    }
+
+    fn call_tool(&mut self, call: &ClientToolCall) {
+        let Some(result) = self.tools.call(call) else {
+            return;
+        };
+        let out_tx = self.out_tx.clone();
+        let tool_call_id = call.tool_call_id().to_string();
+        self.tool_tasks.spawn(async move {
+            let msg = match result.await {
+                Ok(value) => ClientMessage::client_tool_result(tool_call_id, value),
+                Err(e) => ClientMessage::client_tool_error(tool_call_id, e.to_string()),
+            };
+            let _ = out_tx.unbounded_send(msg);
+        });
+    }
+
+    /// Returns the new connection and the attempts it took,
+    /// or `None` if there is no policy or the conversation was dropped meanwhile
+    async fn reconnect(&self) -> Option<Result<(WebSocket, u32)>> {
+        let Reconnect {
+            client,
+            agent_id,
+            public,
+            policy,
+        } = self.reconnect.as_ref()?;
+        let mut attempt = 0;
+        loop {
+            if attempt >= policy.max_attempts || self.msg_tx.is_closed() {
+                return None;
+            }
+            tokio::time::sleep(policy.backoff(attempt)).await;
+            attempt += 1;
+            let result = async {
+                let url = conversation_url(client, agent_id.as_str(), *public).await?;
+                open(&url, &self.initiation_data).await
+            }
+            .await;
+            match result {
+                Ok(ws) => return Some(Ok((ws, attempt))),
+                Err(e) if attempt >= policy.max_attempts => return Some(Err(e)),
+                Err(_) => {}
+            }
+        }
+    }
+}
+
+/// The agent's public url, or a signed url
+async fn conversation_url(
+    client: &ElevenLabsClient,
+    agent_id: &str,
+    public: bool,
+) -> Result<String> {
+    if !public {
+        let signed_url = client.hit(GetSignedUrl::new(agent_id)).await?;
+        return Ok(signed_url.signed_url().to_string());
+    }
+    let mut url = convai_url(CONVERSATION_PATH);
+    url.set_scheme("wss").unwrap();
+    url.query_pairs_mut().append_pair(AGENT_ID_QUERY, agent_id);
+    client.rebase_ws(url.to_string())
+}
+
+/// Completes on the next tick of the interval, never without one
+async fn tick(interval: &mut Option<Interval>) {
+    match interval {
+        Some(interval) => {
+            interval.tick().await;
+        }
+        None => std::future::pending().await,
+    }
+}
+
+/// Completes when the token is cancelled, never without one
+async fn cancelled(token: &Option<CancellationToken>) {
+    match token {
+        Some(token) => token.cancelled().await,
+        None => std::future::pending().await,
+    }
+}
+
+/// Sends a normal close frame and waits for the server to acknowledge it
+async fn close(ws: &mut WebSocket) -> Disconnect {
+    let frame = CloseFrame {
+        code: CloseCode::Normal,
+        reason: "".into(),
+    };
+    if let Err(e) = ws.close(Some(frame)).await {
+        return Disconnect::Failed(e.into());
+    }
+    let drain = async { while let Some(Ok(_)) = ws.next().await {} };
+    if tokio::time::timeout(CLOSE_TIMEOUT, drain).await.is_err() {
+        return Disconnect::Failed(Box::new(WebSocketError::CloseTimeout));
+    }
+    Disconnect::Closed
+}
+
+/// Opens a connection and sends the initiation data
+async fn open(url: &str, initiation_data: &ConversationInitiationClientData) -> Result<WebSocket> {
+    let span = Span::websocket(&ws_path(url));
+    let (mut ws, resp) = match span.instrument(connect_async(url)).await {
+        Ok(connected) => connected,
+        Err(e) => {
+            span.record_error(&e);
+            return Err(e.into());
+        }
+    };
+    span.record_response(resp.status(), resp.headers());
+    let msg = ClientMessage::ConversationInitiationClientData(initiation_data.clone());
+    ws.send(Message::text(msg.json()?)).await?;
+    Ok(ws)
+}
@@ -0,0 +1,78 @@
+//! Messages sent by the client during a conversation
+//!
+//! See the [ElevenLabs docs](https://elevenlabs.io/docs/conversational-ai/api-reference/conversational-ai/websocket) for more information.
+use super::*;
+
+/// A message sent to the agent
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ClientMessage {
+    /// Sent once, when the conversation starts
+    ConversationInitiationClientData(ConversationInitiationClientData),
+    Pong {
+        event_id: u64,
+    },
+    ClientToolResult {
+        tool_call_id: String,
+        result: Value,
+        is_error: bool,
+    },
+    /// Context for the agent that does not interrupt it, e.g. what the user is looking at
+    ContextualUpdate {
+        text: String,
+    },
+    /// A user turn sent as text instead of audio
+    UserMessage {
+        text: String,
+    },
+    /// Tells the agent the user is still there, e.g. while they listen to hold music,
+    /// so the conversation is not ended for inactivity
+    UserActivity,
+    /// Rates the agent turn that the [audio event](super::server_messages::Audio) `event_id` belongs to
+    Feedback {
+        score: FeedbackScore,
+        event_id: u64,
+    },
+    /// Base64 encoded audio in the conversation's input format
+    #[serde(untagged)]
+    UserAudioChunk {
+        user_audio_chunk: String,
+    },
+}
+
+impl ClientMessage {
+    pub fn user_audio_chunk(audio: &[u8]) -> Self {
+        ClientMessage::UserAudioChunk {
+            user_audio_chunk: BASE64_STANDARD.encode(audio),
+        }
+    }
+    pub fn pong(event_id: u64) -> Self {
+        ClientMessage::Pong { event_id }
+    }
+    pub fn client_tool_result<T: Into<String>>(tool_call_id: T, result: Value) -> Self {
+        ClientMessage::ClientToolResult {
+            tool_call_id: tool_call_id.into(),
+            result,
+            is_error: false,
+        }
+    }
+    pub fn client_tool_error<T: Into<String>, E: Into<String>>(tool_call_id: T, error: E) -> Self {
+        ClientMessage::ClientToolResult {
+            tool_call_id: tool_call_id.into(),
+            result: Value::String(error.into()),
+            is_error: true,
+        }
+    }
+    pub fn contextual_update<T: Into<String>>(text: T) -> Self {
+        ClientMessage::ContextualUpdate { text: text.into() }
+    }
+    pub fn user_message<T: Into<String>>(text: T) -> Self {
+        ClientMessage::UserMessage { text: text.into() }
+    }
+    pub fn feedback(event_id: u64, score: FeedbackScore) -> Self {
+        ClientMessage::Feedback { score, event_id }
+    }
+    pub fn json(&self) -> Result<String> {
+        serde_json::to_string(self).map_err(Into::into)
+    }
+}
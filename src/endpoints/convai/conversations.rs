@@ -0,0 +1,450 @@
+//! The conversations endpoints
+//!
+//! A conversation can be rated as a whole once it ended. Single agent turns are rated
+//! during the call, with a [feedback message](super::client_messages::ClientMessage::Feedback),
+//! and their ratings are part of the [transcript](GetConversationDetailsResponse::transcript).
+//!
+//! See the [ElevenLabs docs](https://elevenlabs.io/docs/api-reference/conversations/get) for more information.
+use super::*;
+use crate::webhooks::{CallAnalysis, CallMetadata, CallSuccessful, TranscriptRole, TranscriptTurn};
+use std::fmt::Write;
+
+const CONVERSATIONS_PATH: &str = "/conversations";
+const FEEDBACK_PATH: &str = "/feedback";
+
+const AGENT_ID_QUERY: &str = "agent_id";
+const CALL_SUCCESSFUL_QUERY: &str = "call_successful";
+const CALL_START_AFTER_QUERY: &str = "call_start_after_unix";
+const CALL_START_BEFORE_QUERY: &str = "call_start_before_unix";
+
+/// List conversations, newest first
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::conversations::*;
+/// use futures_util::{pin_mut, StreamExt};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let query = ConversationsQuery::default()
+///         .with_agent_id("agent_id")
+///         .with_call_start_after_unix(1_700_000_000)
+///         .with_page_size(100);
+///     let conversations = c.paginate(ListConversations::with_query(query));
+///     pin_mut!(conversations);
+///     while let Some(conversation) = conversations.next().await {
+///         let conversation = conversation?;
+///         println!("{}: {:?}", conversation.conversation_id(), conversation.call_successful());
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ListConversations(ConversationsQuery);
+
+impl ListConversations {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn with_query(query: ConversationsQuery) -> Self {
+        Self(query)
+    }
+}
+
+/// List conversations query
+#[derive(Clone, Debug, Default)]
+pub struct ConversationsQuery {
+    page: PageQuery,
+    agent_id: Option<AgentID>,
+    call_successful: Option<String>,
+    call_start_after_unix: Option<i64>,
+    call_start_before_unix: Option<i64>,
+}
+
+impl ConversationsQuery {
+    pub fn with_cursor<T: Into<String>>(mut self, cursor: T) -> Self {
+        self.page = self.page.with_cursor(cursor);
+        self
+    }
+    /// Defaults to 30, the maximum is 100
+    pub fn with_page_size(mut self, page_size: u32) -> Self {
+        self.page = self.page.with_page_size(page_size);
+        self
+    }
+    pub fn with_agent_id<T: Into<AgentID>>(mut self, agent_id: T) -> Self {
+        self.agent_id = Some(agent_id.into());
+        self
+    }
+    /// Only conversations that met, or did not meet, the agent's evaluation criteria
+    pub fn with_call_successful(mut self, successful: bool) -> Self {
+        self.call_successful = Some(if successful { "success" } else { "failure" }.to_string());
+        self
+    }
+    pub fn with_call_start_after_unix(mut self, unix_secs: i64) -> Self {
+        self.call_start_after_unix = Some(unix_secs);
+        self
+    }
+    pub fn with_call_start_before_unix(mut self, unix_secs: i64) -> Self {
+        self.call_start_before_unix = Some(unix_secs);
+        self
+    }
+}
+
+impl Endpoint for ListConversations {
+    type ResponseBody = ListConversationsResponse;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        let mut url = convai_url(CONVERSATIONS_PATH);
+        self.0.page.append_to(&mut url);
+        {
+            let mut pairs = url.query_pairs_mut();
+            if let Some(agent_id) = &self.0.agent_id {
+                pairs.append_pair(AGENT_ID_QUERY, agent_id.as_str());
+            }
+            if let Some(call_successful) = &self.0.call_successful {
+                pairs.append_pair(CALL_SUCCESSFUL_QUERY, call_successful);
+            }
+            if let Some(after) = self.0.call_start_after_unix {
+                pairs.append_pair(CALL_START_AFTER_QUERY, &after.to_string());
+            }
+            if let Some(before) = self.0.call_start_before_unix {
+                pairs.append_pair(CALL_START_BEFORE_QUERY, &before.to_string());
+            }
+        }
+        trim_query(url)
+    }
+}
+
+impl Paginated for ListConversations {
+    type Item = ConversationSummary;
+
+    fn next_page(self, page: Self::ResponseBody) -> (Vec<Self::Item>, Option<Self>) {
+        let next = match (page.has_more, &page.next_cursor) {
+            (true, Some(cursor)) => Some(Self(self.0.with_cursor(cursor))),
+            _ => None,
+        };
+        (page.conversations, next)
+    }
+}
+
+/// List conversations response
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListConversationsResponse {
+    conversations: Vec<ConversationSummary>,
+    next_cursor: Option<String>,
+    has_more: bool,
+}
+
+impl ListConversationsResponse {
+    pub fn conversations(&self) -> &[ConversationSummary] {
+        &self.conversations
+    }
+    pub fn next_cursor(&self) -> Option<&str> {
+        self.next_cursor.as_deref()
+    }
+    pub fn has_more(&self) -> bool {
+        self.has_more
+    }
+}
+
+/// A conversation as listed by [ListConversations]
+#[derive(Clone, Debug, Deserialize)]
+pub struct ConversationSummary {
+    agent_id: String,
+    agent_name: Option<String>,
+    conversation_id: String,
+    start_time_unix_secs: u64,
+    call_duration_secs: u32,
+    message_count: u32,
+    status: String,
+    call_successful: CallSuccessful,
+    #[serde(flatten, deserialize_with = "crate::shared::extras::deserialize")]
+    extra: Map<String, Value>,
+}
+
+impl ConversationSummary {
+    pub fn agent_id(&self) -> &str {
+        &self.agent_id
+    }
+    pub fn agent_name(&self) -> Option<&str> {
+        self.agent_name.as_deref()
+    }
+    pub fn conversation_id(&self) -> &str {
+        &self.conversation_id
+    }
+    pub fn start_time_unix_secs(&self) -> u64 {
+        self.start_time_unix_secs
+    }
+    /// [start_time_unix_secs](Self::start_time_unix_secs) as a date
+    #[cfg(feature = "chrono")]
+    pub fn start_time(&self) -> DateTime<Utc> {
+        utc(self.start_time_unix_secs())
+    }
+    pub fn call_duration_secs(&self) -> u32 {
+        self.call_duration_secs
+    }
+    pub fn message_count(&self) -> u32 {
+        self.message_count
+    }
+    /// e.g. `in-progress`, `processing`, `done` or `failed`
+    pub fn status(&self) -> &str {
+        &self.status
+    }
+    pub fn call_successful(&self) -> &CallSuccessful {
+        &self.call_successful
+    }
+    /// Fields of the response this version of the crate does not know about yet
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+}
+
+/// Get the transcript, metadata and analysis of a conversation
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::conversations::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let resp = c.hit(GetConversationDetails::new("conversation_id")).await?;
+///     for turn in resp.transcript() {
+///         let score = turn.feedback().map(|f| f.score());
+///         println!("{:?}: {} {:?}", turn.role(), turn.message().unwrap_or_default(), score);
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct GetConversationDetails(ConversationID);
+
+impl GetConversationDetails {
+    pub fn new<T: Into<ConversationID>>(conversation_id: T) -> Self {
+        Self(conversation_id.into())
+    }
+}
+
+impl ConversationID {
+    fn url(&self, path: &str) -> Url {
+        convai_url(&format!("{}/{}{}", CONVERSATIONS_PATH, self.0, path))
+    }
+}
+
+impl Endpoint for GetConversationDetails {
+    type ResponseBody = GetConversationDetailsResponse;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        self.0.url("")
+    }
+}
+
+/// Get conversation details response
+#[derive(Clone, Debug, Deserialize)]
+pub struct GetConversationDetailsResponse {
+    agent_id: String,
+    conversation_id: String,
+    status: String,
+    user_id: Option<String>,
+    #[serde(default)]
+    transcript: Vec<TranscriptTurn>,
+    metadata: CallMetadata,
+    analysis: Option<CallAnalysis>,
+    #[serde(default)]
+    has_audio: bool,
+    #[serde(flatten, deserialize_with = "crate::shared::extras::deserialize")]
+    extra: Map<String, Value>,
+}
+
+impl GetConversationDetailsResponse {
+    pub fn agent_id(&self) -> &str {
+        &self.agent_id
+    }
+    pub fn conversation_id(&self) -> &str {
+        &self.conversation_id
+    }
+    /// e.g. `in-progress`, `processing`, `done` or `failed`
+    pub fn status(&self) -> &str {
+        &self.status
+    }
+    pub fn user_id(&self) -> Option<&str> {
+        self.user_id.as_deref()
+    }
+    pub fn transcript(&self) -> &[TranscriptTurn] {
+        &self.transcript
+    }
+    pub fn metadata(&self) -> &CallMetadata {
+        &self.metadata
+    }
+    /// `None` until the conversation has been processed
+    pub fn analysis(&self) -> Option<&CallAnalysis> {
+        self.analysis.as_ref()
+    }
+    pub fn has_audio(&self) -> bool {
+        self.has_audio
+    }
+    /// The transcript as Markdown, one paragraph per turn with its tool calls and results as a list
+    pub fn transcript_as_markdown(&self) -> String {
+        let mut md = String::new();
+        for turn in &self.transcript {
+            let _ = write!(
+                md,
+                "**{}** _{}_",
+                role_name(turn.role()),
+                timestamp(turn.time_in_call_secs())
+            );
+            if let Some(message) = turn.message() {
+                let _ = write!(md, ": {}", message);
+            }
+            md.push('\n');
+            for (name, details) in tool_lines(turn) {
+                let _ = writeln!(md, "- {} `{}`", name, details);
+            }
+            md.push('\n');
+        }
+        md
+    }
+    /// The transcript as plain text, one line per turn and per tool call or result,
+    /// e.g. `[01:05] Agent: Your order has shipped.`
+    pub fn transcript_as_text(&self) -> String {
+        let mut text = String::new();
+        for turn in &self.transcript {
+            let time = timestamp(turn.time_in_call_secs());
+            let role = role_name(turn.role());
+            for (name, details) in tool_lines(turn) {
+                let _ = writeln!(text, "[{}] {} {}: {}", time, role, name, details);
+            }
+            if let Some(message) = turn.message() {
+                let _ = writeln!(text, "[{}] {}: {}", time, role, message);
+            }
+        }
+        text
+    }
+    /// The transcript as JSON Lines, one [turn](TranscriptTurn) per line with its tool calls and results
+    pub fn transcript_as_jsonl(&self) -> Result<String> {
+        let mut jsonl = String::new();
+        for turn in &self.transcript {
+            jsonl.push_str(&serde_json::to_string(turn)?);
+            jsonl.push('\n');
+        }
+        Ok(jsonl)
+    }
+    /// Fields of the response this version of the crate does not know about yet
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+}
+
+fn role_name(role: &TranscriptRole) -> &'static str {
+    match role {
+        TranscriptRole::Agent => "Agent",
+        TranscriptRole::User => "User",
+    }
+}
+
+/// `mm:ss`, or `h:mm:ss` for calls of an hour or longer
+fn timestamp(secs: u32) -> String {
+    match secs / 3600 {
+        0 => format!("{:02}:{:02}", secs / 60, secs % 60),
+        hours => format!("{}:{:02}:{:02}", hours, secs / 60 % 60, secs % 60),
+    }
+}
+
+/// A label and the JSON details of each tool call and tool result of a turn
+fn tool_lines(turn: &TranscriptTurn) -> Vec<(String, String)> {
+    let tool_name = |value: &Value| {
+        value
+            .get("tool_name")
+            .and_then(Value::as_str)
+            .unwrap_or("tool")
+            .to_string()
+    };
+    let calls = turn.tool_calls().iter().map(|call| {
+        let params = call.get("params_as_json").unwrap_or(call);
+        let params = params
+            .as_str()
+            .map_or_else(|| params.to_string(), String::from);
+        (format!("called {}", tool_name(call)), params)
+    });
+    let results = turn.tool_results().iter().map(|result| {
+        let value = result.get("result_value").unwrap_or(result);
+        let value = value
+            .as_str()
+            .map_or_else(|| value.to_string(), String::from);
+        let is_error = result.get("is_error").and_then(Value::as_bool) == Some(true);
+        let label = if is_error {
+            "got an error from"
+        } else {
+            "got a result from"
+        };
+        (format!("{} {}", label, tool_name(result)), value)
+    });
+    calls.chain(results).collect()
+}
+
+/// Rate a conversation as a whole
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::FeedbackScore;
+/// use elevenlabs_rs::endpoints::convai::conversations::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     c.hit(SendConversationFeedback::new("conversation_id", FeedbackScore::Like)).await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SendConversationFeedback {
+    conversation_id: ConversationID,
+    body: FeedbackBody,
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct FeedbackBody {
+    feedback: FeedbackScore,
+}
+
+impl SendConversationFeedback {
+    pub fn new<T: Into<ConversationID>>(conversation_id: T, feedback: FeedbackScore) -> Self {
+        Self {
+            conversation_id: conversation_id.into(),
+            body: FeedbackBody { feedback },
+        }
+    }
+}
+
+impl Endpoint for SendConversationFeedback {
+    type ResponseBody = ();
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(serde_json::to_value(&self.body)?))
+    }
+    async fn response_body(self, _resp: Response) -> Result<Self::ResponseBody> {
+        Ok(())
+    }
+    fn url(&self) -> Url {
+        self.conversation_id.url(FEEDBACK_PATH)
+    }
+}
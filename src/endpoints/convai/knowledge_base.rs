@@ -0,0 +1,753 @@
+//! The knowledge base endpoints
+//!
+//! Documents in the knowledge base can be attached to agents and, once indexed, retrieved with RAG.
+//!
+//! See the [ElevenLabs docs](https://elevenlabs.io/docs/api-reference/knowledge-base/list) for more information.
+use super::*;
+
+const KNOWLEDGE_BASE_PATH: &str = "/knowledge-base";
+const URL_PATH: &str = "/url";
+const FILE_PATH: &str = "/file";
+const TEXT_PATH: &str = "/text";
+const RAG_INDEX_PATH: &str = "/rag-index";
+const DEPENDENT_AGENTS_PATH: &str = "/dependent-agents";
+
+const SEARCH_QUERY: &str = "search";
+const SHOW_ONLY_OWNED_DOCUMENTS_QUERY: &str = "show_only_owned_documents";
+const TYPES_QUERY: &str = "types";
+const FORCE_QUERY: &str = "force";
+
+/// Create a knowledge base document by scraping a webpage
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::knowledge_base::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let body = CreateFromUrlBody::new("https://elevenlabs.io/docs").with_name("Docs");
+///     let resp = c.hit(CreateKnowledgeBaseFromUrl::new(body)).await?;
+///     println!("{:?}", resp);
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct CreateKnowledgeBaseFromUrl(CreateFromUrlBody);
+
+impl CreateKnowledgeBaseFromUrl {
+    pub fn new(body: CreateFromUrlBody) -> Self {
+        Self(body)
+    }
+}
+
+/// Create from url body
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateFromUrlBody {
+    url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+}
+
+impl CreateFromUrlBody {
+    pub fn new<T: Into<String>>(url: T) -> Self {
+        Self {
+            url: url.into(),
+            name: None,
+        }
+    }
+    pub fn with_name<T: Into<String>>(mut self, name: T) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+}
+
+impl Endpoint for CreateKnowledgeBaseFromUrl {
+    type ResponseBody = CreateKnowledgeBaseResponse;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(serde_json::to_value(&self.0)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        convai_url(&format!("{}{}", KNOWLEDGE_BASE_PATH, URL_PATH))
+    }
+}
+
+/// Create a knowledge base document from a file
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::knowledge_base::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let body = CreateFromFileBody::new("handbook.pdf").with_name("Handbook");
+///     let resp = c.hit(CreateKnowledgeBaseFromFile::new(body)).await?;
+///     println!("{:?}", resp);
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct CreateKnowledgeBaseFromFile(CreateFromFileBody);
+
+impl CreateKnowledgeBaseFromFile {
+    pub fn new(body: CreateFromFileBody) -> Self {
+        Self(body)
+    }
+}
+
+/// Create from file body
+///
+/// Supported file types are pdf, txt, docx, html and epub.
+#[derive(Clone, Debug)]
+pub struct CreateFromFileBody {
+    file: FileSource,
+    name: Option<String>,
+}
+
+impl CreateFromFileBody {
+    pub fn new<F: Into<FileSource>>(file: F) -> Self {
+        Self {
+            file: file.into(),
+            name: None,
+        }
+    }
+    pub fn with_name<T: Into<String>>(mut self, name: T) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+    fn to_form(&self) -> Result<Form> {
+        let mut form = Form::new().part("file", self.file.to_part()?);
+        if let Some(name) = &self.name {
+            form = form.text("name", name.clone());
+        }
+        Ok(form)
+    }
+}
+
+impl Endpoint for CreateKnowledgeBaseFromFile {
+    type ResponseBody = CreateKnowledgeBaseResponse;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Multipart(self.0.to_form()?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        convai_url(&format!("{}{}", KNOWLEDGE_BASE_PATH, FILE_PATH))
+    }
+}
+
+/// Create a knowledge base document from text
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::knowledge_base::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let body = CreateFromTextBody::new("Opening hours are 9am to 5pm.").with_name("Hours");
+///     let resp = c.hit(CreateKnowledgeBaseFromText::new(body)).await?;
+///     println!("{:?}", resp);
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct CreateKnowledgeBaseFromText(CreateFromTextBody);
+
+impl CreateKnowledgeBaseFromText {
+    pub fn new(body: CreateFromTextBody) -> Self {
+        Self(body)
+    }
+}
+
+/// Create from text body
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateFromTextBody {
+    text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+}
+
+impl CreateFromTextBody {
+    pub fn new<T: Into<String>>(text: T) -> Self {
+        Self {
+            text: text.into(),
+            name: None,
+        }
+    }
+    pub fn with_name<T: Into<String>>(mut self, name: T) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+}
+
+impl Endpoint for CreateKnowledgeBaseFromText {
+    type ResponseBody = CreateKnowledgeBaseResponse;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(serde_json::to_value(&self.0)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        convai_url(&format!("{}{}", KNOWLEDGE_BASE_PATH, TEXT_PATH))
+    }
+}
+
+/// Create knowledge base document response
+#[derive(Clone, Debug, Deserialize)]
+pub struct CreateKnowledgeBaseResponse {
+    id: String,
+    name: String,
+}
+
+impl CreateKnowledgeBaseResponse {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// List the knowledge base documents
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::knowledge_base::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let query = KnowledgeBaseQuery::default()
+///         .with_search("pricing")
+///         .with_types(vec![DocumentType::Url]);
+///     let docs = c.paginate(ListKnowledgeBase::with_query(query));
+///     pin_mut!(docs);
+///     while let Some(doc) = docs.next().await {
+///         println!("{}", doc?.name());
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ListKnowledgeBase(KnowledgeBaseQuery);
+
+impl ListKnowledgeBase {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn with_query(query: KnowledgeBaseQuery) -> Self {
+        Self(query)
+    }
+}
+
+/// List knowledge base query
+#[derive(Clone, Debug, Default)]
+pub struct KnowledgeBaseQuery {
+    page: PageQuery,
+    search: Option<String>,
+    show_only_owned_documents: Option<bool>,
+    types: Vec<DocumentType>,
+}
+
+impl KnowledgeBaseQuery {
+    pub fn with_cursor<T: Into<String>>(mut self, cursor: T) -> Self {
+        self.page = self.page.with_cursor(cursor);
+        self
+    }
+    /// Defaults to 30, the maximum is 100
+    pub fn with_page_size(mut self, page_size: u32) -> Self {
+        self.page = self.page.with_page_size(page_size);
+        self
+    }
+    /// Only documents whose name starts with `search`
+    pub fn with_search<T: Into<String>>(mut self, search: T) -> Self {
+        self.search = Some(search.into());
+        self
+    }
+    pub fn with_show_only_owned_documents(mut self, show_only_owned_documents: bool) -> Self {
+        self.show_only_owned_documents = Some(show_only_owned_documents);
+        self
+    }
+    pub fn with_types(mut self, types: Vec<DocumentType>) -> Self {
+        self.types = types;
+        self
+    }
+}
+
+impl Endpoint for ListKnowledgeBase {
+    type ResponseBody = ListKnowledgeBaseResponse;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        let mut url = convai_url(KNOWLEDGE_BASE_PATH);
+        self.0.page.append_to(&mut url);
+        {
+            let mut pairs = url.query_pairs_mut();
+            if let Some(search) = &self.0.search {
+                pairs.append_pair(SEARCH_QUERY, search);
+            }
+            if let Some(owned) = self.0.show_only_owned_documents {
+                pairs.append_pair(SHOW_ONLY_OWNED_DOCUMENTS_QUERY, &owned.to_string());
+            }
+            for t in &self.0.types {
+                pairs.append_pair(TYPES_QUERY, t.as_str());
+            }
+        }
+        trim_query(url)
+    }
+}
+
+impl Paginated for ListKnowledgeBase {
+    type Item = KnowledgeBaseDocument;
+
+    fn next_page(self, page: Self::ResponseBody) -> (Vec<Self::Item>, Option<Self>) {
+        let next = match (page.has_more, &page.next_cursor) {
+            (true, Some(cursor)) => Some(Self(self.0.with_cursor(cursor))),
+            _ => None,
+        };
+        (page.documents, next)
+    }
+}
+
+/// List knowledge base response
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListKnowledgeBaseResponse {
+    documents: Vec<KnowledgeBaseDocument>,
+    next_cursor: Option<String>,
+    has_more: bool,
+}
+
+impl ListKnowledgeBaseResponse {
+    pub fn documents(&self) -> &[KnowledgeBaseDocument] {
+        &self.documents
+    }
+    pub fn next_cursor(&self) -> Option<&str> {
+        self.next_cursor.as_deref()
+    }
+    pub fn has_more(&self) -> bool {
+        self.has_more
+    }
+}
+
+/// Get a knowledge base document
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::knowledge_base::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let doc = c.hit(GetKnowledgeBaseDocument::new("documentation_id")).await?;
+///     println!("{:?}", doc.extracted_inner_html());
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct GetKnowledgeBaseDocument(DocumentationID);
+
+impl GetKnowledgeBaseDocument {
+    pub fn new<T: Into<String>>(documentation_id: T) -> Self {
+        Self(DocumentationID(documentation_id.into()))
+    }
+}
+
+#[derive(Clone, Debug)]
+struct DocumentationID(String);
+
+impl Endpoint for GetKnowledgeBaseDocument {
+    type ResponseBody = KnowledgeBaseDocument;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        convai_url(&format!("{}/{}", KNOWLEDGE_BASE_PATH, self.0 .0))
+    }
+}
+
+/// Delete a knowledge base document
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::knowledge_base::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     c.hit(DeleteKnowledgeBaseDocument::new("documentation_id").force()).await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct DeleteKnowledgeBaseDocument {
+    documentation_id: DocumentationID,
+    force: bool,
+}
+
+impl DeleteKnowledgeBaseDocument {
+    pub fn new<T: Into<String>>(documentation_id: T) -> Self {
+        Self {
+            documentation_id: DocumentationID(documentation_id.into()),
+            force: false,
+        }
+    }
+    /// Delete the document even if agents depend on it, it is removed from those agents
+    pub fn force(mut self) -> Self {
+        self.force = true;
+        self
+    }
+}
+
+impl Endpoint for DeleteKnowledgeBaseDocument {
+    type ResponseBody = ();
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+    async fn response_body(self, _resp: Response) -> Result<Self::ResponseBody> {
+        Ok(())
+    }
+    fn url(&self) -> Url {
+        let mut url = convai_url(&format!(
+            "{}/{}",
+            KNOWLEDGE_BASE_PATH, self.documentation_id.0
+        ));
+        if self.force {
+            url.query_pairs_mut().append_pair(FORCE_QUERY, "true");
+        }
+        url
+    }
+}
+
+/// A knowledge base document
+///
+/// Fields specific to one [DocumentType] are `None` for the others,
+/// and documents in a listing do not include their content.
+#[derive(Clone, Debug, Deserialize)]
+pub struct KnowledgeBaseDocument {
+    id: String,
+    name: String,
+    #[serde(rename = "type")]
+    document_type: DocumentType,
+    metadata: DocumentMetadata,
+    #[serde(default)]
+    supported_usages: Vec<String>,
+    access_info: Option<AccessInfo>,
+    url: Option<String>,
+    extracted_inner_html: Option<String>,
+    #[serde(default)]
+    dependent_agents: Vec<DependentAgent>,
+}
+
+impl KnowledgeBaseDocument {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn document_type(&self) -> &DocumentType {
+        &self.document_type
+    }
+    pub fn metadata(&self) -> &DocumentMetadata {
+        &self.metadata
+    }
+    /// `prompt` and/or `auto`
+    pub fn supported_usages(&self) -> &[String] {
+        &self.supported_usages
+    }
+    pub fn access_info(&self) -> Option<&AccessInfo> {
+        self.access_info.as_ref()
+    }
+    /// The scraped url, for [DocumentType::Url]
+    pub fn url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+    pub fn extracted_inner_html(&self) -> Option<&str> {
+        self.extracted_inner_html.as_deref()
+    }
+    pub fn dependent_agents(&self) -> &[DependentAgent] {
+        &self.dependent_agents
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DocumentType {
+    File,
+    Url,
+    Text,
+}
+
+impl DocumentType {
+    fn as_str(&self) -> &str {
+        match self {
+            DocumentType::File => "file",
+            DocumentType::Url => "url",
+            DocumentType::Text => "text",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct DocumentMetadata {
+    created_at_unix_secs: i64,
+    last_updated_at_unix_secs: i64,
+    size_bytes: u64,
+}
+
+impl DocumentMetadata {
+    pub fn created_at_unix_secs(&self) -> i64 {
+        self.created_at_unix_secs
+    }
+    /// [created_at_unix_secs](Self::created_at_unix_secs) as a date
+    #[cfg(feature = "chrono")]
+    pub fn created_at(&self) -> DateTime<Utc> {
+        utc(self.created_at_unix_secs())
+    }
+    pub fn last_updated_at_unix_secs(&self) -> i64 {
+        self.last_updated_at_unix_secs
+    }
+    /// [last_updated_at_unix_secs](Self::last_updated_at_unix_secs) as a date
+    #[cfg(feature = "chrono")]
+    pub fn last_updated_at(&self) -> DateTime<Utc> {
+        utc(self.last_updated_at_unix_secs())
+    }
+    pub fn size_bytes(&self) -> u64 {
+        self.size_bytes
+    }
+}
+
+/// Compute the RAG index of a knowledge base document
+///
+/// Indexing runs in the background, hit this endpoint again to poll its status.
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::knowledge_base::*;
+/// use elevenlabs_rs::utils::{poll_until, PollPolicy};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let endpoint = ComputeRagIndex::new("documentation_id", EmbeddingModel::E5Mistral7bInstruct);
+///     poll_until(
+///         &c,
+///         || endpoint.clone(),
+///         |index| match index.status() {
+///             RagIndexStatus::Succeeded => Ok(true),
+///             RagIndexStatus::Failed => Err("indexing failed".into()),
+///             _ => Ok(false),
+///         },
+///         &PollPolicy::default(),
+///     )
+///     .await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ComputeRagIndex {
+    documentation_id: DocumentationID,
+    body: RagIndexBody,
+}
+
+impl ComputeRagIndex {
+    pub fn new<T: Into<String>>(documentation_id: T, model: EmbeddingModel) -> Self {
+        Self {
+            documentation_id: DocumentationID(documentation_id.into()),
+            body: RagIndexBody { model },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct RagIndexBody {
+    model: EmbeddingModel,
+}
+
+/// The embedding model of a RAG index
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EmbeddingModel {
+    #[serde(rename = "e5_mistral_7b_instruct")]
+    E5Mistral7bInstruct,
+    MultilingualE5LargeInstruct,
+}
+
+impl Endpoint for ComputeRagIndex {
+    type ResponseBody = RagIndexResponse;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(serde_json::to_value(&self.body)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        convai_url(&format!(
+            "{}/{}{}",
+            KNOWLEDGE_BASE_PATH, self.documentation_id.0, RAG_INDEX_PATH
+        ))
+    }
+}
+
+/// Compute RAG index response
+#[derive(Clone, Debug, Deserialize)]
+pub struct RagIndexResponse {
+    id: String,
+    model: EmbeddingModel,
+    status: RagIndexStatus,
+    progress_percentage: f32,
+    document_model_index_usage: Option<IndexUsage>,
+}
+
+impl RagIndexResponse {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+    pub fn model(&self) -> &EmbeddingModel {
+        &self.model
+    }
+    pub fn status(&self) -> &RagIndexStatus {
+        &self.status
+    }
+    pub fn progress_percentage(&self) -> f32 {
+        self.progress_percentage
+    }
+    /// Bytes used by the index
+    pub fn used_bytes(&self) -> Option<u64> {
+        self.document_model_index_usage
+            .as_ref()
+            .map(|u| u.used_bytes)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct IndexUsage {
+    used_bytes: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RagIndexStatus {
+    Created,
+    Processing,
+    Failed,
+    Succeeded,
+    RagLimitExceeded,
+    DocumentTooSmall,
+    #[serde(other)]
+    Unknown,
+}
+
+/// List the agents that depend on a knowledge base document
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::DependentAgent;
+/// use elevenlabs_rs::endpoints::convai::knowledge_base::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let agents = c.paginate(GetKnowledgeBaseDependentAgents::new("documentation_id"));
+///     pin_mut!(agents);
+///     while let Some(agent) = agents.next().await {
+///         if let DependentAgent::Available { name, .. } = agent? {
+///             println!("{}", name);
+///         }
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct GetKnowledgeBaseDependentAgents {
+    documentation_id: DocumentationID,
+    query: PageQuery,
+}
+
+impl GetKnowledgeBaseDependentAgents {
+    pub fn new<T: Into<String>>(documentation_id: T) -> Self {
+        Self {
+            documentation_id: DocumentationID(documentation_id.into()),
+            query: PageQuery::default(),
+        }
+    }
+    pub fn with_query(mut self, query: PageQuery) -> Self {
+        self.query = query;
+        self
+    }
+}
+
+impl Endpoint for GetKnowledgeBaseDependentAgents {
+    type ResponseBody = DependentAgentsResponse;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        let mut url = convai_url(&format!(
+            "{}/{}{}",
+            KNOWLEDGE_BASE_PATH, self.documentation_id.0, DEPENDENT_AGENTS_PATH
+        ));
+        self.query.append_to(&mut url);
+        trim_query(url)
+    }
+}
+
+impl Paginated for GetKnowledgeBaseDependentAgents {
+    type Item = DependentAgent;
+
+    fn next_page(self, page: Self::ResponseBody) -> (Vec<Self::Item>, Option<Self>) {
+        let next = match (page.has_more, page.next_cursor) {
+            (true, Some(cursor)) => Some(Self {
+                query: self.query.with_cursor(cursor),
+                ..self
+            }),
+            _ => None,
+        };
+        (page.agents, next)
+    }
+}
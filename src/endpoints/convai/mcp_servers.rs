@@ -0,0 +1,573 @@
+//! The MCP server endpoints
+//!
+//! Tools of a remote MCP server can be used by any agent that lists the server's id in its
+//! [prompt config](super::agents::PromptConfig::with_mcp_server_id).
+//! Whether the agent may call a tool without asking the user first is set by an [ApprovalPolicy].
+//!
+//! See the [ElevenLabs docs](https://elevenlabs.io/docs/api-reference/mcp/create) for more information.
+use super::tools::HeaderValue;
+use super::*;
+
+const MCP_SERVERS_PATH: &str = "/mcp-servers";
+const TOOLS_PATH: &str = "/tools";
+const APPROVAL_POLICY_PATH: &str = "/approval-policy";
+const TOOL_APPROVALS_PATH: &str = "/tool-approvals";
+
+/// Register an MCP server
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::mcp_servers::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let config = MCPServerConfig::new("Orders", "https://mcp.example.com/sse")
+///         .with_description("Looks up and updates orders")
+///         .with_approval_policy(ApprovalPolicy::RequireApprovalPerTool)
+///         .with_secret_token("secret_id");
+///     let server = c.hit(CreateMCPServer::new(config)).await?;
+///     println!("{}", server.id());
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct CreateMCPServer(MCPServerBody);
+
+impl CreateMCPServer {
+    pub fn new(config: MCPServerConfig) -> Self {
+        Self(MCPServerBody { config })
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct MCPServerBody {
+    config: MCPServerConfig,
+}
+
+impl Endpoint for CreateMCPServer {
+    type ResponseBody = MCPServer;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(serde_json::to_value(&self.0)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        convai_url(MCP_SERVERS_PATH)
+    }
+}
+
+/// List the MCP servers of the workspace
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::mcp_servers::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let resp = c.hit(ListMCPServers).await?;
+///     for server in resp.mcp_servers() {
+///         println!("{}: {}", server.id(), server.config().name());
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ListMCPServers;
+
+impl Endpoint for ListMCPServers {
+    type ResponseBody = ListMCPServersResponse;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        convai_url(MCP_SERVERS_PATH)
+    }
+}
+
+/// List MCP servers response
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListMCPServersResponse {
+    mcp_servers: Vec<MCPServer>,
+}
+
+impl ListMCPServersResponse {
+    pub fn mcp_servers(&self) -> &[MCPServer] {
+        &self.mcp_servers
+    }
+}
+
+/// Get an MCP server
+#[derive(Clone, Debug)]
+pub struct GetMCPServer(MCPServerID);
+
+impl GetMCPServer {
+    pub fn new<T: Into<String>>(mcp_server_id: T) -> Self {
+        Self(MCPServerID(mcp_server_id.into()))
+    }
+}
+
+#[derive(Clone, Debug)]
+struct MCPServerID(String);
+
+impl MCPServerID {
+    fn url(&self, path: &str) -> Url {
+        convai_url(&format!("{}/{}{}", MCP_SERVERS_PATH, self.0, path))
+    }
+}
+
+impl Endpoint for GetMCPServer {
+    type ResponseBody = MCPServer;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        self.0.url("")
+    }
+}
+
+/// List the tools an MCP server offers, as reported by the server itself
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::mcp_servers::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let resp = c.hit(ListMCPServerTools::new("mcp_server_id")).await?;
+///     for tool in resp.tools() {
+///         println!("{}: {}", tool.name(), tool.description().unwrap_or_default());
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ListMCPServerTools(MCPServerID);
+
+impl ListMCPServerTools {
+    pub fn new<T: Into<String>>(mcp_server_id: T) -> Self {
+        Self(MCPServerID(mcp_server_id.into()))
+    }
+}
+
+impl Endpoint for ListMCPServerTools {
+    type ResponseBody = MCPServerToolsResponse;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        self.0.url(TOOLS_PATH)
+    }
+}
+
+/// List MCP server tools response
+#[derive(Clone, Debug, Deserialize)]
+pub struct MCPServerToolsResponse {
+    success: bool,
+    #[serde(default)]
+    tools: Vec<MCPTool>,
+    error_message: Option<String>,
+}
+
+impl MCPServerToolsResponse {
+    /// Whether the MCP server could be reached
+    pub fn success(&self) -> bool {
+        self.success
+    }
+    pub fn tools(&self) -> &[MCPTool] {
+        &self.tools
+    }
+    pub fn error_message(&self) -> Option<&str> {
+        self.error_message.as_deref()
+    }
+}
+
+/// A tool of an MCP server
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MCPTool {
+    name: String,
+    description: Option<String>,
+    #[serde(default)]
+    input_schema: Value,
+}
+
+impl MCPTool {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+    /// A JSON schema of the tool's arguments
+    pub fn input_schema(&self) -> &Value {
+        &self.input_schema
+    }
+}
+
+/// Change whether the tools of an MCP server need the user's approval
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::mcp_servers::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let policy = ApprovalPolicy::RequireApprovalAll;
+///     c.hit(UpdateMCPApprovalPolicy::new("mcp_server_id", policy)).await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct UpdateMCPApprovalPolicy {
+    mcp_server_id: MCPServerID,
+    body: ApprovalPolicyBody,
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct ApprovalPolicyBody {
+    approval_policy: ApprovalPolicy,
+}
+
+impl UpdateMCPApprovalPolicy {
+    pub fn new<T: Into<String>>(mcp_server_id: T, approval_policy: ApprovalPolicy) -> Self {
+        Self {
+            mcp_server_id: MCPServerID(mcp_server_id.into()),
+            body: ApprovalPolicyBody { approval_policy },
+        }
+    }
+}
+
+impl Endpoint for UpdateMCPApprovalPolicy {
+    type ResponseBody = MCPServer;
+
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(serde_json::to_value(&self.body)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        self.mcp_server_id.url(APPROVAL_POLICY_PATH)
+    }
+}
+
+/// Approve a tool of a server whose policy is [per tool](ApprovalPolicy::RequireApprovalPerTool)
+///
+/// Tools that are not approved cannot be called by agents.
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::mcp_servers::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let tools = c.hit(ListMCPServerTools::new("mcp_server_id")).await?;
+///     for tool in tools.tools() {
+///         let approval = if tool.name().starts_with("get_") {
+///             ToolApproval::AutoApproved
+///         } else {
+///             ToolApproval::RequiresApproval
+///         };
+///         c.hit(ApproveMCPTool::new("mcp_server_id", tool).with_approval(approval)).await?;
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ApproveMCPTool {
+    mcp_server_id: MCPServerID,
+    body: ToolApprovalBody,
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct ToolApprovalBody {
+    tool_name: String,
+    tool_description: String,
+    #[serde(skip_serializing_if = "Value::is_null")]
+    input_schema: Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    approval_policy: Option<ToolApproval>,
+}
+
+impl ApproveMCPTool {
+    pub fn new<T: Into<String>>(mcp_server_id: T, tool: &MCPTool) -> Self {
+        Self {
+            mcp_server_id: MCPServerID(mcp_server_id.into()),
+            body: ToolApprovalBody {
+                tool_name: tool.name.clone(),
+                tool_description: tool.description.clone().unwrap_or_default(),
+                input_schema: tool.input_schema.clone(),
+                approval_policy: None,
+            },
+        }
+    }
+    /// Defaults to [RequiresApproval](ToolApproval::RequiresApproval)
+    pub fn with_approval(mut self, approval: ToolApproval) -> Self {
+        self.body.approval_policy = Some(approval);
+        self
+    }
+}
+
+impl Endpoint for ApproveMCPTool {
+    type ResponseBody = MCPServer;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(serde_json::to_value(&self.body)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        self.mcp_server_id.url(TOOL_APPROVALS_PATH)
+    }
+}
+
+/// Remove the approval of a tool, so agents can no longer call it
+#[derive(Clone, Debug)]
+pub struct DeclineMCPTool {
+    mcp_server_id: MCPServerID,
+    tool_name: String,
+}
+
+impl DeclineMCPTool {
+    pub fn new<S: Into<String>, T: Into<String>>(mcp_server_id: S, tool_name: T) -> Self {
+        Self {
+            mcp_server_id: MCPServerID(mcp_server_id.into()),
+            tool_name: tool_name.into(),
+        }
+    }
+}
+
+impl Endpoint for DeclineMCPTool {
+    type ResponseBody = MCPServer;
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        let mut url = self.mcp_server_id.url(TOOL_APPROVALS_PATH);
+        url.path_segments_mut().unwrap().push(&self.tool_name);
+        url
+    }
+}
+
+/// An MCP server
+#[derive(Clone, Debug, Deserialize)]
+pub struct MCPServer {
+    id: String,
+    config: MCPServerConfig,
+    access_info: Option<AccessInfo>,
+    #[serde(default)]
+    dependent_agents: Vec<DependentAgent>,
+}
+
+impl MCPServer {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+    pub fn config(&self) -> &MCPServerConfig {
+        &self.config
+    }
+    pub fn access_info(&self) -> Option<&AccessInfo> {
+        self.access_info.as_ref()
+    }
+    pub fn dependent_agents(&self) -> &[DependentAgent] {
+        &self.dependent_agents
+    }
+}
+
+/// How to reach an MCP server and which of its tools agents may call
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MCPServerConfig {
+    name: String,
+    url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    transport: Option<MCPTransport>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    approval_policy: Option<ApprovalPolicy>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    secret_token: Option<SecretLocator>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    request_headers: HashMap<String, HeaderValue>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    tool_approval_hashes: Vec<ToolApprovalHash>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct SecretLocator {
+    secret_id: String,
+}
+
+impl MCPServerConfig {
+    pub fn new<N: Into<String>, U: Into<String>>(name: N, url: U) -> Self {
+        Self {
+            name: name.into(),
+            url: url.into(),
+            description: None,
+            transport: None,
+            approval_policy: None,
+            secret_token: None,
+            request_headers: HashMap::new(),
+            tool_approval_hashes: Vec::new(),
+        }
+    }
+    pub fn with_description<T: Into<String>>(mut self, description: T) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+    /// Defaults to [SSE](MCPTransport::Sse)
+    pub fn with_transport(mut self, transport: MCPTransport) -> Self {
+        self.transport = Some(transport);
+        self
+    }
+    /// Defaults to [RequireApprovalAll](ApprovalPolicy::RequireApprovalAll)
+    pub fn with_approval_policy(mut self, approval_policy: ApprovalPolicy) -> Self {
+        self.approval_policy = Some(approval_policy);
+        self
+    }
+    /// A [workspace secret](super::workspace::CreateSecret) sent as a bearer token
+    pub fn with_secret_token<T: Into<String>>(mut self, secret_id: T) -> Self {
+        self.secret_token = Some(SecretLocator {
+            secret_id: secret_id.into(),
+        });
+        self
+    }
+    pub fn with_header<N: Into<String>, V: Into<String>>(mut self, name: N, value: V) -> Self {
+        self.request_headers
+            .insert(name.into(), HeaderValue::Value(value.into()));
+        self
+    }
+    /// A header whose value is a [workspace secret](super::workspace::CreateSecret)
+    pub fn with_secret_header<N: Into<String>, S: Into<String>>(
+        mut self,
+        name: N,
+        secret_id: S,
+    ) -> Self {
+        self.request_headers.insert(
+            name.into(),
+            HeaderValue::Secret {
+                secret_id: secret_id.into(),
+            },
+        );
+        self
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+    pub fn transport(&self) -> Option<&MCPTransport> {
+        self.transport.as_ref()
+    }
+    pub fn approval_policy(&self) -> Option<&ApprovalPolicy> {
+        self.approval_policy.as_ref()
+    }
+    pub fn secret_token_id(&self) -> Option<&str> {
+        Some(&self.secret_token.as_ref()?.secret_id)
+    }
+    pub fn request_headers(&self) -> &HashMap<String, HeaderValue> {
+        &self.request_headers
+    }
+    /// The approved tools, for servers whose policy is [per tool](ApprovalPolicy::RequireApprovalPerTool)
+    pub fn tool_approval_hashes(&self) -> &[ToolApprovalHash] {
+        &self.tool_approval_hashes
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum MCPTransport {
+    #[serde(rename = "SSE")]
+    Sse,
+    #[serde(rename = "STREAMABLE_HTTP")]
+    StreamableHttp,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// Whether the tools of an MCP server need approval
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApprovalPolicy {
+    /// Every tool can be called without asking the user
+    AutoApproveAll,
+    /// The user is asked before every tool call
+    RequireApprovalAll,
+    /// Only [approved](ApproveMCPTool) tools can be called, each with its own [ToolApproval]
+    RequireApprovalPerTool,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// Whether an approved tool needs the user's approval for each call
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ToolApproval {
+    AutoApproved,
+    RequiresApproval,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// An approved tool, identified by a hash of its name, description and schema
+///
+/// A tool whose definition changed on the server has to be approved again.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ToolApprovalHash {
+    tool_name: String,
+    tool_hash: String,
+    approval_policy: Option<ToolApproval>,
+}
+
+impl ToolApprovalHash {
+    pub fn tool_name(&self) -> &str {
+        &self.tool_name
+    }
+    pub fn tool_hash(&self) -> &str {
+        &self.tool_hash
+    }
+    pub fn approval_policy(&self) -> Option<&ToolApproval> {
+        self.approval_policy.as_ref()
+    }
+}
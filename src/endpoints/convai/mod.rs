@@ -0,0 +1,471 @@
+//! The conversational AI endpoints
+//!
+//! See the [ElevenLabs docs](https://elevenlabs.io/docs/conversational-ai/overview) for more information.
+use super::*;
+use std::collections::HashMap;
+
+pub mod agents;
+#[cfg(feature = "audio-interface")]
+pub mod audio_interface;
+pub mod batch_calling;
+pub mod client;
+pub mod client_messages;
+pub mod conversations;
+pub mod knowledge_base;
+pub mod llm_usage;
+pub mod mcp_servers;
+pub mod outbound_calls;
+pub mod phone_numbers;
+pub mod server_messages;
+pub mod simulation;
+pub mod tools;
+pub mod workspace;
+
+const CONVAI_PATH: &str = "/v1/convai";
+
+const CURSOR_QUERY: &str = "cursor";
+const PAGE_SIZE_QUERY: &str = "page_size";
+
+/// An agent that depends on a knowledge base document or a tool
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DependentAgent {
+    Available {
+        id: String,
+        name: String,
+        created_at_unix_secs: i64,
+        access_level: String,
+    },
+    /// The agent exists but the caller has no access to it
+    Unknown,
+}
+
+/// A page of agents that depend on a knowledge base document or a tool
+#[derive(Clone, Debug, Deserialize)]
+pub struct DependentAgentsResponse {
+    agents: Vec<DependentAgent>,
+    next_cursor: Option<String>,
+    has_more: bool,
+}
+
+impl DependentAgentsResponse {
+    pub fn agents(&self) -> &[DependentAgent] {
+        &self.agents
+    }
+    pub fn next_cursor(&self) -> Option<&str> {
+        self.next_cursor.as_deref()
+    }
+    pub fn has_more(&self) -> bool {
+        self.has_more
+    }
+}
+
+/// A user's rating of a conversation or of one of the agent's turns
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FeedbackScore {
+    Like,
+    Dislike,
+}
+
+/// The caller's access to a conversational AI resource
+#[derive(Clone, Debug, Deserialize)]
+pub struct AccessInfo {
+    is_creator: bool,
+    creator_name: String,
+    creator_email: String,
+    role: String,
+}
+
+impl AccessInfo {
+    pub fn is_creator(&self) -> bool {
+        self.is_creator
+    }
+    pub fn creator_name(&self) -> &str {
+        &self.creator_name
+    }
+    pub fn creator_email(&self) -> &str {
+        &self.creator_email
+    }
+    /// One of `admin`, `editor` or `viewer`
+    pub fn role(&self) -> &str {
+        &self.role
+    }
+}
+
+/// Cursor and page size shared by the conversational AI listings
+#[derive(Clone, Debug, Default)]
+pub struct PageQuery {
+    cursor: Option<String>,
+    page_size: Option<u32>,
+}
+
+impl PageQuery {
+    pub fn with_cursor<T: Into<String>>(mut self, cursor: T) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+    pub fn with_page_size(mut self, page_size: u32) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+    fn append_to(&self, url: &mut Url) {
+        let mut pairs = url.query_pairs_mut();
+        if let Some(cursor) = &self.cursor {
+            pairs.append_pair(CURSOR_QUERY, cursor);
+        }
+        if let Some(page_size) = self.page_size {
+            pairs.append_pair(PAGE_SIZE_QUERY, &page_size.to_string());
+        }
+    }
+}
+
+fn convai_url(path: &str) -> Url {
+    let mut url = BASE_URL.parse::<Url>().unwrap();
+    url.set_path(&format!("{}{}", CONVAI_PATH, path));
+    url
+}
+
+/// Drops the `?` left behind when no query pairs were appended
+fn trim_query(mut url: Url) -> Url {
+    if url.query() == Some("") {
+        url.set_query(None);
+    }
+    url
+}
+
+/// Overrides and dynamic variables sent when a conversation starts
+///
+/// Overrides only apply if they are enabled in the agent's security settings.
+///
+/// # Example
+/// ```
+/// use elevenlabs_rs::endpoints::convai::ConversationInitiationClientData;
+///
+/// let data = ConversationInitiationClientData::default()
+///     .with_first_message("Hi Ada, this is Acme calling about your order.")
+///     .with_language("en")
+///     .with_speed(1.1)
+///     .with_user_id("customer_1234")
+///     .with_dynamic_variable("customer_name", "Ada")
+///     .with_dynamic_variable("order_total", 42.5);
+/// ```
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ConversationInitiationClientData {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    conversation_config_override: Option<ConversationConfigOverride>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    custom_llm_extra_body: Option<Value>,
+    #[serde(
+        default,
+        skip_serializing_if = "HashMap::is_empty",
+        deserialize_with = "deserialize_dynamic_variables"
+    )]
+    dynamic_variables: HashMap<String, DynamicVariable>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    user_id: Option<String>,
+}
+
+impl ConversationInitiationClientData {
+    /// Replaces all overrides, see [ConversationConfigOverride]
+    pub fn with_conversation_config_override(mut self, config: ConversationConfigOverride) -> Self {
+        self.conversation_config_override = Some(config);
+        self
+    }
+    pub fn with_prompt<T: Into<String>>(self, prompt: T) -> Self {
+        self.map_override(|config| config.with_prompt(prompt))
+    }
+    /// See [ConversationConfigOverride::with_llm]
+    pub fn with_llm<T: Into<String>>(self, llm: T) -> Self {
+        self.map_override(|config| config.with_llm(llm))
+    }
+    pub fn with_first_message<T: Into<String>>(self, first_message: T) -> Self {
+        self.map_override(|config| config.with_first_message(first_message))
+    }
+    /// An ISO 639-1 language code
+    pub fn with_language<T: Into<String>>(self, language: T) -> Self {
+        self.map_override(|config| config.with_language(language))
+    }
+    pub fn with_voice_id<T: Into<VoiceID>>(self, voice_id: T) -> Self {
+        self.map_override(|config| config.with_voice_id(voice_id))
+    }
+    /// See [ConversationConfigOverride::with_stability]
+    pub fn with_stability(self, stability: f32) -> Self {
+        self.map_override(|config| config.with_stability(stability))
+    }
+    /// See [ConversationConfigOverride::with_speed]
+    pub fn with_speed(self, speed: f32) -> Self {
+        self.map_override(|config| config.with_speed(speed))
+    }
+    /// See [ConversationConfigOverride::with_similarity_boost]
+    pub fn with_similarity_boost(self, similarity_boost: f32) -> Self {
+        self.map_override(|config| config.with_similarity_boost(similarity_boost))
+    }
+    /// See [ConversationConfigOverride::with_text_only]
+    pub fn with_text_only(self, text_only: bool) -> Self {
+        self.map_override(|config| config.with_text_only(text_only))
+    }
+    /// Extra fields merged into the body of requests to a custom LLM
+    pub fn with_custom_llm_extra_body(mut self, body: Value) -> Self {
+        self.custom_llm_extra_body = Some(body);
+        self
+    }
+    /// Fills `{{name}}` placeholders in the agent's prompt and first message
+    pub fn with_dynamic_variable<K, V>(mut self, name: K, value: V) -> Self
+    where
+        K: Into<String>,
+        V: Into<DynamicVariable>,
+    {
+        self.dynamic_variables.insert(name.into(), value.into());
+        self
+    }
+    /// Fills several `{{name}}` placeholders, see [with_dynamic_variable](Self::with_dynamic_variable)
+    pub fn with_dynamic_variables<I, K, V>(mut self, variables: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<DynamicVariable>,
+    {
+        self.dynamic_variables.extend(
+            variables
+                .into_iter()
+                .map(|(name, value)| (name.into(), value.into())),
+        );
+        self
+    }
+    /// Your own id of the user, stored with the conversation e.g. to find it later
+    pub fn with_user_id<T: Into<String>>(mut self, user_id: T) -> Self {
+        self.user_id = Some(user_id.into());
+        self
+    }
+    pub fn conversation_config_override(&self) -> Option<&ConversationConfigOverride> {
+        self.conversation_config_override.as_ref()
+    }
+    pub fn custom_llm_extra_body(&self) -> Option<&Value> {
+        self.custom_llm_extra_body.as_ref()
+    }
+    pub fn dynamic_variables(&self) -> &HashMap<String, DynamicVariable> {
+        &self.dynamic_variables
+    }
+    pub fn user_id(&self) -> Option<&str> {
+        self.user_id.as_deref()
+    }
+
+    fn map_override<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(ConversationConfigOverride) -> ConversationConfigOverride,
+    {
+        let config = self.conversation_config_override.take().unwrap_or_default();
+        self.conversation_config_override = Some(f(config));
+        self
+    }
+}
+
+/// The parts of the agent's configuration that are overridden for a single conversation
+///
+/// # Example
+/// ```
+/// use elevenlabs_rs::endpoints::convai::{
+///     ConversationConfigOverride, ConversationInitiationClientData,
+/// };
+///
+/// let config = ConversationConfigOverride::default()
+///     .with_prompt("You are a support agent for Acme. Be brief.")
+///     .with_llm("gemini-2.0-flash")
+///     .with_voice_id("21m00Tcm4TlvDq8ikWAM")
+///     .with_stability(0.6);
+/// let data = ConversationInitiationClientData::default().with_conversation_config_override(config);
+/// ```
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ConversationConfigOverride {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    agent: Option<AgentOverride>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    tts: Option<TtsOverride>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    conversation: Option<ConversationOverride>,
+}
+
+impl ConversationConfigOverride {
+    /// The system prompt of the agent
+    pub fn with_prompt<T: Into<String>>(mut self, prompt: T) -> Self {
+        self.prompt_override().prompt = Some(prompt.into());
+        self
+    }
+    /// The id of the LLM, e.g. `gpt-4o-mini` or `gemini-2.0-flash`
+    pub fn with_llm<T: Into<String>>(mut self, llm: T) -> Self {
+        self.prompt_override().llm = Some(llm.into());
+        self
+    }
+    pub fn with_first_message<T: Into<String>>(mut self, first_message: T) -> Self {
+        self.agent_override().first_message = Some(first_message.into());
+        self
+    }
+    /// An ISO 639-1 language code
+    pub fn with_language<T: Into<String>>(mut self, language: T) -> Self {
+        self.agent_override().language = Some(language.into());
+        self
+    }
+    pub fn with_voice_id<T: Into<VoiceID>>(mut self, voice_id: T) -> Self {
+        self.tts_override().voice_id = Some(voice_id.into());
+        self
+    }
+    /// 0.0 to 1.0, lower is more expressive
+    pub fn with_stability(mut self, stability: f32) -> Self {
+        self.tts_override().stability = Some(stability);
+        self
+    }
+    /// 0.7 to 1.2, 1.0 is the voice's normal pace
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.tts_override().speed = Some(speed);
+        self
+    }
+    /// 0.0 to 1.0, how closely the voice should match the original
+    pub fn with_similarity_boost(mut self, similarity_boost: f32) -> Self {
+        self.tts_override().similarity_boost = Some(similarity_boost);
+        self
+    }
+    /// Only exchange text messages, without audio
+    pub fn with_text_only(mut self, text_only: bool) -> Self {
+        self.conversation
+            .get_or_insert_with(ConversationOverride::default)
+            .text_only = Some(text_only);
+        self
+    }
+    pub fn prompt(&self) -> Option<&str> {
+        self.agent.as_ref()?.prompt.as_ref()?.prompt.as_deref()
+    }
+    pub fn llm(&self) -> Option<&str> {
+        self.agent.as_ref()?.prompt.as_ref()?.llm.as_deref()
+    }
+    pub fn first_message(&self) -> Option<&str> {
+        self.agent.as_ref()?.first_message.as_deref()
+    }
+    pub fn language(&self) -> Option<&str> {
+        self.agent.as_ref()?.language.as_deref()
+    }
+    pub fn voice_id(&self) -> Option<&str> {
+        self.tts.as_ref()?.voice_id.as_ref().map(VoiceID::as_str)
+    }
+    pub fn stability(&self) -> Option<f32> {
+        self.tts.as_ref()?.stability
+    }
+    pub fn speed(&self) -> Option<f32> {
+        self.tts.as_ref()?.speed
+    }
+    pub fn similarity_boost(&self) -> Option<f32> {
+        self.tts.as_ref()?.similarity_boost
+    }
+    pub fn text_only(&self) -> Option<bool> {
+        self.conversation.as_ref()?.text_only
+    }
+
+    fn agent_override(&mut self) -> &mut AgentOverride {
+        self.agent.get_or_insert_with(AgentOverride::default)
+    }
+    fn prompt_override(&mut self) -> &mut PromptOverride {
+        self.agent_override()
+            .prompt
+            .get_or_insert_with(PromptOverride::default)
+    }
+    fn tts_override(&mut self) -> &mut TtsOverride {
+        self.tts.get_or_insert_with(TtsOverride::default)
+    }
+}
+
+// Unset fields are `null` when the server sends the overrides back, e.g. in webhooks
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+struct AgentOverride {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    prompt: Option<PromptOverride>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    first_message: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+struct PromptOverride {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    prompt: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    llm: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+struct TtsOverride {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    voice_id: Option<VoiceID>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    stability: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    speed: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    similarity_boost: Option<f32>,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+struct ConversationOverride {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    text_only: Option<bool>,
+}
+
+/// Drops variables that are `null`, i.e. were not set
+fn deserialize_dynamic_variables<'de, D>(
+    deserializer: D,
+) -> std::result::Result<HashMap<String, DynamicVariable>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let variables = HashMap::<String, Option<DynamicVariable>>::deserialize(deserializer)?;
+    Ok(variables
+        .into_iter()
+        .filter_map(|(name, value)| Some((name, value?)))
+        .collect())
+}
+
+/// The value of a dynamic variable
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum DynamicVariable {
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    String(String),
+}
+
+impl From<bool> for DynamicVariable {
+    fn from(value: bool) -> Self {
+        DynamicVariable::Bool(value)
+    }
+}
+
+impl From<i64> for DynamicVariable {
+    fn from(value: i64) -> Self {
+        DynamicVariable::Int(value)
+    }
+}
+
+impl From<i32> for DynamicVariable {
+    fn from(value: i32) -> Self {
+        DynamicVariable::Int(value.into())
+    }
+}
+
+impl From<f64> for DynamicVariable {
+    fn from(value: f64) -> Self {
+        DynamicVariable::Float(value)
+    }
+}
+
+impl From<&str> for DynamicVariable {
+    fn from(value: &str) -> Self {
+        DynamicVariable::String(value.to_string())
+    }
+}
+
+impl From<String> for DynamicVariable {
+    fn from(value: String) -> Self {
+        DynamicVariable::String(value)
+    }
+}
@@ -0,0 +1,192 @@
+//! The outbound call endpoints
+//!
+//! An agent calls a phone number from one of the workspace's [phone numbers](super::phone_numbers).
+//!
+//! See the [ElevenLabs docs](https://elevenlabs.io/docs/api-reference/twilio/outbound-call) for more information.
+use super::*;
+
+const TWILIO_OUTBOUND_CALL_PATH: &str = "/twilio/outbound-call";
+const SIP_TRUNK_OUTBOUND_CALL_PATH: &str = "/sip-trunk/outbound-call";
+
+/// Place an outbound call from a Twilio number
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::ConversationInitiationClientData;
+/// use elevenlabs_rs::endpoints::convai::outbound_calls::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let data = ConversationInitiationClientData::default()
+///         .with_dynamic_variable("customer_name", "Ada");
+///     let body = OutboundCallBody::new("agent_id", "agent_phone_number_id", "+15551234567")
+///         .with_conversation_initiation_client_data(data);
+///     let resp = c.hit(TwilioOutboundCall::new(body)).await?;
+///     println!("{:?}", resp.conversation_id());
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct TwilioOutboundCall(OutboundCallBody);
+
+impl TwilioOutboundCall {
+    pub fn new(body: OutboundCallBody) -> Self {
+        Self(body)
+    }
+}
+
+impl Endpoint for TwilioOutboundCall {
+    type ResponseBody = TwilioOutboundCallResponse;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(serde_json::to_value(&self.0)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        convai_url(TWILIO_OUTBOUND_CALL_PATH)
+    }
+}
+
+/// Place an outbound call from a SIP trunk number
+///
+/// The number needs an outbound trunk config.
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::outbound_calls::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let body = OutboundCallBody::new("agent_id", "agent_phone_number_id", "+15551234567");
+///     let resp = c.hit(SipTrunkOutboundCall::new(body)).await?;
+///     println!("{:?}", resp.sip_call_id());
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SipTrunkOutboundCall(OutboundCallBody);
+
+impl SipTrunkOutboundCall {
+    pub fn new(body: OutboundCallBody) -> Self {
+        Self(body)
+    }
+}
+
+impl Endpoint for SipTrunkOutboundCall {
+    type ResponseBody = SipTrunkOutboundCallResponse;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(serde_json::to_value(&self.0)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        convai_url(SIP_TRUNK_OUTBOUND_CALL_PATH)
+    }
+}
+
+/// Outbound call body
+#[derive(Clone, Debug, Serialize)]
+pub struct OutboundCallBody {
+    agent_id: AgentID,
+    agent_phone_number_id: String,
+    to_number: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    conversation_initiation_client_data: Option<ConversationInitiationClientData>,
+}
+
+impl OutboundCallBody {
+    /// `to_number` is in E.164 format, e.g. `+15551234567`
+    pub fn new<A, P, T>(agent_id: A, agent_phone_number_id: P, to_number: T) -> Self
+    where
+        A: Into<AgentID>,
+        P: Into<String>,
+        T: Into<String>,
+    {
+        Self {
+            agent_id: agent_id.into(),
+            agent_phone_number_id: agent_phone_number_id.into(),
+            to_number: to_number.into(),
+            conversation_initiation_client_data: None,
+        }
+    }
+    pub fn with_conversation_initiation_client_data(
+        mut self,
+        data: ConversationInitiationClientData,
+    ) -> Self {
+        self.conversation_initiation_client_data = Some(data);
+        self
+    }
+    pub fn agent_id(&self) -> &str {
+        self.agent_id.as_str()
+    }
+    pub fn agent_phone_number_id(&self) -> &str {
+        &self.agent_phone_number_id
+    }
+    pub fn to_number(&self) -> &str {
+        &self.to_number
+    }
+}
+
+/// Twilio outbound call response
+#[derive(Clone, Debug, Deserialize)]
+pub struct TwilioOutboundCallResponse {
+    success: bool,
+    message: String,
+    conversation_id: Option<String>,
+    #[serde(rename = "callSid")]
+    call_sid: Option<String>,
+}
+
+impl TwilioOutboundCallResponse {
+    pub fn success(&self) -> bool {
+        self.success
+    }
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+    pub fn conversation_id(&self) -> Option<&str> {
+        self.conversation_id.as_deref()
+    }
+    /// The Twilio call SID
+    pub fn call_sid(&self) -> Option<&str> {
+        self.call_sid.as_deref()
+    }
+}
+
+/// SIP trunk outbound call response
+#[derive(Clone, Debug, Deserialize)]
+pub struct SipTrunkOutboundCallResponse {
+    success: bool,
+    message: String,
+    conversation_id: Option<String>,
+    sip_call_id: Option<String>,
+}
+
+impl SipTrunkOutboundCallResponse {
+    pub fn success(&self) -> bool {
+        self.success
+    }
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+    pub fn conversation_id(&self) -> Option<&str> {
+        self.conversation_id.as_deref()
+    }
+    pub fn sip_call_id(&self) -> Option<&str> {
+        self.sip_call_id.as_deref()
+    }
+}
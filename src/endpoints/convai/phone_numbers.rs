@@ -0,0 +1,572 @@
+//! The phone numbers endpoints
+//!
+//! Numbers are imported from Twilio or a SIP trunk and assigned to an agent,
+//! which then answers inbound calls and places outbound calls from them.
+//!
+//! See the [ElevenLabs docs](https://elevenlabs.io/docs/api-reference/phone-numbers/list) for more information.
+use super::*;
+use std::collections::HashMap;
+
+const PHONE_NUMBERS_PATH: &str = "/phone-numbers";
+
+/// Import a phone number
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::phone_numbers::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let twilio = TwilioPhoneNumber::new("+15551234567", "Support line", "account_sid", "auth_token");
+///     c.hit(CreatePhoneNumber::new(twilio)).await?;
+///
+///     let trunk = OutboundTrunkConfig::new("sip.example.com")
+///         .with_transport(SipTransport::Tls)
+///         .with_credentials("username", "password");
+///     let sip = SipTrunkPhoneNumber::new("+15557654321", "Sales line").with_outbound_trunk(trunk);
+///     let resp = c.hit(CreatePhoneNumber::new(sip)).await?;
+///     println!("{}", resp.phone_number_id());
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct CreatePhoneNumber(CreatePhoneNumberBody);
+
+impl CreatePhoneNumber {
+    pub fn new<T: Into<CreatePhoneNumberBody>>(body: T) -> Self {
+        Self(body.into())
+    }
+}
+
+/// Create phone number body, one per provider
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "provider", rename_all = "snake_case")]
+pub enum CreatePhoneNumberBody {
+    Twilio(TwilioPhoneNumber),
+    SipTrunk(SipTrunkPhoneNumber),
+}
+
+impl From<TwilioPhoneNumber> for CreatePhoneNumberBody {
+    fn from(number: TwilioPhoneNumber) -> Self {
+        CreatePhoneNumberBody::Twilio(number)
+    }
+}
+
+impl From<SipTrunkPhoneNumber> for CreatePhoneNumberBody {
+    fn from(number: SipTrunkPhoneNumber) -> Self {
+        CreatePhoneNumberBody::SipTrunk(number)
+    }
+}
+
+/// A number owned by a Twilio account
+#[derive(Clone, Debug, Serialize)]
+pub struct TwilioPhoneNumber {
+    phone_number: String,
+    label: String,
+    sid: String,
+    token: String,
+}
+
+impl TwilioPhoneNumber {
+    /// `sid` and `token` are the Twilio account SID and auth token
+    pub fn new<P, L, S, T>(phone_number: P, label: L, sid: S, token: T) -> Self
+    where
+        P: Into<String>,
+        L: Into<String>,
+        S: Into<String>,
+        T: Into<String>,
+    {
+        Self {
+            phone_number: phone_number.into(),
+            label: label.into(),
+            sid: sid.into(),
+            token: token.into(),
+        }
+    }
+}
+
+/// A number routed through a SIP trunk
+#[derive(Clone, Debug, Serialize)]
+pub struct SipTrunkPhoneNumber {
+    phone_number: String,
+    label: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inbound_trunk_config: Option<InboundTrunkConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    outbound_trunk_config: Option<OutboundTrunkConfig>,
+}
+
+impl SipTrunkPhoneNumber {
+    pub fn new<P: Into<String>, L: Into<String>>(phone_number: P, label: L) -> Self {
+        Self {
+            phone_number: phone_number.into(),
+            label: label.into(),
+            inbound_trunk_config: None,
+            outbound_trunk_config: None,
+        }
+    }
+    pub fn with_inbound_trunk(mut self, config: InboundTrunkConfig) -> Self {
+        self.inbound_trunk_config = Some(config);
+        self
+    }
+    /// Required to place outbound calls from the number
+    pub fn with_outbound_trunk(mut self, config: OutboundTrunkConfig) -> Self {
+        self.outbound_trunk_config = Some(config);
+        self
+    }
+}
+
+/// Where and how outbound calls are sent
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct OutboundTrunkConfig {
+    address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    transport: Option<SipTransport>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    media_encryption: Option<MediaEncryption>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    headers: HashMap<String, String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    credentials: Option<SipCredentials>,
+}
+
+impl OutboundTrunkConfig {
+    /// The hostname or IP of the SIP trunk, without the `sip:` scheme
+    pub fn new<T: Into<String>>(address: T) -> Self {
+        Self {
+            address: address.into(),
+            transport: None,
+            media_encryption: None,
+            headers: HashMap::new(),
+            credentials: None,
+        }
+    }
+    pub fn with_transport(mut self, transport: SipTransport) -> Self {
+        self.transport = Some(transport);
+        self
+    }
+    pub fn with_media_encryption(mut self, media_encryption: MediaEncryption) -> Self {
+        self.media_encryption = Some(media_encryption);
+        self
+    }
+    /// A SIP `X-` header sent with every call
+    pub fn with_header<N: Into<String>, V: Into<String>>(mut self, name: N, value: V) -> Self {
+        self.headers.insert(name.into(), value.into());
+        self
+    }
+    pub fn with_credentials<U: Into<String>, P: Into<String>>(
+        mut self,
+        username: U,
+        password: P,
+    ) -> Self {
+        self.credentials = Some(SipCredentials::new(username, password));
+        self
+    }
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+    pub fn transport(&self) -> Option<&SipTransport> {
+        self.transport.as_ref()
+    }
+    pub fn media_encryption(&self) -> Option<&MediaEncryption> {
+        self.media_encryption.as_ref()
+    }
+    pub fn headers(&self) -> &HashMap<String, String> {
+        &self.headers
+    }
+}
+
+/// Which inbound calls are accepted
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct InboundTrunkConfig {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    allowed_addresses: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allowed_numbers: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    media_encryption: Option<MediaEncryption>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    credentials: Option<SipCredentials>,
+}
+
+impl InboundTrunkConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// IPs or CIDR blocks calls may come from
+    pub fn with_allowed_addresses(mut self, allowed_addresses: Vec<String>) -> Self {
+        self.allowed_addresses = allowed_addresses;
+        self
+    }
+    /// Caller numbers that may call in, any number when not set
+    pub fn with_allowed_numbers(mut self, allowed_numbers: Vec<String>) -> Self {
+        self.allowed_numbers = Some(allowed_numbers);
+        self
+    }
+    pub fn with_media_encryption(mut self, media_encryption: MediaEncryption) -> Self {
+        self.media_encryption = Some(media_encryption);
+        self
+    }
+    pub fn with_credentials<U: Into<String>, P: Into<String>>(
+        mut self,
+        username: U,
+        password: P,
+    ) -> Self {
+        self.credentials = Some(SipCredentials::new(username, password));
+        self
+    }
+    pub fn allowed_addresses(&self) -> &[String] {
+        &self.allowed_addresses
+    }
+    pub fn allowed_numbers(&self) -> Option<&[String]> {
+        self.allowed_numbers.as_deref()
+    }
+    pub fn media_encryption(&self) -> Option<&MediaEncryption> {
+        self.media_encryption.as_ref()
+    }
+}
+
+/// SIP digest credentials
+///
+/// The password is never returned by the API.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SipCredentials {
+    username: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    password: Option<String>,
+}
+
+impl SipCredentials {
+    pub fn new<U: Into<String>, P: Into<String>>(username: U, password: P) -> Self {
+        Self {
+            username: username.into(),
+            password: Some(password.into()),
+        }
+    }
+    pub fn username(&self) -> &str {
+        &self.username
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SipTransport {
+    Auto,
+    Udp,
+    Tcp,
+    Tls,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MediaEncryption {
+    Disabled,
+    Allowed,
+    Required,
+}
+
+/// Create phone number response
+#[derive(Clone, Debug, Deserialize)]
+pub struct CreatePhoneNumberResponse {
+    phone_number_id: String,
+}
+
+impl CreatePhoneNumberResponse {
+    pub fn phone_number_id(&self) -> &str {
+        &self.phone_number_id
+    }
+}
+
+impl Endpoint for CreatePhoneNumber {
+    type ResponseBody = CreatePhoneNumberResponse;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(serde_json::to_value(&self.0)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        convai_url(PHONE_NUMBERS_PATH)
+    }
+}
+
+/// List the phone numbers of the workspace
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::phone_numbers::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     for number in c.hit(ListPhoneNumbers).await? {
+///         println!("{} {:?}", number.phone_number(), number.assigned_agent());
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ListPhoneNumbers;
+
+impl Endpoint for ListPhoneNumbers {
+    type ResponseBody = Vec<PhoneNumber>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        convai_url(PHONE_NUMBERS_PATH)
+    }
+}
+
+/// Get a phone number
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::phone_numbers::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let number = c.hit(GetPhoneNumber::new("phone_number_id")).await?;
+///     println!("{:#?}", number);
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct GetPhoneNumber(PhoneNumberID);
+
+impl GetPhoneNumber {
+    pub fn new<T: Into<String>>(phone_number_id: T) -> Self {
+        Self(PhoneNumberID(phone_number_id.into()))
+    }
+}
+
+#[derive(Clone, Debug)]
+struct PhoneNumberID(String);
+
+impl Endpoint for GetPhoneNumber {
+    type ResponseBody = PhoneNumber;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        convai_url(&format!("{}/{}", PHONE_NUMBERS_PATH, self.0 .0))
+    }
+}
+
+/// Update a phone number, e.g. to assign it to an agent
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::phone_numbers::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let body = UpdatePhoneNumberBody::default().with_agent_id("agent_id");
+///     let number = c.hit(UpdatePhoneNumber::new("phone_number_id", body)).await?;
+///     println!("{:?}", number.assigned_agent());
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct UpdatePhoneNumber {
+    phone_number_id: PhoneNumberID,
+    body: UpdatePhoneNumberBody,
+}
+
+impl UpdatePhoneNumber {
+    pub fn new<T: Into<String>>(phone_number_id: T, body: UpdatePhoneNumberBody) -> Self {
+        Self {
+            phone_number_id: PhoneNumberID(phone_number_id.into()),
+            body,
+        }
+    }
+}
+
+/// Update phone number body, only the fields that are set are changed
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct UpdatePhoneNumberBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    agent_id: Option<Option<AgentID>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inbound_trunk_config: Option<InboundTrunkConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    outbound_trunk_config: Option<OutboundTrunkConfig>,
+}
+
+impl UpdatePhoneNumberBody {
+    /// Assigns the number to an agent
+    pub fn with_agent_id<T: Into<AgentID>>(mut self, agent_id: T) -> Self {
+        self.agent_id = Some(Some(agent_id.into()));
+        self
+    }
+    /// Unassigns the number from its agent
+    pub fn without_agent(mut self) -> Self {
+        self.agent_id = Some(None);
+        self
+    }
+    pub fn with_label<T: Into<String>>(mut self, label: T) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+    /// SIP trunk numbers only
+    pub fn with_inbound_trunk(mut self, config: InboundTrunkConfig) -> Self {
+        self.inbound_trunk_config = Some(config);
+        self
+    }
+    /// SIP trunk numbers only
+    pub fn with_outbound_trunk(mut self, config: OutboundTrunkConfig) -> Self {
+        self.outbound_trunk_config = Some(config);
+        self
+    }
+}
+
+impl Endpoint for UpdatePhoneNumber {
+    type ResponseBody = PhoneNumber;
+
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(serde_json::to_value(&self.body)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        convai_url(&format!(
+            "{}/{}",
+            PHONE_NUMBERS_PATH, self.phone_number_id.0
+        ))
+    }
+}
+
+/// Delete a phone number
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::phone_numbers::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     c.hit(DeletePhoneNumber::new("phone_number_id")).await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct DeletePhoneNumber(PhoneNumberID);
+
+impl DeletePhoneNumber {
+    pub fn new<T: Into<String>>(phone_number_id: T) -> Self {
+        Self(PhoneNumberID(phone_number_id.into()))
+    }
+}
+
+impl Endpoint for DeletePhoneNumber {
+    type ResponseBody = ();
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+    async fn response_body(self, _resp: Response) -> Result<Self::ResponseBody> {
+        Ok(())
+    }
+    fn url(&self) -> Url {
+        convai_url(&format!("{}/{}", PHONE_NUMBERS_PATH, self.0 .0))
+    }
+}
+
+/// A phone number of the workspace
+#[derive(Clone, Debug, Deserialize)]
+pub struct PhoneNumber {
+    phone_number_id: String,
+    phone_number: String,
+    label: String,
+    provider: PhoneProvider,
+    assigned_agent: Option<AssignedAgent>,
+    #[serde(default)]
+    supports_inbound: Option<bool>,
+    #[serde(default)]
+    supports_outbound: Option<bool>,
+    #[serde(default)]
+    inbound_trunk: Option<InboundTrunkConfig>,
+    #[serde(default)]
+    outbound_trunk: Option<OutboundTrunkConfig>,
+}
+
+impl PhoneNumber {
+    pub fn phone_number_id(&self) -> &str {
+        &self.phone_number_id
+    }
+    pub fn phone_number(&self) -> &str {
+        &self.phone_number
+    }
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+    pub fn provider(&self) -> &PhoneProvider {
+        &self.provider
+    }
+    pub fn assigned_agent(&self) -> Option<&AssignedAgent> {
+        self.assigned_agent.as_ref()
+    }
+    pub fn supports_inbound(&self) -> Option<bool> {
+        self.supports_inbound
+    }
+    pub fn supports_outbound(&self) -> Option<bool> {
+        self.supports_outbound
+    }
+    /// SIP trunk numbers only
+    pub fn inbound_trunk(&self) -> Option<&InboundTrunkConfig> {
+        self.inbound_trunk.as_ref()
+    }
+    /// SIP trunk numbers only
+    pub fn outbound_trunk(&self) -> Option<&OutboundTrunkConfig> {
+        self.outbound_trunk.as_ref()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PhoneProvider {
+    Twilio,
+    SipTrunk,
+}
+
+/// The agent a phone number is assigned to
+#[derive(Clone, Debug, Deserialize)]
+pub struct AssignedAgent {
+    agent_id: String,
+    agent_name: String,
+}
+
+impl AssignedAgent {
+    pub fn agent_id(&self) -> &str {
+        &self.agent_id
+    }
+    pub fn agent_name(&self) -> &str {
+        &self.agent_name
+    }
+}
@@ -0,0 +1,307 @@
+//! Messages sent by the server during a conversation
+//!
+//! See the [ElevenLabs docs](https://elevenlabs.io/docs/conversational-ai/api-reference/conversational-ai/websocket) for more information.
+use super::client_messages::ClientMessage;
+use super::*;
+
+/// A message received from the agent
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ServerMessage {
+    ConversationInitiationMetadata(ConversationInitiationMetadata),
+    UserTranscript(UserTranscript),
+    AgentResponse(AgentResponse),
+    AgentResponseCorrection(AgentResponseCorrection),
+    Audio(Audio),
+    Interruption(Interruption),
+    Ping(Ping),
+    ClientToolCall(ClientToolCall),
+    VadScore(VadScore),
+    InternalTentativeAgentResponse(TentativeAgentResponse),
+    /// Not sent by the server, yielded by the [client](super::client) after it
+    /// reconnected a dropped conversation
+    #[serde(skip)]
+    Reconnected(Reconnected),
+    /// A message type this version of the crate does not know about
+    #[serde(other)]
+    Unknown,
+}
+
+impl ServerMessage {
+    pub(crate) fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json).map_err(crate::error::ConvAIError::from)?)
+    }
+}
+
+/// The first message of a conversation
+#[derive(Clone, Debug, Deserialize)]
+pub struct ConversationInitiationMetadata {
+    conversation_initiation_metadata_event: InitiationMetadataEvent,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct InitiationMetadataEvent {
+    conversation_id: String,
+    agent_output_audio_format: String,
+    user_input_audio_format: Option<String>,
+}
+
+impl ConversationInitiationMetadata {
+    pub fn conversation_id(&self) -> &str {
+        &self.conversation_initiation_metadata_event.conversation_id
+    }
+    /// e.g. `pcm_16000` or `ulaw_8000`
+    pub fn agent_output_audio_format(&self) -> &str {
+        &self
+            .conversation_initiation_metadata_event
+            .agent_output_audio_format
+    }
+    /// The format [user audio chunks](super::client_messages::ClientMessage::UserAudioChunk) are expected in
+    pub fn user_input_audio_format(&self) -> Option<&str> {
+        self.conversation_initiation_metadata_event
+            .user_input_audio_format
+            .as_deref()
+    }
+    /// The [agent output audio format](Self::agent_output_audio_format), if it is a known format
+    pub fn agent_output_format(&self) -> Option<OutputFormat> {
+        self.agent_output_audio_format().parse().ok()
+    }
+    /// The [user input audio format](Self::user_input_audio_format), if it is a known format
+    pub fn user_input_format(&self) -> Option<OutputFormat> {
+        self.user_input_audio_format()?.parse().ok()
+    }
+}
+
+/// The transcript of what the user said
+#[derive(Clone, Debug, Deserialize)]
+pub struct UserTranscript {
+    user_transcription_event: UserTranscriptionEvent,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct UserTranscriptionEvent {
+    user_transcript: String,
+}
+
+impl UserTranscript {
+    pub fn user_transcript(&self) -> &str {
+        &self.user_transcription_event.user_transcript
+    }
+}
+
+/// The text of the agent's reply
+#[derive(Clone, Debug, Deserialize)]
+pub struct AgentResponse {
+    agent_response_event: AgentResponseEvent,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct AgentResponseEvent {
+    agent_response: String,
+}
+
+impl AgentResponse {
+    pub fn agent_response(&self) -> &str {
+        &self.agent_response_event.agent_response
+    }
+}
+
+/// The agent's reply as far as it was spoken before the user interrupted
+#[derive(Clone, Debug, Deserialize)]
+pub struct AgentResponseCorrection {
+    agent_response_correction_event: AgentResponseCorrectionEvent,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct AgentResponseCorrectionEvent {
+    original_agent_response: String,
+    corrected_agent_response: String,
+}
+
+impl AgentResponseCorrection {
+    pub fn original_agent_response(&self) -> &str {
+        &self.agent_response_correction_event.original_agent_response
+    }
+    pub fn corrected_agent_response(&self) -> &str {
+        &self
+            .agent_response_correction_event
+            .corrected_agent_response
+    }
+}
+
+/// A chunk of the agent's speech
+#[derive(Clone, Debug, Deserialize)]
+pub struct Audio {
+    audio_event: AudioEvent,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct AudioEvent {
+    audio_base_64: String,
+    event_id: u64,
+}
+
+impl Audio {
+    pub fn audio_base_64(&self) -> &str {
+        &self.audio_event.audio_base_64
+    }
+    /// Decodes the audio, in the conversation's
+    /// [output format](ConversationInitiationMetadata::agent_output_audio_format)
+    pub fn audio_as_bytes(&self) -> Result<Bytes> {
+        Ok(Bytes::from(
+            BASE64_STANDARD.decode(&self.audio_event.audio_base_64)?,
+        ))
+    }
+    pub fn event_id(&self) -> u64 {
+        self.audio_event.event_id
+    }
+    /// Rates the agent turn this chunk belongs to
+    pub fn feedback(&self, score: FeedbackScore) -> ClientMessage {
+        ClientMessage::feedback(self.event_id(), score)
+    }
+}
+
+/// The user interrupted the agent, audio of events up to `event_id` should stop playing
+#[derive(Clone, Debug, Deserialize)]
+pub struct Interruption {
+    interruption_event: InterruptionEvent,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct InterruptionEvent {
+    event_id: u64,
+}
+
+impl Interruption {
+    pub fn event_id(&self) -> u64 {
+        self.interruption_event.event_id
+    }
+}
+
+/// Answered with a pong automatically by the [client](super::client)
+#[derive(Clone, Debug, Deserialize)]
+pub struct Ping {
+    ping_event: PingEvent,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct PingEvent {
+    event_id: u64,
+    ping_ms: Option<u64>,
+}
+
+impl Ping {
+    pub fn event_id(&self) -> u64 {
+        self.ping_event.event_id
+    }
+    pub fn ping_ms(&self) -> Option<u64> {
+        self.ping_event.ping_ms
+    }
+}
+
+/// The agent calls a [client tool](super::tools::ClientTool)
+#[derive(Clone, Debug, Deserialize)]
+pub struct ClientToolCall {
+    client_tool_call: ClientToolCallEvent,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct ClientToolCallEvent {
+    tool_name: String,
+    tool_call_id: String,
+    #[serde(default)]
+    parameters: Value,
+}
+
+impl ClientToolCall {
+    pub fn tool_name(&self) -> &str {
+        &self.client_tool_call.tool_name
+    }
+    /// Sent back with the tool's result
+    pub fn tool_call_id(&self) -> &str {
+        &self.client_tool_call.tool_call_id
+    }
+    pub fn parameters(&self) -> &Value {
+        &self.client_tool_call.parameters
+    }
+}
+
+/// The probability that the user is speaking
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::client::Conversation;
+/// use elevenlabs_rs::endpoints::convai::server_messages::ServerMessage;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let mut conversation = Conversation::builder("agent_id").connect(&c).await?;
+///     let mut listening = false;
+///     while let Some(msg) = conversation.next().await {
+///         if let ServerMessage::VadScore(score) = msg? {
+///             if score.is_speaking(0.5) != listening {
+///                 listening = !listening;
+///                 println!("listening: {}", listening);
+///             }
+///         }
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, Deserialize)]
+pub struct VadScore {
+    vad_score_event: VadScoreEvent,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct VadScoreEvent {
+    vad_score: f32,
+}
+
+impl VadScore {
+    /// 0.0 to 1.0
+    pub fn vad_score(&self) -> f32 {
+        self.vad_score_event.vad_score
+    }
+    /// Whether the score reaches `threshold`, e.g. to show that the agent is listening
+    pub fn is_speaking(&self, threshold: f32) -> bool {
+        self.vad_score() >= threshold
+    }
+}
+
+/// A preview of the agent's reply while it is being generated
+#[derive(Clone, Debug, Deserialize)]
+pub struct TentativeAgentResponse {
+    tentative_agent_response_internal_event: TentativeAgentResponseEvent,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct TentativeAgentResponseEvent {
+    tentative_agent_response: String,
+}
+
+impl TentativeAgentResponse {
+    pub fn tentative_agent_response(&self) -> &str {
+        &self
+            .tentative_agent_response_internal_event
+            .tentative_agent_response
+    }
+}
+
+/// The connection dropped and a new one was opened
+///
+/// The agent starts a new conversation on the new connection,
+/// its [metadata](ConversationInitiationMetadata) follows.
+#[derive(Clone, Debug)]
+pub struct Reconnected {
+    pub(crate) attempts: u32,
+}
+
+impl Reconnected {
+    /// How many attempts it took to reconnect
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+}
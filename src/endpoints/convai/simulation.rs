@@ -0,0 +1,289 @@
+//! The conversation simulation endpoints
+//!
+//! An agent talks to an LLM playing the user, and the conversation is then
+//! evaluated as a real one would be, e.g. to test changes to a prompt.
+//!
+//! See the [ElevenLabs docs](https://elevenlabs.io/docs/api-reference/agents/simulate-conversation) for more information.
+use super::agents::{AgentConfig, EvaluationCriterion};
+use super::*;
+use crate::webhooks::{CallAnalysis, TranscriptTurn};
+use async_stream::try_stream;
+use futures_util::Stream;
+use std::pin::Pin;
+
+const AGENTS_PATH: &str = "/agents";
+const SIMULATE_CONVERSATION_PATH: &str = "/simulate-conversation";
+const STREAM_PATH: &str = "/stream";
+
+/// Run a conversation between an agent and a simulated user
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::agents::{AgentConfig, EvaluationCriterion, PromptConfig};
+/// use elevenlabs_rs::endpoints::convai::simulation::*;
+/// use elevenlabs_rs::webhooks::CallSuccessful;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let user = AgentConfig::new(PromptConfig::new(
+///         "You are a customer whose order #1234 has not arrived. You are impatient.",
+///     ));
+///     let spec = SimulationSpecification::new(user)
+///         .with_tool_mock("get_order", ToolMock::new(r#"{"status": "shipped"}"#));
+///     let body = SimulateConversationBody::new(spec)
+///         .with_extra_evaluation_criterion(EvaluationCriterion::new(
+///             "order_status",
+///             "Order status",
+///             "The agent told the user the status of their order",
+///         ))
+///         .with_new_turns_limit(10);
+///     let resp = c.hit(SimulateConversation::new("agent_id", body)).await?;
+///     for result in resp.analysis().evaluation_results() {
+///         assert_eq!(result.result(), &CallSuccessful::Success, "{}", result.rationale());
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SimulateConversation {
+    agent_id: AgentID,
+    body: SimulateConversationBody,
+}
+
+impl SimulateConversation {
+    pub fn new<T: Into<AgentID>>(agent_id: T, body: SimulateConversationBody) -> Self {
+        Self {
+            agent_id: agent_id.into(),
+            body,
+        }
+    }
+}
+
+impl Endpoint for SimulateConversation {
+    type ResponseBody = SimulationResponse;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(serde_json::to_value(&self.body)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        convai_url(&format!(
+            "{}/{}{}",
+            AGENTS_PATH, self.agent_id.0, SIMULATE_CONVERSATION_PATH
+        ))
+    }
+}
+
+/// Run a conversation between an agent and a simulated user, yielding the turns as they happen
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::agents::{AgentConfig, PromptConfig};
+/// use elevenlabs_rs::endpoints::convai::simulation::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let user = AgentConfig::new(PromptConfig::new("You want to cancel your subscription."));
+///     let body = SimulateConversationBody::new(SimulationSpecification::new(user));
+///     let mut stream = c.hit(StreamSimulateConversation::new("agent_id", body)).await?;
+///     while let Some(event) = stream.next().await {
+///         match event? {
+///             SimulationEvent::Turns(turns) => {
+///                 for turn in turns {
+///                     println!("{:?}: {}", turn.role(), turn.message().unwrap_or_default());
+///                 }
+///             }
+///             SimulationEvent::Done { analysis } => println!("{:?}", analysis.call_successful()),
+///         }
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct StreamSimulateConversation {
+    agent_id: AgentID,
+    body: SimulateConversationBody,
+}
+
+impl StreamSimulateConversation {
+    pub fn new<T: Into<AgentID>>(agent_id: T, body: SimulateConversationBody) -> Self {
+        Self {
+            agent_id: agent_id.into(),
+            body,
+        }
+    }
+}
+
+type SimulationStream = Pin<Box<dyn Stream<Item = Result<SimulationEvent>> + Send>>;
+
+impl Endpoint for StreamSimulateConversation {
+    type ResponseBody = SimulationStream;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(serde_json::to_value(&self.body)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        let stream = resp.bytes_stream();
+        let mut buf: Vec<u8> = Vec::new();
+        Ok(Box::pin(try_stream! {
+            for await bytes_result in stream {
+                buf.extend_from_slice(&bytes_result?);
+                // A network chunk may hold several JSON values, or only part of one
+                let mut values = serde_json::Deserializer::from_slice(&buf).into_iter::<SimulationEvent>();
+                let mut events = Vec::new();
+                let consumed = loop {
+                    match values.next() {
+                        Some(Ok(event)) => events.push(event),
+                        Some(Err(e)) if e.is_eof() => break values.byte_offset(),
+                        Some(Err(e)) => Err(e)?,
+                        None => break values.byte_offset(),
+                    }
+                };
+                buf.drain(..consumed);
+                for event in events {
+                    yield event;
+                }
+            }
+        }))
+    }
+    fn url(&self) -> Url {
+        convai_url(&format!(
+            "{}/{}{}{}",
+            AGENTS_PATH, self.agent_id.0, SIMULATE_CONVERSATION_PATH, STREAM_PATH
+        ))
+    }
+}
+
+/// Simulate conversation body
+#[derive(Clone, Debug, Serialize)]
+pub struct SimulateConversationBody {
+    simulation_specification: SimulationSpecification,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    extra_evaluation_criteria: Vec<EvaluationCriterion>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    new_turns_limit: Option<u32>,
+}
+
+impl SimulateConversationBody {
+    pub fn new(simulation_specification: SimulationSpecification) -> Self {
+        Self {
+            simulation_specification,
+            extra_evaluation_criteria: Vec::new(),
+            new_turns_limit: None,
+        }
+    }
+    /// Evaluated in addition to the agent's own criteria
+    pub fn with_extra_evaluation_criterion(mut self, criterion: EvaluationCriterion) -> Self {
+        self.extra_evaluation_criteria.push(criterion);
+        self
+    }
+    /// The maximum number of turns to simulate, defaults to 10000
+    pub fn with_new_turns_limit(mut self, new_turns_limit: u32) -> Self {
+        self.new_turns_limit = Some(new_turns_limit);
+        self
+    }
+}
+
+/// Who the simulated user is and what the agent's tools return
+#[derive(Clone, Debug, Serialize)]
+pub struct SimulationSpecification {
+    simulated_user_config: AgentConfig,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    tool_mock_config: HashMap<String, ToolMock>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    partial_conversation_history: Vec<TranscriptTurn>,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    dynamic_variables: HashMap<String, DynamicVariable>,
+}
+
+impl SimulationSpecification {
+    /// The simulated user is configured like an agent, its prompt describes the user to play
+    pub fn new(simulated_user_config: AgentConfig) -> Self {
+        Self {
+            simulated_user_config,
+            tool_mock_config: HashMap::new(),
+            partial_conversation_history: Vec::new(),
+            dynamic_variables: HashMap::new(),
+        }
+    }
+    /// Returned instead of calling the tool, tools without a mock are called
+    pub fn with_tool_mock<T: Into<String>>(mut self, tool_name: T, mock: ToolMock) -> Self {
+        self.tool_mock_config.insert(tool_name.into(), mock);
+        self
+    }
+    /// Turns the simulation continues from, instead of starting a new conversation
+    pub fn with_partial_conversation_history(mut self, history: Vec<TranscriptTurn>) -> Self {
+        self.partial_conversation_history = history;
+        self
+    }
+    /// Fills `{{name}}` placeholders in the agent's prompt and first message
+    pub fn with_dynamic_variable<K, V>(mut self, name: K, value: V) -> Self
+    where
+        K: Into<String>,
+        V: Into<DynamicVariable>,
+    {
+        self.dynamic_variables.insert(name.into(), value.into());
+        self
+    }
+}
+
+/// The result of a tool in a simulation
+#[derive(Clone, Debug, Serialize)]
+pub struct ToolMock {
+    default_return_value: String,
+    default_is_error: bool,
+}
+
+impl ToolMock {
+    pub fn new<T: Into<String>>(return_value: T) -> Self {
+        Self {
+            default_return_value: return_value.into(),
+            default_is_error: false,
+        }
+    }
+    pub fn error<T: Into<String>>(error: T) -> Self {
+        Self {
+            default_return_value: error.into(),
+            default_is_error: true,
+        }
+    }
+}
+
+/// Simulate conversation response
+#[derive(Clone, Debug, Deserialize)]
+pub struct SimulationResponse {
+    simulated_conversation: Vec<TranscriptTurn>,
+    analysis: CallAnalysis,
+}
+
+impl SimulationResponse {
+    pub fn simulated_conversation(&self) -> &[TranscriptTurn] {
+        &self.simulated_conversation
+    }
+    /// The evaluation of the conversation against the agent's criteria and the extra ones
+    pub fn analysis(&self) -> &CallAnalysis {
+        &self.analysis
+    }
+}
+
+/// A message of a [streamed simulation](StreamSimulateConversation)
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum SimulationEvent {
+    /// The next turns of the conversation
+    Turns(Vec<TranscriptTurn>),
+    /// The last message, once the conversation has been evaluated
+    Done { analysis: CallAnalysis },
+}
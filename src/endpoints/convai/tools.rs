@@ -0,0 +1,603 @@
+//! The tools endpoints
+//!
+//! Workspace tools are defined once and can then be referenced by any number of agents.
+//!
+//! See the [ElevenLabs docs](https://elevenlabs.io/docs/api-reference/tools/list) for more information.
+use super::*;
+use std::collections::HashMap;
+
+const TOOLS_PATH: &str = "/tools";
+const DEPENDENT_AGENTS_PATH: &str = "/dependent-agents";
+
+/// Create a workspace tool
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::tools::*;
+/// use serde_json::json;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let schema = ApiSchema::new("https://api.example.com/orders/{order_id}", WebhookMethod::Get)
+///         .with_path_params_schema(json!({
+///             "order_id": {"type": "string", "description": "The order to look up"}
+///         }))
+///         .with_secret_header("Authorization", "secret_id");
+///     let tool = WebhookTool::new("get_order", "Looks up an order by id", schema)
+///         .with_response_timeout_secs(10);
+///     let resp = c.hit(CreateTool::new(tool)).await?;
+///     println!("{}", resp.id());
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct CreateTool(ToolBody);
+
+impl CreateTool {
+    pub fn new<T: Into<ToolConfig>>(tool_config: T) -> Self {
+        Self(ToolBody {
+            tool_config: tool_config.into(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct ToolBody {
+    tool_config: ToolConfig,
+}
+
+impl Endpoint for CreateTool {
+    type ResponseBody = Tool;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(serde_json::to_value(&self.0)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        convai_url(TOOLS_PATH)
+    }
+}
+
+/// List the workspace tools
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::tools::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let resp = c.hit(ListTools).await?;
+///     for tool in resp.tools() {
+///         println!("{}: {}", tool.id(), tool.tool_config().name());
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ListTools;
+
+impl Endpoint for ListTools {
+    type ResponseBody = ListToolsResponse;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        convai_url(TOOLS_PATH)
+    }
+}
+
+/// List tools response
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListToolsResponse {
+    tools: Vec<Tool>,
+}
+
+impl ListToolsResponse {
+    pub fn tools(&self) -> &[Tool] {
+        &self.tools
+    }
+}
+
+/// Get a workspace tool
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::tools::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let tool = c.hit(GetTool::new("tool_id")).await?;
+///     println!("{:#?}", tool.tool_config());
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct GetTool(ToolID);
+
+impl GetTool {
+    pub fn new<T: Into<String>>(tool_id: T) -> Self {
+        Self(ToolID(tool_id.into()))
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ToolID(String);
+
+impl Endpoint for GetTool {
+    type ResponseBody = Tool;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        convai_url(&format!("{}/{}", TOOLS_PATH, self.0 .0))
+    }
+}
+
+/// Update a workspace tool, the whole config is replaced
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::tools::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let tool = c.hit(GetTool::new("tool_id")).await?;
+///     if let ToolConfig::Webhook(webhook) = tool.tool_config().clone() {
+///         let webhook = webhook.with_response_timeout_secs(30);
+///         c.hit(UpdateTool::new(tool.id(), webhook)).await?;
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct UpdateTool {
+    tool_id: ToolID,
+    body: ToolBody,
+}
+
+impl UpdateTool {
+    pub fn new<T: Into<String>, C: Into<ToolConfig>>(tool_id: T, tool_config: C) -> Self {
+        Self {
+            tool_id: ToolID(tool_id.into()),
+            body: ToolBody {
+                tool_config: tool_config.into(),
+            },
+        }
+    }
+}
+
+impl Endpoint for UpdateTool {
+    type ResponseBody = Tool;
+
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(serde_json::to_value(&self.body)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        convai_url(&format!("{}/{}", TOOLS_PATH, self.tool_id.0))
+    }
+}
+
+/// Delete a workspace tool
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::tools::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     c.hit(DeleteTool::new("tool_id")).await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct DeleteTool(ToolID);
+
+impl DeleteTool {
+    pub fn new<T: Into<String>>(tool_id: T) -> Self {
+        Self(ToolID(tool_id.into()))
+    }
+}
+
+impl Endpoint for DeleteTool {
+    type ResponseBody = ();
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+    async fn response_body(self, _resp: Response) -> Result<Self::ResponseBody> {
+        Ok(())
+    }
+    fn url(&self) -> Url {
+        convai_url(&format!("{}/{}", TOOLS_PATH, self.0 .0))
+    }
+}
+
+/// List the agents that depend on a workspace tool
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::tools::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let agents = c.paginate(GetToolDependentAgents::new("tool_id"));
+///     pin_mut!(agents);
+///     while let Some(agent) = agents.next().await {
+///         println!("{:?}", agent?);
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct GetToolDependentAgents {
+    tool_id: ToolID,
+    query: PageQuery,
+}
+
+impl GetToolDependentAgents {
+    pub fn new<T: Into<String>>(tool_id: T) -> Self {
+        Self {
+            tool_id: ToolID(tool_id.into()),
+            query: PageQuery::default(),
+        }
+    }
+    pub fn with_query(mut self, query: PageQuery) -> Self {
+        self.query = query;
+        self
+    }
+}
+
+impl Endpoint for GetToolDependentAgents {
+    type ResponseBody = DependentAgentsResponse;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        let mut url = convai_url(&format!(
+            "{}/{}{}",
+            TOOLS_PATH, self.tool_id.0, DEPENDENT_AGENTS_PATH
+        ));
+        self.query.append_to(&mut url);
+        trim_query(url)
+    }
+}
+
+impl Paginated for GetToolDependentAgents {
+    type Item = DependentAgent;
+
+    fn next_page(self, page: Self::ResponseBody) -> (Vec<Self::Item>, Option<Self>) {
+        let next = match (page.has_more, page.next_cursor) {
+            (true, Some(cursor)) => Some(Self {
+                query: self.query.with_cursor(cursor),
+                ..self
+            }),
+            _ => None,
+        };
+        (page.agents, next)
+    }
+}
+
+/// A workspace tool
+#[derive(Clone, Debug, Deserialize)]
+pub struct Tool {
+    id: String,
+    tool_config: ToolConfig,
+    access_info: Option<AccessInfo>,
+}
+
+impl Tool {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+    pub fn tool_config(&self) -> &ToolConfig {
+        &self.tool_config
+    }
+    pub fn access_info(&self) -> Option<&AccessInfo> {
+        self.access_info.as_ref()
+    }
+}
+
+/// The config of a tool
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ToolConfig {
+    /// Called by ElevenLabs on the agent's behalf
+    Webhook(WebhookTool),
+    /// Executed by the client, over the conversation's websocket
+    Client(ClientTool),
+    /// Built-in tools such as `end_call`
+    System(SystemTool),
+}
+
+impl ToolConfig {
+    pub fn name(&self) -> &str {
+        match self {
+            ToolConfig::Webhook(tool) => &tool.name,
+            ToolConfig::Client(tool) => &tool.name,
+            ToolConfig::System(tool) => &tool.name,
+        }
+    }
+    pub fn description(&self) -> &str {
+        match self {
+            ToolConfig::Webhook(tool) => &tool.description,
+            ToolConfig::Client(tool) => &tool.description,
+            ToolConfig::System(tool) => &tool.description,
+        }
+    }
+}
+
+impl From<WebhookTool> for ToolConfig {
+    fn from(tool: WebhookTool) -> Self {
+        ToolConfig::Webhook(tool)
+    }
+}
+
+impl From<ClientTool> for ToolConfig {
+    fn from(tool: ClientTool) -> Self {
+        ToolConfig::Client(tool)
+    }
+}
+
+impl From<SystemTool> for ToolConfig {
+    fn from(tool: SystemTool) -> Self {
+        ToolConfig::System(tool)
+    }
+}
+
+/// A tool that calls an HTTP api
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WebhookTool {
+    name: String,
+    description: String,
+    api_schema: ApiSchema,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response_timeout_secs: Option<u32>,
+}
+
+impl WebhookTool {
+    /// The agent decides when to call the tool from its `description`
+    pub fn new<N: Into<String>, D: Into<String>>(
+        name: N,
+        description: D,
+        api_schema: ApiSchema,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            description: description.into(),
+            api_schema,
+            response_timeout_secs: None,
+        }
+    }
+    pub fn with_response_timeout_secs(mut self, response_timeout_secs: u32) -> Self {
+        self.response_timeout_secs = Some(response_timeout_secs);
+        self
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+    pub fn api_schema(&self) -> &ApiSchema {
+        &self.api_schema
+    }
+    pub fn response_timeout_secs(&self) -> Option<u32> {
+        self.response_timeout_secs
+    }
+}
+
+/// The request a [WebhookTool] makes
+///
+/// The schemas are JSON schemas describing the values the agent fills in.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ApiSchema {
+    url: String,
+    method: WebhookMethod,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path_params_schema: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query_params_schema: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    request_body_schema: Option<Value>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    request_headers: HashMap<String, HeaderValue>,
+}
+
+impl ApiSchema {
+    /// Path params are written as `{param}` in the `url`
+    pub fn new<T: Into<String>>(url: T, method: WebhookMethod) -> Self {
+        Self {
+            url: url.into(),
+            method,
+            path_params_schema: None,
+            query_params_schema: None,
+            request_body_schema: None,
+            request_headers: HashMap::new(),
+        }
+    }
+    pub fn with_path_params_schema(mut self, schema: Value) -> Self {
+        self.path_params_schema = Some(schema);
+        self
+    }
+    pub fn with_query_params_schema(mut self, schema: Value) -> Self {
+        self.query_params_schema = Some(schema);
+        self
+    }
+    pub fn with_request_body_schema(mut self, schema: Value) -> Self {
+        self.request_body_schema = Some(schema);
+        self
+    }
+    pub fn with_header<N: Into<String>, V: Into<String>>(mut self, name: N, value: V) -> Self {
+        self.request_headers
+            .insert(name.into(), HeaderValue::Value(value.into()));
+        self
+    }
+    /// A header whose value is a workspace secret, so it is never sent back in responses
+    pub fn with_secret_header<N: Into<String>, S: Into<String>>(
+        mut self,
+        name: N,
+        secret_id: S,
+    ) -> Self {
+        self.request_headers.insert(
+            name.into(),
+            HeaderValue::Secret {
+                secret_id: secret_id.into(),
+            },
+        );
+        self
+    }
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+    pub fn method(&self) -> &WebhookMethod {
+        &self.method
+    }
+    pub fn path_params_schema(&self) -> Option<&Value> {
+        self.path_params_schema.as_ref()
+    }
+    pub fn query_params_schema(&self) -> Option<&Value> {
+        self.query_params_schema.as_ref()
+    }
+    pub fn request_body_schema(&self) -> Option<&Value> {
+        self.request_body_schema.as_ref()
+    }
+    pub fn request_headers(&self) -> &HashMap<String, HeaderValue> {
+        &self.request_headers
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum WebhookMethod {
+    Get,
+    Post,
+    Put,
+    Patch,
+    Delete,
+}
+
+/// The value of a webhook request header
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum HeaderValue {
+    Value(String),
+    Secret { secret_id: String },
+}
+
+/// A tool executed by the client
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ClientTool {
+    name: String,
+    description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parameters: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expects_response: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response_timeout_secs: Option<u32>,
+}
+
+impl ClientTool {
+    pub fn new<N: Into<String>, D: Into<String>>(name: N, description: D) -> Self {
+        Self {
+            name: name.into(),
+            description: description.into(),
+            parameters: None,
+            expects_response: None,
+            response_timeout_secs: None,
+        }
+    }
+    /// A JSON schema of the parameters the agent passes to the tool
+    pub fn with_parameters(mut self, parameters: Value) -> Self {
+        self.parameters = Some(parameters);
+        self
+    }
+    /// Whether the agent waits for a `client_tool_result` before continuing
+    pub fn with_expects_response(mut self, expects_response: bool) -> Self {
+        self.expects_response = Some(expects_response);
+        self
+    }
+    pub fn with_response_timeout_secs(mut self, response_timeout_secs: u32) -> Self {
+        self.response_timeout_secs = Some(response_timeout_secs);
+        self
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+    pub fn parameters(&self) -> Option<&Value> {
+        self.parameters.as_ref()
+    }
+    pub fn expects_response(&self) -> Option<bool> {
+        self.expects_response
+    }
+}
+
+/// A built-in tool
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SystemTool {
+    name: String,
+    description: String,
+    #[serde(default, skip_serializing_if = "Value::is_null")]
+    params: Value,
+}
+
+impl SystemTool {
+    pub fn new<N: Into<String>, D: Into<String>>(name: N, description: D) -> Self {
+        Self {
+            name: name.into(),
+            description: description.into(),
+            params: Value::Null,
+        }
+    }
+    pub fn with_params(mut self, params: Value) -> Self {
+        self.params = params;
+        self
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+    pub fn params(&self) -> &Value {
+        &self.params
+    }
+}
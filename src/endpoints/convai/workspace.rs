@@ -0,0 +1,472 @@
+//! The conversational AI workspace endpoints
+//!
+//! Settings shared by all agents of the workspace, and the secrets their tools use,
+//! e.g. in a [secret header](super::tools::ApiSchema::with_secret_header).
+//!
+//! See the [ElevenLabs docs](https://elevenlabs.io/docs/api-reference/workspace/get) for more information.
+use super::tools::HeaderValue;
+use super::*;
+
+const SETTINGS_PATH: &str = "/settings";
+const SECRETS_PATH: &str = "/secrets";
+
+/// Get the conversational AI settings of the workspace
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::workspace::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let settings = c.hit(GetConvAISettings).await?;
+///     println!("{:?}", settings.post_call_webhook_id());
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct GetConvAISettings;
+
+impl Endpoint for GetConvAISettings {
+    type ResponseBody = ConvAISettings;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        convai_url(SETTINGS_PATH)
+    }
+}
+
+/// Update the conversational AI settings of the workspace
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::workspace::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let webhook = InitiationWebhook::new("https://example.com/elevenlabs/initiation")
+///         .with_secret_header("Authorization", "secret_id");
+///     let body = UpdateConvAISettingsBody::default()
+///         .with_conversation_initiation_client_data_webhook(webhook)
+///         .with_post_call_webhook_id("webhook_id");
+///     c.hit(UpdateConvAISettings::new(body)).await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct UpdateConvAISettings(UpdateConvAISettingsBody);
+
+impl UpdateConvAISettings {
+    pub fn new(body: UpdateConvAISettingsBody) -> Self {
+        Self(body)
+    }
+}
+
+/// Update conversational AI settings body
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct UpdateConvAISettingsBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    conversation_initiation_client_data_webhook: Option<InitiationWebhook>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    webhooks: Option<ConvAIWebhooks>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    can_use_mcp_servers: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rag_retention_period_days: Option<u32>,
+}
+
+impl UpdateConvAISettingsBody {
+    /// Called when a Twilio or SIP call comes in, to get the call's
+    /// [initiation data](ConversationInitiationClientData)
+    pub fn with_conversation_initiation_client_data_webhook(
+        mut self,
+        webhook: InitiationWebhook,
+    ) -> Self {
+        self.conversation_initiation_client_data_webhook = Some(webhook);
+        self
+    }
+    /// The [workspace webhook](crate::endpoints::workspace::GetWorkspaceWebhooks)
+    /// sent after every call, see [webhooks](crate::webhooks)
+    pub fn with_post_call_webhook_id<T: Into<String>>(mut self, webhook_id: T) -> Self {
+        self.webhooks
+            .get_or_insert_with(ConvAIWebhooks::default)
+            .post_call_webhook_id = Some(webhook_id.into());
+        self
+    }
+    pub fn with_can_use_mcp_servers(mut self, can_use_mcp_servers: bool) -> Self {
+        self.can_use_mcp_servers = Some(can_use_mcp_servers);
+        self
+    }
+    /// How long retrieval indexes of unused knowledge base documents are kept, at most 30 days
+    pub fn with_rag_retention_period_days(mut self, rag_retention_period_days: u32) -> Self {
+        self.rag_retention_period_days = Some(rag_retention_period_days);
+        self
+    }
+}
+
+impl From<&ConvAISettings> for UpdateConvAISettingsBody {
+    fn from(settings: &ConvAISettings) -> Self {
+        Self {
+            conversation_initiation_client_data_webhook: settings
+                .conversation_initiation_client_data_webhook
+                .clone(),
+            webhooks: settings.webhooks.clone(),
+            can_use_mcp_servers: Some(settings.can_use_mcp_servers),
+            rag_retention_period_days: Some(settings.rag_retention_period_days),
+        }
+    }
+}
+
+impl Endpoint for UpdateConvAISettings {
+    type ResponseBody = ConvAISettings;
+
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(serde_json::to_value(&self.0)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        convai_url(SETTINGS_PATH)
+    }
+}
+
+/// The conversational AI settings of the workspace
+#[derive(Clone, Debug, Deserialize)]
+pub struct ConvAISettings {
+    conversation_initiation_client_data_webhook: Option<InitiationWebhook>,
+    webhooks: Option<ConvAIWebhooks>,
+    #[serde(default)]
+    can_use_mcp_servers: bool,
+    #[serde(default)]
+    rag_retention_period_days: u32,
+}
+
+impl ConvAISettings {
+    pub fn conversation_initiation_client_data_webhook(&self) -> Option<&InitiationWebhook> {
+        self.conversation_initiation_client_data_webhook.as_ref()
+    }
+    pub fn post_call_webhook_id(&self) -> Option<&str> {
+        self.webhooks.as_ref()?.post_call_webhook_id.as_deref()
+    }
+    pub fn can_use_mcp_servers(&self) -> bool {
+        self.can_use_mcp_servers
+    }
+    pub fn rag_retention_period_days(&self) -> u32 {
+        self.rag_retention_period_days
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+struct ConvAIWebhooks {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    post_call_webhook_id: Option<String>,
+}
+
+/// A webhook returning the initiation data of incoming calls
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct InitiationWebhook {
+    url: String,
+    #[serde(default)]
+    request_headers: HashMap<String, HeaderValue>,
+}
+
+impl InitiationWebhook {
+    pub fn new<T: Into<String>>(url: T) -> Self {
+        Self {
+            url: url.into(),
+            request_headers: HashMap::new(),
+        }
+    }
+    pub fn with_header<N: Into<String>, V: Into<String>>(mut self, name: N, value: V) -> Self {
+        self.request_headers
+            .insert(name.into(), HeaderValue::Value(value.into()));
+        self
+    }
+    /// A header whose value is a [workspace secret](CreateSecret)
+    pub fn with_secret_header<N: Into<String>, S: Into<String>>(
+        mut self,
+        name: N,
+        secret_id: S,
+    ) -> Self {
+        self.request_headers.insert(
+            name.into(),
+            HeaderValue::Secret {
+                secret_id: secret_id.into(),
+            },
+        );
+        self
+    }
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+    pub fn request_headers(&self) -> &HashMap<String, HeaderValue> {
+        &self.request_headers
+    }
+}
+
+/// List the secrets of the workspace, their values are never returned
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::workspace::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let resp = c.hit(ListSecrets).await?;
+///     for secret in resp.secrets() {
+///         println!("{}: {} tools", secret.name(), secret.used_by_tools().len());
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ListSecrets;
+
+impl Endpoint for ListSecrets {
+    type ResponseBody = ListSecretsResponse;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        convai_url(SECRETS_PATH)
+    }
+}
+
+/// List secrets response
+#[derive(Clone, Debug, Deserialize)]
+pub struct ListSecretsResponse {
+    secrets: Vec<Secret>,
+}
+
+impl ListSecretsResponse {
+    pub fn secrets(&self) -> &[Secret] {
+        &self.secrets
+    }
+    pub fn secret_by_name(&self, name: &str) -> Option<&Secret> {
+        self.secrets.iter().find(|secret| secret.name == name)
+    }
+}
+
+/// Create a workspace secret
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::workspace::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let token = std::env::var("ORDERS_API_TOKEN")?;
+///     let secret = c.hit(CreateSecret::new("orders_api_token", token)).await?;
+///     println!("{}", secret.secret_id());
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct CreateSecret(SecretBody);
+
+impl CreateSecret {
+    pub fn new<N: Into<String>, V: Into<String>>(name: N, value: V) -> Self {
+        Self(SecretBody {
+            r#type: "new",
+            name: name.into(),
+            value: value.into(),
+        })
+    }
+}
+
+// The value is left out of the debug output
+#[derive(Clone, Serialize)]
+struct SecretBody {
+    r#type: &'static str,
+    name: String,
+    value: String,
+}
+
+impl std::fmt::Debug for SecretBody {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SecretBody")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Endpoint for CreateSecret {
+    type ResponseBody = Secret;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(serde_json::to_value(&self.0)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        convai_url(SECRETS_PATH)
+    }
+}
+
+/// Replace the value of a workspace secret, e.g. to rotate a credential
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::workspace::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let secrets = c.hit(ListSecrets).await?;
+///     if let Some(secret) = secrets.secret_by_name("orders_api_token") {
+///         let token = std::env::var("ORDERS_API_TOKEN")?;
+///         c.hit(UpdateSecret::new(secret.secret_id(), secret.name(), token)).await?;
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct UpdateSecret {
+    secret_id: SecretID,
+    body: SecretBody,
+}
+
+impl UpdateSecret {
+    pub fn new<I, N, V>(secret_id: I, name: N, value: V) -> Self
+    where
+        I: Into<String>,
+        N: Into<String>,
+        V: Into<String>,
+    {
+        Self {
+            secret_id: SecretID(secret_id.into()),
+            body: SecretBody {
+                r#type: "update",
+                name: name.into(),
+                value: value.into(),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct SecretID(String);
+
+impl Endpoint for UpdateSecret {
+    type ResponseBody = Secret;
+
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(serde_json::to_value(&self.body)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        convai_url(&format!("{}/{}", SECRETS_PATH, self.secret_id.0))
+    }
+}
+
+/// Delete a workspace secret, it must not be used by any tool or agent
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::workspace::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     c.hit(DeleteSecret::new("secret_id")).await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct DeleteSecret(SecretID);
+
+impl DeleteSecret {
+    pub fn new<T: Into<String>>(secret_id: T) -> Self {
+        Self(SecretID(secret_id.into()))
+    }
+}
+
+impl Endpoint for DeleteSecret {
+    type ResponseBody = ();
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+    async fn response_body(self, _resp: Response) -> Result<Self::ResponseBody> {
+        Ok(())
+    }
+    fn url(&self) -> Url {
+        convai_url(&format!("{}/{}", SECRETS_PATH, self.0 .0))
+    }
+}
+
+/// A workspace secret
+#[derive(Clone, Debug, Deserialize)]
+pub struct Secret {
+    secret_id: String,
+    name: String,
+    used_by: Option<SecretUsage>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct SecretUsage {
+    #[serde(default)]
+    tools: Vec<Value>,
+    #[serde(default)]
+    agents: Vec<DependentAgent>,
+    #[serde(default)]
+    phone_numbers: Vec<Value>,
+}
+
+impl Secret {
+    pub fn secret_id(&self) -> &str {
+        &self.secret_id
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// Only set when [listed](ListSecrets)
+    pub fn used_by_tools(&self) -> &[Value] {
+        self.used_by.as_ref().map_or(&[], |u| &u.tools)
+    }
+    /// Only set when [listed](ListSecrets)
+    pub fn used_by_agents(&self) -> &[DependentAgent] {
+        self.used_by.as_ref().map_or(&[], |u| &u.agents)
+    }
+    /// Only set when [listed](ListSecrets)
+    pub fn used_by_phone_numbers(&self) -> &[Value] {
+        self.used_by.as_ref().map_or(&[], |u| &u.phone_numbers)
+    }
+    /// Whether anything uses the secret, only secrets that are not used can be deleted
+    pub fn is_used(&self) -> bool {
+        !(self.used_by_tools().is_empty()
+            && self.used_by_agents().is_empty()
+            && self.used_by_phone_numbers().is_empty())
+    }
+}
@@ -2,7 +2,6 @@
 //! The dubbing endpoints
 use super::*;
 use crate::error::Error;
-use std::path::Path;
 
 const DUBBING_PATH: &str = "v1/dubbing";
 const AUDIO_PATH: &str = "/audio";
@@ -153,10 +152,10 @@ impl DubAVideoOrAnAudioFileResponse {
 #[derive(Clone, Debug, Default)]
 pub struct DubbingBody {
     mode: Option<Mode>,
-    file: Option<String>,
+    file: Option<FileSource>,
     csv_file: Option<String>,
-    foreground_audio_file: Option<String>,
-    background_audio_file: Option<String>,
+    foreground_audio_file: Option<FileSource>,
+    background_audio_file: Option<FileSource>,
     name: Option<String>,
     source_url: Option<String>,
     source_lang: Option<String>,
@@ -209,8 +208,8 @@ impl DubbingBody {
         self.mode = Some(mode);
         self
     }
-    pub fn with_file(mut self, file: String) -> Self {
-        self.file = Some(file);
+    pub fn with_file<F: Into<FileSource>>(mut self, file: F) -> Self {
+        self.file = Some(file.into());
         self
     }
 
@@ -219,13 +218,19 @@ impl DubbingBody {
         self
     }
 
-    pub fn with_foreground_audio_file(mut self, foreground_audio_file: String) -> Self {
-        self.foreground_audio_file = Some(foreground_audio_file);
+    pub fn with_foreground_audio_file<F: Into<FileSource>>(
+        mut self,
+        foreground_audio_file: F,
+    ) -> Self {
+        self.foreground_audio_file = Some(foreground_audio_file.into());
         self
     }
 
-    pub fn with_background_audio_file(mut self, background_audio_file: String) -> Self {
-        self.background_audio_file = Some(background_audio_file);
+    pub fn with_background_audio_file<F: Into<FileSource>>(
+        mut self,
+        background_audio_file: F,
+    ) -> Self {
+        self.background_audio_file = Some(background_audio_file.into());
         self
     }
 
@@ -421,76 +426,21 @@ fn to_form(body: DubbingBody) -> Result<Form> {
         form = form.text("mode", mode.to_string());
     }
     if let Some(file) = body.file {
-        let path = Path::new(&file);
-        let dubbing_file = std::fs::read(path)?;
-        let mut part = Part::bytes(dubbing_file);
-        part = part.file_name(
-            path.to_str()
-                .ok_or(Box::new(Error::FileExtensionNotValidUTF8))?
-                .to_string(),
-        );
-        let mime_subtype = path
-            .extension()
-            .ok_or(Box::new(Error::FileExtensionNotFound))?
-            .to_str()
-            .ok_or(Box::new(Error::FileExtensionNotValidUTF8))?;
-        if mime_subtype == "mp4" {
-            part = part.mime_str("video/mp4")?;
-        } else if mime_subtype == "mp3" {
-            part = part.mime_str("audio/mp3")?;
-        } else {
-            return Err(Box::new(Error::FileExtensionNotSupported));
-        }
-        form = form.part("file", part);
+        let mime = match file.extension()? {
+            "mp4" => "video/mp4",
+            "mp3" => "audio/mp3",
+            _ => return Err(Box::new(Error::FileExtensionNotSupported)),
+        };
+        form = form.part("file", file.to_part()?.mime_str(mime)?);
     }
     if let Some(csv_file) = body.csv_file {
         form = form.text("csv_file", csv_file);
     }
     if let Some(file) = body.foreground_audio_file {
-        let path = Path::new(&file);
-        let foreground_audio_file = std::fs::read(path)?;
-        let mut part = Part::bytes(foreground_audio_file);
-        part = part.file_name(
-            path.to_str()
-                .ok_or(Box::new(Error::FileExtensionNotValidUTF8))?
-                .to_string(),
-        );
-        let mime_subtype = path
-            .extension()
-            .ok_or(Box::new(Error::FileExtensionNotFound))?
-            .to_str()
-            .ok_or(Box::new(Error::FileExtensionNotValidUTF8))?;
-        if mime_subtype == "mp3" {
-            part = part.mime_str("audio/mp3")?;
-        } else if mime_subtype == "wav" {
-            part = part.mime_str("audio/wav")?;
-        } else {
-            return Err(Box::new(Error::FileExtensionNotSupported));
-        }
-        form = form.part("foreground_audio_file", part);
+        form = form.part("foreground_audio_file", audio_track_part(&file)?);
     }
     if let Some(file) = body.background_audio_file {
-        let path = Path::new(&file);
-        let background_audio_file = std::fs::read(path)?;
-        let mut part = Part::bytes(background_audio_file);
-        part = part.file_name(
-            path.to_str()
-                .ok_or(Box::new(Error::FileExtensionNotValidUTF8))?
-                .to_string(),
-        );
-        let mime_subtype = path
-            .extension()
-            .ok_or(Box::new(Error::FileExtensionNotFound))?
-            .to_str()
-            .ok_or(Box::new(Error::FileExtensionNotValidUTF8))?;
-        if mime_subtype == "mp3" {
-            part = part.mime_str("audio/mp3")?;
-        } else if mime_subtype == "wav" {
-            part = part.mime_str("audio/wav")?;
-        } else {
-            return Err(Box::new(Error::FileExtensionNotSupported));
-        }
-        form = form.part("background_audio_file", part);
+        form = form.part("background_audio_file", audio_track_part(&file)?);
     }
     if let Some(name) = body.name {
         form = form.text("name", name);
@@ -524,3 +474,12 @@ fn to_form(body: DubbingBody) -> Result<Form> {
     }
     Ok(form)
 }
+
+fn audio_track_part(file: &FileSource) -> Result<Part> {
+    let mime = match file.extension()? {
+        "mp3" => "audio/mp3",
+        "wav" => "audio/wav",
+        _ => return Err(Box::new(Error::FileExtensionNotSupported)),
+    };
+    Ok(file.to_part()?.mime_str(mime)?)
+}
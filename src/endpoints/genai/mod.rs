@@ -0,0 +1,4 @@
+//! The generative AI endpoints, creating audio other than speech
+use super::*;
+
+pub mod music;
@@ -0,0 +1,481 @@
+#![allow(dead_code)]
+//! The music endpoints
+//!
+//! Compose music from a text prompt or from a composition plan.
+//!
+//! A composition plan lets you control the global styles of a song as well as
+//! the styles, lyrics and duration of each of its sections.
+//! Use [CreateCompositionPlan] to have one generated from a prompt, tweak it, then
+//! pass it to [ComposeMusic] or [ComposeMusicDetailed].
+//!
+//! See the [ElevenLabs docs](https://elevenlabs.io/docs/api-reference/music/compose) for more information.
+use super::*;
+use crate::error::Error;
+
+const MUSIC_PATH: &str = "/v1/music";
+const DETAILED_PATH: &str = "/detailed";
+const PLAN_PATH: &str = "/plan";
+const OUTPUT_FORMAT_QUERY: &str = "output_format";
+
+/// Compose a song from a prompt or a composition plan, the response is the raw audio
+///
+/// # Example
+///
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::utils::save;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///    let c = ElevenLabsClient::default()?;
+///    let body = ComposeMusicBody::from_prompt("An upbeat lo-fi hip hop beat for studying")
+///        .with_music_length_ms(30_000);
+///    let song = c.hit(ComposeMusic::new(body)).await?;
+///    save("lofi.mp3", song)?;
+///    Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ComposeMusic {
+    body: ComposeMusicBody,
+    output_format: Option<OutputFormat>,
+}
+
+impl ComposeMusic {
+    pub fn new(body: ComposeMusicBody) -> Self {
+        ComposeMusic {
+            body,
+            output_format: None,
+        }
+    }
+    pub fn with_output_format(mut self, output_format: OutputFormat) -> Self {
+        self.output_format = Some(output_format);
+        self
+    }
+}
+
+impl Endpoint for ComposeMusic {
+    type ResponseBody = Bytes;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(serde_json::to_value(&self.body)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.bytes().await?)
+    }
+    fn url(&self) -> Url {
+        let mut url = BASE_URL.parse::<Url>().unwrap();
+        url.set_path(MUSIC_PATH);
+        url.set_query(output_format_query(&self.output_format).as_deref());
+        url
+    }
+}
+
+/// Compose a song and get back the composition plan and song metadata along with the audio
+///
+/// # Example
+///
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::utils::save;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///    let c = ElevenLabsClient::default()?;
+///    let body = ComposeMusicBody::from_prompt("A cinematic orchestral piece with a slow build");
+///    let resp = c.hit(ComposeMusicDetailed::new(body)).await?;
+///    println!("{:#?}", resp.composition_plan());
+///    save("orchestral.mp3", resp.audio().clone())?;
+///    Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ComposeMusicDetailed {
+    body: ComposeMusicBody,
+    output_format: Option<OutputFormat>,
+}
+
+impl ComposeMusicDetailed {
+    pub fn new(body: ComposeMusicBody) -> Self {
+        ComposeMusicDetailed {
+            body,
+            output_format: None,
+        }
+    }
+    pub fn with_output_format(mut self, output_format: OutputFormat) -> Self {
+        self.output_format = Some(output_format);
+        self
+    }
+}
+
+impl Endpoint for ComposeMusicDetailed {
+    type ResponseBody = ComposeMusicDetailedResponse;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(serde_json::to_value(&self.body)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        let boundary = resp
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.split("boundary=").nth(1))
+            .map(|b| b.trim_matches('"').to_string())
+            .ok_or(Box::new(Error::MultipartBoundaryNotFound))?;
+        let bytes = resp.bytes().await?;
+        ComposeMusicDetailedResponse::from_multipart(&bytes, &boundary)
+    }
+    fn url(&self) -> Url {
+        let mut url = BASE_URL.parse::<Url>().unwrap();
+        url.set_path(&format!("{}{}", MUSIC_PATH, DETAILED_PATH));
+        url.set_query(output_format_query(&self.output_format).as_deref());
+        url
+    }
+}
+
+/// Generate a composition plan from a prompt
+///
+/// This endpoint does not cost any credits.
+///
+/// # Example
+///
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///    let c = ElevenLabsClient::default()?;
+///    let body = CompositionPlanBody::new("A punk rock anthem about missing the bus")
+///        .with_music_length_ms(60_000);
+///    let plan = c.hit(CreateCompositionPlan::new(body)).await?;
+///    let song = c.hit(ComposeMusic::new(ComposeMusicBody::from_plan(plan))).await?;
+///    Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct CreateCompositionPlan(CompositionPlanBody);
+
+impl CreateCompositionPlan {
+    pub fn new(body: CompositionPlanBody) -> Self {
+        CreateCompositionPlan(body)
+    }
+}
+
+impl Endpoint for CreateCompositionPlan {
+    type ResponseBody = CompositionPlan;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(serde_json::to_value(&self.0)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        let mut url = BASE_URL.parse::<Url>().unwrap();
+        url.set_path(&format!("{}{}", MUSIC_PATH, PLAN_PATH));
+        url
+    }
+}
+
+/// Compose music body
+///
+/// Either a prompt or a composition plan must be given, not both.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ComposeMusicBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    prompt: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    composition_plan: Option<CompositionPlan>,
+    /// Only used with a prompt, a composition plan carries its own section durations
+    #[serde(skip_serializing_if = "Option::is_none")]
+    music_length_ms: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    model_id: Option<String>,
+}
+
+impl ComposeMusicBody {
+    pub fn from_prompt(prompt: &str) -> Self {
+        ComposeMusicBody {
+            prompt: Some(prompt.to_string()),
+            ..Default::default()
+        }
+    }
+    pub fn from_plan(composition_plan: CompositionPlan) -> Self {
+        ComposeMusicBody {
+            composition_plan: Some(composition_plan),
+            ..Default::default()
+        }
+    }
+    /// `music_length_ms` expected to be between 10,000 and 300,000
+    pub fn with_music_length_ms(mut self, music_length_ms: u32) -> Self {
+        self.music_length_ms = Some(music_length_ms);
+        self
+    }
+    pub fn with_model_id(mut self, model_id: &str) -> Self {
+        self.model_id = Some(model_id.to_string());
+        self
+    }
+}
+
+/// Composition plan body
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CompositionPlanBody {
+    prompt: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    music_length_ms: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source_composition_plan: Option<CompositionPlan>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    model_id: Option<String>,
+}
+
+impl CompositionPlanBody {
+    pub fn new(prompt: &str) -> Self {
+        CompositionPlanBody {
+            prompt: prompt.to_string(),
+            ..Default::default()
+        }
+    }
+    pub fn with_music_length_ms(mut self, music_length_ms: u32) -> Self {
+        self.music_length_ms = Some(music_length_ms);
+        self
+    }
+    /// A plan to use as a starting point, the prompt is then used to alter it
+    pub fn with_source_composition_plan(mut self, plan: CompositionPlan) -> Self {
+        self.source_composition_plan = Some(plan);
+        self
+    }
+    pub fn with_model_id(mut self, model_id: &str) -> Self {
+        self.model_id = Some(model_id.to_string());
+        self
+    }
+}
+
+/// A composition plan
+///
+/// # Example
+/// ```
+/// use elevenlabs_rs::*;
+///
+/// let plan = CompositionPlan::new()
+///     .with_positive_global_styles(vec!["synthwave".into(), "driving".into()])
+///     .with_negative_global_styles(vec!["acoustic".into()])
+///     .with_section(
+///         Section::new("Intro", 8_000)
+///             .with_positive_local_styles(vec!["arpeggiated synth".into()])
+///     )
+///     .with_section(
+///         Section::new("Verse", 20_000)
+///             .with_lines(vec!["Neon lights on an empty road".into()])
+///     );
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CompositionPlan {
+    positive_global_styles: Vec<String>,
+    negative_global_styles: Vec<String>,
+    sections: Vec<Section>,
+}
+
+impl CompositionPlan {
+    pub fn new() -> Self {
+        CompositionPlan::default()
+    }
+    pub fn with_positive_global_styles(mut self, styles: Vec<String>) -> Self {
+        self.positive_global_styles = styles;
+        self
+    }
+    pub fn with_negative_global_styles(mut self, styles: Vec<String>) -> Self {
+        self.negative_global_styles = styles;
+        self
+    }
+    pub fn with_section(mut self, section: Section) -> Self {
+        self.sections.push(section);
+        self
+    }
+    pub fn positive_global_styles(&self) -> &[String] {
+        &self.positive_global_styles
+    }
+    pub fn negative_global_styles(&self) -> &[String] {
+        &self.negative_global_styles
+    }
+    pub fn sections(&self) -> &[Section] {
+        &self.sections
+    }
+    pub fn sections_mut(&mut self) -> &mut Vec<Section> {
+        &mut self.sections
+    }
+}
+
+/// A section of a composition plan
+///
+/// `duration_ms` expected to be between 3,000 and 120,000
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Section {
+    section_name: String,
+    positive_local_styles: Vec<String>,
+    negative_local_styles: Vec<String>,
+    duration_ms: u32,
+    lines: Vec<String>,
+}
+
+impl Section {
+    pub fn new(section_name: &str, duration_ms: u32) -> Self {
+        Section {
+            section_name: section_name.to_string(),
+            duration_ms,
+            ..Default::default()
+        }
+    }
+    pub fn with_positive_local_styles(mut self, styles: Vec<String>) -> Self {
+        self.positive_local_styles = styles;
+        self
+    }
+    pub fn with_negative_local_styles(mut self, styles: Vec<String>) -> Self {
+        self.negative_local_styles = styles;
+        self
+    }
+    /// The lyrics of the section, leave empty for an instrumental section
+    pub fn with_lines(mut self, lines: Vec<String>) -> Self {
+        self.lines = lines;
+        self
+    }
+    pub fn section_name(&self) -> &str {
+        &self.section_name
+    }
+    pub fn positive_local_styles(&self) -> &[String] {
+        &self.positive_local_styles
+    }
+    pub fn negative_local_styles(&self) -> &[String] {
+        &self.negative_local_styles
+    }
+    pub fn duration_ms(&self) -> u32 {
+        self.duration_ms
+    }
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+}
+
+/// The response from the [ComposeMusicDetailed] endpoint
+#[derive(Clone, Debug)]
+pub struct ComposeMusicDetailedResponse {
+    json: MusicDetailedJson,
+    audio: Bytes,
+    filename: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct MusicDetailedJson {
+    composition_plan: CompositionPlan,
+    song_metadata: Option<SongMetadata>,
+}
+
+impl ComposeMusicDetailedResponse {
+    pub fn composition_plan(&self) -> &CompositionPlan {
+        &self.json.composition_plan
+    }
+    pub fn song_metadata(&self) -> Option<&SongMetadata> {
+        self.json.song_metadata.as_ref()
+    }
+    pub fn audio(&self) -> &Bytes {
+        &self.audio
+    }
+    pub fn filename(&self) -> Option<&str> {
+        self.filename.as_deref()
+    }
+
+    /// The detailed response is a `multipart/mixed` body,
+    /// the first part is the JSON metadata and the second part the audio.
+    fn from_multipart(body: &Bytes, boundary: &str) -> Result<Self> {
+        let delimiter = format!("--{}", boundary);
+        let mut json = None;
+        let mut audio = None;
+        let mut filename = None;
+
+        for part in split_bytes(body, delimiter.as_bytes()) {
+            let Some(header_end) = find_bytes(part, b"\r\n\r\n") else {
+                continue;
+            };
+            let headers = String::from_utf8_lossy(&part[..header_end]).to_lowercase();
+            let mut content = &part[header_end + 4..];
+            if content.ends_with(b"\r\n") {
+                content = &content[..content.len() - 2];
+            }
+            if headers.contains("application/json") {
+                json = Some(serde_json::from_slice::<MusicDetailedJson>(content)?);
+            } else if headers.contains("content-type") {
+                filename = headers
+                    .split("filename=")
+                    .nth(1)
+                    .and_then(|f| f.split(['\r', '\n', ';']).next())
+                    .map(|f| f.trim_matches('"').to_string());
+                audio = Some(body.slice_ref(content));
+            }
+        }
+
+        Ok(ComposeMusicDetailedResponse {
+            json: json.ok_or(Box::new(Error::MultipartPartNotFound("json".to_string())))?,
+            audio: audio.ok_or(Box::new(Error::MultipartPartNotFound("audio".to_string())))?,
+            filename,
+        })
+    }
+}
+
+/// Metadata of a composed song
+#[derive(Clone, Debug, Deserialize)]
+pub struct SongMetadata {
+    title: Option<String>,
+    description: Option<String>,
+    genres: Option<Vec<String>>,
+    languages: Option<Vec<String>>,
+    is_explicit: Option<bool>,
+}
+
+impl SongMetadata {
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+    pub fn genres(&self) -> Option<&Vec<String>> {
+        self.genres.as_ref()
+    }
+    pub fn languages(&self) -> Option<&Vec<String>> {
+        self.languages.as_ref()
+    }
+    pub fn is_explicit(&self) -> Option<bool> {
+        self.is_explicit
+    }
+}
+
+fn output_format_query(output_format: &Option<OutputFormat>) -> Option<String> {
+    output_format
+        .as_ref()
+        .map(|f| format!("{}={}", OUTPUT_FORMAT_QUERY, f.as_str()))
+}
+
+fn find_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+fn split_bytes<'a>(mut haystack: &'a [u8], delimiter: &[u8]) -> Vec<&'a [u8]> {
+    let mut parts = Vec::new();
+    while let Some(i) = find_bytes(haystack, delimiter) {
+        parts.push(&haystack[..i]);
+        haystack = &haystack[i + delimiter.len()..];
+    }
+    parts.push(haystack);
+    parts
+}
@@ -10,7 +10,23 @@ const PAGE_SIZE_QUERY: &str = "page_size";
 const HISTORY_ITEM_IDS: &str = "history_item_ids";
 const START_AFTER_HISTORY_ITEM_ID_QUERY: &str = "start_after_history_item_id";
 const VOICE_ID_QUERY: &str = "voice_id";
+const SEARCH_QUERY: &str = "search";
+const SOURCE_QUERY: &str = "source";
 
+/// Delete a history item
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let resp = c.hit(DeleteHistoryItem::new("history_item_id")).await?;
+///     println!("{:?}", resp);
+///     Ok(())
+/// }
+/// ```
 #[derive(Clone, Debug)]
 pub struct DeleteHistoryItem(HistoryItemID);
 
@@ -142,21 +158,24 @@ impl DownloadOutputFormat {
 ///         .history()
 ///         .first().unwrap()
 ///         .history_item_id();
-///     let audio = client.hit(GetAudio::new(item_id)).await?;
+///     let audio = client.hit(GetHistoryItemAudio::new(item_id)).await?;
 ///     play(audio)?;
 ///     Ok(())
 /// }
 /// ```
 #[derive(Clone, Debug)]
-pub struct GetAudio(HistoryItemID);
+pub struct GetHistoryItemAudio(HistoryItemID);
 
-impl GetAudio {
+#[deprecated(note = "Use `GetHistoryItemAudio` instead")]
+pub type GetAudio = GetHistoryItemAudio;
+
+impl GetHistoryItemAudio {
     pub fn new<T: Into<String>>(history_item_id: T) -> Self {
         Self(HistoryItemID(history_item_id.into()))
     }
 }
 
-impl Endpoint for GetAudio {
+impl Endpoint for GetHistoryItemAudio {
     type ResponseBody = Bytes;
     fn method(&self) -> Method {
         Method::GET
@@ -182,7 +201,9 @@ impl Endpoint for GetAudio {
 ///     let c = ElevenLabsClient::default()?;
 ///     let query = HistoryQuery::default()
 ///         .with_page_size(10)
-///         .with_voice_id(PreMadeVoiceID::Alice);
+///         .with_voice_id(PreMadeVoiceID::Alice)
+///         .with_search("chapter one")
+///         .with_source(HistorySource::TextToSpeech);
 ///     let endpoint = GetGeneratedItems::new(query);
 ///     let resp = c.hit(endpoint).await?;
 ///     println!("{:#?}", resp);
@@ -215,6 +236,36 @@ impl Endpoint for GetGeneratedItems {
     }
 }
 
+impl Paginated for GetGeneratedItems {
+    type Item = HistoryItem;
+
+    fn next_page(self, page: Self::ResponseBody) -> (Vec<Self::Item>, Option<Self>) {
+        let next = if page.has_more && !page.history.is_empty() {
+            let query = self
+                .0
+                .with_start_after_history_item_id(&page.last_history_item_id);
+            Some(GetGeneratedItems(query))
+        } else {
+            None
+        };
+        (page.history, next)
+    }
+}
+
+/// Get a history item
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let item = c.hit(GetHistoryItem::new("history_item_id")).await?;
+///     println!("{}: {}", item.history_item_id(), item.text());
+///     Ok(())
+/// }
+/// ```
 #[derive(Clone, Debug)]
 pub struct GetHistoryItem(HistoryItemID);
 
@@ -244,6 +295,8 @@ pub struct HistoryQuery {
     pub page_size: Option<String>,
     pub start_after_history_item_id: Option<String>,
     pub voice_id: Option<String>,
+    pub search: Option<String>,
+    pub source: Option<String>,
 }
 
 impl HistoryQuery {
@@ -259,10 +312,21 @@ impl HistoryQuery {
         self
     }
 
-    pub fn with_voice_id<T: Into<String>>(mut self, voice_id: T) -> Self {
+    pub fn with_voice_id<T: Into<VoiceID>>(mut self, voice_id: T) -> Self {
         self.voice_id = Some(format!("{}={}", VOICE_ID_QUERY, voice_id.into()));
         self
     }
+    /// Matches the text of the items, the search is fuzzy
+    pub fn with_search(mut self, search: &str) -> Self {
+        let mut url = BASE_URL.parse::<Url>().unwrap();
+        url.query_pairs_mut().append_pair(SEARCH_QUERY, search);
+        self.search = url.query().map(str::to_string);
+        self
+    }
+    pub fn with_source(mut self, source: HistorySource) -> Self {
+        self.source = Some(format!("{}={}", SOURCE_QUERY, source.as_str()));
+        self
+    }
     pub fn join(&mut self) -> Option<String> {
         let mut result = String::new();
 
@@ -281,6 +345,18 @@ impl HistoryQuery {
             }
             result.push_str(&value);
         }
+        if let Some(value) = self.search.take() {
+            if !result.is_empty() {
+                result.push('&');
+            }
+            result.push_str(&value);
+        }
+        if let Some(value) = self.source.take() {
+            if !result.is_empty() {
+                result.push('&');
+            }
+            result.push_str(&value);
+        }
         if result.is_empty() {
             None
         } else {
@@ -289,6 +365,22 @@ impl HistoryQuery {
     }
 }
 
+/// The endpoint a history item was generated with
+#[derive(Clone, Debug)]
+pub enum HistorySource {
+    TextToSpeech,
+    SpeechToSpeech,
+}
+
+impl HistorySource {
+    pub fn as_str(&self) -> &str {
+        match self {
+            HistorySource::TextToSpeech => "TTS",
+            HistorySource::SpeechToSpeech => "STS",
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct GeneratedItems {
     history: Vec<HistoryItem>,
@@ -352,6 +444,11 @@ impl HistoryItem {
     pub fn date_unix(&self) -> u64 {
         self.date_unix
     }
+    /// [date_unix](Self::date_unix) as a date
+    #[cfg(feature = "chrono")]
+    pub fn date(&self) -> DateTime<Utc> {
+        utc(self.date_unix())
+    }
     pub fn character_count_change_from(&self) -> u64 {
         self.character_count_change_from
     }
@@ -3,49 +3,141 @@ pub(crate) use crate::shared::identifiers::*;
 pub(crate) use crate::shared::path_segments::*;
 pub use crate::shared::query_params::*;
 pub use crate::shared::response_bodies::*;
+#[cfg(feature = "chrono")]
+pub(crate) use crate::shared::time::utc;
+pub use crate::shared::upload::FileSource;
 pub use base64::prelude::{Engine, BASE64_STANDARD};
 pub use bytes::Bytes;
+#[cfg(feature = "chrono")]
+pub use chrono::{DateTime, Utc};
 pub use reqwest::{
     multipart::{Form, Part},
     Method, Response, Url,
 };
 pub use serde::{Deserialize, Serialize};
-pub use serde_json::Value;
+pub use serde_json::{Map, Value};
 
 pub mod audio_native;
+pub mod convai;
 pub mod dubbing;
+pub mod genai;
 pub mod history;
 pub mod models;
 pub mod projects;
 pub mod pronunciation;
+pub mod pvc;
 pub mod samples;
+pub mod service_accounts;
 pub mod sound_generation;
 pub mod sts;
+pub mod stt;
+pub mod text_to_dialogue;
 pub mod tts;
+pub mod usage;
 pub mod user;
 pub mod voice;
 #[deprecated(since = "0.3.2 ", note = "Use `voice_design` instead")]
 pub mod voice_generation;
 pub mod voice_library;
+pub mod workspace;
 pub mod audio_isolation;
 #[cfg(feature = "dev")]
 pub mod conversational_ai;
 pub mod voice_design;
 
+/// A request to the API, sent with [`ElevenLabsClient::hit`](crate::ElevenLabsClient::hit)
+///
+/// Implement it for endpoints this crate does not cover yet. The client adds the api key,
+/// moves the url onto its [base url](crate::ClientBuilder::base_url) and applies retries,
+/// middleware and error handling as for any other endpoint.
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::{api_url, Endpoint, RequestBody};
+/// use elevenlabs_rs::endpoints::{Method, Response, Url};
+/// use serde::Deserialize;
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize)]
+/// struct Translation {
+///     text: String,
+/// }
+///
+/// struct Translate {
+///     text: String,
+///     language: String,
+/// }
+///
+/// impl Endpoint for Translate {
+///     type ResponseBody = Translation;
+///
+///     fn method(&self) -> Method {
+///         Method::POST
+///     }
+///     fn request_body(&self) -> Result<RequestBody> {
+///         Ok(RequestBody::Json(json!({ "text": self.text })))
+///     }
+///     async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+///         Ok(resp.json().await?)
+///     }
+///     fn url(&self) -> Url {
+///         let mut url = api_url("/v1/translate");
+///         url.query_pairs_mut().append_pair("language", &self.language);
+///         url
+///     }
+/// }
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let endpoint = Translate {
+///         text: "Hello".to_string(),
+///         language: "fr".to_string(),
+///     };
+///     let translation = c.hit(endpoint).await?;
+///     println!("{}", translation.text);
+///     Ok(())
+/// }
+/// ```
 #[allow(async_fn_in_trait)]
 pub trait Endpoint {
     type ResponseBody;
 
     fn method(&self) -> Method;
+    /// Only sent for `POST`, `PATCH` and `DELETE` requests
     fn request_body(&self) -> Result<RequestBody> {
         Ok(RequestBody::Empty)
     }
+    /// Parses a successful response, error statuses are handled by the client
     async fn response_body(self, resp: Response) -> Result<Self::ResponseBody>;
+    /// The full url on [`BASE_URL`], including any query, see [api_url]
     fn url(&self) -> Url;
 }
 
+/// An endpoint whose response is one page of a listing
+///
+/// Used by [`ElevenLabsClient::paginate`](crate::ElevenLabsClient::paginate)
+/// to follow the cursor of a listing until there are no more pages.
+pub trait Paginated: Endpoint + Clone {
+    type Item;
+
+    /// Splits a page into its items and the endpoint for the next page, if there is one
+    fn next_page(self, page: Self::ResponseBody) -> (Vec<Self::Item>, Option<Self>);
+}
+
+/// The body of a request
 pub enum RequestBody {
+    /// Sent with a `Content-Type: application/json` header
     Json(Value),
+    /// e.g. uploads, see [FileSource::to_part]
     Multipart(Form),
     Empty,
 }
+
+/// A url with the given path on [`BASE_URL`]
+pub fn api_url(path: &str) -> Url {
+    let mut url = BASE_URL.parse::<Url>().expect("valid base url");
+    url.set_path(path);
+    url
+}
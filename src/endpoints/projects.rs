@@ -1,22 +1,531 @@
-// TODO: Get API access to the projects endpoint
-#![allow(dead_code)]
+//! The Studio projects endpoints
+//!
+//! Create long form audio, e.g. audiobooks, from a document, a URL or plain text,
+//! then convert it and download or stream the audio of its snapshots.
+//!
+//! See the [ElevenLabs docs](https://elevenlabs.io/docs/api-reference/studio/get-projects) for more information.
 use super::*;
+use crate::endpoints::tts::PronunciationDictionaryLocator;
+use crate::shared::path_segments::STREAM_PATH;
+use futures_util::{Stream, StreamExt};
+use std::pin::Pin;
 
-const PROJECTS_PATH: &str = "/v1/projects";
+const PROJECTS_PATH: &str = "/v1/studio/projects";
+const PODCASTS_PATH: &str = "/v1/studio/podcasts";
+const CONTENT_PATH: &str = "/content";
+const CONVERT_PATH: &str = "/convert";
+const SNAPSHOTS_PATH: &str = "/snapshots";
+const TEXT_FILE_NAME: &str = "content.txt";
 
-/// Projects endpoint
+/// Create a Studio project
+///
+/// # Example
+///
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let body = CreateProjectBody::new("My audiobook")
+///         .with_source(ProjectSource::document("my_audiobook.epub"))
+///         .with_default_title_voice_id(PreMadeVoiceID::George)
+///         .with_default_paragraph_voice_id(PreMadeVoiceID::George)
+///         .with_default_model_id(Model::ElevenMultilingualV2)
+///         .with_quality_preset(QualityPreset::High)
+///         .with_author("Jane Doe")
+///         .with_auto_convert(true);
+///     let resp = c.hit(CreateProject::new(body)).await?;
+///     println!("{}", resp.project().project_id());
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct CreateProject(CreateProjectBody);
+
+impl CreateProject {
+    pub fn new(body: CreateProjectBody) -> Self {
+        CreateProject(body)
+    }
+}
+
+impl Endpoint for CreateProject {
+    type ResponseBody = ProjectResponse;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Multipart(self.0.to_form()?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        let mut url = BASE_URL.parse::<Url>().unwrap();
+        url.set_path(PROJECTS_PATH);
+        url
+    }
+}
+
+/// What a project is created from, an empty project is created if none is given
+#[derive(Clone, Debug)]
+pub enum ProjectSource {
+    /// Plain text, uploaded as a `.txt` document
+    Text(String),
+    /// A URL to a web page whose content is extracted
+    Url(String),
+    /// An `.epub`, `.pdf`, `.txt`, `.docx` or `.html` file
+    Document(FileSource),
+}
+
+impl ProjectSource {
+    pub fn text(text: &str) -> Self {
+        ProjectSource::Text(text.to_string())
+    }
+    pub fn url(url: &str) -> Self {
+        ProjectSource::Url(url.to_string())
+    }
+    pub fn document<F: Into<FileSource>>(document: F) -> Self {
+        ProjectSource::Document(document.into())
+    }
+
+    fn add_to(&self, form: Form) -> Result<Form> {
+        Ok(match self {
+            ProjectSource::Text(text) => {
+                let part = Part::text(text.clone())
+                    .file_name(TEXT_FILE_NAME)
+                    .mime_str("text/plain")?;
+                form.part("from_document", part)
+            }
+            ProjectSource::Url(url) => form.text("from_url", url.clone()),
+            ProjectSource::Document(document) => form.part("from_document", document.to_part()?),
+        })
+    }
+}
+
+/// The quality of the generated audio, higher presets cost more credits
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum QualityPreset {
+    Standard,
+    High,
+    Ultra,
+    UltraLossless,
+}
+
+impl QualityPreset {
+    pub fn as_str(&self) -> &str {
+        match self {
+            QualityPreset::Standard => "standard",
+            QualityPreset::High => "high",
+            QualityPreset::Ultra => "ultra",
+            QualityPreset::UltraLossless => "ultra_lossless",
+        }
+    }
+}
+
+/// Create project body
+#[derive(Clone, Debug, Default)]
+pub struct CreateProjectBody {
+    name: String,
+    source: Option<ProjectSource>,
+    default_title_voice_id: Option<VoiceID>,
+    default_paragraph_voice_id: Option<VoiceID>,
+    default_model_id: Option<String>,
+    quality_preset: Option<QualityPreset>,
+    title: Option<String>,
+    author: Option<String>,
+    description: Option<String>,
+    genres: Vec<String>,
+    language: Option<String>,
+    isbn_number: Option<String>,
+    volume_normalization: Option<bool>,
+    pronunciation_dictionary_locators: Vec<PronunciationDictionaryLocator>,
+    callback_url: Option<String>,
+    auto_convert: Option<bool>,
+    auto_assign_voices: Option<bool>,
+}
+
+impl CreateProjectBody {
+    pub fn new(name: &str) -> Self {
+        CreateProjectBody {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+    pub fn with_source(mut self, source: ProjectSource) -> Self {
+        self.source = Some(source);
+        self
+    }
+    pub fn with_default_title_voice_id<T: Into<VoiceID>>(mut self, voice_id: T) -> Self {
+        self.default_title_voice_id = Some(voice_id.into());
+        self
+    }
+    pub fn with_default_paragraph_voice_id<T: Into<VoiceID>>(mut self, voice_id: T) -> Self {
+        self.default_paragraph_voice_id = Some(voice_id.into());
+        self
+    }
+    pub fn with_default_model_id<T: Into<String>>(mut self, model_id: T) -> Self {
+        self.default_model_id = Some(model_id.into());
+        self
+    }
+    pub fn with_quality_preset(mut self, quality_preset: QualityPreset) -> Self {
+        self.quality_preset = Some(quality_preset);
+        self
+    }
+    pub fn with_title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+    pub fn with_author(mut self, author: &str) -> Self {
+        self.author = Some(author.to_string());
+        self
+    }
+    pub fn with_description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+    pub fn with_genres(mut self, genres: Vec<String>) -> Self {
+        self.genres = genres;
+        self
+    }
+    /// An ISO 639-1 language code, e.g. `en`
+    pub fn with_language(mut self, language: &str) -> Self {
+        self.language = Some(language.to_string());
+        self
+    }
+    pub fn with_isbn_number(mut self, isbn_number: &str) -> Self {
+        self.isbn_number = Some(isbn_number.to_string());
+        self
+    }
+    /// Normalize the volume to the audiobook requirements of e.g. ACX
+    pub fn with_volume_normalization(mut self, volume_normalization: bool) -> Self {
+        self.volume_normalization = Some(volume_normalization);
+        self
+    }
+    pub fn with_pronunciation_dictionary<L: Into<PronunciationDictionaryLocator>>(
+        mut self,
+        locator: L,
+    ) -> Self {
+        self.pronunciation_dictionary_locators.push(locator.into());
+        self
+    }
+    /// A URL that is called once the project or one of its chapters is converted
+    pub fn with_callback_url(mut self, callback_url: &str) -> Self {
+        self.callback_url = Some(callback_url.to_string());
+        self
+    }
+    /// Convert the project to audio as soon as it is created
+    pub fn with_auto_convert(mut self, auto_convert: bool) -> Self {
+        self.auto_convert = Some(auto_convert);
+        self
+    }
+    /// Assign a voice to each character of the text, e.g. for dialogue
+    pub fn with_auto_assign_voices(mut self, auto_assign_voices: bool) -> Self {
+        self.auto_assign_voices = Some(auto_assign_voices);
+        self
+    }
+
+    fn to_form(&self) -> Result<Form> {
+        let mut form = Form::new().text("name", self.name.clone());
+        if let Some(source) = &self.source {
+            form = source.add_to(form)?;
+        }
+        if let Some(voice_id) = &self.default_title_voice_id {
+            form = form.text("default_title_voice_id", voice_id.to_string());
+        }
+        if let Some(voice_id) = &self.default_paragraph_voice_id {
+            form = form.text("default_paragraph_voice_id", voice_id.to_string());
+        }
+        if let Some(model_id) = &self.default_model_id {
+            form = form.text("default_model_id", model_id.clone());
+        }
+        if let Some(quality_preset) = &self.quality_preset {
+            form = form.text("quality_preset", quality_preset.as_str().to_string());
+        }
+        if let Some(title) = &self.title {
+            form = form.text("title", title.clone());
+        }
+        if let Some(author) = &self.author {
+            form = form.text("author", author.clone());
+        }
+        if let Some(description) = &self.description {
+            form = form.text("description", description.clone());
+        }
+        for genre in &self.genres {
+            form = form.text("genres", genre.clone());
+        }
+        if let Some(language) = &self.language {
+            form = form.text("language", language.clone());
+        }
+        if let Some(isbn_number) = &self.isbn_number {
+            form = form.text("isbn_number", isbn_number.clone());
+        }
+        if let Some(volume_normalization) = self.volume_normalization {
+            form = form.text("volume_normalization", volume_normalization.to_string());
+        }
+        // Each locator is sent as its own JSON encoded field
+        for locator in &self.pronunciation_dictionary_locators {
+            form = form.text(
+                "pronunciation_dictionary_locators",
+                serde_json::to_string(locator)?,
+            );
+        }
+        if let Some(callback_url) = &self.callback_url {
+            form = form.text("callback_url", callback_url.clone());
+        }
+        if let Some(auto_convert) = self.auto_convert {
+            form = form.text("auto_convert", auto_convert.to_string());
+        }
+        if let Some(auto_assign_voices) = self.auto_assign_voices {
+            form = form.text("auto_assign_voices", auto_assign_voices.to_string());
+        }
+        Ok(form)
+    }
+}
+
+/// The response of the endpoints that create or update a project
+#[derive(Clone, Debug, Deserialize)]
+pub struct ProjectResponse {
+    project: Project,
+}
+
+impl ProjectResponse {
+    pub fn project(&self) -> &Project {
+        &self.project
+    }
+}
+
+/// Create a podcast, a Studio project with one or two voices discussing the source content
+///
+/// The project is converted once created, see [ConvertProject] for how to get its audio.
+///
+/// # Example
+///
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let mode = PodcastMode::conversation(PreMadeVoiceID::George, PreMadeVoiceID::Sarah);
+///     let body = CreatePodcastBody::new(Model::ElevenMultilingualV2, mode)
+///         .with_source(PodcastSource::url("https://example.com/news/today"))
+///         .with_duration_scale(PodcastDuration::Short)
+///         .with_intro("Welcome to the daily news.");
+///     let resp = c.hit(CreatePodcast::new(body)).await?;
+///     println!("{}", resp.project().project_id());
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct CreatePodcast(CreatePodcastBody);
+
+impl CreatePodcast {
+    pub fn new(body: CreatePodcastBody) -> Self {
+        CreatePodcast(body)
+    }
+}
+
+impl Endpoint for CreatePodcast {
+    type ResponseBody = ProjectResponse;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(serde_json::to_value(&self.0)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        let mut url = BASE_URL.parse::<Url>().unwrap();
+        url.set_path(PODCASTS_PATH);
+        url
+    }
+}
+
+/// Create podcast body
+#[derive(Clone, Debug, Serialize)]
+pub struct CreatePodcastBody {
+    model_id: String,
+    mode: PodcastMode,
+    source: Vec<PodcastSource>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    quality_preset: Option<QualityPreset>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duration_scale: Option<PodcastDuration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    intro: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    outro: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    instructions_prompt: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    highlights: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    callback_url: Option<String>,
+}
+
+impl CreatePodcastBody {
+    pub fn new<M: Into<String>>(model_id: M, mode: PodcastMode) -> Self {
+        CreatePodcastBody {
+            model_id: model_id.into(),
+            mode,
+            source: Vec::new(),
+            quality_preset: None,
+            duration_scale: None,
+            language: None,
+            intro: None,
+            outro: None,
+            instructions_prompt: None,
+            highlights: Vec::new(),
+            callback_url: None,
+        }
+    }
+    /// Adds a source, the podcast covers the content of every source
+    pub fn with_source(mut self, source: PodcastSource) -> Self {
+        self.source.push(source);
+        self
+    }
+    pub fn with_quality_preset(mut self, quality_preset: QualityPreset) -> Self {
+        self.quality_preset = Some(quality_preset);
+        self
+    }
+    pub fn with_duration_scale(mut self, duration_scale: PodcastDuration) -> Self {
+        self.duration_scale = Some(duration_scale);
+        self
+    }
+    /// An ISO 639-1 language code, e.g. `en`
+    pub fn with_language(mut self, language: &str) -> Self {
+        self.language = Some(language.to_string());
+        self
+    }
+    /// Text that is always spoken at the start of the podcast
+    pub fn with_intro(mut self, intro: &str) -> Self {
+        self.intro = Some(intro.to_string());
+        self
+    }
+    /// Text that is always spoken at the end of the podcast
+    pub fn with_outro(mut self, outro: &str) -> Self {
+        self.outro = Some(outro.to_string());
+        self
+    }
+    /// Guidance for the script, e.g. the tone or the audience
+    pub fn with_instructions_prompt(mut self, instructions_prompt: &str) -> Self {
+        self.instructions_prompt = Some(instructions_prompt.to_string());
+        self
+    }
+    /// Key points the podcast should cover
+    pub fn with_highlights(mut self, highlights: Vec<String>) -> Self {
+        self.highlights = highlights;
+        self
+    }
+    /// A URL that is called once the podcast is converted
+    pub fn with_callback_url(mut self, callback_url: &str) -> Self {
+        self.callback_url = Some(callback_url.to_string());
+        self
+    }
+}
+
+/// The format of a podcast and the voices speaking in it
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PodcastMode {
+    /// A host and a guest discussing the content
+    Conversation { conversation: ConversationVoices },
+    /// A single host presenting the content
+    Bulletin { bulletin: BulletinVoice },
+}
+
+impl PodcastMode {
+    pub fn conversation<H: Into<VoiceID>, G: Into<VoiceID>>(
+        host_voice_id: H,
+        guest_voice_id: G,
+    ) -> Self {
+        PodcastMode::Conversation {
+            conversation: ConversationVoices {
+                host_voice_id: host_voice_id.into(),
+                guest_voice_id: guest_voice_id.into(),
+            },
+        }
+    }
+    pub fn bulletin<H: Into<VoiceID>>(host_voice_id: H) -> Self {
+        PodcastMode::Bulletin {
+            bulletin: BulletinVoice {
+                host_voice_id: host_voice_id.into(),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ConversationVoices {
+    host_voice_id: VoiceID,
+    guest_voice_id: VoiceID,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct BulletinVoice {
+    host_voice_id: VoiceID,
+}
+
+/// The content a podcast is about
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PodcastSource {
+    Text {
+        text: String,
+    },
+    /// A URL to a web page whose content is extracted
+    Url {
+        url: String,
+    },
+}
+
+impl PodcastSource {
+    pub fn text(text: &str) -> Self {
+        PodcastSource::Text {
+            text: text.to_string(),
+        }
+    }
+    pub fn url(url: &str) -> Self {
+        PodcastSource::Url {
+            url: url.to_string(),
+        }
+    }
+}
+
+/// The length of a podcast
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PodcastDuration {
+    /// Under 3 minutes
+    Short,
+    /// Between 3 and 7 minutes
+    Default,
+    /// Over 7 minutes
+    Long,
+}
+
+/// List the Studio projects
 ///
 /// # Example
 ///
 /// ```no_run
 /// use elevenlabs_rs::*;
-/// use elevenlabs_rs::endpoints::projects::*;
 ///
 /// #[tokio::main]
 /// async fn main() -> Result<()> {
 ///    let c = ElevenLabsClient::default()?;
 ///    let resp = c.hit(GetProjects::new()).await?;
-///    println!("{:?}", resp);
+///    for project in resp.projects() {
+///        println!("{}: {:?}", project.name(), project.state());
+///    }
 ///    Ok(())
 /// }
 /// ```
@@ -51,19 +560,651 @@ pub struct ProjectsResponse {
     projects: Vec<Project>,
 }
 
+impl ProjectsResponse {
+    pub fn projects(&self) -> &[Project] {
+        &self.projects
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct Project {
     project_id: String,
     name: String,
     create_date_unix: u64,
-    default_title_voice_id: String,
-    default_paragraph_voice_id: String,
-    default_model_id: String,
-    last_conversion_date_unix: u64,
+    default_title_voice_id: Option<String>,
+    default_paragraph_voice_id: Option<String>,
+    default_model_id: Option<String>,
+    last_conversion_date_unix: Option<u64>,
+    #[serde(default)]
     can_be_downloaded: bool,
-    title: String,
-    author: String,
-    isbn_number: String,
+    title: Option<String>,
+    author: Option<String>,
+    description: Option<String>,
+    #[serde(default)]
+    genres: Vec<String>,
+    language: Option<String>,
+    isbn_number: Option<String>,
+    #[serde(default)]
     volume_normalization: bool,
-    state: String,
+    state: ProjectState,
+}
+
+impl Project {
+    pub fn project_id(&self) -> &str {
+        &self.project_id
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn create_date_unix(&self) -> u64 {
+        self.create_date_unix
+    }
+    /// [create_date_unix](Self::create_date_unix) as a date
+    #[cfg(feature = "chrono")]
+    pub fn create_date(&self) -> DateTime<Utc> {
+        utc(self.create_date_unix())
+    }
+    pub fn default_title_voice_id(&self) -> Option<&str> {
+        self.default_title_voice_id.as_deref()
+    }
+    pub fn default_paragraph_voice_id(&self) -> Option<&str> {
+        self.default_paragraph_voice_id.as_deref()
+    }
+    pub fn default_model_id(&self) -> Option<&str> {
+        self.default_model_id.as_deref()
+    }
+    /// `None` if the project was never converted
+    pub fn last_conversion_date_unix(&self) -> Option<u64> {
+        self.last_conversion_date_unix
+    }
+    /// [last_conversion_date_unix](Self::last_conversion_date_unix) as a date
+    #[cfg(feature = "chrono")]
+    pub fn last_conversion_date(&self) -> Option<DateTime<Utc>> {
+        self.last_conversion_date_unix().map(utc)
+    }
+    pub fn can_be_downloaded(&self) -> bool {
+        self.can_be_downloaded
+    }
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+    pub fn author(&self) -> Option<&str> {
+        self.author.as_deref()
+    }
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+    pub fn genres(&self) -> &[String] {
+        &self.genres
+    }
+    pub fn language(&self) -> Option<&str> {
+        self.language.as_deref()
+    }
+    pub fn isbn_number(&self) -> Option<&str> {
+        self.isbn_number.as_deref()
+    }
+    pub fn volume_normalization(&self) -> bool {
+        self.volume_normalization
+    }
+    pub fn state(&self) -> &ProjectState {
+        &self.state
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ProjectState {
+    Creating,
+    Default,
+    Converting,
+    InQueue,
+    /// A state not known to this version of the crate
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// Get a Studio project along with its chapters
+#[derive(Clone, Debug)]
+pub struct GetProject {
+    project_id: String,
+}
+
+impl GetProject {
+    pub fn new(project_id: &str) -> Self {
+        GetProject {
+            project_id: project_id.to_string(),
+        }
+    }
+}
+
+impl Endpoint for GetProject {
+    type ResponseBody = ProjectDetails;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        project_url(&self.project_id, "")
+    }
+}
+
+/// A project along with its chapters
+#[derive(Clone, Debug, Deserialize)]
+pub struct ProjectDetails {
+    #[serde(flatten)]
+    project: Project,
+    #[serde(default)]
+    chapters: Vec<Chapter>,
+    quality_preset: Option<QualityPreset>,
+}
+
+impl ProjectDetails {
+    pub fn project(&self) -> &Project {
+        &self.project
+    }
+    pub fn chapters(&self) -> &[Chapter] {
+        &self.chapters
+    }
+    pub fn quality_preset(&self) -> Option<&QualityPreset> {
+        self.quality_preset.as_ref()
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Chapter {
+    chapter_id: String,
+    name: String,
+    last_conversion_date_unix: Option<u64>,
+    /// From 0 to 1
+    conversion_progress: Option<f32>,
+    #[serde(default)]
+    can_be_downloaded: bool,
+    state: ChapterState,
+}
+
+impl Chapter {
+    pub fn chapter_id(&self) -> &str {
+        &self.chapter_id
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn last_conversion_date_unix(&self) -> Option<u64> {
+        self.last_conversion_date_unix
+    }
+    /// [last_conversion_date_unix](Self::last_conversion_date_unix) as a date
+    #[cfg(feature = "chrono")]
+    pub fn last_conversion_date(&self) -> Option<DateTime<Utc>> {
+        self.last_conversion_date_unix().map(utc)
+    }
+    /// From 0 to 1, while the chapter is converting
+    pub fn conversion_progress(&self) -> Option<f32> {
+        self.conversion_progress
+    }
+    pub fn can_be_downloaded(&self) -> bool {
+        self.can_be_downloaded
+    }
+    pub fn state(&self) -> &ChapterState {
+        &self.state
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ChapterState {
+    Default,
+    Converting,
+    /// A state not known to this version of the crate
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// Update the metadata of a Studio project
+///
+/// # Example
+///
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let project = c.hit(GetProject::new("project_id")).await?;
+///     let body = UpdateProjectBody::from(project.project()).with_isbn_number("978-3-16-148410-0");
+///     c.hit(UpdateProject::new("project_id", body)).await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct UpdateProject {
+    project_id: String,
+    body: UpdateProjectBody,
+}
+
+impl UpdateProject {
+    pub fn new(project_id: &str, body: UpdateProjectBody) -> Self {
+        UpdateProject {
+            project_id: project_id.to_string(),
+            body,
+        }
+    }
+}
+
+impl Endpoint for UpdateProject {
+    type ResponseBody = ProjectResponse;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(serde_json::to_value(&self.body)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        project_url(&self.project_id, "")
+    }
+}
+
+/// Update project body, the name and default voices are required by the API
+#[derive(Clone, Debug, Serialize)]
+pub struct UpdateProjectBody {
+    name: String,
+    default_title_voice_id: VoiceID,
+    default_paragraph_voice_id: VoiceID,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    author: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    isbn_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    volume_normalization: Option<bool>,
+}
+
+impl UpdateProjectBody {
+    pub fn new<T: Into<VoiceID>, P: Into<VoiceID>>(
+        name: &str,
+        default_title_voice_id: T,
+        default_paragraph_voice_id: P,
+    ) -> Self {
+        UpdateProjectBody {
+            name: name.to_string(),
+            default_title_voice_id: default_title_voice_id.into(),
+            default_paragraph_voice_id: default_paragraph_voice_id.into(),
+            title: None,
+            author: None,
+            isbn_number: None,
+            volume_normalization: None,
+        }
+    }
+    pub fn with_title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+    pub fn with_author(mut self, author: &str) -> Self {
+        self.author = Some(author.to_string());
+        self
+    }
+    pub fn with_isbn_number(mut self, isbn_number: &str) -> Self {
+        self.isbn_number = Some(isbn_number.to_string());
+        self
+    }
+    pub fn with_volume_normalization(mut self, volume_normalization: bool) -> Self {
+        self.volume_normalization = Some(volume_normalization);
+        self
+    }
+}
+
+impl From<&Project> for UpdateProjectBody {
+    /// The current metadata of a project, to change only some of it
+    fn from(project: &Project) -> Self {
+        UpdateProjectBody {
+            name: project.name.clone(),
+            default_title_voice_id: VoiceID::from(
+                project.default_title_voice_id.clone().unwrap_or_default(),
+            ),
+            default_paragraph_voice_id: VoiceID::from(
+                project
+                    .default_paragraph_voice_id
+                    .clone()
+                    .unwrap_or_default(),
+            ),
+            title: project.title.clone(),
+            author: project.author.clone(),
+            isbn_number: project.isbn_number.clone(),
+            volume_normalization: Some(project.volume_normalization),
+        }
+    }
+}
+
+/// Replace the content of a Studio project
+#[derive(Clone, Debug)]
+pub struct UpdateProjectContent {
+    project_id: String,
+    source: ProjectSource,
+    auto_convert: Option<bool>,
+}
+
+impl UpdateProjectContent {
+    pub fn new(project_id: &str, source: ProjectSource) -> Self {
+        UpdateProjectContent {
+            project_id: project_id.to_string(),
+            source,
+            auto_convert: None,
+        }
+    }
+    /// Convert the project to audio as soon as its content is replaced
+    pub fn with_auto_convert(mut self, auto_convert: bool) -> Self {
+        self.auto_convert = Some(auto_convert);
+        self
+    }
+}
+
+impl Endpoint for UpdateProjectContent {
+    type ResponseBody = ProjectResponse;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        let mut form = self.source.add_to(Form::new())?;
+        if let Some(auto_convert) = self.auto_convert {
+            form = form.text("auto_convert", auto_convert.to_string());
+        }
+        Ok(RequestBody::Multipart(form))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        project_url(&self.project_id, CONTENT_PATH)
+    }
+}
+
+/// Delete a Studio project
+#[derive(Clone, Debug)]
+pub struct DeleteProject {
+    project_id: String,
+}
+
+impl DeleteProject {
+    pub fn new(project_id: &str) -> Self {
+        DeleteProject {
+            project_id: project_id.to_string(),
+        }
+    }
+}
+
+impl Endpoint for DeleteProject {
+    type ResponseBody = StatusResponseBody;
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        project_url(&self.project_id, "")
+    }
+}
+
+/// Start converting a Studio project to audio, which creates a new snapshot once done
+///
+/// # Example
+///
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::utils::{poll_until, PollPolicy};
+/// use std::time::Duration;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     c.hit(ConvertProject::new("project_id")).await?;
+///     let policy = PollPolicy::default().with_interval(Duration::from_secs(10));
+///     poll_until(
+///         &c,
+///         || GetProject::new("project_id"),
+///         |p| Ok(p.project().state() == &ProjectState::Default),
+///         &policy,
+///     )
+///     .await?;
+///     let snapshots = c.hit(GetProjectSnapshots::new("project_id")).await?;
+///     let latest = snapshots.latest().expect("a converted project has a snapshot");
+///     let endpoint = StreamProjectSnapshotAudio::new("project_id", latest.project_snapshot_id());
+///     let audio = c.hit(endpoint).await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ConvertProject {
+    project_id: String,
+}
+
+impl ConvertProject {
+    pub fn new(project_id: &str) -> Self {
+        ConvertProject {
+            project_id: project_id.to_string(),
+        }
+    }
+}
+
+impl Endpoint for ConvertProject {
+    type ResponseBody = StatusResponseBody;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        project_url(&self.project_id, CONVERT_PATH)
+    }
+}
+
+/// List the snapshots of a Studio project, one is created each time it is converted
+#[derive(Clone, Debug)]
+pub struct GetProjectSnapshots {
+    project_id: String,
+}
+
+impl GetProjectSnapshots {
+    pub fn new(project_id: &str) -> Self {
+        GetProjectSnapshots {
+            project_id: project_id.to_string(),
+        }
+    }
+}
+
+impl Endpoint for GetProjectSnapshots {
+    type ResponseBody = ProjectSnapshotsResponse;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        project_url(&self.project_id, SNAPSHOTS_PATH)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ProjectSnapshotsResponse {
+    snapshots: Vec<ProjectSnapshot>,
+}
+
+impl ProjectSnapshotsResponse {
+    pub fn snapshots(&self) -> &[ProjectSnapshot] {
+        &self.snapshots
+    }
+    /// The most recently created snapshot
+    pub fn latest(&self) -> Option<&ProjectSnapshot> {
+        self.snapshots.iter().max_by_key(|s| s.created_at_unix)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ProjectSnapshot {
+    project_snapshot_id: String,
+    project_id: String,
+    created_at_unix: u64,
+    name: String,
+}
+
+impl ProjectSnapshot {
+    pub fn project_snapshot_id(&self) -> &str {
+        &self.project_snapshot_id
+    }
+    pub fn project_id(&self) -> &str {
+        &self.project_id
+    }
+    pub fn created_at_unix(&self) -> u64 {
+        self.created_at_unix
+    }
+    /// [created_at_unix](Self::created_at_unix) as a date
+    #[cfg(feature = "chrono")]
+    pub fn created_at(&self) -> DateTime<Utc> {
+        utc(self.created_at_unix())
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// Get a snapshot of a Studio project along with the timing of its characters
+#[derive(Clone, Debug)]
+pub struct GetProjectSnapshot {
+    project_id: String,
+    project_snapshot_id: String,
+}
+
+impl GetProjectSnapshot {
+    pub fn new(project_id: &str, project_snapshot_id: &str) -> Self {
+        GetProjectSnapshot {
+            project_id: project_id.to_string(),
+            project_snapshot_id: project_snapshot_id.to_string(),
+        }
+    }
+}
+
+impl Endpoint for GetProjectSnapshot {
+    type ResponseBody = ProjectSnapshotDetails;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        let path = format!("{}/{}", SNAPSHOTS_PATH, self.project_snapshot_id);
+        project_url(&self.project_id, &path)
+    }
+}
+
+/// A snapshot along with the timing of its characters
+#[derive(Clone, Debug, Deserialize)]
+pub struct ProjectSnapshotDetails {
+    #[serde(flatten)]
+    snapshot: ProjectSnapshot,
+    #[serde(default)]
+    character_alignments: Vec<CharacterAlignment>,
+}
+
+impl ProjectSnapshotDetails {
+    pub fn snapshot(&self) -> &ProjectSnapshot {
+        &self.snapshot
+    }
+    pub fn character_alignments(&self) -> &[CharacterAlignment] {
+        &self.character_alignments
+    }
+}
+
+/// The start and end time, in seconds, of each character
+#[derive(Clone, Debug, Deserialize)]
+pub struct CharacterAlignment {
+    characters: Vec<String>,
+    character_start_times_seconds: Vec<f32>,
+    character_end_times_seconds: Vec<f32>,
+}
+
+impl CharacterAlignment {
+    pub fn characters(&self) -> &[String] {
+        &self.characters
+    }
+    pub fn character_start_times_seconds(&self) -> &[f32] {
+        &self.character_start_times_seconds
+    }
+    pub fn character_end_times_seconds(&self) -> &[f32] {
+        &self.character_end_times_seconds
+    }
+}
+
+/// Stream the audio of a Studio project snapshot
+#[derive(Clone, Debug)]
+pub struct StreamProjectSnapshotAudio {
+    project_id: String,
+    project_snapshot_id: String,
+    body: StreamSnapshotBody,
+}
+
+impl StreamProjectSnapshotAudio {
+    pub fn new(project_id: &str, project_snapshot_id: &str) -> Self {
+        StreamProjectSnapshotAudio {
+            project_id: project_id.to_string(),
+            project_snapshot_id: project_snapshot_id.to_string(),
+            body: StreamSnapshotBody::default(),
+        }
+    }
+    /// Convert the audio to MPEG before streaming it
+    pub fn with_convert_to_mpeg(mut self, convert_to_mpeg: bool) -> Self {
+        self.body.convert_to_mpeg = Some(convert_to_mpeg);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+struct StreamSnapshotBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    convert_to_mpeg: Option<bool>,
+}
+
+type ProjectSnapshotAudioStream = Pin<Box<dyn Stream<Item = Result<Bytes>> + Send>>;
+
+impl Endpoint for StreamProjectSnapshotAudio {
+    type ResponseBody = ProjectSnapshotAudioStream;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(serde_json::to_value(&self.body)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        let stream = resp.bytes_stream();
+        let stream = stream.map(|r| r.map_err(Into::into));
+        Ok(Box::pin(stream))
+    }
+    fn url(&self) -> Url {
+        let path = format!(
+            "{}/{}{}",
+            SNAPSHOTS_PATH, self.project_snapshot_id, STREAM_PATH
+        );
+        project_url(&self.project_id, &path)
+    }
+}
+
+fn project_url(project_id: &str, path: &str) -> Url {
+    let mut url = BASE_URL.parse::<Url>().unwrap();
+    url.set_path(&format!("{}/{}{}", PROJECTS_PATH, project_id, path));
+    url
 }
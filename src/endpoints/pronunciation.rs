@@ -49,16 +49,16 @@ impl AddFromFile {
 /// ```
 #[derive(Clone, Debug)]
 pub struct AddFromFileBody {
-    file: String,
+    file: FileSource,
     name: String,
     description: Option<String>,
     workspace_access: Option<String>,
 }
 
 impl AddFromFileBody {
-    pub fn new(file: &str, name: &str) -> Self {
+    pub fn new<F: Into<FileSource>>(file: F, name: &str) -> Self {
         Self {
-            file: file.to_string(),
+            file: file.into(),
             name: name.to_string(),
             description: None,
             workspace_access: None,
@@ -77,8 +77,7 @@ impl AddFromFileBody {
 
     fn to_form(&self) -> Result<Form> {
         let mut form = Form::new();
-        let file = std::fs::read(self.file.clone())?;
-        form = form.part("file", Part::bytes(file).file_name("file"));
+        form = form.part("file", self.file.to_part()?);
         form = form.text("name", self.name.clone());
         if let Some(description) = &self.description {
             form = form.text("description", description.clone());
@@ -139,6 +138,11 @@ impl AddFromFileResponse {
     pub fn creation_time_unix(&self) -> i64 {
         self.creation_time_unix
     }
+    /// [creation_time_unix](Self::creation_time_unix) as a date
+    #[cfg(feature = "chrono")]
+    pub fn creation_time(&self) -> DateTime<Utc> {
+        utc(self.creation_time_unix())
+    }
 
     pub fn version_id(&self) -> &str {
         &self.version_id
@@ -162,7 +166,7 @@ impl AddFromFileResponse {
 ///   let rules = vec![
 ///      Rule::new_alias("TTS", "text to speech"),
 ///      Rule::new_alias("API", "application programming interface"),
-///
+///      Rule::new_phoneme("tomato", "/tə'meɪtoʊ/", PhonemeAlphabet::Ipa),
 ///   ];
 ///   let resp = c.hit(AddRules::new("dictionary_id", rules)).await?;
 ///   println!("{:?}", resp);
@@ -198,38 +202,108 @@ impl AddRulesBody {
 }
 
 /// This enum represents the rules that can be added to a pronunciation dictionary.
-#[derive(Clone, Debug, Serialize)]
-#[serde(untagged)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
 pub enum Rule {
-    Alias {
-        r#type: String,
-        string_to_replace: String,
-        alias: String,
-    },
-    Phoneme {
-        r#type: String,
-        string_to_replace: String,
-        phoneme: String,
-        alphabet: String,
-    },
+    Alias(AliasRule),
+    Phoneme(PhonemeRule),
 }
 
 impl Rule {
     pub fn new_alias(string_to_replace: &str, alias: &str) -> Self {
-        Self::Alias {
-            r#type: "alias".to_string(),
+        Self::Alias(AliasRule::new(string_to_replace, alias))
+    }
+    pub fn new_phoneme(string_to_replace: &str, phoneme: &str, alphabet: PhonemeAlphabet) -> Self {
+        Self::Phoneme(PhonemeRule::new(string_to_replace, phoneme, alphabet))
+    }
+
+    /// The string the rule applies to, which is what [RemoveRules] matches on
+    pub fn string_to_replace(&self) -> &str {
+        match self {
+            Self::Alias(rule) => &rule.string_to_replace,
+            Self::Phoneme(rule) => &rule.string_to_replace,
+        }
+    }
+}
+
+impl From<AliasRule> for Rule {
+    fn from(rule: AliasRule) -> Self {
+        Self::Alias(rule)
+    }
+}
+
+impl From<PhonemeRule> for Rule {
+    fn from(rule: PhonemeRule) -> Self {
+        Self::Phoneme(rule)
+    }
+}
+
+/// Replaces a string with another string, e.g. an acronym with its expansion
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct AliasRule {
+    string_to_replace: String,
+    alias: String,
+}
+
+impl AliasRule {
+    pub fn new(string_to_replace: &str, alias: &str) -> Self {
+        Self {
             string_to_replace: string_to_replace.to_string(),
             alias: alias.to_string(),
         }
     }
-    pub fn new_phoneme(string_to_replace: &str, phoneme: &str, alphabet: &str) -> Self {
-        Self::Phoneme {
-            r#type: "phoneme".to_string(),
+
+    pub fn string_to_replace(&self) -> &str {
+        &self.string_to_replace
+    }
+
+    pub fn alias(&self) -> &str {
+        &self.alias
+    }
+}
+
+/// Pronounces a string as a phoneme sequence
+///
+/// Phoneme rules are only supported by the `eleven_flash_v2`, `eleven_turbo_v2`
+/// and `eleven_monolingual_v1` models.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct PhonemeRule {
+    string_to_replace: String,
+    phoneme: String,
+    alphabet: PhonemeAlphabet,
+}
+
+impl PhonemeRule {
+    pub fn new(string_to_replace: &str, phoneme: &str, alphabet: PhonemeAlphabet) -> Self {
+        Self {
             string_to_replace: string_to_replace.to_string(),
             phoneme: phoneme.to_string(),
-            alphabet: alphabet.to_string(),
+            alphabet,
         }
     }
+
+    pub fn string_to_replace(&self) -> &str {
+        &self.string_to_replace
+    }
+
+    pub fn phoneme(&self) -> &str {
+        &self.phoneme
+    }
+
+    pub fn alphabet(&self) -> &PhonemeAlphabet {
+        &self.alphabet
+    }
+}
+
+/// The alphabet a [PhonemeRule] is written in
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub enum PhonemeAlphabet {
+    /// The International Phonetic Alphabet, e.g. `/ˈvaɪə/`
+    #[serde(rename = "ipa")]
+    Ipa,
+    /// The CMU Arpabet, e.g. `V AY1 AH0`
+    #[serde(rename = "cmu-arpabet")]
+    CmuArpabet,
 }
 
 impl Endpoint for AddRules {
@@ -361,6 +435,18 @@ impl Endpoint for GetDictionaries {
     }
 }
 
+impl Paginated for GetDictionaries {
+    type Item = PronunciationDictionary;
+
+    fn next_page(self, page: Self::ResponseBody) -> (Vec<Self::Item>, Option<Self>) {
+        let next = match (page.has_more, &page.next_cursor) {
+            (true, Some(cursor)) => Some(GetDictionaries(self.0.with_cursor(cursor))),
+            _ => None,
+        };
+        (page.pronunciation_dictionaries, next)
+    }
+}
+
 /// Get dictionaries response
 #[derive(Clone, Debug, Deserialize)]
 pub struct GetDictionariesResponse {
@@ -414,6 +500,11 @@ impl PronunciationDictionary {
     pub fn creation_time_unix(&self) -> i64 {
         self.creation_time_unix
     }
+    /// [creation_time_unix](Self::creation_time_unix) as a date
+    #[cfg(feature = "chrono")]
+    pub fn creation_time(&self) -> DateTime<Utc> {
+        utc(self.creation_time_unix())
+    }
 
     pub fn description(&self) -> Option<&str> {
         self.description.as_deref()
@@ -483,18 +574,21 @@ impl Endpoint for GetDictionary {
 /// #[tokio::main]
 /// async fn main() -> Result<()> {
 ///   let c = ElevenLabsClient::default()?;
-///   let resp_bytes = c.hit(DownloadVersionByID::new("dictionary_id", "version_id")).await?;
+///   let resp_bytes = c.hit(DownloadDictionaryVersionPls::new("dictionary_id", "version_id")).await?;
 ///   save("dictionary_rules.pls", resp_bytes)?;
 ///   Ok(())
 /// }
 /// ```
 #[derive(Clone, Debug)]
-pub struct DownloadVersionByID {
+pub struct DownloadDictionaryVersionPls {
     dictionary_id: DictionaryID,
     version_id: VersionID,
 }
 
-impl DownloadVersionByID {
+#[deprecated(note = "Use `DownloadDictionaryVersionPls` instead")]
+pub type DownloadVersionByID = DownloadDictionaryVersionPls;
+
+impl DownloadDictionaryVersionPls {
     pub fn new(dictionary_id: &str, version_id: &str) -> Self {
         Self {
             dictionary_id: DictionaryID::from(dictionary_id.to_string()),
@@ -513,7 +607,7 @@ impl From<String> for VersionID {
     }
 }
 
-impl Endpoint for DownloadVersionByID {
+impl Endpoint for DownloadDictionaryVersionPls {
     type ResponseBody = Bytes;
 
     fn method(&self) -> Method {
@@ -0,0 +1,719 @@
+//! The professional voice cloning endpoints
+//!
+//! A PVC voice is created empty, samples are added to it,
+//! the voice is verified as belonging to the account's owner, then trained.
+//!
+//! See the [ElevenLabs docs](https://elevenlabs.io/docs/api-reference/voices/pvc/create) for more information.
+use super::*;
+use std::collections::HashMap;
+
+const PVC_PATH: &str = "/pvc";
+const SAMPLES_PATH: &str = "/samples";
+const SPEAKERS_PATH: &str = "/speakers";
+const SEPARATE_SPEAKERS_PATH: &str = "/separate-speakers";
+const VERIFICATION_PATH: &str = "/verification";
+const CAPTCHA_PATH: &str = "/captcha";
+const TRAIN_PATH: &str = "/train";
+
+/// Create a PVC voice, without samples
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::pvc::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let body = CreatePvcVoiceBody::new("Narrator", "en")
+///         .with_description("Warm, slow paced");
+///     let voice = c.hit(CreatePvcVoice::new(body)).await?;
+///
+///     let samples = AddPvcSamplesBody::new(vec!["chapter_01.mp3", "chapter_02.mp3"])
+///         .with_remove_background_noise(true);
+///     let samples = c.hit(AddPvcSamples::new(voice.voice_id(), samples)).await?;
+///     for sample in &samples {
+///         c.hit(StartSpeakerSeparation::new(voice.voice_id(), sample.sample_id())).await?;
+///     }
+///
+///     c.hit(RequestPvcVerification::new(voice.voice_id(), vec!["consent.pdf"])).await?;
+///     c.hit(TrainPvcVoice::new(voice.voice_id())).await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct CreatePvcVoice(CreatePvcVoiceBody);
+
+impl CreatePvcVoice {
+    pub fn new(body: CreatePvcVoiceBody) -> Self {
+        CreatePvcVoice(body)
+    }
+}
+
+impl Endpoint for CreatePvcVoice {
+    type ResponseBody = PvcVoiceResponse;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(serde_json::to_value(&self.0)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        pvc_url("")
+    }
+}
+
+/// Create PVC voice body
+#[derive(Clone, Debug, Serialize)]
+pub struct CreatePvcVoiceBody {
+    name: String,
+    language: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    labels: Option<HashMap<String, String>>,
+}
+
+impl CreatePvcVoiceBody {
+    /// `language` is the ISO 639-1 code of the language the samples are in
+    pub fn new(name: &str, language: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            language: language.to_string(),
+            description: None,
+            labels: None,
+        }
+    }
+    pub fn with_description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+    pub fn with_labels(mut self, labels: Vec<(String, String)>) -> Self {
+        self.labels = Some(labels.into_iter().collect());
+        self
+    }
+}
+
+/// Edit the name, language, description or labels of a PVC voice
+#[derive(Clone, Debug)]
+pub struct EditPvcVoice {
+    voice_id: VoiceID,
+    body: EditPvcVoiceBody,
+}
+
+impl EditPvcVoice {
+    pub fn new<T: Into<VoiceID>>(voice_id: T, body: EditPvcVoiceBody) -> Self {
+        EditPvcVoice {
+            voice_id: voice_id.into(),
+            body,
+        }
+    }
+}
+
+impl Endpoint for EditPvcVoice {
+    type ResponseBody = PvcVoiceResponse;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(serde_json::to_value(&self.body)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        pvc_url(&format!("/{}", self.voice_id.0))
+    }
+}
+
+/// Edit PVC voice body, fields that are not set are left as they are
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct EditPvcVoiceBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    labels: Option<HashMap<String, String>>,
+}
+
+impl EditPvcVoiceBody {
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+    pub fn with_language(mut self, language: &str) -> Self {
+        self.language = Some(language.to_string());
+        self
+    }
+    pub fn with_description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+    pub fn with_labels(mut self, labels: Vec<(String, String)>) -> Self {
+        self.labels = Some(labels.into_iter().collect());
+        self
+    }
+}
+
+/// Create or edit PVC voice response
+#[derive(Clone, Debug, Deserialize)]
+pub struct PvcVoiceResponse {
+    voice_id: String,
+}
+
+impl PvcVoiceResponse {
+    pub fn voice_id(&self) -> &str {
+        &self.voice_id
+    }
+}
+
+/// Add audio samples to a PVC voice
+#[derive(Clone, Debug)]
+pub struct AddPvcSamples {
+    voice_id: VoiceID,
+    body: AddPvcSamplesBody,
+}
+
+impl AddPvcSamples {
+    pub fn new<T: Into<VoiceID>>(voice_id: T, body: AddPvcSamplesBody) -> Self {
+        AddPvcSamples {
+            voice_id: voice_id.into(),
+            body,
+        }
+    }
+}
+
+impl Endpoint for AddPvcSamples {
+    type ResponseBody = Vec<PvcSample>;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        let mut form = Form::new();
+        for file in &self.body.files {
+            let mime = format!("audio/{}", file.extension()?);
+            form = form.part("files", file.to_part()?.mime_str(&mime)?);
+        }
+        if let Some(remove_background_noise) = self.body.remove_background_noise {
+            form = form.text(
+                "remove_background_noise",
+                remove_background_noise.to_string(),
+            );
+        }
+        Ok(RequestBody::Multipart(form))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        pvc_url(&format!("/{}{}", self.voice_id.0, SAMPLES_PATH))
+    }
+}
+
+/// Add PVC samples body
+#[derive(Clone, Debug)]
+pub struct AddPvcSamplesBody {
+    files: Vec<FileSource>,
+    remove_background_noise: Option<bool>,
+}
+
+impl AddPvcSamplesBody {
+    /// Each sample can be a path, which is streamed from disk, or any other [FileSource]
+    pub fn new<F: Into<FileSource>>(files: Vec<F>) -> Self {
+        Self {
+            files: files.into_iter().map(Into::into).collect(),
+            remove_background_noise: None,
+        }
+    }
+    pub fn with_remove_background_noise(mut self, remove_background_noise: bool) -> Self {
+        self.remove_background_noise = Some(remove_background_noise);
+        self
+    }
+}
+
+/// Trim a PVC sample, or pick the speakers to train on after speaker separation
+#[derive(Clone, Debug)]
+pub struct UpdatePvcSample {
+    path: PvcSamplePath,
+    body: UpdatePvcSampleBody,
+}
+
+impl UpdatePvcSample {
+    pub fn new<T: Into<VoiceID>>(voice_id: T, sample_id: &str, body: UpdatePvcSampleBody) -> Self {
+        UpdatePvcSample {
+            path: PvcSamplePath::new(voice_id, sample_id),
+            body,
+        }
+    }
+}
+
+impl Endpoint for UpdatePvcSample {
+    type ResponseBody = PvcVoiceResponse;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(serde_json::to_value(&self.body)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        pvc_url(&self.path.to_path())
+    }
+}
+
+/// Update PVC sample body, fields that are not set are left as they are
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct UpdatePvcSampleBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    remove_background_noise: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    selected_speaker_ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    trim_start_time: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    trim_end_time: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    file_name: Option<String>,
+}
+
+impl UpdatePvcSampleBody {
+    pub fn with_remove_background_noise(mut self, remove_background_noise: bool) -> Self {
+        self.remove_background_noise = Some(remove_background_noise);
+        self
+    }
+    /// Speakers found by [speaker separation](StartSpeakerSeparation) to keep
+    pub fn with_selected_speaker_ids(mut self, speaker_ids: Vec<String>) -> Self {
+        self.selected_speaker_ids = Some(speaker_ids);
+        self
+    }
+    /// In milliseconds
+    pub fn with_trim_start_time(mut self, trim_start_time: u32) -> Self {
+        self.trim_start_time = Some(trim_start_time);
+        self
+    }
+    /// In milliseconds
+    pub fn with_trim_end_time(mut self, trim_end_time: u32) -> Self {
+        self.trim_end_time = Some(trim_end_time);
+        self
+    }
+    pub fn with_file_name(mut self, file_name: &str) -> Self {
+        self.file_name = Some(file_name.to_string());
+        self
+    }
+}
+
+/// Delete a sample of a PVC voice
+#[derive(Clone, Debug)]
+pub struct DeletePvcSample(PvcSamplePath);
+
+impl DeletePvcSample {
+    pub fn new<T: Into<VoiceID>>(voice_id: T, sample_id: &str) -> Self {
+        DeletePvcSample(PvcSamplePath::new(voice_id, sample_id))
+    }
+}
+
+impl Endpoint for DeletePvcSample {
+    type ResponseBody = StatusResponseBody;
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        pvc_url(&self.0.to_path())
+    }
+}
+
+/// Start separating the speakers of a PVC sample
+///
+/// Poll [GetSpeakerSeparationStatus] until it is completed.
+#[derive(Clone, Debug)]
+pub struct StartSpeakerSeparation(PvcSamplePath);
+
+impl StartSpeakerSeparation {
+    pub fn new<T: Into<VoiceID>>(voice_id: T, sample_id: &str) -> Self {
+        StartSpeakerSeparation(PvcSamplePath::new(voice_id, sample_id))
+    }
+}
+
+impl Endpoint for StartSpeakerSeparation {
+    type ResponseBody = StatusResponseBody;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        pvc_url(&format!("{}{}", self.0.to_path(), SEPARATE_SPEAKERS_PATH))
+    }
+}
+
+/// Get the status of the speaker separation of a PVC sample, and the speakers it found
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::pvc::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let separation = c.hit(GetSpeakerSeparationStatus::new("voice_id", "sample_id")).await?;
+///     if separation.status() == &SpeakerSeparationStatus::Completed {
+///         // Keep the speaker who talks the longest
+///         let speaker = separation
+///             .speakers()
+///             .map(|(_, speaker)| speaker)
+///             .max_by(|a, b| a.duration_secs().total_cmp(&b.duration_secs()));
+///         if let Some(speaker) = speaker {
+///             let body = UpdatePvcSampleBody::default()
+///                 .with_selected_speaker_ids(vec![speaker.speaker_id().to_string()]);
+///             c.hit(UpdatePvcSample::new("voice_id", "sample_id", body)).await?;
+///         }
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct GetSpeakerSeparationStatus(PvcSamplePath);
+
+impl GetSpeakerSeparationStatus {
+    pub fn new<T: Into<VoiceID>>(voice_id: T, sample_id: &str) -> Self {
+        GetSpeakerSeparationStatus(PvcSamplePath::new(voice_id, sample_id))
+    }
+}
+
+impl Endpoint for GetSpeakerSeparationStatus {
+    type ResponseBody = SpeakerSeparationResponse;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        pvc_url(&format!("{}{}", self.0.to_path(), SPEAKERS_PATH))
+    }
+}
+
+/// Request a manual verification of a PVC voice, with documents proving the voice is the account owner's
+#[derive(Clone, Debug)]
+pub struct RequestPvcVerification {
+    voice_id: VoiceID,
+    files: Vec<FileSource>,
+    extra_text: Option<String>,
+}
+
+impl RequestPvcVerification {
+    pub fn new<T: Into<VoiceID>, F: Into<FileSource>>(voice_id: T, files: Vec<F>) -> Self {
+        RequestPvcVerification {
+            voice_id: voice_id.into(),
+            files: files.into_iter().map(Into::into).collect(),
+            extra_text: None,
+        }
+    }
+    /// Anything the reviewer should know
+    pub fn with_extra_text(mut self, extra_text: &str) -> Self {
+        self.extra_text = Some(extra_text.to_string());
+        self
+    }
+}
+
+impl Endpoint for RequestPvcVerification {
+    type ResponseBody = StatusResponseBody;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        let mut form = Form::new();
+        for file in &self.files {
+            form = form.part("files", file.to_part()?);
+        }
+        if let Some(extra_text) = &self.extra_text {
+            form = form.text("extra_text", extra_text.clone());
+        }
+        Ok(RequestBody::Multipart(form))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        pvc_url(&format!("/{}{}", self.voice_id.0, VERIFICATION_PATH))
+    }
+}
+
+/// Get the captcha to read out to verify a PVC voice
+#[derive(Clone, Debug)]
+pub struct GetPvcCaptcha(VoiceID);
+
+impl GetPvcCaptcha {
+    pub fn new<T: Into<VoiceID>>(voice_id: T) -> Self {
+        GetPvcCaptcha(voice_id.into())
+    }
+}
+
+impl Endpoint for GetPvcCaptcha {
+    type ResponseBody = Value;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        pvc_url(&format!("/{}{}", self.0 .0, CAPTCHA_PATH))
+    }
+}
+
+/// Submit a recording of the [captcha](GetPvcCaptcha) being read out
+#[derive(Clone, Debug)]
+pub struct VerifyPvcCaptcha {
+    voice_id: VoiceID,
+    recording: FileSource,
+}
+
+impl VerifyPvcCaptcha {
+    pub fn new<T: Into<VoiceID>, F: Into<FileSource>>(voice_id: T, recording: F) -> Self {
+        VerifyPvcCaptcha {
+            voice_id: voice_id.into(),
+            recording: recording.into(),
+        }
+    }
+}
+
+impl Endpoint for VerifyPvcCaptcha {
+    type ResponseBody = StatusResponseBody;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        let mime = format!("audio/{}", self.recording.extension()?);
+        let part = self.recording.to_part()?.mime_str(&mime)?;
+        Ok(RequestBody::Multipart(Form::new().part("recording", part)))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        pvc_url(&format!("/{}{}", self.voice_id.0, CAPTCHA_PATH))
+    }
+}
+
+/// Start training a verified PVC voice
+#[derive(Clone, Debug)]
+pub struct TrainPvcVoice {
+    voice_id: VoiceID,
+    body: TrainPvcVoiceBody,
+}
+
+impl TrainPvcVoice {
+    pub fn new<T: Into<VoiceID>>(voice_id: T) -> Self {
+        TrainPvcVoice {
+            voice_id: voice_id.into(),
+            body: TrainPvcVoiceBody::default(),
+        }
+    }
+    /// The model to train for, defaults to the latest multilingual model
+    pub fn with_model_id<T: Into<String>>(mut self, model_id: T) -> Self {
+        self.body.model_id = Some(ModelID::from(model_id.into()));
+        self
+    }
+}
+
+impl Endpoint for TrainPvcVoice {
+    type ResponseBody = StatusResponseBody;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(serde_json::to_value(&self.body)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        pvc_url(&format!("/{}{}", self.voice_id.0, TRAIN_PATH))
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+struct TrainPvcVoiceBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    model_id: Option<ModelID>,
+}
+
+/// A sample of a PVC voice
+#[derive(Clone, Debug, Deserialize)]
+pub struct PvcSample {
+    sample_id: String,
+    file_name: Option<String>,
+    mime_type: Option<String>,
+    size_bytes: Option<u64>,
+    duration_secs: Option<f32>,
+    remove_background_noise: Option<bool>,
+    trim_start: Option<u32>,
+    trim_end: Option<u32>,
+    speaker_separation: Option<SpeakerSeparation>,
+}
+
+impl PvcSample {
+    pub fn sample_id(&self) -> &str {
+        &self.sample_id
+    }
+    pub fn file_name(&self) -> Option<&str> {
+        self.file_name.as_deref()
+    }
+    pub fn mime_type(&self) -> Option<&str> {
+        self.mime_type.as_deref()
+    }
+    pub fn size_bytes(&self) -> Option<u64> {
+        self.size_bytes
+    }
+    pub fn duration_secs(&self) -> Option<f32> {
+        self.duration_secs
+    }
+    pub fn remove_background_noise(&self) -> Option<bool> {
+        self.remove_background_noise
+    }
+    pub fn trim_start(&self) -> Option<u32> {
+        self.trim_start
+    }
+    pub fn trim_end(&self) -> Option<u32> {
+        self.trim_end
+    }
+    pub fn speaker_separation_status(&self) -> Option<&SpeakerSeparationStatus> {
+        self.speaker_separation.as_ref().map(|s| &s.status)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct SpeakerSeparation {
+    status: SpeakerSeparationStatus,
+}
+
+/// Get speaker separation status response
+#[derive(Clone, Debug, Deserialize)]
+pub struct SpeakerSeparationResponse {
+    voice_id: String,
+    sample_id: String,
+    status: SpeakerSeparationStatus,
+    #[serde(default)]
+    speakers: Option<HashMap<String, Speaker>>,
+    #[serde(default)]
+    selected_speaker_ids: Option<Vec<String>>,
+}
+
+impl SpeakerSeparationResponse {
+    pub fn voice_id(&self) -> &str {
+        &self.voice_id
+    }
+    pub fn sample_id(&self) -> &str {
+        &self.sample_id
+    }
+    pub fn status(&self) -> &SpeakerSeparationStatus {
+        &self.status
+    }
+    /// The speakers found in the sample with their ids, none until the separation is completed
+    pub fn speakers(&self) -> impl Iterator<Item = (&str, &Speaker)> {
+        self.speakers
+            .iter()
+            .flatten()
+            .map(|(id, speaker)| (id.as_str(), speaker))
+    }
+    pub fn selected_speaker_ids(&self) -> &[String] {
+        self.selected_speaker_ids.as_deref().unwrap_or_default()
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SpeakerSeparationStatus {
+    NotStarted,
+    Pending,
+    Completed,
+    Failed,
+    #[serde(other)]
+    Unknown,
+}
+
+/// A speaker found by speaker separation
+#[derive(Clone, Debug, Deserialize)]
+pub struct Speaker {
+    speaker_id: String,
+    duration_secs: f32,
+    #[serde(default)]
+    utterances: Option<Vec<Utterance>>,
+}
+
+impl Speaker {
+    pub fn speaker_id(&self) -> &str {
+        &self.speaker_id
+    }
+    pub fn duration_secs(&self) -> f32 {
+        self.duration_secs
+    }
+    pub fn utterances(&self) -> &[Utterance] {
+        self.utterances.as_deref().unwrap_or_default()
+    }
+}
+
+/// When a speaker talks, in seconds from the start of the sample
+#[derive(Clone, Debug, Deserialize)]
+pub struct Utterance {
+    start: f32,
+    end: f32,
+}
+
+impl Utterance {
+    pub fn start(&self) -> f32 {
+        self.start
+    }
+    pub fn end(&self) -> f32 {
+        self.end
+    }
+}
+
+#[derive(Clone, Debug)]
+struct PvcSamplePath {
+    voice_id: VoiceID,
+    sample_id: String,
+}
+
+impl PvcSamplePath {
+    fn new<T: Into<VoiceID>>(voice_id: T, sample_id: &str) -> Self {
+        Self {
+            voice_id: voice_id.into(),
+            sample_id: sample_id.to_string(),
+        }
+    }
+    fn to_path(&self) -> String {
+        format!("/{}{}/{}", self.voice_id.0, SAMPLES_PATH, self.sample_id)
+    }
+}
+
+fn pvc_url(path: &str) -> Url {
+    let mut url = BASE_URL.parse::<Url>().unwrap();
+    url.set_path(&format!("{}{}{}", VOICES_PATH, PVC_PATH, path));
+    url
+}
@@ -32,7 +32,7 @@ const AUDIO_PATH: &str = "/audio";
 pub struct DeleteSample(SamplePathParams);
 
 impl DeleteSample {
-    pub fn new<T: Into<String>>(voice_id: T, sample_id: &str) -> Self {
+    pub fn new<T: Into<VoiceID>>(voice_id: T, sample_id: &str) -> Self {
         Self {
             0: SamplePathParams::new(voice_id, sample_id),
         }
@@ -65,9 +65,9 @@ pub struct SamplePathParams {
 }
 
 impl SamplePathParams {
-    pub fn new<T: Into<String>>(voice_id: T, sample_id: &str) -> Self {
+    pub fn new<T: Into<VoiceID>>(voice_id: T, sample_id: &str) -> Self {
         Self {
-            voice_id: VoiceID::from(voice_id.into()),
+            voice_id: voice_id.into(),
             sample_id: sample_id.to_string(),
         }
     }
@@ -99,7 +99,7 @@ impl SamplePathParams {
 pub struct GetAudioFromSample(SamplePathParams);
 
 impl GetAudioFromSample {
-    pub fn new<T: Into<String>>(voice_id: T, sample_id: &str) -> Self {
+    pub fn new<T: Into<VoiceID>>(voice_id: T, sample_id: &str) -> Self {
         Self {
             0: SamplePathParams::new(voice_id, sample_id),
         }
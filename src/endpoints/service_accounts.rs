@@ -0,0 +1,462 @@
+//! The service account endpoints
+//!
+//! List the service accounts of the workspace and manage their API keys,
+//! requires a workspace admin API key.
+//!
+//! See the [ElevenLabs docs](https://elevenlabs.io/docs/api-reference/service-accounts/get-service-accounts) for more information.
+use super::*;
+
+const SERVICE_ACCOUNTS_PATH: &str = "/v1/service-accounts";
+const API_KEYS_PATH: &str = "/api-keys";
+
+/// List the service accounts of the workspace along with their API keys
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let resp = c.hit(GetServiceAccounts).await?;
+///     for account in resp.service_accounts() {
+///         println!("{}: {} keys", account.name(), account.api_keys().len());
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct GetServiceAccounts;
+
+impl Endpoint for GetServiceAccounts {
+    type ResponseBody = ServiceAccountsResponse;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        let mut url = BASE_URL.parse::<Url>().unwrap();
+        url.set_path(SERVICE_ACCOUNTS_PATH);
+        url
+    }
+}
+
+/// Service accounts response
+#[derive(Clone, Debug, Deserialize)]
+pub struct ServiceAccountsResponse {
+    #[serde(rename = "service-accounts")]
+    service_accounts: Vec<ServiceAccount>,
+}
+
+impl ServiceAccountsResponse {
+    pub fn service_accounts(&self) -> &[ServiceAccount] {
+        &self.service_accounts
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ServiceAccount {
+    service_account_user_id: String,
+    name: String,
+    created_at_unix: Option<i64>,
+    #[serde(rename = "api-keys", default)]
+    api_keys: Vec<ServiceAccountAPIKey>,
+}
+
+impl ServiceAccount {
+    pub fn service_account_user_id(&self) -> &str {
+        &self.service_account_user_id
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn created_at_unix(&self) -> Option<i64> {
+        self.created_at_unix
+    }
+    /// [created_at_unix](Self::created_at_unix) as a date
+    #[cfg(feature = "chrono")]
+    pub fn created_at(&self) -> Option<DateTime<Utc>> {
+        self.created_at_unix().map(utc)
+    }
+    pub fn api_keys(&self) -> &[ServiceAccountAPIKey] {
+        &self.api_keys
+    }
+}
+
+/// List the API keys of a service account
+#[derive(Clone, Debug)]
+pub struct GetServiceAccountAPIKeys {
+    service_account_user_id: String,
+}
+
+impl GetServiceAccountAPIKeys {
+    pub fn new(service_account_user_id: &str) -> Self {
+        GetServiceAccountAPIKeys {
+            service_account_user_id: service_account_user_id.to_string(),
+        }
+    }
+}
+
+impl Endpoint for GetServiceAccountAPIKeys {
+    type ResponseBody = ServiceAccountAPIKeysResponse;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        api_keys_url(&self.service_account_user_id, None)
+    }
+}
+
+/// Service account API keys response
+#[derive(Clone, Debug, Deserialize)]
+pub struct ServiceAccountAPIKeysResponse {
+    #[serde(rename = "api-keys")]
+    api_keys: Vec<ServiceAccountAPIKey>,
+}
+
+impl ServiceAccountAPIKeysResponse {
+    pub fn api_keys(&self) -> &[ServiceAccountAPIKey] {
+        &self.api_keys
+    }
+}
+
+/// An API key of a service account, the key itself is only returned when it is created
+#[derive(Clone, Debug, Deserialize)]
+pub struct ServiceAccountAPIKey {
+    key_id: String,
+    name: String,
+    /// The first characters of the key
+    hint: String,
+    service_account_user_id: String,
+    created_at_unix: Option<i64>,
+    #[serde(default)]
+    is_disabled: bool,
+    #[serde(default)]
+    permissions: APIKeyPermissions,
+    character_limit: Option<u64>,
+    #[serde(default)]
+    character_count: u64,
+}
+
+impl ServiceAccountAPIKey {
+    pub fn key_id(&self) -> &str {
+        &self.key_id
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn hint(&self) -> &str {
+        &self.hint
+    }
+    pub fn service_account_user_id(&self) -> &str {
+        &self.service_account_user_id
+    }
+    pub fn created_at_unix(&self) -> Option<i64> {
+        self.created_at_unix
+    }
+    /// [created_at_unix](Self::created_at_unix) as a date
+    #[cfg(feature = "chrono")]
+    pub fn created_at(&self) -> Option<DateTime<Utc>> {
+        self.created_at_unix().map(utc)
+    }
+    pub fn is_disabled(&self) -> bool {
+        self.is_disabled
+    }
+    pub fn permissions(&self) -> &APIKeyPermissions {
+        &self.permissions
+    }
+    /// The monthly character limit of the key, `None` if unlimited
+    pub fn character_limit(&self) -> Option<u64> {
+        self.character_limit
+    }
+    /// The characters used by the key this month
+    pub fn character_count(&self) -> u64 {
+        self.character_count
+    }
+}
+
+/// Create an API key for a service account
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let account_id = "service_account_user_id";
+///     let permissions = vec![APIKeyPermission::TextToSpeech, APIKeyPermission::VoicesRead];
+///     let body = CreateAPIKeyBody::new("tts-2025-01", permissions.into())
+///         .with_character_limit(1_000_000);
+///     let new_key = c.hit(CreateServiceAccountAPIKey::new(account_id, body)).await?;
+///
+///     // Retire the previous key once the new one is deployed
+///     c.hit(DeleteServiceAccountAPIKey::new(account_id, "old_key_id")).await?;
+///     println!("{}", new_key.key_id());
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct CreateServiceAccountAPIKey {
+    service_account_user_id: String,
+    body: CreateAPIKeyBody,
+}
+
+impl CreateServiceAccountAPIKey {
+    pub fn new(service_account_user_id: &str, body: CreateAPIKeyBody) -> Self {
+        CreateServiceAccountAPIKey {
+            service_account_user_id: service_account_user_id.to_string(),
+            body,
+        }
+    }
+}
+
+impl Endpoint for CreateServiceAccountAPIKey {
+    type ResponseBody = CreateAPIKeyResponse;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(serde_json::to_value(&self.body)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        api_keys_url(&self.service_account_user_id, None)
+    }
+}
+
+/// Create API key body
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateAPIKeyBody {
+    name: String,
+    permissions: APIKeyPermissions,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    character_limit: Option<u64>,
+}
+
+impl CreateAPIKeyBody {
+    pub fn new(name: &str, permissions: APIKeyPermissions) -> Self {
+        CreateAPIKeyBody {
+            name: name.to_string(),
+            permissions,
+            character_limit: None,
+        }
+    }
+    /// The monthly character limit of the key, unlimited if not set
+    pub fn with_character_limit(mut self, character_limit: u64) -> Self {
+        self.character_limit = Some(character_limit);
+        self
+    }
+}
+
+/// Create API key response
+#[derive(Clone, Debug, Deserialize)]
+pub struct CreateAPIKeyResponse {
+    #[serde(rename = "xi-api-key")]
+    api_key: String,
+    key_id: String,
+}
+
+impl CreateAPIKeyResponse {
+    /// The API key, this is the only time it is returned
+    pub fn api_key(&self) -> &str {
+        &self.api_key
+    }
+    pub fn key_id(&self) -> &str {
+        &self.key_id
+    }
+}
+
+/// Update the name, permissions, character limit or enabled state of a service account API key
+#[derive(Clone, Debug)]
+pub struct UpdateServiceAccountAPIKey {
+    service_account_user_id: String,
+    key_id: String,
+    body: UpdateAPIKeyBody,
+}
+
+impl UpdateServiceAccountAPIKey {
+    pub fn new(service_account_user_id: &str, key_id: &str, body: UpdateAPIKeyBody) -> Self {
+        UpdateServiceAccountAPIKey {
+            service_account_user_id: service_account_user_id.to_string(),
+            key_id: key_id.to_string(),
+            body,
+        }
+    }
+}
+
+impl Endpoint for UpdateServiceAccountAPIKey {
+    type ResponseBody = StatusResponseBody;
+
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(serde_json::to_value(&self.body)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        api_keys_url(&self.service_account_user_id, Some(&self.key_id))
+    }
+}
+
+/// Update API key body, all fields are required by the API except the character limit
+#[derive(Clone, Debug, Serialize)]
+pub struct UpdateAPIKeyBody {
+    is_enabled: bool,
+    name: String,
+    permissions: APIKeyPermissions,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    character_limit: Option<u64>,
+}
+
+impl UpdateAPIKeyBody {
+    pub fn new(name: &str, permissions: APIKeyPermissions, is_enabled: bool) -> Self {
+        UpdateAPIKeyBody {
+            is_enabled,
+            name: name.to_string(),
+            permissions,
+            character_limit: None,
+        }
+    }
+    pub fn with_character_limit(mut self, character_limit: u64) -> Self {
+        self.character_limit = Some(character_limit);
+        self
+    }
+}
+
+impl From<&ServiceAccountAPIKey> for UpdateAPIKeyBody {
+    /// The current settings of a key, to change only some of them
+    fn from(key: &ServiceAccountAPIKey) -> Self {
+        UpdateAPIKeyBody {
+            is_enabled: !key.is_disabled,
+            name: key.name.clone(),
+            permissions: key.permissions.clone(),
+            character_limit: key.character_limit,
+        }
+    }
+}
+
+/// Delete an API key of a service account
+#[derive(Clone, Debug)]
+pub struct DeleteServiceAccountAPIKey {
+    service_account_user_id: String,
+    key_id: String,
+}
+
+impl DeleteServiceAccountAPIKey {
+    pub fn new(service_account_user_id: &str, key_id: &str) -> Self {
+        DeleteServiceAccountAPIKey {
+            service_account_user_id: service_account_user_id.to_string(),
+            key_id: key_id.to_string(),
+        }
+    }
+}
+
+impl Endpoint for DeleteServiceAccountAPIKey {
+    type ResponseBody = StatusResponseBody;
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        api_keys_url(&self.service_account_user_id, Some(&self.key_id))
+    }
+}
+
+/// The permissions of an API key, either every permission or a set of scopes
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum APIKeyPermissions {
+    #[default]
+    #[serde(with = "all")]
+    All,
+    Scopes(Vec<APIKeyPermission>),
+}
+
+impl From<Vec<APIKeyPermission>> for APIKeyPermissions {
+    fn from(scopes: Vec<APIKeyPermission>) -> Self {
+        APIKeyPermissions::Scopes(scopes)
+    }
+}
+
+/// (De)serializes [APIKeyPermissions::All] as the string `"all"`
+mod all {
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str("all")
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<(), D::Error> {
+        match String::deserialize(deserializer)?.as_str() {
+            "all" => Ok(()),
+            other => Err(de::Error::invalid_value(de::Unexpected::Str(other), &"all")),
+        }
+    }
+}
+
+/// A permission scope of an API key
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum APIKeyPermission {
+    TextToSpeech,
+    SpeechToSpeech,
+    SpeechToText,
+    ModelsRead,
+    ModelsWrite,
+    VoicesRead,
+    VoicesWrite,
+    SpeechHistoryRead,
+    SpeechHistoryWrite,
+    SoundGeneration,
+    AudioIsolation,
+    VoiceGeneration,
+    DubbingRead,
+    DubbingWrite,
+    PronunciationDictionariesRead,
+    PronunciationDictionariesWrite,
+    UserRead,
+    UserWrite,
+    ProjectsRead,
+    ProjectsWrite,
+    AudioNativeRead,
+    AudioNativeWrite,
+    WorkspaceRead,
+    WorkspaceWrite,
+    ForcedAlignment,
+    ConvaiRead,
+    ConvaiWrite,
+    MusicGeneration,
+    /// A scope not known to this version of the crate
+    #[serde(untagged)]
+    Other(String),
+}
+
+fn api_keys_url(service_account_user_id: &str, key_id: Option<&str>) -> Url {
+    let mut url = BASE_URL.parse::<Url>().unwrap();
+    let mut path = format!(
+        "{}/{}{}",
+        SERVICE_ACCOUNTS_PATH, service_account_user_id, API_KEYS_PATH
+    );
+    if let Some(key_id) = key_id {
+        path = format!("{}/{}", path, key_id);
+    }
+    url.set_path(&path);
+    url
+}
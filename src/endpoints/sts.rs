@@ -3,7 +3,6 @@
 use super::*;
 pub use crate::endpoints::tts::SpeechQuery;
 pub use crate::endpoints::voice::VoiceSettings;
-use crate::error::Error;
 use futures_util::{Stream, StreamExt};
 use std::pin::Pin;
 
@@ -40,7 +39,7 @@ pub struct SpeechToSpeech {
 /// Speech-to-speech body
 #[derive(Debug, Clone)]
 pub struct SpeechToSpeechBody {
-    audio: String,
+    audio: FileSource,
     model_id: Option<String>,
     voice_settings: Option<VoiceSettings>,
     seed: Option<u64>,
@@ -48,9 +47,11 @@ pub struct SpeechToSpeechBody {
 
 impl SpeechToSpeechBody {
     /// Create a new SpeechToSpeechBody
-    pub fn new(audio: &str) -> Self {
+    ///
+    /// `audio` can be a path, which is streamed from disk, or any other [FileSource]
+    pub fn new<A: Into<FileSource>>(audio: A) -> Self {
         SpeechToSpeechBody {
-            audio: audio.to_string(),
+            audio: audio.into(),
             model_id: None,
             voice_settings: None,
             seed: None,
@@ -73,20 +74,13 @@ impl SpeechToSpeechBody {
     }
     fn to_form(&self) -> Result<Form> {
         let mut form = Form::new();
-        let path = std::path::Path::new(&self.audio);
-        let audio_bytes = std::fs::read(path)?;
-        let file_name = path
-            .to_str()
-            .ok_or(Box::new(Error::PathNotValidUTF8))?
-            .to_string();
-        let audio = Part::bytes(audio_bytes)
-            .file_name(file_name)
-            .mime_str("audio/mpeg")?;
+        let audio = self.audio.to_part()?.mime_str("audio/mpeg")?;
         form = form.part("audio", audio);
         if let Some(model_id) = &self.model_id {
             form = form.text("model_id", model_id.clone());
         }
         if let Some(voice_settings) = &self.voice_settings {
+            voice_settings.validate()?;
             form = form.text("voice_settings", serde_json::to_string(voice_settings)?);
         }
         if let Some(seed) = &self.seed {
@@ -98,9 +92,9 @@ impl SpeechToSpeechBody {
 
 impl SpeechToSpeech {
     /// Create a new SpeechToSpeech endpoint
-    pub fn new<T: Into<String>>(voice_id: T, speech_to_speech_body: SpeechToSpeechBody) -> Self {
+    pub fn new<T: Into<VoiceID>>(voice_id: T, speech_to_speech_body: SpeechToSpeechBody) -> Self {
         SpeechToSpeech {
-            voice_id: VoiceID::from(voice_id.into()),
+            voice_id: voice_id.into(),
             speech_to_speech_body,
             speech_query: None,
         }
@@ -175,9 +169,9 @@ pub struct SpeechToSpeechStream {
 }
 
 impl SpeechToSpeechStream {
-    pub fn new<T: Into<String>>(voice_id: T, speech_to_speech_body: SpeechToSpeechBody) -> Self {
+    pub fn new<T: Into<VoiceID>>(voice_id: T, speech_to_speech_body: SpeechToSpeechBody) -> Self {
         SpeechToSpeechStream {
-            voice_id: VoiceID::from(voice_id.into()),
+            voice_id: voice_id.into(),
             speech_to_speech_body,
             speech_query: None,
         }
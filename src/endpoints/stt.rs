@@ -0,0 +1,432 @@
+//! The speech-to-text endpoint
+use super::*;
+use std::collections::BTreeMap;
+
+const STT_PATH: &str = "/v1/speech-to-text";
+
+/// Transcribe an audio or video file
+///
+/// # Example
+///
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     // A stereo call recording with the agent on the left and the customer on the right
+///     let body = SpeechToTextBody::new("call.wav", Model::ScribeV1)
+///         .with_use_multi_channel(true)
+///         .with_keyterms(["ElevenLabs", "SKU-4821"])
+///         .with_timestamps_granularity(TimestampsGranularity::Word);
+///     let resp = c.hit(SpeechToText::new(body)).await?;
+///     for transcript in resp.transcripts() {
+///         println!("channel {:?}: {}", transcript.channel_index(), transcript.text());
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SpeechToText(SpeechToTextBody);
+
+impl SpeechToText {
+    pub fn new(body: SpeechToTextBody) -> Self {
+        SpeechToText(body)
+    }
+}
+
+impl Endpoint for SpeechToText {
+    type ResponseBody = SpeechToTextResponse;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Multipart(self.0.to_form()?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        let mut url = BASE_URL.parse::<Url>().unwrap();
+        url.set_path(STT_PATH);
+        url
+    }
+}
+
+/// Submit a transcription whose result is sent to the workspace's speech-to-text webhooks
+///
+/// Returns as soon as the request is accepted, so long recordings do not time out.
+/// The result arrives as a [SpeechToTextTranscription](crate::webhooks::WebhookEvent::SpeechToTextTranscription)
+/// event carrying the same request id.
+///
+/// # Example
+///
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let body = SpeechToTextBody::new("town_hall.mp3", Model::ScribeV1)
+///         .with_webhook_metadata(serde_json::json!({ "meeting_id": 42 }));
+///     let resp = c.hit(SpeechToTextWithWebhook::new(body)).await?;
+///     println!("submitted {}", resp.request_id());
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SpeechToTextWithWebhook(SpeechToTextBody);
+
+impl SpeechToTextWithWebhook {
+    pub fn new(body: SpeechToTextBody) -> Self {
+        SpeechToTextWithWebhook(body)
+    }
+}
+
+impl Endpoint for SpeechToTextWithWebhook {
+    type ResponseBody = SpeechToTextWebhookResponse;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        let form = self.0.to_form()?.text("webhook", "true");
+        Ok(RequestBody::Multipart(form))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        let mut url = BASE_URL.parse::<Url>().unwrap();
+        url.set_path(STT_PATH);
+        url
+    }
+}
+
+/// Speech-to-text webhook response
+#[derive(Clone, Debug, Deserialize)]
+pub struct SpeechToTextWebhookResponse {
+    message: String,
+    request_id: String,
+    transcription_id: Option<String>,
+}
+
+impl SpeechToTextWebhookResponse {
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+    /// Matches the [request id](crate::webhooks::SpeechToTextTranscription::request_id) of the webhook
+    pub fn request_id(&self) -> &str {
+        &self.request_id
+    }
+    pub fn transcription_id(&self) -> Option<&str> {
+        self.transcription_id.as_deref()
+    }
+}
+
+/// Speech-to-text body
+#[derive(Clone, Debug)]
+pub struct SpeechToTextBody {
+    model_id: String,
+    source: AudioSource,
+    language_code: Option<String>,
+    tag_audio_events: Option<bool>,
+    num_speakers: Option<u32>,
+    timestamps_granularity: Option<TimestampsGranularity>,
+    diarize: Option<bool>,
+    use_multi_channel: Option<bool>,
+    keyterms: Vec<String>,
+    webhook_id: Option<String>,
+    webhook_metadata: Option<Value>,
+}
+
+#[derive(Clone, Debug)]
+enum AudioSource {
+    File(FileSource),
+    CloudStorageUrl(String),
+}
+
+impl SpeechToTextBody {
+    pub fn new<F: Into<FileSource>, M: Into<String>>(file: F, model_id: M) -> Self {
+        Self::with_source(AudioSource::File(file.into()), model_id.into())
+    }
+    /// Transcribe a file the API downloads itself, e.g. a presigned S3 url
+    pub fn from_cloud_storage_url<M: Into<String>>(url: &str, model_id: M) -> Self {
+        Self::with_source(
+            AudioSource::CloudStorageUrl(url.to_string()),
+            model_id.into(),
+        )
+    }
+    fn with_source(source: AudioSource, model_id: String) -> Self {
+        SpeechToTextBody {
+            model_id,
+            source,
+            language_code: None,
+            tag_audio_events: None,
+            num_speakers: None,
+            timestamps_granularity: None,
+            diarize: None,
+            use_multi_channel: None,
+            keyterms: Vec::new(),
+            webhook_id: None,
+            webhook_metadata: None,
+        }
+    }
+    /// An ISO 639-1 or 639-3 code, detected if not given
+    pub fn with_language_code(mut self, language_code: &str) -> Self {
+        self.language_code = Some(language_code.to_string());
+        self
+    }
+    /// Whether to tag events like laughter in the transcript, defaults to true
+    pub fn with_tag_audio_events(mut self, tag_audio_events: bool) -> Self {
+        self.tag_audio_events = Some(tag_audio_events);
+        self
+    }
+    /// The most speakers in the file, up to 32, helps [diarization](Self::with_diarize)
+    pub fn with_num_speakers(mut self, num_speakers: u32) -> Self {
+        self.num_speakers = Some(num_speakers);
+        self
+    }
+    pub fn with_timestamps_granularity(mut self, granularity: TimestampsGranularity) -> Self {
+        self.timestamps_granularity = Some(granularity);
+        self
+    }
+    /// Annotate each word with the [speaker](TranscriptWord::speaker_id) who said it
+    pub fn with_diarize(mut self, diarize: bool) -> Self {
+        self.diarize = Some(diarize);
+        self
+    }
+    /// Transcribe each channel separately, up to five, returning a transcript per channel
+    ///
+    /// Words are attributed to a speaker per channel, so diarization is not needed
+    /// when each speaker is recorded on a channel of their own.
+    pub fn with_use_multi_channel(mut self, use_multi_channel: bool) -> Self {
+        self.use_multi_channel = Some(use_multi_channel);
+        self
+    }
+    /// Words and phrases to bias recognition towards, e.g. product names or people
+    pub fn with_keyterms<I, T>(mut self, keyterms: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.keyterms.extend(keyterms.into_iter().map(Into::into));
+        self
+    }
+    /// Send the result of a [SpeechToTextWithWebhook] to this webhook only,
+    /// instead of all speech-to-text webhooks of the workspace
+    pub fn with_webhook_id(mut self, webhook_id: &str) -> Self {
+        self.webhook_id = Some(webhook_id.to_string());
+        self
+    }
+    /// A JSON object, sent back as is in the webhook of a [SpeechToTextWithWebhook]
+    pub fn with_webhook_metadata(mut self, webhook_metadata: Value) -> Self {
+        self.webhook_metadata = Some(webhook_metadata);
+        self
+    }
+
+    fn to_form(&self) -> Result<Form> {
+        let mut form = Form::new().text("model_id", self.model_id.clone());
+        match &self.source {
+            AudioSource::File(file) => form = form.part("file", file.to_part()?),
+            AudioSource::CloudStorageUrl(url) => form = form.text("cloud_storage_url", url.clone()),
+        }
+        if let Some(language_code) = &self.language_code {
+            form = form.text("language_code", language_code.clone());
+        }
+        if let Some(tag_audio_events) = self.tag_audio_events {
+            form = form.text("tag_audio_events", tag_audio_events.to_string());
+        }
+        if let Some(num_speakers) = self.num_speakers {
+            form = form.text("num_speakers", num_speakers.to_string());
+        }
+        if let Some(granularity) = &self.timestamps_granularity {
+            form = form.text("timestamps_granularity", granularity.as_str());
+        }
+        if let Some(diarize) = self.diarize {
+            form = form.text("diarize", diarize.to_string());
+        }
+        if let Some(use_multi_channel) = self.use_multi_channel {
+            form = form.text("use_multi_channel", use_multi_channel.to_string());
+        }
+        for keyterm in &self.keyterms {
+            form = form.text("keyterms", keyterm.clone());
+        }
+        if let Some(webhook_id) = &self.webhook_id {
+            form = form.text("webhook_id", webhook_id.clone());
+        }
+        if let Some(webhook_metadata) = &self.webhook_metadata {
+            form = form.text("webhook_metadata", webhook_metadata.to_string());
+        }
+        Ok(form)
+    }
+}
+
+/// How precise the timestamps of a transcript are
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TimestampsGranularity {
+    None,
+    #[default]
+    Word,
+    /// Words and each of their characters
+    Character,
+}
+
+impl TimestampsGranularity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TimestampsGranularity::None => "none",
+            TimestampsGranularity::Word => "word",
+            TimestampsGranularity::Character => "character",
+        }
+    }
+}
+
+/// Speech-to-text response, one transcript per channel with multichannel transcription
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum SpeechToTextResponse {
+    Multichannel {
+        transcripts: Vec<Transcript>,
+        transcription_id: Option<String>,
+    },
+    Transcript(Transcript),
+}
+
+impl SpeechToTextResponse {
+    /// The transcripts of each channel, or the only transcript
+    pub fn transcripts(&self) -> &[Transcript] {
+        match self {
+            SpeechToTextResponse::Multichannel { transcripts, .. } => transcripts,
+            SpeechToTextResponse::Transcript(transcript) => std::slice::from_ref(transcript),
+        }
+    }
+    pub fn transcription_id(&self) -> Option<&str> {
+        match self {
+            SpeechToTextResponse::Multichannel {
+                transcription_id, ..
+            } => transcription_id.as_deref(),
+            SpeechToTextResponse::Transcript(transcript) => transcript.transcription_id(),
+        }
+    }
+}
+
+/// The transcript of a file, or of one of its channels
+#[derive(Clone, Debug, Deserialize)]
+pub struct Transcript {
+    language_code: String,
+    language_probability: f32,
+    text: String,
+    #[serde(default)]
+    words: Vec<TranscriptWord>,
+    channel_index: Option<u32>,
+    transcription_id: Option<String>,
+}
+
+impl Transcript {
+    pub fn language_code(&self) -> &str {
+        &self.language_code
+    }
+    pub fn language_probability(&self) -> f32 {
+        self.language_probability
+    }
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+    /// Words, spaces between them and audio events, in order
+    pub fn words(&self) -> &[TranscriptWord] {
+        &self.words
+    }
+    /// Only with multichannel transcription
+    pub fn channel_index(&self) -> Option<u32> {
+        self.channel_index
+    }
+    pub fn transcription_id(&self) -> Option<&str> {
+        self.transcription_id.as_deref()
+    }
+    /// The words of each speaker, in order, when [diarized](SpeechToTextBody::with_diarize)
+    pub fn words_by_speaker(&self) -> BTreeMap<&str, Vec<&TranscriptWord>> {
+        let mut speakers = BTreeMap::<&str, Vec<&TranscriptWord>>::new();
+        for word in &self.words {
+            if let Some(speaker_id) = word.speaker_id() {
+                speakers.entry(speaker_id).or_default().push(word);
+            }
+        }
+        speakers
+    }
+}
+
+/// A word, space or audio event of a transcript
+#[derive(Clone, Debug, Deserialize)]
+pub struct TranscriptWord {
+    text: String,
+    start: Option<f32>,
+    end: Option<f32>,
+    #[serde(rename = "type")]
+    word_type: WordType,
+    speaker_id: Option<String>,
+    logprob: Option<f32>,
+    characters: Option<Vec<TranscriptCharacter>>,
+}
+
+impl TranscriptWord {
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+    /// In seconds
+    pub fn start(&self) -> Option<f32> {
+        self.start
+    }
+    /// In seconds
+    pub fn end(&self) -> Option<f32> {
+        self.end
+    }
+    pub fn word_type(&self) -> WordType {
+        self.word_type
+    }
+    pub fn speaker_id(&self) -> Option<&str> {
+        self.speaker_id.as_deref()
+    }
+    /// The log probability of the word, the closer to 0 the more confident
+    pub fn logprob(&self) -> Option<f32> {
+        self.logprob
+    }
+    /// Only with [character](TimestampsGranularity::Character) timestamps
+    pub fn characters(&self) -> Option<&[TranscriptCharacter]> {
+        self.characters.as_deref()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum WordType {
+    Word,
+    Spacing,
+    AudioEvent,
+    /// A type this version of the crate does not know about
+    #[serde(other)]
+    Unknown,
+}
+
+/// A character of a transcript word
+#[derive(Clone, Debug, Deserialize)]
+pub struct TranscriptCharacter {
+    text: String,
+    start: Option<f32>,
+    end: Option<f32>,
+}
+
+impl TranscriptCharacter {
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+    /// In seconds
+    pub fn start(&self) -> Option<f32> {
+        self.start
+    }
+    /// In seconds
+    pub fn end(&self) -> Option<f32> {
+        self.end
+    }
+}
@@ -0,0 +1,210 @@
+#![allow(dead_code)]
+//! The text-to-dialogue endpoints
+//!
+//! Generate a multi-speaker dialogue in a single call, each input is spoken by its own voice.
+//!
+//! See the [ElevenLabs docs](https://elevenlabs.io/docs/api-reference/text-to-dialogue/convert) for more information.
+use super::*;
+pub use crate::endpoints::tts::SpeechQuery;
+use futures_util::{Stream, StreamExt};
+use std::pin::Pin;
+
+const TEXT_TO_DIALOGUE_PATH: &str = "/v1/text-to-dialogue";
+
+/// Text to dialogue endpoint
+///
+/// # Example
+///
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::utils::play;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let body = TextToDialogueBody::new(vec![
+///         (PreMadeVoiceID::Alice, "[excited] Did you hear? They finally fixed the coffee machine!"),
+///         (PreMadeVoiceID::George, "[sighs] About time. I've been drinking tea for a week."),
+///     ])
+///     .with_model_id("eleven_v3");
+///     let dialogue = c.hit(TextToDialogue::new(body)).await?;
+///     play(dialogue)?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct TextToDialogue {
+    body: TextToDialogueBody,
+    speech_query: Option<SpeechQuery>,
+}
+
+impl TextToDialogue {
+    pub fn new(body: TextToDialogueBody) -> Self {
+        TextToDialogue {
+            body,
+            speech_query: None,
+        }
+    }
+    pub fn with_query(mut self, speech_query: SpeechQuery) -> Self {
+        self.speech_query = Some(speech_query);
+        self
+    }
+}
+
+impl Endpoint for TextToDialogue {
+    type ResponseBody = Bytes;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(serde_json::to_value(&self.body)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.bytes().await?)
+    }
+    fn url(&self) -> Url {
+        let mut url = BASE_URL.parse::<Url>().unwrap();
+        url.set_path(TEXT_TO_DIALOGUE_PATH);
+        url.set_query(self.speech_query.as_ref().map(|q| q.to_string()).as_deref());
+        url
+    }
+}
+
+/// Text to dialogue stream endpoint
+///
+/// # Example
+///
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::utils::stream_audio;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let body = TextToDialogueBody::new(vec![
+///         (PreMadeVoiceID::Charlie, "Knock knock."),
+///         (PreMadeVoiceID::Lily, "Who's there?"),
+///     ]);
+///     let stream = c.hit(TextToDialogueStream::new(body)).await?;
+///     stream_audio(stream).await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct TextToDialogueStream {
+    body: TextToDialogueBody,
+    speech_query: Option<SpeechQuery>,
+}
+
+impl TextToDialogueStream {
+    pub fn new(body: TextToDialogueBody) -> Self {
+        TextToDialogueStream {
+            body,
+            speech_query: None,
+        }
+    }
+    pub fn with_query(mut self, speech_query: SpeechQuery) -> Self {
+        self.speech_query = Some(speech_query);
+        self
+    }
+}
+
+impl Endpoint for TextToDialogueStream {
+    type ResponseBody = Pin<Box<dyn Stream<Item = Result<Bytes>> + Send>>;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(serde_json::to_value(&self.body)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        let stream = resp.bytes_stream();
+        let stream = stream.map(|r| r.map_err(Into::into));
+        Ok(Box::pin(stream))
+    }
+    fn url(&self) -> Url {
+        let mut url = BASE_URL.parse::<Url>().unwrap();
+        url.set_path(&format!("{}{}", TEXT_TO_DIALOGUE_PATH, STREAM_PATH));
+        url.set_query(self.speech_query.as_ref().map(|q| q.to_string()).as_deref());
+        url
+    }
+}
+
+/// Text to dialogue body
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct TextToDialogueBody {
+    inputs: Vec<DialogueInput>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    model_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    settings: Option<DialogueSettings>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    seed: Option<u64>,
+}
+
+impl TextToDialogueBody {
+    /// Takes the dialogue as `(voice_id, text)` pairs, in speaking order
+    pub fn new<V, T>(inputs: Vec<(V, T)>) -> Self
+    where
+        V: Into<VoiceID>,
+        T: Into<String>,
+    {
+        TextToDialogueBody {
+            inputs: inputs
+                .into_iter()
+                .map(|(voice_id, text)| DialogueInput::new(voice_id, text))
+                .collect(),
+            ..Default::default()
+        }
+    }
+    pub fn with_input(mut self, input: DialogueInput) -> Self {
+        self.inputs.push(input);
+        self
+    }
+    /// Defaults to `eleven_v3`
+    pub fn with_model_id<T: Into<String>>(mut self, model_id: T) -> Self {
+        self.model_id = Some(model_id.into());
+        self
+    }
+    /// `stability` expected to be greater or equal to 0.0 and less or equal to 1.0
+    pub fn with_stability(mut self, stability: f32) -> Self {
+        self.settings = Some(DialogueSettings { stability });
+        self
+    }
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+    pub fn inputs(&self) -> &[DialogueInput] {
+        &self.inputs
+    }
+}
+
+/// A line of dialogue
+#[derive(Clone, Debug, Serialize)]
+pub struct DialogueInput {
+    text: String,
+    voice_id: VoiceID,
+}
+
+impl DialogueInput {
+    pub fn new<V: Into<VoiceID>, T: Into<String>>(voice_id: V, text: T) -> Self {
+        DialogueInput {
+            text: text.into(),
+            voice_id: voice_id.into(),
+        }
+    }
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+    pub fn voice_id(&self) -> &str {
+        self.voice_id.as_str()
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct DialogueSettings {
+    stability: f32,
+}
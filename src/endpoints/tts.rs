@@ -1,7 +1,11 @@
 #![allow(dead_code)]
 //! The text-to-speech endpoints
 use super::*;
+use crate::endpoints::pronunciation::{
+    AddFromFileResponse, PronunciationDictionary, RulesResponse,
+};
 use crate::endpoints::voice::VoiceSettings;
+use crate::error::Error;
 use async_stream::try_stream;
 use base64::{engine::general_purpose, Engine as _};
 use futures_util::{Stream, StreamExt};
@@ -13,6 +17,7 @@ const LATENCY_QUERY: &str = "optimize_streaming_latency";
 const OUTPUT_FORMAT_QUERY: &str = "output_format";
 const ENABLE_LOGGING_QUERY: &str = "enable_logging";
 const ENABLE_SSML_PARSING_QUERY: &str = "enable_ssml_parsing";
+const REQUEST_ID_HEADER: &str = "request-id";
 
 /// Text to Speech endpoint
 ///
@@ -49,6 +54,7 @@ impl Endpoint for TextToSpeech {
         Method::POST
     }
     fn request_body(&self) -> Result<RequestBody> {
+        self.text_to_speech_body.validate()?;
         Ok(RequestBody::Json(serde_json::to_value(
             &self.text_to_speech_body,
         )?))
@@ -66,9 +72,9 @@ impl Endpoint for TextToSpeech {
 }
 
 impl TextToSpeech {
-    pub fn new<T: Into<String>>(voice_id: T, text_to_speech_body: TextToSpeechBody) -> Self {
+    pub fn new<T: Into<VoiceID>>(voice_id: T, text_to_speech_body: TextToSpeechBody) -> Self {
         TextToSpeech {
-            voice_id: VoiceID::from(voice_id.into()),
+            voice_id: voice_id.into(),
             text_to_speech_body,
             speech_query: None,
         }
@@ -87,6 +93,97 @@ impl TextToSpeech {
     }
 }
 
+/// Text to Speech endpoint that also returns the id of the request,
+/// so that the next request can be [stitched](TextToSpeechBody::with_previous_request_ids) to it
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let paragraphs = ["It was a dark and stormy night.", "The rain fell in torrents."];
+///     let mut request_ids = Vec::new();
+///     let mut audio = Vec::new();
+///     for paragraph in paragraphs {
+///         // Only the last three requests are taken into account
+///         let previous = request_ids[request_ids.len().saturating_sub(3)..].to_vec();
+///         let body = TextToSpeechBody::new(paragraph, Model::ElevenMultilingualV2)
+///             .with_previous_request_ids(previous);
+///         let endpoint = TextToSpeechWithRequestID::new(PreMadeVoiceID::George, body);
+///         let resp = c.hit(endpoint).await?;
+///         request_ids.extend(resp.request_id().map(str::to_string));
+///         audio.extend_from_slice(resp.audio());
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct TextToSpeechWithRequestID(TextToSpeech);
+
+impl TextToSpeechWithRequestID {
+    pub fn new<T: Into<VoiceID>>(voice_id: T, text_to_speech_body: TextToSpeechBody) -> Self {
+        TextToSpeechWithRequestID(TextToSpeech::new(voice_id, text_to_speech_body))
+    }
+    pub fn with_query(self, speech_query: SpeechQuery) -> Self {
+        TextToSpeechWithRequestID(self.0.with_query(speech_query))
+    }
+}
+
+impl Endpoint for TextToSpeechWithRequestID {
+    type ResponseBody = TextToSpeechWithRequestIDResponse;
+
+    fn method(&self) -> Method {
+        self.0.method()
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        self.0.request_body()
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        let request_id = request_id(&resp);
+        Ok(TextToSpeechWithRequestIDResponse {
+            audio: resp.bytes().await?,
+            request_id,
+            seed: self.0.text_to_speech_body.seed,
+        })
+    }
+    fn url(&self) -> Url {
+        self.0.url()
+    }
+}
+
+/// The response from the TextToSpeechWithRequestID endpoint
+#[derive(Clone, Debug)]
+pub struct TextToSpeechWithRequestIDResponse {
+    audio: Bytes,
+    request_id: Option<String>,
+    seed: Option<u64>,
+}
+
+impl TextToSpeechWithRequestIDResponse {
+    pub fn audio(&self) -> &Bytes {
+        &self.audio
+    }
+    pub fn into_audio(self) -> Bytes {
+        self.audio
+    }
+    pub fn request_id(&self) -> Option<&str> {
+        self.request_id.as_deref()
+    }
+    /// The [seed](TextToSpeechBody::with_seed) the request was made with, if any
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+}
+
+fn request_id(resp: &Response) -> Option<String> {
+    resp.headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string)
+}
+
 /// Text to Speech Body for all TTS endpoints
 #[derive(Clone, Debug, Serialize, Default)]
 pub struct TextToSpeechBody {
@@ -103,9 +200,27 @@ pub struct TextToSpeechBody {
     #[serde(skip_serializing_if = "Option::is_none")]
     next_text: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    previous_text_ids: Option<Vec<String>>,
+    previous_request_ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    next_request_ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    language_code: Option<LanguageCode>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    next_text_ids: Option<Vec<String>>,
+    apply_text_normalization: Option<TextNormalization>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    apply_language_text_normalization: Option<bool>,
+}
+
+/// Whether numbers, dates and the like are spelled out before synthesis
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TextNormalization {
+    /// Left to the model
+    #[default]
+    Auto,
+    /// Only on Enterprise plans with the turbo and flash v2.5 models
+    On,
+    Off,
 }
 
 impl TextToSpeechBody {
@@ -116,51 +231,200 @@ impl TextToSpeechBody {
             ..Default::default()
         }
     }
-    pub fn with_pronunciation_dict(mut self, pronunciation_id: &str, version_id: &str) -> Self {
-        if let Some(dictionary) = &mut self.pronunciation_dictionary_locators {
-            dictionary.push(PronunciationDictionaryLocator {
-                pronunciation_dictionary_id: pronunciation_id.to_string(),
-                version_id: version_id.to_string(),
-            });
-        } else {
-            self.pronunciation_dictionary_locators = Some(vec![PronunciationDictionaryLocator {
-                pronunciation_dictionary_id: pronunciation_id.to_string(),
-                version_id: version_id.to_string(),
-            }]);
-        }
+    pub fn with_pronunciation_dict(self, pronunciation_id: &str, version_id: &str) -> Self {
+        self.with_pronunciation_dictionary(PronunciationDictionaryLocator::new(
+            pronunciation_id,
+            version_id,
+        ))
+    }
+    /// Applies a dictionary, e.g. straight from the response of
+    /// [AddFromFile](crate::endpoints::pronunciation::AddFromFile) or
+    /// [AddRules](crate::endpoints::pronunciation::AddRules)
+    ///
+    /// Up to three dictionaries can be applied, in the order they are added.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use elevenlabs_rs::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     let c = ElevenLabsClient::default()?;
+    ///     let dictionary = AddFromFileBody::new("acronyms.pls", "acronyms");
+    ///     let dictionary = c.hit(AddFromFile::new(dictionary)).await?;
+    ///     let body = TextToSpeechBody::new("The TTS API", Model::ElevenTurboV2)
+    ///         .with_pronunciation_dictionary(&dictionary);
+    ///     let speech = c.hit(TextToSpeech::new(PreMadeVoiceID::Alice, body)).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_pronunciation_dictionary<L: Into<PronunciationDictionaryLocator>>(
+        mut self,
+        locator: L,
+    ) -> Self {
+        self.pronunciation_dictionary_locators
+            .get_or_insert_with(Vec::new)
+            .push(locator.into());
+        self
+    }
+    pub fn with_pronunciation_dictionary_locators(
+        mut self,
+        locators: Vec<PronunciationDictionaryLocator>,
+    ) -> Self {
+        self.pronunciation_dictionary_locators = Some(locators);
         self
     }
     pub fn with_voice_settings(mut self, voice_settings: VoiceSettings) -> Self {
         self.voice_settings = Some(voice_settings);
         self
     }
+    /// Between 0 and 4294967295, repeating a request with the same seed
+    /// and otherwise the same parameters should return the same audio
+    ///
+    /// Determinism is not guaranteed, small differences can occur.
+    /// A larger seed fails the request with [Error::SeedOutOfRange] before it is sent.
     pub fn with_seed(mut self, seed: u64) -> Self {
         self.seed = Some(seed);
         self
     }
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+    pub(crate) fn validate(&self) -> Result<()> {
+        if let Some(voice_settings) = &self.voice_settings {
+            voice_settings.validate()?;
+        }
+        if let Some(seed) = self.seed.filter(|seed| *seed > u32::MAX as u64) {
+            return Err(Box::new(Error::SeedOutOfRange(seed)));
+        }
+        Ok(())
+    }
+    /// The text that comes before, to keep the prosody continuous when splitting up a text
+    ///
+    /// Ignored if [previous request ids](Self::with_previous_request_ids) are given.
     pub fn with_previous_text(mut self, previous_text: &str) -> Self {
         self.previous_text = Some(previous_text.to_string());
         self
     }
+    /// The text that comes after, see [with_previous_text](Self::with_previous_text)
     pub fn with_next_text(mut self, next_text: &str) -> Self {
         self.next_text = Some(next_text.to_string());
         self
     }
-    pub fn with_previous_text_ids(mut self, previous_text_ids: Vec<String>) -> Self {
-        self.previous_text_ids = Some(previous_text_ids);
+    /// The ids of up to three requests generated just before this one, with the same voice
+    ///
+    /// Use [TextToSpeechWithRequestID] to get the id of a request.
+    pub fn with_previous_request_ids(mut self, previous_request_ids: Vec<String>) -> Self {
+        self.previous_request_ids = Some(previous_request_ids);
         self
     }
-    pub fn with_next_text_ids(mut self, next_text_ids: Vec<String>) -> Self {
-        self.next_text_ids = Some(next_text_ids);
+    /// The ids of up to three requests that come after this one,
+    /// useful when regenerating a chunk in the middle of a text
+    pub fn with_next_request_ids(mut self, next_request_ids: Vec<String>) -> Self {
+        self.next_request_ids = Some(next_request_ids);
         self
     }
+    /// Enforces the language of a text that could be read in several, e.g. a short one
+    ///
+    /// Only the [turbo and flash v2.5](Model::ElevenFlashV2Dot5) models support it.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use elevenlabs_rs::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     let c = ElevenLabsClient::default()?;
+    ///     let body = TextToSpeechBody::new("Gift", Model::ElevenFlashV2Dot5)
+    ///         .with_language_code(LanguageCode::German);
+    ///     let speech = c.hit(TextToSpeech::new(PreMadeVoiceID::Sarah, body)).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_language_code<L: Into<LanguageCode>>(mut self, language_code: L) -> Self {
+        self.language_code = Some(language_code.into());
+        self
+    }
+    /// E.g. to read "$1,250.50" as "one thousand two hundred fifty dollars and fifty cents"
+    /// even with models that would otherwise leave it to chance
+    pub fn with_apply_text_normalization(mut self, normalization: TextNormalization) -> Self {
+        self.apply_text_normalization = Some(normalization);
+        self
+    }
+    /// Language specific normalization, which adds latency, currently only for Japanese
+    pub fn with_apply_language_text_normalization(mut self, apply: bool) -> Self {
+        self.apply_language_text_normalization = Some(apply);
+        self
+    }
+    #[deprecated(note = "Use `with_previous_request_ids` instead")]
+    pub fn with_previous_text_ids(self, previous_text_ids: Vec<String>) -> Self {
+        self.with_previous_request_ids(previous_text_ids)
+    }
+    #[deprecated(note = "Use `with_next_request_ids` instead")]
+    pub fn with_next_text_ids(self, next_text_ids: Vec<String>) -> Self {
+        self.with_next_request_ids(next_text_ids)
+    }
 }
-#[derive(Clone, Debug, Serialize)]
-struct PronunciationDictionaryLocator {
+
+/// A version of a pronunciation dictionary to apply to the text
+#[derive(Clone, Debug, Serialize, PartialEq)]
+pub struct PronunciationDictionaryLocator {
     pronunciation_dictionary_id: String,
     version_id: String,
 }
 
+impl PronunciationDictionaryLocator {
+    pub fn new(pronunciation_dictionary_id: &str, version_id: &str) -> Self {
+        PronunciationDictionaryLocator {
+            pronunciation_dictionary_id: pronunciation_dictionary_id.to_string(),
+            version_id: version_id.to_string(),
+        }
+    }
+    pub fn pronunciation_dictionary_id(&self) -> &str {
+        &self.pronunciation_dictionary_id
+    }
+    pub fn version_id(&self) -> &str {
+        &self.version_id
+    }
+}
+
+impl From<&AddFromFileResponse> for PronunciationDictionaryLocator {
+    fn from(resp: &AddFromFileResponse) -> Self {
+        PronunciationDictionaryLocator::new(resp.id(), resp.version_id())
+    }
+}
+
+impl From<&RulesResponse> for PronunciationDictionaryLocator {
+    fn from(resp: &RulesResponse) -> Self {
+        PronunciationDictionaryLocator::new(resp.id(), resp.version_id())
+    }
+}
+
+/// The latest version of the dictionary
+impl From<&PronunciationDictionary> for PronunciationDictionaryLocator {
+    fn from(dictionary: &PronunciationDictionary) -> Self {
+        PronunciationDictionaryLocator::new(dictionary.id(), dictionary.latest_version_id())
+    }
+}
+
+/// Query parameters of the text to speech, speech to speech and dialogue endpoints
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let body = TextToSpeechBody::new("Account 4411 is overdue", Model::ElevenFlashV2Dot5);
+///     let query = SpeechQuery::default()
+///         .with_latency(Latency::Strong)
+///         .with_logging(false);
+///     let endpoint = TextToSpeechStream::new(PreMadeVoiceID::Sarah, body).with_query(query);
+///     let stream = c.hit(endpoint).await?;
+///     Ok(())
+/// }
+/// ```
 #[derive(Clone, Debug, Default)]
 pub struct SpeechQuery {
     latency: Option<String>,
@@ -170,6 +434,7 @@ pub struct SpeechQuery {
 }
 
 impl SpeechQuery {
+    /// Trades quality for a shorter time to the first byte, mostly of streamed audio
     pub fn with_latency(mut self, latency: Latency) -> Self {
         self.latency = Some(format!("{}={}", LATENCY_QUERY, latency as u8));
         self
@@ -178,10 +443,14 @@ impl SpeechQuery {
         self.output_format = Some(format!(
             "{}={}",
             OUTPUT_FORMAT_QUERY,
-            output_format.to_query()
+            output_format.as_str()
         ));
         self
     }
+    /// `false` enables zero retention mode, available to enterprise customers only
+    ///
+    /// Neither the text nor the audio is stored, so the request is missing from the history
+    /// and cannot be [stitched](TextToSpeechBody::with_previous_request_ids) to.
     pub fn with_logging(mut self, enable_logging: bool) -> Self {
         self.enable_logging = Some(format!("{}={}", ENABLE_LOGGING_QUERY, enable_logging));
         self
@@ -250,9 +519,9 @@ pub struct TextToSpeechStream {
 }
 
 impl TextToSpeechStream {
-    pub fn new<T: Into<String>>(voice_id: T, text_to_speech_body: TextToSpeechBody) -> Self {
+    pub fn new<T: Into<VoiceID>>(voice_id: T, text_to_speech_body: TextToSpeechBody) -> Self {
         TextToSpeechStream {
-            voice_id: VoiceID::from(voice_id.into()),
+            voice_id: voice_id.into(),
             text_to_speech_body,
             speech_query: None,
         }
@@ -270,7 +539,7 @@ impl TextToSpeechStream {
     }
 }
 
-type TextToSpeechStreamResponse = Pin<Box<dyn Stream<Item = Result<Bytes>>>>;
+type TextToSpeechStreamResponse = Pin<Box<dyn Stream<Item = Result<Bytes>> + Send>>;
 impl Endpoint for TextToSpeechStream {
     type ResponseBody = TextToSpeechStreamResponse;
 
@@ -278,6 +547,7 @@ impl Endpoint for TextToSpeechStream {
         Method::POST
     }
     fn request_body(&self) -> Result<RequestBody> {
+        self.text_to_speech_body.validate()?;
         Ok(RequestBody::Json(serde_json::to_value(
             &self.text_to_speech_body,
         )?))
@@ -291,6 +561,7 @@ impl Endpoint for TextToSpeechStream {
     fn url(&self) -> Url {
         let mut url = BASE_URL.parse::<Url>().unwrap();
         url.set_path(&format!("{}/{}{}", TTS_PATH, self.voice_id.0, STREAM_PATH));
+        url.set_query(self.any_query().as_deref());
         url
     }
 }
@@ -330,9 +601,9 @@ pub struct TextToSpeechWithTimestamps {
 }
 
 impl TextToSpeechWithTimestamps {
-    pub fn new<T: Into<String>>(voice_id: T, text_to_speech_body: TextToSpeechBody) -> Self {
+    pub fn new<T: Into<VoiceID>>(voice_id: T, text_to_speech_body: TextToSpeechBody) -> Self {
         TextToSpeechWithTimestamps {
-            voice_id: VoiceID::from(voice_id.into()),
+            voice_id: voice_id.into(),
             text_to_speech_body,
             speech_query: None,
         }
@@ -357,12 +628,16 @@ impl Endpoint for TextToSpeechWithTimestamps {
         Method::POST
     }
     fn request_body(&self) -> Result<RequestBody> {
+        self.text_to_speech_body.validate()?;
         Ok(RequestBody::Json(serde_json::to_value(
             &self.text_to_speech_body,
         )?))
     }
     async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
-        Ok(resp.json().await?)
+        let request_id = request_id(&resp);
+        let mut body: Self::ResponseBody = resp.json().await?;
+        body.request_id = request_id;
+        Ok(body)
     }
     fn url(&self) -> Url {
         let mut url = BASE_URL.parse::<Url>().unwrap();
@@ -383,6 +658,8 @@ pub struct TextToSpeechWithTimestampsResponse {
     alignment: Option<Alignment>,
     audio_base64: String,
     normalized_alignment: Option<Alignment>,
+    #[serde(skip)]
+    request_id: Option<String>,
 }
 
 impl TextToSpeechWithTimestampsResponse {
@@ -399,6 +676,10 @@ impl TextToSpeechWithTimestampsResponse {
     pub fn normalized_alignment(&self) -> Option<&Alignment> {
         self.normalized_alignment.as_ref()
     }
+    /// The id to pass to [TextToSpeechBody::with_previous_request_ids]
+    pub fn request_id(&self) -> Option<&str> {
+        self.request_id.as_deref()
+    }
     pub fn iter(&self) -> Option<impl Iterator<Item = (&String, (&f32, &f32))>> {
         if let Some(alignment) = &self.alignment {
             Some(
@@ -438,6 +719,107 @@ impl Alignment {
     pub fn characters(&self) -> &[String] {
         &self.characters
     }
+    /// Iterate over each character with its start and end time in seconds
+    pub fn iter(&self) -> impl Iterator<Item = CharacterTiming<'_>> {
+        self.characters
+            .iter()
+            .zip(self.character_start_times_seconds.iter())
+            .zip(self.character_end_times_seconds.iter())
+            .map(|((character, start), end)| CharacterTiming {
+                character,
+                start_seconds: *start,
+                end_seconds: *end,
+            })
+    }
+    /// Groups the characters into whitespace separated words
+    ///
+    /// # Example
+    /// ```no_run
+    /// use elevenlabs_rs::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     let c = ElevenLabsClient::default()?;
+    ///     let body = TextToSpeechBody::new("Hello there, general Kenobi", Model::ElevenTurboV2Dot5);
+    ///     let resp = c.hit(TextToSpeechWithTimestamps::new(PreMadeVoiceID::Brian, body)).await?;
+    ///     if let Some(alignment) = resp.alignment() {
+    ///         for word in alignment.words() {
+    ///             println!("{} = {} - {}", word.word(), word.start_seconds(), word.end_seconds());
+    ///         }
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn words(&self) -> Vec<WordTiming> {
+        let mut words = Vec::new();
+        let mut current: Option<WordTiming> = None;
+
+        for timing in self.iter() {
+            if timing.character.trim().is_empty() {
+                if let Some(word) = current.take() {
+                    words.push(word);
+                }
+                continue;
+            }
+            match current.as_mut() {
+                Some(word) => {
+                    word.word.push_str(timing.character);
+                    word.end_seconds = timing.end_seconds;
+                }
+                None => {
+                    current = Some(WordTiming {
+                        word: timing.character.clone(),
+                        start_seconds: timing.start_seconds,
+                        end_seconds: timing.end_seconds,
+                    })
+                }
+            }
+        }
+        if let Some(word) = current {
+            words.push(word);
+        }
+        words
+    }
+}
+
+/// A character of an [Alignment] with its timing
+#[derive(Clone, Debug, PartialEq)]
+pub struct CharacterTiming<'a> {
+    character: &'a String,
+    start_seconds: f32,
+    end_seconds: f32,
+}
+
+impl CharacterTiming<'_> {
+    pub fn character(&self) -> &str {
+        self.character
+    }
+    pub fn start_seconds(&self) -> f32 {
+        self.start_seconds
+    }
+    pub fn end_seconds(&self) -> f32 {
+        self.end_seconds
+    }
+}
+
+/// A word of an [Alignment] with its timing
+#[derive(Clone, Debug, PartialEq)]
+pub struct WordTiming {
+    word: String,
+    start_seconds: f32,
+    end_seconds: f32,
+}
+
+impl WordTiming {
+    pub fn word(&self) -> &str {
+        &self.word
+    }
+    pub fn start_seconds(&self) -> f32 {
+        self.start_seconds
+    }
+    pub fn end_seconds(&self) -> f32 {
+        self.end_seconds
+    }
 }
 
 /// Text to Speech Stream with Timestamps endpoint
@@ -487,9 +869,9 @@ pub struct TextToSpeechStreamWithTimestamps {
 }
 
 impl TextToSpeechStreamWithTimestamps {
-    pub fn new<T: Into<String>>(voice_id: T, text_to_speech_body: TextToSpeechBody) -> Self {
+    pub fn new<T: Into<VoiceID>>(voice_id: T, text_to_speech_body: TextToSpeechBody) -> Self {
         TextToSpeechStreamWithTimestamps {
-            voice_id: VoiceID::from(voice_id.into()),
+            voice_id: voice_id.into(),
             text_to_speech_body,
             speech_query: None,
         }
@@ -517,6 +899,7 @@ impl Endpoint for TextToSpeechStreamWithTimestamps {
         Method::POST
     }
     fn request_body(&self) -> Result<RequestBody> {
+        self.text_to_speech_body.validate()?;
         Ok(RequestBody::Json(serde_json::to_value(
             &self.text_to_speech_body,
         )?))
@@ -524,20 +907,30 @@ impl Endpoint for TextToSpeechStreamWithTimestamps {
 
     async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
         let stream = resp.bytes_stream();
-        let end_of_chunk = b"\n\n";
-        let mut buf = String::new();
+        let end_of_chunk = "\n\n";
+        let mut buf: Vec<u8> = Vec::new();
         Ok(Box::pin(try_stream! {
             for await bytes_result in stream {
-                let bytes = bytes_result?;
-                if bytes.ends_with(end_of_chunk) {
-                    buf.push_str(std::str::from_utf8(&bytes)?);
-                    let json: TextToSpeechWithTimestampsResponse = serde_json::from_str(&buf)?;
+                buf.extend_from_slice(&bytes_result?);
+                // A network chunk may hold several JSON objects, or only part of one
+                while let Some(i) = buf
+                    .windows(end_of_chunk.len())
+                    .position(|w| w == end_of_chunk.as_bytes())
+                {
+                    let chunk: Vec<u8> = buf.drain(..i + end_of_chunk.len()).collect();
+                    let chunk = std::str::from_utf8(&chunk)?.trim();
+                    if chunk.is_empty() {
+                        continue;
+                    }
+                    let json: TextToSpeechWithTimestampsResponse = serde_json::from_str(chunk)?;
                     yield json;
-                    buf.clear();
-                } else {
-                    buf.push_str(std::str::from_utf8(&bytes)?);
                 }
             }
+            let rest = std::str::from_utf8(&buf)?.trim();
+            if !rest.is_empty() {
+                let json: TextToSpeechWithTimestampsResponse = serde_json::from_str(rest)?;
+                yield json;
+            }
         }))
     }
     fn url(&self) -> Url {
@@ -651,7 +1044,8 @@ pub mod ws {
     /// ```
     pub struct WebSocketTTS<S>
     where
-        S: Stream<Item = String> + Send + 'static,
+        S: Stream + Send + 'static,
+        S::Item: Into<TextChunk>,
     {
         path_params: WebSocketTTSPathParams,
         text_to_speech_body: WebSocketTTSBody<S>,
@@ -660,15 +1054,16 @@ pub mod ws {
 
     impl<S> WebSocketTTS<S>
     where
-        S: Stream<Item = String> + Send + 'static,
+        S: Stream + Send + 'static,
+        S::Item: Into<TextChunk>,
     {
         pub fn new<V, M>(voice_id: V, model_id: M, text_to_speech_body: WebSocketTTSBody<S>) -> Self
         where
-            V: Into<String>,
+            V: Into<VoiceID>,
             M: Into<String>,
         {
             let path_params = WebSocketTTSPathParams {
-                voice_id: VoiceID::from(voice_id.into()),
+                voice_id: voice_id.into(),
                 model_id: ModelID::from(model_id.into()),
             };
             WebSocketTTS {
@@ -730,7 +1125,8 @@ pub mod ws {
     #[derive(Serialize)]
     pub struct WebSocketTTSBody<S>
     where
-        S: Stream<Item = String> + Send + 'static,
+        S: Stream + Send + 'static,
+        S::Item: Into<TextChunk>,
     {
         bos_message: BOSMessage,
         #[serde(skip_serializing)]
@@ -745,8 +1141,11 @@ pub mod ws {
 
     impl<S> WebSocketTTSBody<S>
     where
-        S: Stream<Item = String> + Send + 'static,
+        S: Stream + Send + 'static,
+        S::Item: Into<TextChunk>,
     {
+        /// The text stream yields `String`s, or [TextChunk]s to flush or trigger generation
+        /// at a given message
         pub fn new(bos_message: BOSMessage, text_stream: S) -> Self {
             WebSocketTTSBody {
                 bos_message,
@@ -757,6 +1156,7 @@ pub mod ws {
                 //is_try_trigger_always: false,
             }
         }
+        /// Triggers generation after the chunks at these positions of the text stream, starting at 1
         pub fn with_try_trigger_generation(mut self, try_trigger_generation: Vec<usize>) -> Self {
             self.try_trigger_generation = Some(try_trigger_generation);
             self
@@ -765,6 +1165,8 @@ pub mod ws {
         //    self.is_try_trigger_always = true;
         //    self
         //}
+        /// Flushes the text buffered after the text stream ends, before the
+        /// [streams after flush](Self::with_streams_after_flush) are sent
         pub fn with_flush(mut self) -> Self {
             self.flush = true;
             self
@@ -789,6 +1191,8 @@ pub mod ws {
         authorization: Option<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
         generation_config: Option<GenerationConfig>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pronunciation_dictionary_locators: Option<Vec<PronunciationDictionaryLocator>>,
     }
     impl BOSMessage {
         pub fn with_api_key(mut self, api_key: &str) -> Self {
@@ -803,12 +1207,33 @@ pub mod ws {
             self.authorization = Some(format!("Bearer {}", authorisation));
             self
         }
-        pub fn with_generation_config(mut self, generation_config: [usize; 4]) -> Self {
+        pub fn with_generation_config(self, generation_config: [usize; 4]) -> Self {
+            self.with_chunk_length_schedule(generation_config)
+        }
+        /// How many characters are buffered before audio is generated, for the first chunk,
+        /// the second, and so on, the last value is used for the rest
+        ///
+        /// Each value is between 50 and 500, defaults to `[120, 160, 250, 290]`.
+        /// Lower values reduce latency, higher values improve prosody.
+        pub fn with_chunk_length_schedule<I: IntoIterator<Item = usize>>(
+            mut self,
+            chunk_length_schedule: I,
+        ) -> Self {
             self.generation_config = Some(GenerationConfig {
-                chunk_length_schedule: generation_config,
+                chunk_length_schedule: chunk_length_schedule.into_iter().collect(),
             });
             self
         }
+        /// See [TextToSpeechBody::with_pronunciation_dictionary]
+        pub fn with_pronunciation_dictionary<L: Into<PronunciationDictionaryLocator>>(
+            mut self,
+            locator: L,
+        ) -> Self {
+            self.pronunciation_dictionary_locators
+                .get_or_insert_with(Vec::new)
+                .push(locator.into());
+            self
+        }
     }
 
     impl Default for BOSMessage {
@@ -819,19 +1244,48 @@ pub mod ws {
                 voice_settings: None,
                 authorization: None,
                 generation_config: None,
+                pronunciation_dictionary_locators: None,
             }
         }
     }
 
     #[derive(Clone, Debug, Serialize)]
     struct GenerationConfig {
-        chunk_length_schedule: [usize; 4],
+        chunk_length_schedule: Vec<usize>,
     }
 
+    /// A message of the text stream
+    ///
+    /// # Example
+    /// ```no_run
+    /// use async_stream::stream;
+    /// use elevenlabs_rs::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     let text_stream = stream! {
+    ///         // Spoken as soon as possible
+    ///         yield TextChunk::from("Please hold.").with_flush();
+    ///         yield TextChunk::from("Your call is important to us, ");
+    ///         yield TextChunk::from("an agent will be with you shortly.");
+    ///     };
+    ///     let bos = BOSMessage::default().with_chunk_length_schedule([50, 120, 200]);
+    ///     let body = WebSocketTTSBody::new(bos, text_stream);
+    ///     let endpoint = WebSocketTTS::new(PreMadeVoiceID::Alice, Model::ElevenFlashV2Dot5, body);
+    ///     let c = ElevenLabsClient::default()?;
+    ///     let mut stream = c.hit_ws(endpoint).await?;
+    ///     while let Some(resp) = stream.next().await {
+    ///         let audio = resp?.audio_as_bytes()?;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
     #[derive(Clone, Debug, Serialize)]
     pub struct TextChunk {
         text: String,
         try_trigger_generation: bool,
+        #[serde(skip_serializing_if = "std::ops::Not::not")]
+        flush: bool,
     }
 
     impl TextChunk {
@@ -839,13 +1293,41 @@ pub mod ws {
             TextChunk {
                 text,
                 try_trigger_generation,
+                flush: false,
             }
         }
+        /// Generates audio for the buffered text once this chunk is received,
+        /// if there is enough text for the current chunk length
+        pub fn with_try_trigger_generation(mut self) -> Self {
+            self.try_trigger_generation = true;
+            self
+        }
+        /// Generates audio for all the buffered text, including this chunk,
+        /// e.g. at the end of a turn
+        pub fn with_flush(mut self) -> Self {
+            self.flush = true;
+            self
+        }
+        pub fn text(&self) -> &str {
+            &self.text
+        }
         pub fn json(self) -> Result<String> {
             serde_json::to_string(&self).map_err(Into::into)
         }
     }
 
+    impl From<String> for TextChunk {
+        fn from(text: String) -> Self {
+            TextChunk::new(text, false)
+        }
+    }
+
+    impl From<&str> for TextChunk {
+        fn from(text: &str) -> Self {
+            TextChunk::new(text.to_string(), false)
+        }
+    }
+
     #[derive(Clone, Debug, Serialize)]
     pub struct Flush {
         text: String,
@@ -915,4 +1397,36 @@ pub mod ws {
         char_durations_ms: Vec<f32>,
         chars: Vec<String>,
     }
+
+    impl WebSocketAlignment {
+        pub fn char_start_times_ms(&self) -> &[f32] {
+            &self.char_start_times_ms
+        }
+        pub fn char_durations_ms(&self) -> &[f32] {
+            &self.char_durations_ms
+        }
+        pub fn chars(&self) -> &[String] {
+            &self.chars
+        }
+    }
+
+    impl From<&WebSocketAlignment> for Alignment {
+        fn from(alignment: &WebSocketAlignment) -> Self {
+            let to_seconds = |ms: f32| ms / 1000.0;
+            Alignment {
+                character_start_times_seconds: alignment
+                    .char_start_times_ms
+                    .iter()
+                    .map(|start| to_seconds(*start))
+                    .collect(),
+                character_end_times_seconds: alignment
+                    .char_start_times_ms
+                    .iter()
+                    .zip(alignment.char_durations_ms.iter())
+                    .map(|(start, duration)| to_seconds(start + duration))
+                    .collect(),
+                characters: alignment.chars.clone(),
+            }
+        }
+    }
 }
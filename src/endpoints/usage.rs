@@ -0,0 +1,292 @@
+//! The usage endpoints
+//!
+//! See the [ElevenLabs docs](https://elevenlabs.io/docs/api-reference/usage/get) for more information.
+use super::*;
+use std::collections::HashMap;
+
+const CHARACTER_STATS_PATH: &str = "/v1/usage/character-stats";
+const START_UNIX_QUERY: &str = "start_unix";
+const END_UNIX_QUERY: &str = "end_unix";
+const INCLUDE_WORKSPACE_METRICS_QUERY: &str = "include_workspace_metrics";
+const BREAKDOWN_TYPE_QUERY: &str = "breakdown_type";
+const AGGREGATION_INTERVAL_QUERY: &str = "aggregation_interval";
+const METRIC_QUERY: &str = "metric";
+
+/// Get the usage of the account over time, optionally broken down e.g. per voice or API key
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let query = UsageStatsQuery::new(1_735_689_600_000, 1_738_368_000_000)
+///         .with_breakdown_type(BreakdownType::ApiKeys)
+///         .with_aggregation_interval(AggregationInterval::Day);
+///     let stats = c.hit(GetCharacterStats::new(query)).await?;
+///     for series in stats.series() {
+///         println!("{}: {}", series.name(), series.total());
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct GetCharacterStats(UsageStatsQuery);
+
+impl GetCharacterStats {
+    pub fn new(query: UsageStatsQuery) -> Self {
+        GetCharacterStats(query)
+    }
+}
+
+impl Endpoint for GetCharacterStats {
+    type ResponseBody = UsageStatsResponse;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        let mut url = BASE_URL.parse::<Url>().unwrap();
+        url.set_path(CHARACTER_STATS_PATH);
+        self.0.append_to(&mut url);
+        url
+    }
+}
+
+/// Usage stats query
+#[derive(Clone, Debug)]
+pub struct UsageStatsQuery {
+    start_unix: i64,
+    end_unix: i64,
+    include_workspace_metrics: Option<bool>,
+    breakdown_type: Option<BreakdownType>,
+    aggregation_interval: Option<AggregationInterval>,
+    metric: Option<UsageMetric>,
+}
+
+impl UsageStatsQuery {
+    /// The start and end of the period, both as unix timestamps in milliseconds
+    pub fn new(start_unix: i64, end_unix: i64) -> Self {
+        UsageStatsQuery {
+            start_unix,
+            end_unix,
+            include_workspace_metrics: None,
+            breakdown_type: None,
+            aggregation_interval: None,
+            metric: None,
+        }
+    }
+    /// Include the usage of the whole workspace instead of only the user's
+    pub fn with_include_workspace_metrics(mut self, include_workspace_metrics: bool) -> Self {
+        self.include_workspace_metrics = Some(include_workspace_metrics);
+        self
+    }
+    pub fn with_breakdown_type(mut self, breakdown_type: BreakdownType) -> Self {
+        self.breakdown_type = Some(breakdown_type);
+        self
+    }
+    pub fn with_aggregation_interval(mut self, aggregation_interval: AggregationInterval) -> Self {
+        self.aggregation_interval = Some(aggregation_interval);
+        self
+    }
+    /// Defaults to credits
+    pub fn with_metric(mut self, metric: UsageMetric) -> Self {
+        self.metric = Some(metric);
+        self
+    }
+
+    fn append_to(&self, url: &mut Url) {
+        let mut pairs = url.query_pairs_mut();
+        pairs.append_pair(START_UNIX_QUERY, &self.start_unix.to_string());
+        pairs.append_pair(END_UNIX_QUERY, &self.end_unix.to_string());
+        if let Some(include_workspace_metrics) = self.include_workspace_metrics {
+            pairs.append_pair(
+                INCLUDE_WORKSPACE_METRICS_QUERY,
+                &include_workspace_metrics.to_string(),
+            );
+        }
+        if let Some(breakdown_type) = &self.breakdown_type {
+            pairs.append_pair(BREAKDOWN_TYPE_QUERY, breakdown_type.as_str());
+        }
+        if let Some(aggregation_interval) = &self.aggregation_interval {
+            pairs.append_pair(AGGREGATION_INTERVAL_QUERY, aggregation_interval.as_str());
+        }
+        if let Some(metric) = &self.metric {
+            pairs.append_pair(METRIC_QUERY, metric.as_str());
+        }
+    }
+}
+
+/// How the usage is split into [series](UsageStatsResponse::series)
+#[derive(Clone, Debug)]
+pub enum BreakdownType {
+    /// A single series named `All`
+    None,
+    Voice,
+    VoiceMultiplier,
+    User,
+    Groups,
+    ApiKeys,
+    AllApiKeys,
+    ProductType,
+    Model,
+    Resource,
+    RequestQueue,
+    Region,
+}
+
+impl BreakdownType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            BreakdownType::None => "none",
+            BreakdownType::Voice => "voice",
+            BreakdownType::VoiceMultiplier => "voice_multiplier",
+            BreakdownType::User => "user",
+            BreakdownType::Groups => "groups",
+            BreakdownType::ApiKeys => "api_keys",
+            BreakdownType::AllApiKeys => "all_api_keys",
+            BreakdownType::ProductType => "product_type",
+            BreakdownType::Model => "model",
+            BreakdownType::Resource => "resource",
+            BreakdownType::RequestQueue => "request_queue",
+            BreakdownType::Region => "region",
+        }
+    }
+}
+
+/// The length of the time buckets
+#[derive(Clone, Debug)]
+pub enum AggregationInterval {
+    Hour,
+    Day,
+    Week,
+    Month,
+    /// A single bucket for the whole period
+    Cumulative,
+}
+
+impl AggregationInterval {
+    pub fn as_str(&self) -> &str {
+        match self {
+            AggregationInterval::Hour => "hour",
+            AggregationInterval::Day => "day",
+            AggregationInterval::Week => "week",
+            AggregationInterval::Month => "month",
+            AggregationInterval::Cumulative => "cumulative",
+        }
+    }
+}
+
+/// What is measured
+#[derive(Clone, Debug)]
+pub enum UsageMetric {
+    Credits,
+    TtsCharacters,
+    MinutesUsed,
+    RequestCount,
+    FiatUnitsSpent,
+    Concurrency,
+    ConcurrencyAverage,
+}
+
+impl UsageMetric {
+    pub fn as_str(&self) -> &str {
+        match self {
+            UsageMetric::Credits => "credits",
+            UsageMetric::TtsCharacters => "tts_characters",
+            UsageMetric::MinutesUsed => "minutes_used",
+            UsageMetric::RequestCount => "request_count",
+            UsageMetric::FiatUnitsSpent => "fiat_units_spent",
+            UsageMetric::Concurrency => "concurrency",
+            UsageMetric::ConcurrencyAverage => "concurrency_average",
+        }
+    }
+}
+
+/// Usage stats response
+#[derive(Clone, Debug, Deserialize)]
+pub struct UsageStatsResponse {
+    time: Vec<i64>,
+    usage: HashMap<String, Vec<f64>>,
+}
+
+impl UsageStatsResponse {
+    /// The start of each bucket, as unix timestamps in milliseconds
+    pub fn time(&self) -> &[i64] {
+        &self.time
+    }
+    /// The raw values of each series, in the same order as [time](Self::time)
+    pub fn usage(&self) -> &HashMap<String, Vec<f64>> {
+        &self.usage
+    }
+    /// The values of one series, e.g. a voice id when broken down by voice
+    pub fn series_by_name(&self, name: &str) -> Option<UsageSeries> {
+        let values = self.usage.get(name)?;
+        Some(UsageSeries::new(name, &self.time, values))
+    }
+    /// Every series, sorted by name
+    pub fn series(&self) -> Vec<UsageSeries> {
+        let mut series = self
+            .usage
+            .iter()
+            .map(|(name, values)| UsageSeries::new(name, &self.time, values))
+            .collect::<Vec<_>>();
+        series.sort_by(|a, b| a.name.cmp(&b.name));
+        series
+    }
+}
+
+/// The usage of one breakdown key over time
+#[derive(Clone, Debug, PartialEq)]
+pub struct UsageSeries {
+    name: String,
+    points: Vec<UsagePoint>,
+}
+
+impl UsageSeries {
+    fn new(name: &str, time: &[i64], values: &[f64]) -> Self {
+        UsageSeries {
+            name: name.to_string(),
+            points: time
+                .iter()
+                .zip(values)
+                .map(|(&time_unix, &value)| UsagePoint { time_unix, value })
+                .collect(),
+        }
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn points(&self) -> &[UsagePoint] {
+        &self.points
+    }
+    pub fn total(&self) -> f64 {
+        self.points.iter().map(|p| p.value).sum()
+    }
+}
+
+/// The usage within one time bucket
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UsagePoint {
+    time_unix: i64,
+    value: f64,
+}
+
+impl UsagePoint {
+    /// The start of the bucket, as a unix timestamp in milliseconds
+    pub fn time_unix(&self) -> i64 {
+        self.time_unix
+    }
+    /// [time_unix](Self::time_unix) as a date
+    #[cfg(feature = "chrono")]
+    pub fn time(&self) -> DateTime<Utc> {
+        DateTime::from_timestamp_millis(self.time_unix).unwrap_or_default()
+    }
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
@@ -60,6 +60,40 @@ pub struct Subscription {
     has_open_invoices: Option<bool>,
 }
 
+impl Subscription {
+    pub fn tier(&self) -> &str {
+        &self.tier
+    }
+    /// Characters used in the current period
+    pub fn character_count(&self) -> i64 {
+        self.character_count
+    }
+    pub fn character_limit(&self) -> i64 {
+        self.character_limit
+    }
+    /// Characters left in the current period
+    pub fn remaining_characters(&self) -> i64 {
+        (self.character_limit - self.character_count).max(0)
+    }
+    pub fn can_extend_character_limit(&self) -> bool {
+        self.can_extend_character_limit
+    }
+    pub fn next_character_count_reset_unix(&self) -> i64 {
+        self.next_character_count_reset_unix
+    }
+    /// [next_character_count_reset_unix](Self::next_character_count_reset_unix) as a date
+    #[cfg(feature = "chrono")]
+    pub fn next_character_count_reset(&self) -> DateTime<Utc> {
+        utc(self.next_character_count_reset_unix)
+    }
+    pub fn voice_limit(&self) -> i64 {
+        self.voice_limit
+    }
+    pub fn status(&self) -> &str {
+        &self.status
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct NextInvoice {
     amount_due_cents: i64,
@@ -4,15 +4,25 @@
 //! See the [ElevenLabs docs](https://elevenlabs.io/docs/api-reference/get-voices) for more information.
 //!
 use super::*;
-use crate::error::Error;
+use crate::error::{Error, VoiceSettingsError};
 use std::collections::HashMap;
-use std::path::Path;
 
 const EDIT_VOICE_PATH: &str = "/edit";
 const EDIT_VOICE_SETTINGS_PATH: &str = "/settings/edit";
 const DEFAULT_SETTINGS_PATH: &str = "/v1/voices/settings/default";
 const VOICE_SETTINGS_PATH: &str = "/settings";
 const WITH_SETTINGS_QUERY: &str = "with_settings=true";
+const VOICES_V2_PATH: &str = "/v2/voices";
+const SEARCH_QUERY: &str = "search";
+const CATEGORY_QUERY: &str = "category";
+const VOICE_TYPE_QUERY: &str = "voice_type";
+const SORT_QUERY: &str = "sort";
+const SORT_DIRECTION_QUERY: &str = "sort_direction";
+const PAGE_SIZE_QUERY: &str = "page_size";
+const NEXT_PAGE_TOKEN_QUERY: &str = "next_page_token";
+const INCLUDE_TOTAL_COUNT_QUERY: &str = "include_total_count";
+const MIN_SPEED: f32 = 0.7;
+const MAX_SPEED: f32 = 1.2;
 
 /// Get all voices endpoint
 ///
@@ -47,6 +57,374 @@ impl Endpoint for GetVoices {
     }
 }
 
+/// Search the voices of the account, a page at a time
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let query = VoicesV2Query::default()
+///         .with_search("narrator")
+///         .with_category(VoiceCategory::Professional)
+///         .with_sort(VoiceSort::Name)
+///         .with_page_size(50);
+///     let voices = c.paginate(GetVoicesV2::new(query));
+///     pin_mut!(voices);
+///     while let Some(voice) = voices.next().await {
+///         let voice = voice?;
+///         println!("{}: {}", voice.voice_id(), voice.name());
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct GetVoicesV2(VoicesV2Query);
+
+impl GetVoicesV2 {
+    pub fn new(query: VoicesV2Query) -> Self {
+        GetVoicesV2(query)
+    }
+}
+
+impl Endpoint for GetVoicesV2 {
+    type ResponseBody = VoicesV2Response;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        let mut url = BASE_URL.parse::<Url>().unwrap();
+        url.set_path(VOICES_V2_PATH);
+        self.0.append_to(&mut url);
+        if url.query() == Some("") {
+            url.set_query(None);
+        }
+        url
+    }
+}
+
+impl Paginated for GetVoicesV2 {
+    type Item = VoiceV2;
+
+    fn next_page(self, page: Self::ResponseBody) -> (Vec<Self::Item>, Option<Self>) {
+        let next = match (page.has_more, page.next_page_token) {
+            (true, Some(token)) => Some(GetVoicesV2(self.0.with_next_page_token(&token))),
+            _ => None,
+        };
+        (page.voices, next)
+    }
+}
+
+/// Voices v2 query
+#[derive(Clone, Debug, Default)]
+pub struct VoicesV2Query {
+    search: Option<String>,
+    category: Option<VoiceCategory>,
+    voice_type: Option<VoiceType>,
+    sort: Option<VoiceSort>,
+    sort_direction: Option<SortDirection>,
+    page_size: Option<u32>,
+    next_page_token: Option<String>,
+    include_total_count: Option<bool>,
+}
+
+impl VoicesV2Query {
+    /// Matches the name, description, labels and category of a voice
+    pub fn with_search(mut self, search: &str) -> Self {
+        self.search = Some(search.to_string());
+        self
+    }
+    pub fn with_category(mut self, category: VoiceCategory) -> Self {
+        self.category = Some(category);
+        self
+    }
+    pub fn with_voice_type(mut self, voice_type: VoiceType) -> Self {
+        self.voice_type = Some(voice_type);
+        self
+    }
+    pub fn with_sort(mut self, sort: VoiceSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+    pub fn with_sort_direction(mut self, sort_direction: SortDirection) -> Self {
+        self.sort_direction = Some(sort_direction);
+        self
+    }
+    /// At most 100, defaults to 10
+    pub fn with_page_size(mut self, page_size: u32) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+    pub fn with_next_page_token(mut self, next_page_token: &str) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+    /// Counting is slower, leave it off when paging through all voices
+    pub fn with_include_total_count(mut self, include_total_count: bool) -> Self {
+        self.include_total_count = Some(include_total_count);
+        self
+    }
+
+    fn append_to(&self, url: &mut Url) {
+        let mut pairs = url.query_pairs_mut();
+        if let Some(search) = &self.search {
+            pairs.append_pair(SEARCH_QUERY, search);
+        }
+        if let Some(category) = &self.category {
+            pairs.append_pair(CATEGORY_QUERY, category.as_str());
+        }
+        if let Some(voice_type) = &self.voice_type {
+            pairs.append_pair(VOICE_TYPE_QUERY, voice_type.as_str());
+        }
+        if let Some(sort) = &self.sort {
+            pairs.append_pair(SORT_QUERY, sort.as_str());
+        }
+        if let Some(sort_direction) = &self.sort_direction {
+            pairs.append_pair(SORT_DIRECTION_QUERY, sort_direction.as_str());
+        }
+        if let Some(page_size) = self.page_size {
+            pairs.append_pair(PAGE_SIZE_QUERY, &page_size.to_string());
+        }
+        if let Some(next_page_token) = &self.next_page_token {
+            pairs.append_pair(NEXT_PAGE_TOKEN_QUERY, next_page_token);
+        }
+        if let Some(include_total_count) = self.include_total_count {
+            pairs.append_pair(INCLUDE_TOTAL_COUNT_QUERY, &include_total_count.to_string());
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum VoiceCategory {
+    Premade,
+    Cloned,
+    Generated,
+    Professional,
+    Famous,
+    HighQuality,
+    #[serde(other)]
+    Unknown,
+}
+
+impl VoiceCategory {
+    pub fn as_str(&self) -> &str {
+        match self {
+            VoiceCategory::Premade => "premade",
+            VoiceCategory::Cloned => "cloned",
+            VoiceCategory::Generated => "generated",
+            VoiceCategory::Professional => "professional",
+            VoiceCategory::Famous => "famous",
+            VoiceCategory::HighQuality => "high_quality",
+            VoiceCategory::Unknown => "unknown",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum VoiceType {
+    Personal,
+    Community,
+    Default,
+    Workspace,
+    NonDefault,
+}
+
+impl VoiceType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            VoiceType::Personal => "personal",
+            VoiceType::Community => "community",
+            VoiceType::Default => "default",
+            VoiceType::Workspace => "workspace",
+            VoiceType::NonDefault => "non-default",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum VoiceSort {
+    CreatedAtUnix,
+    Name,
+}
+
+impl VoiceSort {
+    pub fn as_str(&self) -> &str {
+        match self {
+            VoiceSort::CreatedAtUnix => "created_at_unix",
+            VoiceSort::Name => "name",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum SortDirection {
+    Asc,
+    Desc,
+}
+
+impl SortDirection {
+    pub fn as_str(&self) -> &str {
+        match self {
+            SortDirection::Asc => "asc",
+            SortDirection::Desc => "desc",
+        }
+    }
+}
+
+/// Voices v2 response
+#[derive(Clone, Debug, Deserialize)]
+pub struct VoicesV2Response {
+    voices: Vec<VoiceV2>,
+    has_more: bool,
+    total_count: Option<u32>,
+    next_page_token: Option<String>,
+}
+
+impl VoicesV2Response {
+    pub fn voices(&self) -> &[VoiceV2] {
+        &self.voices
+    }
+    pub fn has_more(&self) -> bool {
+        self.has_more
+    }
+    /// Only counted if [requested](VoicesV2Query::with_include_total_count)
+    pub fn total_count(&self) -> Option<u32> {
+        self.total_count
+    }
+    pub fn next_page_token(&self) -> Option<&str> {
+        self.next_page_token.as_deref()
+    }
+}
+
+/// A voice as returned by [GetVoicesV2]
+#[derive(Clone, Debug, Deserialize)]
+pub struct VoiceV2 {
+    voice_id: String,
+    name: String,
+    category: Option<VoiceCategory>,
+    description: Option<String>,
+    #[serde(default)]
+    labels: HashMap<String, String>,
+    preview_url: Option<String>,
+    samples: Option<Vec<VoiceSample>>,
+    settings: Option<VoiceSettings>,
+    #[serde(default)]
+    available_for_tiers: Vec<String>,
+    #[serde(default)]
+    high_quality_base_model_ids: Vec<String>,
+    #[serde(default)]
+    verified_languages: Vec<VerifiedLanguage>,
+    fine_tuning: Option<Value>,
+    sharing: Option<Value>,
+    is_owner: Option<bool>,
+    is_legacy: Option<bool>,
+    is_mixed: Option<bool>,
+    created_at_unix: Option<i64>,
+    #[serde(flatten, deserialize_with = "crate::shared::extras::deserialize")]
+    extra: Map<String, Value>,
+}
+
+impl VoiceV2 {
+    pub fn voice_id(&self) -> &str {
+        &self.voice_id
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn category(&self) -> Option<&VoiceCategory> {
+        self.category.as_ref()
+    }
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+    pub fn labels(&self) -> &HashMap<String, String> {
+        &self.labels
+    }
+    pub fn preview_url(&self) -> Option<&str> {
+        self.preview_url.as_deref()
+    }
+    pub fn samples(&self) -> Option<&[VoiceSample]> {
+        self.samples.as_deref()
+    }
+    pub fn settings(&self) -> Option<&VoiceSettings> {
+        self.settings.as_ref()
+    }
+    pub fn available_for_tiers(&self) -> &[String] {
+        &self.available_for_tiers
+    }
+    /// The models the voice sounds best with
+    pub fn high_quality_base_model_ids(&self) -> &[String] {
+        &self.high_quality_base_model_ids
+    }
+    pub fn verified_languages(&self) -> &[VerifiedLanguage] {
+        &self.verified_languages
+    }
+    /// The training state of cloned voices
+    pub fn fine_tuning(&self) -> Option<&Value> {
+        self.fine_tuning.as_ref()
+    }
+    /// How the voice is shared in the voice library
+    pub fn sharing(&self) -> Option<&Value> {
+        self.sharing.as_ref()
+    }
+    pub fn is_owner(&self) -> Option<bool> {
+        self.is_owner
+    }
+    pub fn is_legacy(&self) -> Option<bool> {
+        self.is_legacy
+    }
+    pub fn is_mixed(&self) -> Option<bool> {
+        self.is_mixed
+    }
+    pub fn created_at_unix(&self) -> Option<i64> {
+        self.created_at_unix
+    }
+    /// [created_at_unix](Self::created_at_unix) as a date
+    #[cfg(feature = "chrono")]
+    pub fn created_at(&self) -> Option<DateTime<Utc>> {
+        self.created_at_unix().map(utc)
+    }
+    /// Fields of the response this version of the crate does not know about yet
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+}
+
+/// A language a voice was verified to speak
+#[derive(Clone, Debug, Deserialize)]
+pub struct VerifiedLanguage {
+    language: String,
+    model_id: String,
+    accent: Option<String>,
+    locale: Option<String>,
+    preview_url: Option<String>,
+}
+
+impl VerifiedLanguage {
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+    pub fn model_id(&self) -> &str {
+        &self.model_id
+    }
+    pub fn accent(&self) -> Option<&str> {
+        self.accent.as_deref()
+    }
+    pub fn locale(&self) -> Option<&str> {
+        self.locale.as_deref()
+    }
+    pub fn preview_url(&self) -> Option<&str> {
+        self.preview_url.as_deref()
+    }
+}
+
 /// Hits [GetVoices] endpoint then finds the voices by name given
 #[derive(Clone, Debug)]
 pub struct GetVoiceIDByName(String);
@@ -92,6 +470,7 @@ impl Endpoint for GetVoiceIDByName {
 ///    Ok(())
 /// }
 /// ```
+#[doc(alias = "GetDefaultVoiceSettings")]
 #[derive(Clone, Debug)]
 pub struct GetDefaultSettings;
 
@@ -129,8 +508,8 @@ impl Endpoint for GetDefaultSettings {
 pub struct GetVoiceSettings(VoiceID);
 
 impl GetVoiceSettings {
-    pub fn new<T: Into<String>>(voice_id: T) -> Self {
-        GetVoiceSettings(VoiceID::from(voice_id.into()))
+    pub fn new<T: Into<VoiceID>>(voice_id: T) -> Self {
+        GetVoiceSettings(voice_id.into())
     }
 }
 
@@ -171,8 +550,8 @@ impl Endpoint for GetVoiceSettings {
 pub struct GetVoice(VoiceID);
 
 impl GetVoice {
-    pub fn new<T: Into<String>>(voice_id: T) -> Self {
-        GetVoice(VoiceID::from(voice_id.into()))
+    pub fn new<T: Into<VoiceID>>(voice_id: T) -> Self {
+        GetVoice(voice_id.into())
     }
 }
 
@@ -198,8 +577,8 @@ impl Endpoint for GetVoice {
 pub struct GetVoiceWithSettings(VoiceID);
 
 impl GetVoiceWithSettings {
-    pub fn new<T: Into<String>>(voice_id: T) -> Self {
-        GetVoiceWithSettings(VoiceID::from(voice_id.into()))
+    pub fn new<T: Into<VoiceID>>(voice_id: T) -> Self {
+        GetVoiceWithSettings(voice_id.into())
     }
 }
 
@@ -225,8 +604,8 @@ impl Endpoint for GetVoiceWithSettings {
 pub struct DeleteVoice(VoiceID);
 
 impl DeleteVoice {
-    pub fn new<T: Into<String>>(voice_id: T) -> Self {
-        DeleteVoice(VoiceID::from(voice_id.into()))
+    pub fn new<T: Into<VoiceID>>(voice_id: T) -> Self {
+        DeleteVoice(voice_id.into())
     }
 }
 
@@ -247,6 +626,8 @@ impl Endpoint for DeleteVoice {
 }
 
 /// Edit voice settings endpoint
+///
+/// The settings are stored with the voice and used by every request not overriding them.
 /// # Example
 /// ```no_run
 /// use elevenlabs_rs::*;
@@ -260,8 +641,14 @@ impl Endpoint for DeleteVoice {
 ///   let endpoint = EditVoiceSettings::new("some_voice_id", body);
 ///   let resp = c.hit(endpoint).await?;
 ///   println!("{:#?}", resp);
+///
+///   // Or tune the stored settings
+///   let settings = c.hit(GetVoiceSettings::new("some_voice_id")).await?;
+///   let body = EditVoiceSettingsBody::from(settings).with_stability(0.3);
+///   c.hit(EditVoiceSettings::new("some_voice_id", body)).await?;
 ///   Ok(())
 /// }
+/// ```
 #[derive(Clone, Debug)]
 pub struct EditVoiceSettings {
     voice_id: VoiceID,
@@ -269,9 +656,9 @@ pub struct EditVoiceSettings {
 }
 
 impl EditVoiceSettings {
-    pub fn new<T: Into<String>>(voice_id: T, body: EditVoiceSettingsBody) -> Self {
+    pub fn new<T: Into<VoiceID>>(voice_id: T, body: EditVoiceSettingsBody) -> Self {
         EditVoiceSettings {
-            voice_id: VoiceID::from(voice_id.into()),
+            voice_id: voice_id.into(),
             body,
         }
     }
@@ -284,6 +671,7 @@ impl Endpoint for EditVoiceSettings {
         Method::POST
     }
     fn request_body(&self) -> Result<RequestBody> {
+        self.body.to_voice_settings().validate()?;
         Ok(RequestBody::Json(serde_json::to_value(&self.body)?))
     }
     async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
@@ -308,6 +696,8 @@ pub struct EditVoiceSettingsBody {
     style: Option<f32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     use_speaker_boost: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    speed: Option<f32>,
 }
 
 impl EditVoiceSettingsBody {
@@ -317,6 +707,29 @@ impl EditVoiceSettingsBody {
             stability,
             style: None,
             use_speaker_boost: None,
+            speed: None,
+        }
+    }
+    pub fn with_similarity_boost(mut self, similarity_boost: f32) -> Self {
+        self.similarity_boost = similarity_boost;
+        self
+    }
+    pub fn with_stability(mut self, stability: f32) -> Self {
+        self.stability = stability;
+        self
+    }
+    /// Between 0.7 and 1.2
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = Some(speed);
+        self
+    }
+    fn to_voice_settings(&self) -> VoiceSettings {
+        VoiceSettings {
+            similarity_boost: self.similarity_boost,
+            stability: self.stability,
+            style: self.style,
+            use_speaker_boost: self.use_speaker_boost,
+            speed: self.speed,
         }
     }
     pub fn with_style(mut self, style: f32) -> Self {
@@ -329,6 +742,18 @@ impl EditVoiceSettingsBody {
     }
 }
 
+impl From<VoiceSettings> for EditVoiceSettingsBody {
+    fn from(settings: VoiceSettings) -> Self {
+        Self {
+            similarity_boost: settings.similarity_boost,
+            stability: settings.stability,
+            style: settings.style,
+            use_speaker_boost: settings.use_speaker_boost,
+            speed: settings.speed,
+        }
+    }
+}
+
 /// Add a voice endpoint
 /// # Example
 /// ```no_run
@@ -367,7 +792,7 @@ impl Endpoint for AddVoice {
     fn request_body(&self) -> Result<RequestBody> {
         Ok(RequestBody::Multipart(to_multipart(
             self.0.name.clone(),
-            Some(self.0.files.clone()),
+            Some(&self.0.files),
             self.0.description.clone(),
             self.0.labels.clone(),
         )?))
@@ -387,16 +812,17 @@ impl Endpoint for AddVoice {
 #[derive(Clone, Debug)]
 pub struct AddVoiceBody {
     name: String,
-    files: Vec<String>,
+    files: Vec<FileSource>,
     description: Option<String>,
     labels: Option<Vec<(String, String)>>,
 }
 
 impl AddVoiceBody {
-    pub fn new(name: &str, files: Vec<String>) -> Self {
+    /// Each sample can be a path, which is streamed from disk, or any other [FileSource]
+    pub fn new<F: Into<FileSource>>(name: &str, files: Vec<F>) -> Self {
         Self {
             name: name.to_string(),
-            files,
+            files: files.into_iter().map(Into::into).collect(),
             description: None,
             labels: None,
         }
@@ -424,6 +850,25 @@ impl AddVoiceResponse {
 }
 
 /// Edit a voice endpoint
+///
+/// Files are added to the samples the voice already has, so a cloned voice can be improved
+/// without recreating it. Remove samples with [DeleteSample](crate::endpoints::samples::DeleteSample).
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let voice = c.hit(GetVoice::new("some_voice_id")).await?;
+///     let body = EditVoiceBody::new(voice.get_name()).with_files(vec!["more_speech.mp3"]);
+///     let resp = c.hit(EditVoice::new("some_voice_id", body)).await?;
+///     println!("{:#?}", resp);
+///     Ok(())
+/// }
+/// ```
+#[doc(alias = "AddSamples")]
 #[derive(Clone, Debug)]
 pub struct EditVoice {
     voice_id: VoiceID,
@@ -431,9 +876,9 @@ pub struct EditVoice {
 }
 
 impl EditVoice {
-    pub fn new<T: Into<String>>(voice_id: T, body: EditVoiceBody) -> Self {
+    pub fn new<T: Into<VoiceID>>(voice_id: T, body: EditVoiceBody) -> Self {
         EditVoice {
-            voice_id: VoiceID::from(voice_id.into()),
+            voice_id: voice_id.into(),
             body,
         }
     }
@@ -448,7 +893,7 @@ impl Endpoint for EditVoice {
     fn request_body(&self) -> Result<RequestBody> {
         Ok(RequestBody::Multipart(to_multipart(
             self.body.name.clone(),
-            self.body.files.clone(),
+            self.body.files.as_deref(),
             self.body.description.clone(),
             self.body.labels.clone(),
         )?))
@@ -471,7 +916,7 @@ impl Endpoint for EditVoice {
 #[derive(Clone, Debug)]
 pub struct EditVoiceBody {
     name: String,
-    files: Option<Vec<String>>,
+    files: Option<Vec<FileSource>>,
     description: Option<String>,
     labels: Option<Vec<(String, String)>>,
 }
@@ -485,8 +930,8 @@ impl EditVoiceBody {
             labels: None,
         }
     }
-    pub fn with_files(mut self, files: Vec<String>) -> Self {
-        self.files = Some(files);
+    pub fn with_files<F: Into<FileSource>>(mut self, files: Vec<F>) -> Self {
+        self.files = Some(files.into_iter().map(Into::into).collect());
         self
     }
     pub fn with_description(mut self, description: &str) -> Self {
@@ -523,6 +968,9 @@ pub struct VoiceResponseBody {
     description: Option<String>,
     preview_url: Option<String>,
     settings: Option<VoiceSettings>,
+    fine_tuning: Option<FineTuning>,
+    #[serde(flatten, deserialize_with = "crate::shared::extras::deserialize")]
+    extra: Map<String, Value>,
 }
 
 /// Voice sample
@@ -554,6 +1002,9 @@ impl VoiceSample {
 }
 
 /// Voice settings
+///
+/// Use the [builder](VoiceSettings::builder) to check the settings are in range
+/// when they are built, settings are otherwise checked before a request is sent.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 pub struct VoiceSettings {
     similarity_boost: f32,
@@ -562,6 +1013,8 @@ pub struct VoiceSettings {
     style: Option<f32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     use_speaker_boost: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    speed: Option<f32>,
 }
 
 impl VoiceSettings {
@@ -571,8 +1024,24 @@ impl VoiceSettings {
             stability,
             style: None,
             use_speaker_boost: None,
+            speed: None,
         }
     }
+    /// # Example
+    /// ```
+    /// use elevenlabs_rs::*;
+    ///
+    /// let settings = VoiceSettings::builder()
+    ///     .with_stability(0.4)
+    ///     .with_similarity_boost(0.8)
+    ///     .with_speed(1.1)
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(VoiceSettings::builder().with_speed(2.0).build().is_err());
+    /// ```
+    pub fn builder() -> VoiceSettingsBuilder {
+        VoiceSettingsBuilder::default()
+    }
     pub fn with_style(mut self, style: f32) -> Self {
         self.style = Some(style);
         self
@@ -581,6 +1050,11 @@ impl VoiceSettings {
         self.use_speaker_boost = Some(use_speaker_boost);
         self
     }
+    /// Between 0.7 and 1.2, 1.0 being the default speed
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = Some(speed);
+        self
+    }
 
     pub fn similarity_boost(&self) -> f32 {
         self.similarity_boost
@@ -597,6 +1071,23 @@ impl VoiceSettings {
     pub fn use_speaker_boost(&self) -> Option<bool> {
         self.use_speaker_boost
     }
+
+    pub fn speed(&self) -> Option<f32> {
+        self.speed
+    }
+
+    /// Checks that the settings are within the ranges the API accepts
+    pub fn validate(&self) -> std::result::Result<(), VoiceSettingsError> {
+        check_range("stability", self.stability, 0.0, 1.0)?;
+        check_range("similarity_boost", self.similarity_boost, 0.0, 1.0)?;
+        if let Some(style) = self.style {
+            check_range("style", style, 0.0, 1.0)?;
+        }
+        if let Some(speed) = self.speed {
+            check_range("speed", speed, MIN_SPEED, MAX_SPEED)?;
+        }
+        Ok(())
+    }
 }
 
 impl Default for VoiceSettings {
@@ -606,10 +1097,78 @@ impl Default for VoiceSettings {
             stability: 0.5,
             style: Some(0.5),
             use_speaker_boost: Some(true),
+            speed: None,
         }
     }
 }
 
+/// Builds [VoiceSettings], unset stability and similarity boost are the API's defaults
+#[derive(Clone, Debug, Default)]
+pub struct VoiceSettingsBuilder {
+    stability: Option<f32>,
+    similarity_boost: Option<f32>,
+    style: Option<f32>,
+    use_speaker_boost: Option<bool>,
+    speed: Option<f32>,
+}
+
+impl VoiceSettingsBuilder {
+    /// Between 0 and 1, lower is more expressive
+    pub fn with_stability(mut self, stability: f32) -> Self {
+        self.stability = Some(stability);
+        self
+    }
+    /// Between 0 and 1, higher is closer to the original voice
+    pub fn with_similarity_boost(mut self, similarity_boost: f32) -> Self {
+        self.similarity_boost = Some(similarity_boost);
+        self
+    }
+    /// Between 0 and 1, exaggerates the style of the original voice
+    pub fn with_style(mut self, style: f32) -> Self {
+        self.style = Some(style);
+        self
+    }
+    pub fn with_use_speaker_boost(mut self, use_speaker_boost: bool) -> Self {
+        self.use_speaker_boost = Some(use_speaker_boost);
+        self
+    }
+    /// Between 0.7 and 1.2
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = Some(speed);
+        self
+    }
+    pub fn build(self) -> std::result::Result<VoiceSettings, VoiceSettingsError> {
+        let defaults = VoiceSettings::default();
+        let settings = VoiceSettings {
+            stability: self.stability.unwrap_or(defaults.stability),
+            similarity_boost: self.similarity_boost.unwrap_or(defaults.similarity_boost),
+            style: self.style,
+            use_speaker_boost: self.use_speaker_boost,
+            speed: self.speed,
+        };
+        settings.validate()?;
+        Ok(settings)
+    }
+}
+
+fn check_range(
+    setting: &'static str,
+    value: f32,
+    min: f32,
+    max: f32,
+) -> std::result::Result<(), VoiceSettingsError> {
+    if (min..=max).contains(&value) {
+        Ok(())
+    } else {
+        Err(VoiceSettingsError::OutOfRange {
+            setting,
+            value,
+            min,
+            max,
+        })
+    }
+}
+
 impl VoiceResponseBody {
     pub fn get_voice_id(&self) -> &String {
         &self.voice_id
@@ -635,31 +1194,86 @@ impl VoiceResponseBody {
     pub fn get_settings(&self) -> Option<&VoiceSettings> {
         self.settings.as_ref()
     }
+    /// Only for voices that can be fine tuned, i.e. professional voice clones
+    pub fn get_fine_tuning(&self) -> Option<&FineTuning> {
+        self.fine_tuning.as_ref()
+    }
+    /// Fields of the response this version of the crate does not know about yet
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+}
+
+/// The fine tuning of a voice, per model it is trained for
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct FineTuning {
+    #[serde(default)]
+    state: HashMap<String, FineTuningState>,
+    #[serde(default)]
+    progress: HashMap<String, f32>,
+    #[serde(default)]
+    message: HashMap<String, String>,
+    is_allowed_to_fine_tune: Option<bool>,
+    #[serde(default)]
+    verification_failures: Vec<String>,
+    verification_attempts_count: Option<u32>,
+    manual_verification_requested: Option<bool>,
+}
+
+impl FineTuning {
+    /// The state per model id
+    pub fn state(&self) -> &HashMap<String, FineTuningState> {
+        &self.state
+    }
+    /// Between 0 and 1, per model id
+    pub fn progress(&self) -> &HashMap<String, f32> {
+        &self.progress
+    }
+    /// A status or error message, per model id
+    pub fn message(&self) -> &HashMap<String, String> {
+        &self.message
+    }
+    pub fn is_allowed_to_fine_tune(&self) -> Option<bool> {
+        self.is_allowed_to_fine_tune
+    }
+    pub fn verification_failures(&self) -> &[String] {
+        &self.verification_failures
+    }
+    pub fn verification_attempts_count(&self) -> Option<u32> {
+        self.verification_attempts_count
+    }
+    pub fn manual_verification_requested(&self) -> Option<bool> {
+        self.manual_verification_requested
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FineTuningState {
+    NotStarted,
+    Queued,
+    FineTuning,
+    FineTuned,
+    Failed,
+    Delayed,
+    /// A state this version of the crate does not know about
+    #[serde(other)]
+    Unknown,
 }
 
-fn to_multipart<P: AsRef<Path>>(
+fn to_multipart(
     voice_name: String,
-    file_paths: Option<Vec<P>>,
+    files: Option<&[FileSource]>,
     description: Option<String>,
     labels: Option<Vec<(String, String)>>,
 ) -> Result<Form> {
     let mut form = Form::new();
     form = form.text("name", voice_name);
 
-    if let Some(file_paths) = file_paths {
-        for file_path in file_paths {
-            let fp = file_path.as_ref();
-            let audio_bytes = std::fs::read(fp)?;
-            let mut part = Part::bytes(audio_bytes);
-            let file_path_str = fp.to_str().ok_or(Box::new(Error::PathNotValidUTF8))?;
-            part = part.file_name(file_path_str.to_string());
-            let mime_subtype = fp
-                .extension()
-                .ok_or(Box::new(Error::FileExtensionNotFound))?
-                .to_str()
-                .ok_or(Box::new(Error::FileExtensionNotValidUTF8))?;
-            let mime = format!("audio/{}", mime_subtype);
-            part = part.mime_str(&mime)?;
+    if let Some(files) = files {
+        for file in files {
+            let mime = format!("audio/{}", file.extension()?);
+            let part = file.to_part()?.mime_str(&mime)?;
             form = form.part("files", part);
         }
         if let Some(description) = description {
@@ -72,6 +72,7 @@
 
 use std::collections::HashMap;
 use super::*;
+use crate::endpoints::voice::VoiceResponseBody;
 
 const CREATE_PREVIEW_PATH: &str = "/v1/text-to-voice/create-previews";
 const CREATE_VOICE_FROM_PREVIEW_PATH: &str = "/v1/text-to-voice/create-voice-from-preview";
@@ -166,6 +167,12 @@ pub struct CreatePreviewsResponse {
     previews: Vec<VoicePreview>
 }
 
+impl CreatePreviewsResponse {
+    pub fn previews(&self) -> &Vec<VoicePreview> {
+        &self.previews
+    }
+}
+
 impl Iterator for CreatePreviewsResponse {
     type Item = VoicePreview;
 
@@ -177,7 +184,7 @@ impl Iterator for CreatePreviewsResponse {
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct VoicePreview {
     audio_base_64: String,
-    generated_voice_id: String,
+    generated_voice_id: GeneratedVoiceID,
     media_type: String,
 }
 
@@ -192,7 +199,7 @@ impl VoicePreview {
     }
 
     pub fn generated_voice_id(&self) -> &str {
-        &self.generated_voice_id
+        &self.generated_voice_id.0
     }
 
     pub fn media_type(&self) -> &str {
@@ -200,6 +207,37 @@ impl VoicePreview {
     }
 }
 
+/// The id of a voice preview returned by [CreatePreviews]
+///
+/// It is only valid for a call to [CreateVoiceFromPreview],
+/// it cannot be used in place of a voice id.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct GeneratedVoiceID(String);
+
+impl From<String> for GeneratedVoiceID {
+    fn from(id: String) -> Self {
+        GeneratedVoiceID(id)
+    }
+}
+
+impl From<&str> for GeneratedVoiceID {
+    fn from(id: &str) -> Self {
+        GeneratedVoiceID(id.to_string())
+    }
+}
+
+impl From<&VoicePreview> for GeneratedVoiceID {
+    fn from(preview: &VoicePreview) -> Self {
+        preview.generated_voice_id.clone()
+    }
+}
+
+impl GeneratedVoiceID {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
 /// Create a voice from a preview
 ///
 /// ## Official Documentation
@@ -224,8 +262,30 @@ impl VoicePreview {
 ///    let mut labels = HashMap::new();
 ///    labels.insert("language".to_string(), "en".into());
 ///    body.with_labels(labels);
-///    let resp = c.hit(CreateVoiceFromPreview::new(body)).await?;
-///    println!("{:?}", resp);
+///    let voice = c.hit(CreateVoiceFromPreview::new(body)).await?;
+///    println!("{}", voice.get_voice_id());
+///    Ok(())
+/// }
+/// ```
+///
+/// ## From a preview
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///    let c = ElevenLabsClient::default()?;
+///    let voice_description = "A grumpy old dwarf blacksmith, gravelly and slow";
+///    let body = CreatePreviewsBody::new(
+///        voice_description,
+///        "Aye, the forge has been burning since before your grandfather was born. \
+///         Hand me that hammer and mind the sparks, lad, I haven't got all day to teach you.",
+///    );
+///    let previews = c.hit(CreatePreviews::new(body)).await?;
+///    let preview = previews.previews().first().expect("at least one preview");
+///    let body = CreateVoiceFromPreviewBody::from_preview("Durin", voice_description, preview);
+///    let voice = c.hit(CreateVoiceFromPreview::new(body)).await?;
+///    println!("{}", voice.get_voice_id());
 ///    Ok(())
 /// }
 /// ```
@@ -245,12 +305,16 @@ impl CreateVoiceFromPreview {
 pub struct CreateVoiceFromPreviewBody {
     voice_name: String,
     voice_description: String,
-    generated_voice_id: String,
+    generated_voice_id: GeneratedVoiceID,
     labels: HashMap<String, String>,
 }
 
 impl CreateVoiceFromPreviewBody {
-    pub fn new<T: Into<String>>(name: T, voice_description: T, generated_voice_id: T) -> Self {
+    pub fn new<T, G>(name: T, voice_description: T, generated_voice_id: G) -> Self
+    where
+        T: Into<String>,
+        G: Into<GeneratedVoiceID>,
+    {
         Self {
             voice_name: name.into(),
             voice_description: voice_description.into(),
@@ -259,14 +323,26 @@ impl CreateVoiceFromPreviewBody {
         }
     }
 
+    /// Use the `generated_voice_id` of a preview returned by [CreatePreviews]
+    pub fn from_preview<T: Into<String>>(
+        name: T,
+        voice_description: T,
+        preview: &VoicePreview,
+    ) -> Self {
+        Self::new(name, voice_description, preview)
+    }
+
+    pub fn generated_voice_id(&self) -> &GeneratedVoiceID {
+        &self.generated_voice_id
+    }
+
     pub fn with_labels(&mut self, labels: HashMap<String, String>) {
         self.labels = labels;
     }
 }
 
 impl Endpoint for CreateVoiceFromPreview {
-    //type ResponseBody = CreateVoiceFromPreviewResponse;
-    type ResponseBody = Value;
+    type ResponseBody = VoiceResponseBody;
 
     fn method(&self) -> Method {
         Method::POST
@@ -285,13 +361,3 @@ impl Endpoint for CreateVoiceFromPreview {
         Url::parse(&url).unwrap()
     }
 }
-
-//#[derive(Clone, Debug, Deserialize, Serialize)]
-//pub struct CreateVoiceFromPreviewResponse {
-//    voice_id: String,
-//}
-
-
-
-
-
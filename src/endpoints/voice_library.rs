@@ -70,6 +70,7 @@ const RENDERED_APP_ENABLED_QUERY: &str = "rendered_app_enabled";
 const OWNER_ID_QUERY: &str = "owner_id";
 const SORT_QUERY: &str = "sort";
 const PAGE_QUERY: &str = "page";
+const SIMILAR_VOICES_PATH: &str = "/v1/similar-voices";
 
 /// Get shared voices
 ///
@@ -456,6 +457,9 @@ impl Category {
 
 /// Add a sharing voice to your collection of voices in VoiceLab.
 ///
+/// Sharing your own voices to the library is only possible on the website,
+/// the API has no endpoint for it.
+///
 /// # Example
 /// ```no_run
 /// use elevenlabs_rs::*;
@@ -468,7 +472,7 @@ impl Category {
 ///     let name = "new_voice_name";
 ///     let endpoint = AddSharedVoice::new(public_user_id, voice_id, name);
 ///     let resp = c.hit(endpoint).await?;
-///     println!("{:#?}", resp);
+///     println!("added as {}", resp.voice_id());
 ///     Ok(())
 /// }
 /// ```
@@ -543,6 +547,13 @@ pub struct AddSharedVoiceResponse {
     voice_id: String,
 }
 
+impl AddSharedVoiceResponse {
+    /// The id of the voice in your collection, usable wherever a voice id is taken
+    pub fn voice_id(&self) -> &str {
+        &self.voice_id
+    }
+}
+
 /// Parameters for adding a shared voice
 #[derive(Clone, Debug)]
 pub struct AddSharedVoiceParams {
@@ -583,3 +594,112 @@ impl AddSharedVoiceBody {
         }
     }
 }
+
+/// Find the library voices that sound like an audio sample
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let body = SimilarVoicesBody::new("clip.mp3")
+///         .with_similarity_threshold(0.8)
+///         .with_top_k(5);
+///     let resp = c.hit(FindSimilarVoices::new(body)).await?;
+///     for (rank, voice) in resp.voices().iter().enumerate() {
+///         println!("{}. {} ({})", rank + 1, voice.name(), voice.voice_id());
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct FindSimilarVoices(SimilarVoicesBody);
+
+impl FindSimilarVoices {
+    pub fn new(body: SimilarVoicesBody) -> Self {
+        FindSimilarVoices(body)
+    }
+}
+
+impl Endpoint for FindSimilarVoices {
+    type ResponseBody = SimilarVoicesResponse;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        let mime = format!("audio/{}", self.0.audio_file.extension()?);
+        let part = self.0.audio_file.to_part()?.mime_str(&mime)?;
+        let mut form = Form::new().part("audio_file", part);
+        if let Some(threshold) = self.0.similarity_threshold {
+            form = form.text("similarity_threshold", threshold.to_string());
+        }
+        if let Some(top_k) = self.0.top_k {
+            form = form.text("top_k", top_k.to_string());
+        }
+        Ok(RequestBody::Multipart(form))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        let mut url = BASE_URL.parse::<Url>().unwrap();
+        url.set_path(SIMILAR_VOICES_PATH);
+        url
+    }
+}
+
+/// Similar voices body
+#[derive(Clone, Debug)]
+pub struct SimilarVoicesBody {
+    audio_file: FileSource,
+    similarity_threshold: Option<f32>,
+    top_k: Option<u32>,
+}
+
+impl SimilarVoicesBody {
+    pub fn new<F: Into<FileSource>>(audio_file: F) -> Self {
+        SimilarVoicesBody {
+            audio_file: audio_file.into(),
+            similarity_threshold: None,
+            top_k: None,
+        }
+    }
+    /// Between 0 and 2, the lower the threshold the closer the voices have to sound
+    pub fn with_similarity_threshold(mut self, similarity_threshold: f32) -> Self {
+        self.similarity_threshold = Some(similarity_threshold);
+        self
+    }
+    /// The number of voices to return, between 1 and 100
+    pub fn with_top_k(mut self, top_k: u32) -> Self {
+        self.top_k = Some(top_k);
+        self
+    }
+}
+
+/// Similar voices response
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SimilarVoicesResponse {
+    voices: Vec<SharedVoice>,
+    has_more: bool,
+    last_sort_id: Option<String>,
+}
+
+impl SimilarVoicesResponse {
+    /// The voices, the most similar first
+    pub fn voices(&self) -> &[SharedVoice] {
+        &self.voices
+    }
+    /// The most similar voice, if any are within the threshold
+    pub fn most_similar(&self) -> Option<&SharedVoice> {
+        self.voices.first()
+    }
+    pub fn has_more(&self) -> bool {
+        self.has_more
+    }
+    pub fn last_sort_id(&self) -> Option<&str> {
+        self.last_sort_id.as_deref()
+    }
+}
@@ -0,0 +1,458 @@
+//! The workspace endpoints
+//!
+//! Invite users to the workspace and manage its members, requires a workspace admin API key.
+//!
+//! See the [ElevenLabs docs](https://elevenlabs.io/docs/api-reference/workspace/invite-user) for more information.
+use super::*;
+
+const WORKSPACE_PATH: &str = "/v1/workspace";
+const INVITES_PATH: &str = "/invites";
+const ADD_PATH: &str = "/add";
+const ADD_BULK_PATH: &str = "/add-bulk";
+const MEMBERS_PATH: &str = "/members";
+const GROUPS_SEARCH_PATH: &str = "/groups/search";
+const WEBHOOKS_PATH: &str = "/webhooks";
+const NAME_QUERY: &str = "name";
+const INCLUDE_USAGES_QUERY: &str = "include_usages";
+
+/// Invite a user to the workspace
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     c.hit(InviteUser::new(InviteUserBody::new("ada@example.com"))).await?;
+///
+///     let emails = vec!["grace@example.com".to_string(), "alan@example.com".into()];
+///     let body = InviteUsersBody::new(emails).with_group_ids(vec!["group_id".to_string()]);
+///     c.hit(InviteUsers::new(body)).await?;
+///
+///     let body = UpdateMemberBody::new("ada@example.com").with_role(WorkspaceRole::Admin);
+///     c.hit(UpdateMember::new(body)).await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct InviteUser(InviteUserBody);
+
+impl InviteUser {
+    pub fn new(body: InviteUserBody) -> Self {
+        InviteUser(body)
+    }
+}
+
+impl Endpoint for InviteUser {
+    type ResponseBody = StatusResponseBody;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(serde_json::to_value(&self.0)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        workspace_url(&format!("{}{}", INVITES_PATH, ADD_PATH))
+    }
+}
+
+/// Invite user body
+#[derive(Clone, Debug, Serialize)]
+pub struct InviteUserBody {
+    email: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group_ids: Option<Vec<String>>,
+}
+
+impl InviteUserBody {
+    pub fn new(email: &str) -> Self {
+        InviteUserBody {
+            email: email.to_string(),
+            group_ids: None,
+        }
+    }
+    /// The groups the user is added to once they accept the invite
+    pub fn with_group_ids(mut self, group_ids: Vec<String>) -> Self {
+        self.group_ids = Some(group_ids);
+        self
+    }
+}
+
+/// Invite several users to the workspace at once
+#[derive(Clone, Debug)]
+pub struct InviteUsers(InviteUsersBody);
+
+impl InviteUsers {
+    pub fn new(body: InviteUsersBody) -> Self {
+        InviteUsers(body)
+    }
+}
+
+impl Endpoint for InviteUsers {
+    type ResponseBody = StatusResponseBody;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(serde_json::to_value(&self.0)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        workspace_url(&format!("{}{}", INVITES_PATH, ADD_BULK_PATH))
+    }
+}
+
+/// Invite users body
+#[derive(Clone, Debug, Serialize)]
+pub struct InviteUsersBody {
+    emails: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group_ids: Option<Vec<String>>,
+}
+
+impl InviteUsersBody {
+    pub fn new(emails: Vec<String>) -> Self {
+        InviteUsersBody {
+            emails,
+            group_ids: None,
+        }
+    }
+    /// The groups the users are added to once they accept the invite
+    pub fn with_group_ids(mut self, group_ids: Vec<String>) -> Self {
+        self.group_ids = Some(group_ids);
+        self
+    }
+}
+
+/// Withdraw the pending invite of a user
+#[derive(Clone, Debug)]
+pub struct DeleteInvite(EmailBody);
+
+impl DeleteInvite {
+    pub fn new(email: &str) -> Self {
+        DeleteInvite(EmailBody {
+            email: email.to_string(),
+        })
+    }
+}
+
+impl Endpoint for DeleteInvite {
+    type ResponseBody = StatusResponseBody;
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(serde_json::to_value(&self.0)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        workspace_url(INVITES_PATH)
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct EmailBody {
+    email: String,
+}
+
+/// Change the role of a member or lock them out of the workspace
+#[derive(Clone, Debug)]
+pub struct UpdateMember(UpdateMemberBody);
+
+impl UpdateMember {
+    pub fn new(body: UpdateMemberBody) -> Self {
+        UpdateMember(body)
+    }
+}
+
+impl Endpoint for UpdateMember {
+    type ResponseBody = StatusResponseBody;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn request_body(&self) -> Result<RequestBody> {
+        Ok(RequestBody::Json(serde_json::to_value(&self.0)?))
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        workspace_url(MEMBERS_PATH)
+    }
+}
+
+/// Update member body, fields that are not set are left as they are
+#[derive(Clone, Debug, Serialize)]
+pub struct UpdateMemberBody {
+    email: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_locked: Option<bool>,
+    #[serde(rename = "workspace_role", skip_serializing_if = "Option::is_none")]
+    role: Option<WorkspaceRole>,
+}
+
+impl UpdateMemberBody {
+    pub fn new(email: &str) -> Self {
+        UpdateMemberBody {
+            email: email.to_string(),
+            is_locked: None,
+            role: None,
+        }
+    }
+    /// A locked member can no longer sign in to the workspace
+    pub fn with_locked(mut self, is_locked: bool) -> Self {
+        self.is_locked = Some(is_locked);
+        self
+    }
+    pub fn with_role(mut self, role: WorkspaceRole) -> Self {
+        self.role = Some(role);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub enum WorkspaceRole {
+    #[serde(rename = "workspace_admin")]
+    Admin,
+    #[serde(rename = "workspace_member")]
+    Member,
+}
+
+/// Search the groups of the workspace by name, returning their members
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let groups = c.hit(SearchWorkspaceGroups::new("Support")).await?;
+///     for group in &groups {
+///         println!("{}: {:?}", group.name(), group.members_emails());
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SearchWorkspaceGroups {
+    name: String,
+}
+
+impl SearchWorkspaceGroups {
+    pub fn new(name: &str) -> Self {
+        SearchWorkspaceGroups {
+            name: name.to_string(),
+        }
+    }
+}
+
+impl Endpoint for SearchWorkspaceGroups {
+    type ResponseBody = Vec<WorkspaceGroup>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        let mut url = workspace_url(GROUPS_SEARCH_PATH);
+        url.query_pairs_mut().append_pair(NAME_QUERY, &self.name);
+        url
+    }
+}
+
+/// A group of workspace members
+#[derive(Clone, Debug, Deserialize)]
+pub struct WorkspaceGroup {
+    id: String,
+    name: String,
+    #[serde(default)]
+    members_emails: Vec<String>,
+}
+
+impl WorkspaceGroup {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn members_emails(&self) -> &[String] {
+        &self.members_emails
+    }
+}
+
+/// List the webhooks configured for the workspace
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let resp = c.hit(GetWorkspaceWebhooks::new().with_usages()).await?;
+///     for webhook in resp.webhooks().iter().filter(|w| w.is_auto_disabled()) {
+///         eprintln!(
+///             "webhook {} was disabled, last error code: {:?}",
+///             webhook.name(),
+///             webhook.most_recent_failure_error_code()
+///         );
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct GetWorkspaceWebhooks {
+    include_usages: Option<bool>,
+}
+
+impl GetWorkspaceWebhooks {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Include what each webhook is used for, only available to workspace admins
+    pub fn with_usages(mut self) -> Self {
+        self.include_usages = Some(true);
+        self
+    }
+}
+
+impl Endpoint for GetWorkspaceWebhooks {
+    type ResponseBody = WorkspaceWebhooksResponse;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    async fn response_body(self, resp: Response) -> Result<Self::ResponseBody> {
+        Ok(resp.json().await?)
+    }
+    fn url(&self) -> Url {
+        let mut url = workspace_url(WEBHOOKS_PATH);
+        if let Some(include_usages) = self.include_usages {
+            url.query_pairs_mut()
+                .append_pair(INCLUDE_USAGES_QUERY, &include_usages.to_string());
+        }
+        url
+    }
+}
+
+/// Workspace webhooks response
+#[derive(Clone, Debug, Deserialize)]
+pub struct WorkspaceWebhooksResponse {
+    webhooks: Vec<WorkspaceWebhook>,
+}
+
+impl WorkspaceWebhooksResponse {
+    pub fn webhooks(&self) -> &[WorkspaceWebhook] {
+        &self.webhooks
+    }
+}
+
+/// A webhook of the workspace
+#[derive(Clone, Debug, Deserialize)]
+pub struct WorkspaceWebhook {
+    webhook_id: String,
+    name: String,
+    webhook_url: String,
+    #[serde(default)]
+    is_disabled: bool,
+    #[serde(default)]
+    is_auto_disabled: bool,
+    created_at_unix: Option<i64>,
+    auth_type: Option<String>,
+    #[serde(default)]
+    usage: Vec<WebhookUsage>,
+    most_recent_failure_error_code: Option<u16>,
+    most_recent_failure_timestamp: Option<i64>,
+}
+
+impl WorkspaceWebhook {
+    pub fn webhook_id(&self) -> &str {
+        &self.webhook_id
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn webhook_url(&self) -> &str {
+        &self.webhook_url
+    }
+    pub fn is_disabled(&self) -> bool {
+        self.is_disabled
+    }
+    /// Whether the webhook was disabled by ElevenLabs after failing repeatedly
+    pub fn is_auto_disabled(&self) -> bool {
+        self.is_auto_disabled
+    }
+    pub fn created_at_unix(&self) -> Option<i64> {
+        self.created_at_unix
+    }
+    /// [created_at_unix](Self::created_at_unix) as a date
+    #[cfg(feature = "chrono")]
+    pub fn created_at(&self) -> Option<DateTime<Utc>> {
+        self.created_at_unix().map(utc)
+    }
+    /// e.g. `hmac`
+    pub fn auth_type(&self) -> Option<&str> {
+        self.auth_type.as_deref()
+    }
+    /// Empty unless [with_usages](GetWorkspaceWebhooks::with_usages) was set
+    pub fn usage(&self) -> &[WebhookUsage] {
+        &self.usage
+    }
+    /// The HTTP status code of the most recent failed delivery
+    pub fn most_recent_failure_error_code(&self) -> Option<u16> {
+        self.most_recent_failure_error_code
+    }
+    /// The unix timestamp, in seconds, of the most recent failed delivery
+    pub fn most_recent_failure_timestamp(&self) -> Option<i64> {
+        self.most_recent_failure_timestamp
+    }
+}
+
+/// What a webhook is used for
+#[derive(Clone, Debug, Deserialize)]
+pub struct WebhookUsage {
+    usage_type: WebhookUsageType,
+}
+
+impl WebhookUsage {
+    pub fn usage_type(&self) -> &WebhookUsageType {
+        &self.usage_type
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub enum WebhookUsageType {
+    #[serde(rename = "ConvAI Agent Settings")]
+    ConvaiAgentSettings,
+    #[serde(rename = "ConvAI Settings")]
+    ConvaiSettings,
+    #[serde(rename = "Voice Library Removal Notices")]
+    VoiceLibraryRemovalNotices,
+    #[serde(rename = "Speech to Text")]
+    SpeechToText,
+    /// A usage not known to this version of the crate
+    #[serde(untagged)]
+    Other(String),
+}
+
+fn workspace_url(path: &str) -> Url {
+    let mut url = BASE_URL.parse::<Url>().unwrap();
+    url.set_path(&format!("{}{}", WORKSPACE_PATH, path));
+    url
+}
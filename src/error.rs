@@ -1,5 +1,8 @@
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::{Response, StatusCode};
 use serde::Deserialize;
 use serde_json::Value;
+use std::time::Duration;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -16,42 +19,192 @@ pub enum Error {
     PathNotValidUTF8,
     #[error("VoiceNotFound")]
     VoiceNotFound,
+    /// See [`VoiceResolver::resolve`](crate::utils::VoiceResolver::resolve)
+    #[error("UnknownVoiceName: {name}, closest names: {suggestions:?}")]
+    UnknownVoiceName {
+        name: String,
+        suggestions: Vec<String>,
+    },
+    /// See [`FineTuningWait`](crate::utils::FineTuningWait)
+    #[error("FineTuningFailed: {0}")]
+    FineTuningFailed(String),
     #[error("GeneratedVoiceIDHeaderNotFound")]
     GeneratedVoiceIDHeaderNotFound,
+    #[error("MultipartBoundaryNotFound")]
+    MultipartBoundaryNotFound,
+    #[error("MultipartPartNotFound: {0}")]
+    MultipartPartNotFound(String),
+    #[error("ReaderAlreadyConsumed")]
+    ReaderAlreadyConsumed,
+    #[error("UnknownOutputFormat: {0}")]
+    UnknownOutputFormat(String),
+    /// See [`TextToSpeechBody::with_seed`](crate::TextToSpeechBody::with_seed)
+    #[error("SeedOutOfRange: {0}")]
+    SeedOutOfRange(u64),
+    /// See [`AudioTag::new`](crate::utils::AudioTag::new)
+    #[error("InvalidAudioTag: {0}")]
+    InvalidAudioTag(String),
+    /// See [`ElevenLabsClient::hit_with_cancellation`](crate::ElevenLabsClient::hit_with_cancellation)
+    #[error("Cancelled")]
+    Cancelled,
+    /// See [`ElevenLabsClient::hit_with_timeout`](crate::ElevenLabsClient::hit_with_timeout)
+    #[error("Timeout: {0:?}")]
+    Timeout(Duration),
 }
 
-#[derive(Error, Debug, Deserialize)]
-#[error("ElevenLabsServerError: {detail:?}")]
-pub struct ElevenLabsServerError {
-    detail: Detail,
+/// An error response from the ElevenLabs API
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::error::ApiError;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     match c.hit(GetVoice::new("not_a_voice_id")).await {
+///         Ok(voice) => println!("{:#?}", voice),
+///         Err(e) => match e.downcast_ref::<ApiError>() {
+///             Some(ApiError::VoiceNotFound(detail)) => println!("{}", detail.message()),
+///             Some(ApiError::RateLimited { retry_after, .. }) => println!("{:?}", retry_after),
+///             _ => return Err(e),
+///         },
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Error, Debug)]
+pub enum ApiError {
+    #[error("InvalidApiKey: {0}")]
+    InvalidApiKey(Detail),
+    #[error("QuotaExceeded: {0}")]
+    QuotaExceeded(Detail),
+    #[error("VoiceNotFound: {0}")]
+    VoiceNotFound(Detail),
+    #[error("RateLimited: retry after {retry_after:?}, {detail:?}")]
+    RateLimited {
+        retry_after: Option<Duration>,
+        detail: Option<Detail>,
+    },
+    #[error("UnprocessableEntity: {0:?}")]
+    UnprocessableEntity(Vec<DetailObject>),
+    #[error("{status}: {detail}")]
+    Other { status: StatusCode, detail: Detail },
+    #[error("{status}: {body}")]
+    Unknown { status: StatusCode, body: String },
+}
+
+/// Seconds to wait as sent in a `Retry-After` header, `None` if missing or not a valid duration
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let secs = headers
+        .get(RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<f64>()
+        .ok()?;
+    Duration::try_from_secs_f64(secs).ok()
+}
+
+impl ApiError {
+    pub(crate) async fn from_response(resp: Response) -> Self {
+        let status = resp.status();
+        let retry_after = retry_after(resp.headers());
+        let body = match resp.text().await {
+            Ok(body) => body,
+            Err(e) => e.to_string(),
+        };
+        let detail = match serde_json::from_str::<ErrorBody>(&body) {
+            Ok(ErrorBody { detail }) => detail,
+            Err(_) => return ApiError::Unknown { status, body },
+        };
+        let detail = match detail {
+            ErrorDetail::Detail(detail) => detail,
+            ErrorDetail::Validation(objects) => return ApiError::UnprocessableEntity(objects),
+            ErrorDetail::Message(message) => Detail {
+                status: String::new(),
+                message,
+            },
+        };
+
+        // The status of the detail first, e.g. an exceeded quota is sent with a 401
+        match (detail.status.as_str(), status) {
+            ("invalid_api_key" | "needs_authorization", _) => ApiError::InvalidApiKey(detail),
+            ("quota_exceeded", _) => ApiError::QuotaExceeded(detail),
+            ("voice_not_found", _) => ApiError::VoiceNotFound(detail),
+            ("too_many_concurrent_requests" | "system_busy", _) => ApiError::RateLimited {
+                retry_after,
+                detail: Some(detail),
+            },
+            (_, StatusCode::UNAUTHORIZED) => ApiError::InvalidApiKey(detail),
+            (_, StatusCode::TOO_MANY_REQUESTS) => ApiError::RateLimited {
+                retry_after,
+                detail: Some(detail),
+            },
+            _ => ApiError::Other { status, detail },
+        }
+    }
+
+    /// The `detail` object of the error response, if the server sent one
+    pub fn detail(&self) -> Option<&Detail> {
+        match self {
+            ApiError::InvalidApiKey(detail)
+            | ApiError::QuotaExceeded(detail)
+            | ApiError::VoiceNotFound(detail)
+            | ApiError::Other { detail, .. } => Some(detail),
+            ApiError::RateLimited { detail, .. } => detail.as_ref(),
+            ApiError::UnprocessableEntity(_) | ApiError::Unknown { .. } => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ErrorBody {
+    detail: ErrorDetail,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum ErrorDetail {
+    Detail(Detail),
+    Validation(Vec<DetailObject>),
+    Message(String),
 }
 
-#[derive(Debug, Deserialize, Error)]
-#[error("ClientErrorDetail: {message:?}, {status:?}")]
+#[derive(Clone, Debug, Deserialize, Error)]
+#[error("{status}: {message}")]
 pub struct Detail {
-    message: String,
+    #[serde(default)]
     status: String,
+    message: String,
 }
 
-#[allow(dead_code)]
-#[derive(Debug, Deserialize)]
+impl Detail {
+    pub fn status(&self) -> &str {
+        &self.status
+    }
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
 pub struct DetailObject {
-    loc: Vec<String>,
+    loc: Vec<Value>,
     msg: String,
     r#type: String,
 }
 
-#[derive(Debug, Deserialize, Error)]
-#[serde(untagged)]
-pub enum ElevenLabsClientError {
-    #[error("ElevenLabsClientError: {detail:?}")]
-    BadRequest { detail: Detail },
-    #[error("ElevenLabsClientError: {detail:?}")]
-    NotFound { detail: Detail },
-    #[error("ElevenLabsClientError: {detail:?}")]
-    UnprocessableEntity { detail: Vec<DetailObject> },
-    #[error("ElevenLabsClientError: {detail:?}")]
-    Code4xx { detail: String },
+impl DetailObject {
+    pub fn loc(&self) -> &[Value] {
+        &self.loc
+    }
+    pub fn msg(&self) -> &str {
+        &self.msg
+    }
+    pub fn r#type(&self) -> &str {
+        &self.r#type
+    }
 }
 
 #[derive(Error, Debug)]
@@ -62,6 +215,41 @@ pub enum WebSocketError {
     ClosedWithoutCloseFrame,
     #[error("UnexpectedMessageType")]
     UnexpectedMessageType,
+    #[error("CloseTimeout")]
+    CloseTimeout,
+}
+
+#[derive(Debug, Error)]
+pub enum AudioInterfaceError {
+    #[error("NoInputDevice")]
+    NoInputDevice,
+    #[error("NoOutputDevice")]
+    NoOutputDevice,
+    #[error("UnsupportedAudioFormat: {0}")]
+    UnsupportedAudioFormat(String),
+    #[error("UnsupportedSampleFormat: {0}")]
+    UnsupportedSampleFormat(String),
+}
+
+#[derive(Debug, Error)]
+pub enum VoiceSettingsError {
+    #[error("OutOfRange: {setting} is {value}, expected {min} to {max}")]
+    OutOfRange {
+        setting: &'static str,
+        value: f32,
+        min: f32,
+        max: f32,
+    },
+}
+
+#[derive(Debug, Error)]
+pub enum WebhookError {
+    #[error("InvalidSignatureHeader")]
+    InvalidSignatureHeader,
+    #[error("TimestampOutOfTolerance")]
+    TimestampOutOfTolerance,
+    #[error("SignatureMismatch")]
+    SignatureMismatch,
 }
 
 #[derive(Debug, Error)]
@@ -75,3 +263,11 @@ pub enum ConvAIError {
     #[error("WebSocket message error: {0}")]
     WebSocketError(String),
 }
+
+#[derive(Debug, Error)]
+pub enum TelephonyError {
+    #[error("StreamEndedBeforeStart")]
+    StreamEndedBeforeStart,
+    #[error("UnknownAgentAudioFormat: {0}")]
+    UnknownAgentAudioFormat(String),
+}
@@ -26,8 +26,24 @@
 //!     Ok(())
 //! }
 //! ```
+//!
+//! ## Speech in one line
+//!
+//! ```no_run
+//! use elevenlabs_rs::*;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<()> {
+//!     Speech::new("Hello there", "Clyde", Model::ElevenMultilingualV2).await?.play()?;
+//!     Ok(())
+//! }
+//! ```
 
-pub use crate::client::{ElevenLabsClient, Result};
+pub use crate::api_keys::{ApiKeys, KeyRouting, KeyUsage};
+pub use crate::client::{
+    ByteStream, ClientBuilder, ElevenLabsClient, ResponseMeta, Result, RetryPolicy,
+};
+pub use crate::config::ClientConfig;
 #[cfg(feature = "dev")]
 pub use crate::convai_client::ConvAIClient;
 pub use crate::endpoints::audio_isolation::*;
@@ -35,27 +51,51 @@ pub use crate::endpoints::audio_native::*;
 pub use crate::endpoints::dubbing::*;
 pub use crate::endpoints::history::*;
 pub use crate::endpoints::models::*;
+pub use crate::endpoints::genai::music::*;
 pub use crate::endpoints::projects::*;
 pub use crate::endpoints::pronunciation::*;
+pub use crate::endpoints::pvc::*;
 pub use crate::endpoints::samples::*;
+pub use crate::endpoints::service_accounts::*;
 pub use crate::endpoints::sound_generation::*;
 pub use crate::endpoints::sts::*;
+pub use crate::endpoints::stt::*;
+pub use crate::endpoints::text_to_dialogue::*;
 pub use crate::endpoints::tts::*;
 pub use crate::endpoints::tts::ws::*;
+pub use crate::endpoints::usage::*;
 pub use crate::endpoints::user::*;
 pub use crate::endpoints::voice::*;
 pub use crate::endpoints::voice_design::*;
 pub use crate::endpoints::voice_generation::*;
 pub use crate::endpoints::voice_library::*;
-pub use crate::shared::identifiers::{Model, PreMadeVoiceID};
+pub use crate::endpoints::workspace::*;
+pub use crate::shared::identifiers::{
+    AgentID, ConversationID, LanguageCode, Model, PreMadeVoiceID, VoiceID,
+};
 pub use crate::shared::query_params::*;
+pub use crate::shared::upload::FileSource;
+pub use crate::speech::Speech;
 pub use bytes::Bytes;
+pub use reqwest::Proxy;
+pub use tokio_util::sync::CancellationToken;
 pub use futures_util::{pin_mut, StreamExt};
 
+mod api_keys;
+#[cfg(feature = "blocking")]
+pub mod blocking;
 mod client;
+mod config;
 pub mod endpoints;
 pub mod error;
+pub mod middleware;
 mod shared;
+mod speech;
+mod telemetry;
+#[cfg(feature = "telephony")]
+pub mod telephony;
+pub mod transport;
 pub mod utils;
+pub mod webhooks;
 #[cfg(feature = "dev")]
 mod convai_client;
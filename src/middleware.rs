@@ -0,0 +1,93 @@
+//! Middleware wrapping every request sent by the client
+//!
+//! A [Middleware] receives each request before it is sent and the [Next] step of the chain,
+//! so it can change the request, inspect or replace the response, or skip sending altogether.
+//! Middleware added first runs outermost. Retried requests pass through the chain again.
+//!
+//! Websocket endpoints are not routed through middleware.
+//!
+//! # Example
+//! ```no_run
+//! use elevenlabs_rs::*;
+//! use elevenlabs_rs::middleware::Next;
+//! use elevenlabs_rs::transport::Request;
+//! use std::time::Instant;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<()> {
+//!     let c = ElevenLabsClient::builder()
+//!         .middleware(|mut req: Request, next: Next| async move {
+//!             req.headers_mut().insert("x-team", "narration".parse()?);
+//!             next.run(req).await
+//!         })
+//!         .middleware(|req: Request, next: Next| async move {
+//!             let path = req.url().path().to_string();
+//!             let start = Instant::now();
+//!             let resp = next.run(req).await?;
+//!             println!("{} {} in {:?}", path, resp.status(), start.elapsed());
+//!             Ok(resp)
+//!         })
+//!         .build()?;
+//!     let voices = c.hit(GetVoices).await?;
+//!     Ok(())
+//! }
+//! ```
+use crate::client::Result;
+use crate::transport::{Request, Response, Transport, TransportFuture};
+use std::future::Future;
+use std::sync::Arc;
+
+/// Handles a request, usually by passing it on to [`next`](Next::run)
+///
+/// Implemented for any `Fn(Request, Next) -> impl Future<Output = Result<Response>>`.
+pub trait Middleware: Send + Sync {
+    fn handle(&self, request: Request, next: Next) -> TransportFuture<'_>;
+}
+
+impl<F, Fut> Middleware for F
+where
+    F: Fn(Request, Next) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<Response>> + Send + 'static,
+{
+    fn handle(&self, request: Request, next: Next) -> TransportFuture<'_> {
+        Box::pin(self(request, next))
+    }
+}
+
+/// The rest of the middleware chain, ending with sending the request
+pub struct Next {
+    chain: Arc<[Arc<dyn Middleware>]>,
+    index: usize,
+    send: Sender,
+}
+
+pub(crate) enum Sender {
+    Client(reqwest::Client),
+    Transport(Arc<dyn Transport>),
+}
+
+impl Next {
+    pub(crate) fn new(chain: Arc<[Arc<dyn Middleware>]>, send: Sender) -> Self {
+        Next {
+            chain,
+            index: 0,
+            send,
+        }
+    }
+
+    /// Passes the request on to the next middleware, or sends it
+    pub async fn run(self, request: Request) -> Result<Response> {
+        let Some(middleware) = self.chain.get(self.index).cloned() else {
+            return match self.send {
+                Sender::Client(client) => Ok(client.execute(request).await?),
+                Sender::Transport(transport) => transport.send(request).await,
+            };
+        };
+        let next = Next {
+            chain: self.chain,
+            index: self.index + 1,
+            send: self.send,
+        };
+        middleware.handle(request, next).await
+    }
+}
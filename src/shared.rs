@@ -21,50 +21,432 @@ pub mod identifiers {
         }
     }
 
-    #[derive(Clone, Debug)]
+    /// The models of the API, or any other model id with [Model::Custom]
+    ///
+    /// # Example
+    /// ```
+    /// use elevenlabs_rs::*;
+    ///
+    /// let model = Model::ElevenFlashV2Dot5;
+    /// assert!(model.is_tts());
+    /// assert!(model.supports_language("vi"));
+    /// assert_eq!(model.max_characters(), Some(40_000));
+    /// assert_eq!(Model::from("eleven_flash_v2_5"), model);
+    /// assert_eq!(Model::from("my_model"), Model::Custom("my_model".to_string()));
+    /// ```
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
     pub enum Model {
         ElevenMultilingualV2,
         ElevenMultilingualV1,
         ElevenEnglishV1,
+        /// Speech to speech, English only
         ElevenEnglishV2,
         ElevenTurboV2,
         ElevenMultilingualV2STS,
         ElevenTurboV2Dot5,
+        ElevenFlashV2,
+        ElevenFlashV2Dot5,
+        ElevenV3,
+        /// Voice design
+        ElevenMultilingualTTVV2,
+        /// Voice design
+        ElevenTTVV3,
+        /// Speech to text
+        ScribeV1,
+        /// Speech to text
+        ScribeV1Experimental,
+        /// A model id this crate does not know about
+        Custom(String),
+    }
+
+    /// Languages of the multilingual v2 models, as ISO 639-1 codes except for Filipino
+    const MULTILINGUAL_V2_LANGUAGES: &[&str] = &[
+        "en", "ja", "zh", "de", "hi", "fr", "ko", "pt", "it", "es", "id", "nl", "tr", "fil", "pl",
+        "sv", "bg", "ro", "ar", "cs", "el", "fi", "hr", "ms", "sk", "da", "ta", "uk", "ru",
+    ];
+    const V2_5_LANGUAGES: &[&str] = &[
+        "en", "ja", "zh", "de", "hi", "fr", "ko", "pt", "it", "es", "id", "nl", "tr", "fil", "pl",
+        "sv", "bg", "ro", "ar", "cs", "el", "fi", "hr", "ms", "sk", "da", "ta", "uk", "ru", "hu",
+        "no", "vi",
+    ];
+    const MULTILINGUAL_V1_LANGUAGES: &[&str] =
+        &["en", "de", "pl", "es", "it", "fr", "pt", "hi", "ar"];
+    const ENGLISH: &[&str] = &["en"];
+
+    impl Model {
+        const KNOWN: [Model; 14] = [
+            Model::ElevenMultilingualV2,
+            Model::ElevenMultilingualV1,
+            Model::ElevenEnglishV1,
+            Model::ElevenEnglishV2,
+            Model::ElevenTurboV2,
+            Model::ElevenMultilingualV2STS,
+            Model::ElevenTurboV2Dot5,
+            Model::ElevenFlashV2,
+            Model::ElevenFlashV2Dot5,
+            Model::ElevenV3,
+            Model::ElevenMultilingualTTVV2,
+            Model::ElevenTTVV3,
+            Model::ScribeV1,
+            Model::ScribeV1Experimental,
+        ];
+
+        /// The model id used by the API
+        pub fn as_str(&self) -> &str {
+            match self {
+                Model::ElevenMultilingualV2 => "eleven_multilingual_v2",
+                Model::ElevenMultilingualV1 => "eleven_multilingual_v1",
+                Model::ElevenEnglishV1 => "eleven_monolingual_v1",
+                Model::ElevenEnglishV2 => "eleven_english_sts_v2",
+                Model::ElevenTurboV2 => "eleven_turbo_v2",
+                Model::ElevenMultilingualV2STS => "eleven_multilingual_sts_v2",
+                Model::ElevenTurboV2Dot5 => "eleven_turbo_v2_5",
+                Model::ElevenFlashV2 => "eleven_flash_v2",
+                Model::ElevenFlashV2Dot5 => "eleven_flash_v2_5",
+                Model::ElevenV3 => "eleven_v3",
+                Model::ElevenMultilingualTTVV2 => "eleven_multilingual_ttv_v2",
+                Model::ElevenTTVV3 => "eleven_ttv_v3",
+                Model::ScribeV1 => "scribe_v1",
+                Model::ScribeV1Experimental => "scribe_v1_experimental",
+                Model::Custom(id) => id,
+            }
+        }
+        /// Whether the model can be used for text to speech, false for custom models
+        pub fn is_tts(&self) -> bool {
+            matches!(
+                self,
+                Model::ElevenMultilingualV2
+                    | Model::ElevenMultilingualV1
+                    | Model::ElevenEnglishV1
+                    | Model::ElevenTurboV2
+                    | Model::ElevenTurboV2Dot5
+                    | Model::ElevenFlashV2
+                    | Model::ElevenFlashV2Dot5
+                    | Model::ElevenV3
+            )
+        }
+        /// Whether the model can be used for speech to speech, false for custom models
+        pub fn is_sts(&self) -> bool {
+            matches!(
+                self,
+                Model::ElevenEnglishV2 | Model::ElevenMultilingualV2STS
+            )
+        }
+        /// Whether the model can be used for speech to text, false for custom models
+        pub fn is_stt(&self) -> bool {
+            matches!(self, Model::ScribeV1 | Model::ScribeV1Experimental)
+        }
+        /// The languages of the model as ISO 639-1 codes, `fil` for Filipino
+        ///
+        /// `None` for custom models and for models that support too many languages to list,
+        /// i.e. Eleven v3 and Scribe.
+        pub fn languages(&self) -> Option<&'static [&'static str]> {
+            match self {
+                Model::ElevenMultilingualV2
+                | Model::ElevenMultilingualV2STS
+                | Model::ElevenMultilingualTTVV2 => Some(MULTILINGUAL_V2_LANGUAGES),
+                Model::ElevenTurboV2Dot5 | Model::ElevenFlashV2Dot5 => Some(V2_5_LANGUAGES),
+                Model::ElevenMultilingualV1 => Some(MULTILINGUAL_V1_LANGUAGES),
+                Model::ElevenEnglishV1
+                | Model::ElevenEnglishV2
+                | Model::ElevenTurboV2
+                | Model::ElevenFlashV2 => Some(ENGLISH),
+                _ => None,
+            }
+        }
+        /// Whether the model supports a language, given as an ISO 639-1 code
+        ///
+        /// Always true if the [languages](Self::languages) of the model are unknown.
+        pub fn supports_language(&self, code: &str) -> bool {
+            self.languages().is_none_or(|languages| {
+                languages
+                    .iter()
+                    .any(|language| language.eq_ignore_ascii_case(code))
+            })
+        }
+        /// The maximum number of characters in a single text to speech request
+        pub fn max_characters(&self) -> Option<u32> {
+            match self {
+                Model::ElevenMultilingualV2
+                | Model::ElevenMultilingualV1
+                | Model::ElevenEnglishV1 => Some(10_000),
+                Model::ElevenTurboV2 | Model::ElevenFlashV2 => Some(30_000),
+                Model::ElevenTurboV2Dot5 | Model::ElevenFlashV2Dot5 => Some(40_000),
+                Model::ElevenV3 => Some(5_000),
+                _ => None,
+            }
+        }
+    }
+
+    impl From<&str> for Model {
+        fn from(id: &str) -> Self {
+            Model::KNOWN
+                .into_iter()
+                .find(|model| model.as_str() == id)
+                .unwrap_or_else(|| Model::Custom(id.to_string()))
+        }
+    }
+
+    impl From<String> for Model {
+        fn from(id: String) -> Self {
+            Model::from(id.as_str())
+        }
     }
 
     impl From<Model> for String {
         fn from(model: Model) -> String {
             match model {
-                Model::ElevenMultilingualV2 => "eleven_multilingual_v2".to_string(),
-                Model::ElevenMultilingualV1 => "eleven_multilingual_v1".to_string(),
-                Model::ElevenEnglishV1 => "eleven_monolingual_v1".to_string(),
-                Model::ElevenEnglishV2 => "eleven_english_sts_v2".to_string(),
-                Model::ElevenTurboV2 => "eleven_turbo_v2".to_string(),
-                Model::ElevenMultilingualV2STS => "eleven_multilingual_sts_v2".to_string(),
-                Model::ElevenTurboV2Dot5 => "eleven_turbo_v2_5".to_string(),
+                Model::Custom(id) => id,
+                model => model.as_str().to_string(),
             }
         }
     }
 
-    #[derive(Clone, Debug)]
-    pub(crate) struct VoiceID(pub(crate) String);
+    impl std::fmt::Display for Model {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
 
-    impl From<String> for VoiceID {
-        fn from(id: String) -> Self {
-            VoiceID(id)
+    /// A language to synthesize in, as an ISO 639-1 code except for Filipino
+    ///
+    /// Lists the languages of the v2.5 models, any other code can be given with [LanguageCode::Custom].
+    ///
+    /// # Example
+    /// ```
+    /// use elevenlabs_rs::*;
+    ///
+    /// assert_eq!(LanguageCode::from("pt"), LanguageCode::Portuguese);
+    /// assert!(Model::ElevenFlashV2Dot5.supports_language(LanguageCode::Portuguese.as_str()));
+    /// ```
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    pub enum LanguageCode {
+        English,
+        Japanese,
+        Chinese,
+        German,
+        Hindi,
+        French,
+        Korean,
+        Portuguese,
+        Italian,
+        Spanish,
+        Indonesian,
+        Dutch,
+        Turkish,
+        Filipino,
+        Polish,
+        Swedish,
+        Bulgarian,
+        Romanian,
+        Arabic,
+        Czech,
+        Greek,
+        Finnish,
+        Croatian,
+        Malay,
+        Slovak,
+        Danish,
+        Tamil,
+        Ukrainian,
+        Russian,
+        Hungarian,
+        Norwegian,
+        Vietnamese,
+        Custom(String),
+    }
+
+    impl LanguageCode {
+        const KNOWN: [LanguageCode; 32] = [
+            LanguageCode::English,
+            LanguageCode::Japanese,
+            LanguageCode::Chinese,
+            LanguageCode::German,
+            LanguageCode::Hindi,
+            LanguageCode::French,
+            LanguageCode::Korean,
+            LanguageCode::Portuguese,
+            LanguageCode::Italian,
+            LanguageCode::Spanish,
+            LanguageCode::Indonesian,
+            LanguageCode::Dutch,
+            LanguageCode::Turkish,
+            LanguageCode::Filipino,
+            LanguageCode::Polish,
+            LanguageCode::Swedish,
+            LanguageCode::Bulgarian,
+            LanguageCode::Romanian,
+            LanguageCode::Arabic,
+            LanguageCode::Czech,
+            LanguageCode::Greek,
+            LanguageCode::Finnish,
+            LanguageCode::Croatian,
+            LanguageCode::Malay,
+            LanguageCode::Slovak,
+            LanguageCode::Danish,
+            LanguageCode::Tamil,
+            LanguageCode::Ukrainian,
+            LanguageCode::Russian,
+            LanguageCode::Hungarian,
+            LanguageCode::Norwegian,
+            LanguageCode::Vietnamese,
+        ];
+
+        pub fn as_str(&self) -> &str {
+            match self {
+                LanguageCode::English => "en",
+                LanguageCode::Japanese => "ja",
+                LanguageCode::Chinese => "zh",
+                LanguageCode::German => "de",
+                LanguageCode::Hindi => "hi",
+                LanguageCode::French => "fr",
+                LanguageCode::Korean => "ko",
+                LanguageCode::Portuguese => "pt",
+                LanguageCode::Italian => "it",
+                LanguageCode::Spanish => "es",
+                LanguageCode::Indonesian => "id",
+                LanguageCode::Dutch => "nl",
+                LanguageCode::Turkish => "tr",
+                LanguageCode::Filipino => "fil",
+                LanguageCode::Polish => "pl",
+                LanguageCode::Swedish => "sv",
+                LanguageCode::Bulgarian => "bg",
+                LanguageCode::Romanian => "ro",
+                LanguageCode::Arabic => "ar",
+                LanguageCode::Czech => "cs",
+                LanguageCode::Greek => "el",
+                LanguageCode::Finnish => "fi",
+                LanguageCode::Croatian => "hr",
+                LanguageCode::Malay => "ms",
+                LanguageCode::Slovak => "sk",
+                LanguageCode::Danish => "da",
+                LanguageCode::Tamil => "ta",
+                LanguageCode::Ukrainian => "uk",
+                LanguageCode::Russian => "ru",
+                LanguageCode::Hungarian => "hu",
+                LanguageCode::Norwegian => "no",
+                LanguageCode::Vietnamese => "vi",
+                LanguageCode::Custom(code) => code,
+            }
+        }
+    }
+
+    impl From<&str> for LanguageCode {
+        fn from(code: &str) -> Self {
+            LanguageCode::KNOWN
+                .into_iter()
+                .find(|language| language.as_str().eq_ignore_ascii_case(code))
+                .unwrap_or_else(|| LanguageCode::Custom(code.to_string()))
+        }
+    }
+
+    impl std::fmt::Display for LanguageCode {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
+
+    impl Serialize for LanguageCode {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(self.as_str())
+        }
+    }
+
+    /// Defines an id type, made from any string so existing code keeps working,
+    /// but not from another id type
+    macro_rules! id_type {
+        ($(#[$attr:meta])* $name:ident) => {
+            $(#[$attr])*
+            #[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+            #[serde(transparent)]
+            pub struct $name(pub(crate) String);
+
+            impl $name {
+                pub fn as_str(&self) -> &str {
+                    &self.0
+                }
+            }
+
+            impl From<String> for $name {
+                fn from(id: String) -> Self {
+                    $name(id)
+                }
+            }
+
+            impl From<&String> for $name {
+                fn from(id: &String) -> Self {
+                    $name(id.clone())
+                }
+            }
+
+            impl From<&$name> for $name {
+                fn from(id: &$name) -> Self {
+                    id.clone()
+                }
+            }
+
+            impl From<&str> for $name {
+                fn from(id: &str) -> Self {
+                    $name(id.to_string())
+                }
+            }
+
+            impl std::fmt::Display for $name {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    f.write_str(&self.0)
+                }
+            }
+        };
+    }
+
+    id_type!(
+        /// The id of a voice, also made from a [PreMadeVoiceID]
+        ///
+        /// Endpoints taking a voice id accept strings as well as this type,
+        /// but not e.g. an [AgentID], so ids of different kinds cannot be mixed up.
+        ///
+        /// # Example
+        /// ```
+        /// use elevenlabs_rs::*;
+        ///
+        /// let voice_id = VoiceID::from("21m00Tcm4TlvDq8ikWAM");
+        /// assert_eq!(VoiceID::from(PreMadeVoiceID::Rachel), voice_id);
+        /// assert_eq!(voice_id.as_str(), "21m00Tcm4TlvDq8ikWAM");
+        /// ```
+        VoiceID
+    );
+
+    id_type!(
+        /// The id of a conversational AI agent
+        AgentID
+    );
+
+    id_type!(
+        /// The id of a conversation with an agent
+        ConversationID
+    );
+
+    impl From<PreMadeVoiceID> for VoiceID {
+        fn from(id: PreMadeVoiceID) -> Self {
+            VoiceID(id.into())
         }
     }
 
-    #[derive(Clone, Debug, Default)]
+    /// The premade voices available to every account
+    ///
+    /// Converts into a [VoiceID], so it can be passed wherever a voice id is taken.
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
     pub enum PreMadeVoiceID {
         Adam,
         Alice,
         Antoni,
+        Aria,
         Arnold,
         Bill,
         Brian,
         Callum,
         Charlie,
+        Charlotte,
         Chris,
         Clyde,
         Daniel,
@@ -74,6 +456,7 @@ pub mod identifiers {
         Domi,
         Eli,
         Emily,
+        Eric,
         Ethan,
         Fin,
         Freya,
@@ -84,10 +467,12 @@ pub mod identifiers {
         Grace,
         Harry,
         James,
+        Jessica,
         Jessie,
         Jeremy,
         Joseph,
         Josh,
+        Laura,
         Liam,
         Lily,
         Matilda,
@@ -98,60 +483,188 @@ pub mod identifiers {
         Paul,
         #[default]
         Rachel,
+        River,
+        Roger,
         Sam,
         Sarah,
         Serena,
         Thomas,
+        Will,
+    }
+
+    impl PreMadeVoiceID {
+        pub const ALL: [PreMadeVoiceID; 52] = [
+            PreMadeVoiceID::Adam,
+            PreMadeVoiceID::Alice,
+            PreMadeVoiceID::Antoni,
+            PreMadeVoiceID::Aria,
+            PreMadeVoiceID::Arnold,
+            PreMadeVoiceID::Bill,
+            PreMadeVoiceID::Brian,
+            PreMadeVoiceID::Callum,
+            PreMadeVoiceID::Charlie,
+            PreMadeVoiceID::Charlotte,
+            PreMadeVoiceID::Chris,
+            PreMadeVoiceID::Clyde,
+            PreMadeVoiceID::Daniel,
+            PreMadeVoiceID::Dave,
+            PreMadeVoiceID::Dorothy,
+            PreMadeVoiceID::Drew,
+            PreMadeVoiceID::Domi,
+            PreMadeVoiceID::Eli,
+            PreMadeVoiceID::Emily,
+            PreMadeVoiceID::Eric,
+            PreMadeVoiceID::Ethan,
+            PreMadeVoiceID::Fin,
+            PreMadeVoiceID::Freya,
+            PreMadeVoiceID::George,
+            PreMadeVoiceID::Gigi,
+            PreMadeVoiceID::Giovanni,
+            PreMadeVoiceID::Glinda,
+            PreMadeVoiceID::Grace,
+            PreMadeVoiceID::Harry,
+            PreMadeVoiceID::James,
+            PreMadeVoiceID::Jessica,
+            PreMadeVoiceID::Jessie,
+            PreMadeVoiceID::Jeremy,
+            PreMadeVoiceID::Joseph,
+            PreMadeVoiceID::Josh,
+            PreMadeVoiceID::Laura,
+            PreMadeVoiceID::Liam,
+            PreMadeVoiceID::Lily,
+            PreMadeVoiceID::Matilda,
+            PreMadeVoiceID::Michael,
+            PreMadeVoiceID::Mimi,
+            PreMadeVoiceID::Nicole,
+            PreMadeVoiceID::Patrick,
+            PreMadeVoiceID::Paul,
+            PreMadeVoiceID::Rachel,
+            PreMadeVoiceID::River,
+            PreMadeVoiceID::Roger,
+            PreMadeVoiceID::Sam,
+            PreMadeVoiceID::Sarah,
+            PreMadeVoiceID::Serena,
+            PreMadeVoiceID::Thomas,
+            PreMadeVoiceID::Will,
+        ];
+
+        pub fn voice_id(&self) -> &'static str {
+            match self {
+                PreMadeVoiceID::Adam => "pNInz6obpgDQGcFmaJgB",
+                PreMadeVoiceID::Alice => "Xb7hH8MSUJpSbSDYk0k2",
+                PreMadeVoiceID::Antoni => "ErXwobaYiN019PkySvjV",
+                PreMadeVoiceID::Aria => "9BWtsMINqrJLrRacOk9x",
+                PreMadeVoiceID::Arnold => "VR6AewLTigWG4xSOukaG",
+                PreMadeVoiceID::Bill => "pqHfZKP75CvOlQylNhV4",
+                PreMadeVoiceID::Brian => "nPczCjzI2devNBz1zQrb",
+                PreMadeVoiceID::Callum => "N2lVS1w4EtoT3dr4eOWO",
+                PreMadeVoiceID::Charlie => "IKne3meq5aSn9XLyUdCD",
+                PreMadeVoiceID::Charlotte => "XB0fDUnXU5powFXDhCwa",
+                PreMadeVoiceID::Chris => "iP95p4xoKVk53GoZ742B",
+                PreMadeVoiceID::Clyde => "2EiwWnXFnvU5JabPnv8n",
+                PreMadeVoiceID::Daniel => "onwK4e9ZLuTAKqWW03F9",
+                PreMadeVoiceID::Dave => "CYw3kZ02Hs0563khs1Fj",
+                PreMadeVoiceID::Dorothy => "ThT5KcBeYPX3keUQqHPh",
+                PreMadeVoiceID::Drew => "29vD33N1CtxCmqQRPOHJ",
+                PreMadeVoiceID::Domi => "AZnzlk1XvdvUeBnXmlld",
+                PreMadeVoiceID::Eli => "MF3mGyEYCl7XYWbV9V6O",
+                PreMadeVoiceID::Emily => "LcfcDJNUP1GQjkzn1xUU",
+                PreMadeVoiceID::Eric => "cjVigY5qzO86Huf0OWal",
+                PreMadeVoiceID::Ethan => "g5CIjZEefAph4nQFvHAz",
+                PreMadeVoiceID::Fin => "D38z5RcWu1voky8WS1ja",
+                PreMadeVoiceID::Freya => "jsCqWAovK2LkecY7zXl4",
+                PreMadeVoiceID::George => "JBFqnCBsd6RMkjVDRZzb",
+                PreMadeVoiceID::Gigi => "jBpfuIE2acCO8z3wKNLl",
+                PreMadeVoiceID::Giovanni => "zcAOhNBS3c14rBihAFp1",
+                PreMadeVoiceID::Glinda => "z9fAnlkpzviPz146aGWa",
+                PreMadeVoiceID::Grace => "oWAxZDx7w5VEj9dCyTzz",
+                PreMadeVoiceID::Harry => "SOYHLrjzK2X1ezoPC6cr",
+                PreMadeVoiceID::James => "ZQe5CZNOzWyzPSCn5a3c",
+                PreMadeVoiceID::Jessica => "cgSgspJ2msm6clMCkdW9",
+                PreMadeVoiceID::Jessie => "t0jbNlBVZ17f02VDIeMI",
+                PreMadeVoiceID::Jeremy => "bVMeCyTHy58xNoL34h3p",
+                PreMadeVoiceID::Joseph => "Zlb1dXrM653N07WRdFW3",
+                PreMadeVoiceID::Josh => "TxGEqnHWrfWFTfGW9XjX",
+                PreMadeVoiceID::Laura => "FGY2WhTYpPnrIDTdsKH5",
+                PreMadeVoiceID::Liam => "TX3LPaxmHKxFdv7VOQHJ",
+                PreMadeVoiceID::Lily => "pFZP5JQG7iQjIQuC4Bku",
+                PreMadeVoiceID::Matilda => "XrExE9yKIg1WjnnlVkGX",
+                PreMadeVoiceID::Michael => "flq6f7yk4E4fJM5XTYuZ",
+                PreMadeVoiceID::Mimi => "zrHiDhphv9ZnVXBqCLjz",
+                PreMadeVoiceID::Nicole => "piTKgcLEGmPE4e6mEKli",
+                PreMadeVoiceID::Patrick => "ODq5zmih8GrVes37Dizd",
+                PreMadeVoiceID::Paul => "5Q0t7uMcjvnagumLfvZi",
+                PreMadeVoiceID::Rachel => "21m00Tcm4TlvDq8ikWAM",
+                PreMadeVoiceID::River => "SAz9YHcvj6GT2YYXdXww",
+                PreMadeVoiceID::Roger => "CwhRBWXzGAHq8TQ4Fs17",
+                PreMadeVoiceID::Sam => "yoZ06aMxZJJ28mfd3POQ",
+                PreMadeVoiceID::Sarah => "EXAVITQu4vr4xnSDxMaL",
+                PreMadeVoiceID::Serena => "pMsXgVXv3BLzUgSXRplE",
+                PreMadeVoiceID::Thomas => "GBv7mTt0atIp3Br8iCZE",
+                PreMadeVoiceID::Will => "bIHbv24MWmeRgasZH58o",
+            }
+        }
+        pub fn name(&self) -> &'static str {
+            match self {
+                PreMadeVoiceID::Adam => "Adam",
+                PreMadeVoiceID::Alice => "Alice",
+                PreMadeVoiceID::Antoni => "Antoni",
+                PreMadeVoiceID::Aria => "Aria",
+                PreMadeVoiceID::Arnold => "Arnold",
+                PreMadeVoiceID::Bill => "Bill",
+                PreMadeVoiceID::Brian => "Brian",
+                PreMadeVoiceID::Callum => "Callum",
+                PreMadeVoiceID::Charlie => "Charlie",
+                PreMadeVoiceID::Charlotte => "Charlotte",
+                PreMadeVoiceID::Chris => "Chris",
+                PreMadeVoiceID::Clyde => "Clyde",
+                PreMadeVoiceID::Daniel => "Daniel",
+                PreMadeVoiceID::Dave => "Dave",
+                PreMadeVoiceID::Dorothy => "Dorothy",
+                PreMadeVoiceID::Drew => "Drew",
+                PreMadeVoiceID::Domi => "Domi",
+                PreMadeVoiceID::Eli => "Eli",
+                PreMadeVoiceID::Emily => "Emily",
+                PreMadeVoiceID::Eric => "Eric",
+                PreMadeVoiceID::Ethan => "Ethan",
+                PreMadeVoiceID::Fin => "Fin",
+                PreMadeVoiceID::Freya => "Freya",
+                PreMadeVoiceID::George => "George",
+                PreMadeVoiceID::Gigi => "Gigi",
+                PreMadeVoiceID::Giovanni => "Giovanni",
+                PreMadeVoiceID::Glinda => "Glinda",
+                PreMadeVoiceID::Grace => "Grace",
+                PreMadeVoiceID::Harry => "Harry",
+                PreMadeVoiceID::James => "James",
+                PreMadeVoiceID::Jessica => "Jessica",
+                PreMadeVoiceID::Jessie => "Jessie",
+                PreMadeVoiceID::Jeremy => "Jeremy",
+                PreMadeVoiceID::Joseph => "Joseph",
+                PreMadeVoiceID::Josh => "Josh",
+                PreMadeVoiceID::Laura => "Laura",
+                PreMadeVoiceID::Liam => "Liam",
+                PreMadeVoiceID::Lily => "Lily",
+                PreMadeVoiceID::Matilda => "Matilda",
+                PreMadeVoiceID::Michael => "Michael",
+                PreMadeVoiceID::Mimi => "Mimi",
+                PreMadeVoiceID::Nicole => "Nicole",
+                PreMadeVoiceID::Patrick => "Patrick",
+                PreMadeVoiceID::Paul => "Paul",
+                PreMadeVoiceID::Rachel => "Rachel",
+                PreMadeVoiceID::River => "River",
+                PreMadeVoiceID::Roger => "Roger",
+                PreMadeVoiceID::Sam => "Sam",
+                PreMadeVoiceID::Sarah => "Sarah",
+                PreMadeVoiceID::Serena => "Serena",
+                PreMadeVoiceID::Thomas => "Thomas",
+                PreMadeVoiceID::Will => "Will",
+            }
+        }
     }
 
     impl From<PreMadeVoiceID> for String {
         fn from(id: PreMadeVoiceID) -> String {
-            match id {
-                PreMadeVoiceID::Adam => "pNInz6obpgDQGcFmaJgB".to_string(),
-                PreMadeVoiceID::Alice => "Xb7hH8MSUJpSbSDYk0k2".to_string(),
-                PreMadeVoiceID::Antoni => "ErXwobaYiN019PkySvjV".to_string(),
-                PreMadeVoiceID::Arnold => "VR6AewLTigWG4xSOukaG".to_string(),
-                PreMadeVoiceID::Bill => "pqHfZKP75CvOlQylNhV4".to_string(),
-                PreMadeVoiceID::Brian => "nPczCjzI2devNBz1zQrb".to_string(),
-                PreMadeVoiceID::Callum => "N2lVS1w4EtoT3dr4eOWO".to_string(),
-                PreMadeVoiceID::Charlie => "IKne3meq5aSn9XLyUdCD".to_string(),
-                PreMadeVoiceID::Chris => "iP95p4xoKVk53GoZ742B".to_string(),
-                PreMadeVoiceID::Clyde => "2EiwWnXFnvU5JabPnv8n".to_string(),
-                PreMadeVoiceID::Daniel => "onwK4e9ZLuTAKqWW03F9".to_string(),
-                PreMadeVoiceID::Dave => "CYw3kZ02Hs0563khs1Fj".to_string(),
-                PreMadeVoiceID::Dorothy => "ThT5KcBeYPX3keUQqHPh".to_string(),
-                PreMadeVoiceID::Drew => "29vD33N1CtxCmqQRPOHJ".to_string(),
-                PreMadeVoiceID::Domi => "AZnzlk1XvdvUeBnXmlld".to_string(),
-                PreMadeVoiceID::Eli => "MF3mGyEYCl7XYWbV9V6O".to_string(),
-                PreMadeVoiceID::Emily => "LcfcDJNUP1GQjkzn1xUU".to_string(),
-                PreMadeVoiceID::Ethan => "g5CIjZEefAph4nQFvHAz".to_string(),
-                PreMadeVoiceID::Fin => "D38z5RcWu1voky8WS1ja".to_string(),
-                PreMadeVoiceID::Freya => "jsCqWAovK2LkecY7zXl4".to_string(),
-                PreMadeVoiceID::George => "JBFqnCBsd6RMkjVDRZzb".to_string(),
-                PreMadeVoiceID::Gigi => "jBpfuIE2acCO8z3wKNLl".to_string(),
-                PreMadeVoiceID::Giovanni => "zcAOhNBS3c14rBihAFp1".to_string(),
-                PreMadeVoiceID::Glinda => "z9fAnlkpzviPz146aGWa".to_string(),
-                PreMadeVoiceID::Grace => "oWAxZDx7w5VEj9dCyTzz".to_string(),
-                PreMadeVoiceID::Harry => "SOYHLrjzK2X1ezoPC6cr".to_string(),
-                PreMadeVoiceID::James => "ZQe5CZNOzWyzPSCn5a3c".to_string(),
-                PreMadeVoiceID::Jessie => "t0jbNlBVZ17f02VDIeMI".to_string(),
-                PreMadeVoiceID::Jeremy => "bVMeCyTHy58xNoL34h3p".to_string(),
-                PreMadeVoiceID::Joseph => "Zlb1dXrM653N07WRdFW3".to_string(),
-                PreMadeVoiceID::Josh => "TxGEqnHWrfWFTfGW9XjX".to_string(),
-                PreMadeVoiceID::Liam => "TX3LPaxmHKxFdv7VOQHJ".to_string(),
-                PreMadeVoiceID::Lily => "pFZP5JQG7iQjIQuC4Bku".to_string(),
-                PreMadeVoiceID::Matilda => "XrExE9yKIg1WjnnlVkGX".to_string(),
-                PreMadeVoiceID::Michael => "flq6f7yk4E4fJM5XTYuZ".to_string(),
-                PreMadeVoiceID::Mimi => "zrHiDhphv9ZnVXBqCLjz".to_string(),
-                PreMadeVoiceID::Nicole => "piTKgcLEGmPE4e6mEKli".to_string(),
-                PreMadeVoiceID::Patrick => "ODq5zmih8GrVes37Dizd".to_string(),
-                PreMadeVoiceID::Paul => "5Q0t7uMcjvnagumLfvZi".to_string(),
-                PreMadeVoiceID::Rachel => "21m00Tcm4TlvDq8ikWAM".to_string(),
-                PreMadeVoiceID::Sam => "yoZ06aMxZJJ28mfd3POQ".to_string(),
-                PreMadeVoiceID::Sarah => "EXAVITQu4vr4xnSDxMaL".to_string(),
-                PreMadeVoiceID::Serena => "pMsXgVXv3BLzUgSXRplE".to_string(),
-                PreMadeVoiceID::Thomas => "GBv7mTt0atIp3Br8iCZE".to_string(),
-            }
+            id.voice_id().to_string()
         }
     }
 }
@@ -163,6 +676,45 @@ pub(crate) mod path_segments {
     pub(crate) const VOICES_PATH: &str = "/v1/voices";
 }
 
+#[cfg(feature = "chrono")]
+pub(crate) mod time {
+    use chrono::{DateTime, Utc};
+
+    /// A unix timestamp in seconds as a date, the epoch if it is out of range
+    pub(crate) fn utc<T: TryInto<i64>>(secs: T) -> DateTime<Utc> {
+        secs.try_into()
+            .ok()
+            .and_then(|secs| DateTime::from_timestamp(secs, 0))
+            .unwrap_or_default()
+    }
+}
+
+pub(crate) mod extras {
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer};
+    use serde_json::{Map, Value};
+
+    tokio::task_local! {
+        /// Set while the client reads a response, see
+        /// [ElevenLabsClient::with_strict_responses](crate::ElevenLabsClient::with_strict_responses)
+        pub(crate) static STRICT: bool;
+    }
+
+    /// Collects the fields of a response this version of the crate does not know about,
+    /// with strict responses they fail the deserialization instead
+    pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<Map<String, Value>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let extra = Map::deserialize(deserializer)?;
+        if STRICT.try_with(|strict| *strict).unwrap_or(false) && !extra.is_empty() {
+            let fields = extra.keys().cloned().collect::<Vec<_>>().join(", ");
+            return Err(D::Error::custom(format!("unknown fields: {}", fields)));
+        }
+        Ok(extra)
+    }
+}
+
 pub mod response_bodies {
     use serde::Deserialize;
     #[derive(Clone, Debug, Deserialize)]
@@ -172,6 +724,11 @@ pub mod response_bodies {
 }
 
 pub mod query_params {
+    use crate::error::Error;
+    use serde::{Deserialize, Serialize};
+    use std::fmt;
+    use std::str::FromStr;
+
     #[derive(Clone, Debug)]
     pub enum Latency {
         /// Default latency
@@ -188,33 +745,363 @@ pub mod query_params {
     }
 
     /// See Elevenlabs documentation on [supported output formats](https://help.elevenlabs.io/hc/en-us/articles/15754340124305-What-audio-formats-do-you-support).
-    #[derive(Clone, Debug)]
+    ///
+    /// Some formats are only available on higher tiers, e.g. `mp3_44100_192` requires Creator
+    /// and `pcm_44100` requires Pro.
+    ///
+    /// # Example
+    /// ```
+    /// use elevenlabs_rs::*;
+    ///
+    /// let format: OutputFormat = "pcm_16000".parse().unwrap();
+    /// assert_eq!(format, OutputFormat::Pcm16000Hz);
+    /// assert_eq!(format.codec(), AudioCodec::Pcm);
+    /// assert_eq!(format.sample_rate(), 16_000);
+    /// assert_eq!(OutputFormat::Mp3_44100Hz128kbps.bitrate(), Some(128));
+    /// ```
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
     pub enum OutputFormat {
+        #[serde(rename = "mp3_22050_32")]
         Mp3_22050Hz32kbps,
+        #[serde(rename = "mp3_24000_48")]
+        Mp3_24000Hz48kbps,
+        #[serde(rename = "mp3_44100_32")]
         Mp3_44100Hz32kbps,
+        #[serde(rename = "mp3_44100_64")]
         Mp3_44100Hz64kbps,
+        #[serde(rename = "mp3_44100_96")]
         Mp3_44100Hz96kbps,
+        /// The default of most endpoints
+        #[serde(rename = "mp3_44100_128")]
+        Mp3_44100Hz128kbps,
+        #[serde(rename = "mp3_44100_192")]
         Mp3_44100Hz192kbps,
+        #[serde(rename = "pcm_8000")]
+        Pcm8000Hz,
+        #[serde(rename = "pcm_16000")]
         Pcm16000Hz,
+        #[serde(rename = "pcm_22050")]
         Pcm22050Hz,
+        #[serde(rename = "pcm_24000")]
         Pcm24000Hz,
+        #[serde(rename = "pcm_44100")]
         Pcm44100Hz,
+        #[serde(rename = "pcm_48000")]
+        Pcm48000Hz,
+        /// The format used by Twilio
+        #[serde(rename = "ulaw_8000")]
         MuLaw8000Hz,
+        #[serde(rename = "alaw_8000")]
+        ALaw8000Hz,
+        #[serde(rename = "opus_48000_32")]
+        Opus48000Hz32kbps,
+        #[serde(rename = "opus_48000_64")]
+        Opus48000Hz64kbps,
+        #[serde(rename = "opus_48000_96")]
+        Opus48000Hz96kbps,
+        #[serde(rename = "opus_48000_128")]
+        Opus48000Hz128kbps,
+        #[serde(rename = "opus_48000_192")]
+        Opus48000Hz192kbps,
     }
+
     impl OutputFormat {
-        pub(crate) fn to_query(&self) -> &str {
+        const ALL: [OutputFormat; 20] = [
+            OutputFormat::Mp3_22050Hz32kbps,
+            OutputFormat::Mp3_24000Hz48kbps,
+            OutputFormat::Mp3_44100Hz32kbps,
+            OutputFormat::Mp3_44100Hz64kbps,
+            OutputFormat::Mp3_44100Hz96kbps,
+            OutputFormat::Mp3_44100Hz128kbps,
+            OutputFormat::Mp3_44100Hz192kbps,
+            OutputFormat::Pcm8000Hz,
+            OutputFormat::Pcm16000Hz,
+            OutputFormat::Pcm22050Hz,
+            OutputFormat::Pcm24000Hz,
+            OutputFormat::Pcm44100Hz,
+            OutputFormat::Pcm48000Hz,
+            OutputFormat::MuLaw8000Hz,
+            OutputFormat::ALaw8000Hz,
+            OutputFormat::Opus48000Hz32kbps,
+            OutputFormat::Opus48000Hz64kbps,
+            OutputFormat::Opus48000Hz96kbps,
+            OutputFormat::Opus48000Hz128kbps,
+            OutputFormat::Opus48000Hz192kbps,
+        ];
+
+        /// The name of the format in the API, e.g. `mp3_44100_128`
+        pub fn as_str(&self) -> &'static str {
             match self {
-                OutputFormat::Pcm16000Hz => "pcm_16000",
-                OutputFormat::Pcm22050Hz => "pcm_22050",
-                OutputFormat::Pcm24000Hz => "pcm_24000",
-                OutputFormat::Pcm44100Hz => "pcm_44100",
                 OutputFormat::Mp3_22050Hz32kbps => "mp3_22050_32",
+                OutputFormat::Mp3_24000Hz48kbps => "mp3_24000_48",
                 OutputFormat::Mp3_44100Hz32kbps => "mp3_44100_32",
                 OutputFormat::Mp3_44100Hz64kbps => "mp3_44100_64",
                 OutputFormat::Mp3_44100Hz96kbps => "mp3_44100_96",
+                OutputFormat::Mp3_44100Hz128kbps => "mp3_44100_128",
                 OutputFormat::Mp3_44100Hz192kbps => "mp3_44100_192",
+                OutputFormat::Pcm8000Hz => "pcm_8000",
+                OutputFormat::Pcm16000Hz => "pcm_16000",
+                OutputFormat::Pcm22050Hz => "pcm_22050",
+                OutputFormat::Pcm24000Hz => "pcm_24000",
+                OutputFormat::Pcm44100Hz => "pcm_44100",
+                OutputFormat::Pcm48000Hz => "pcm_48000",
                 OutputFormat::MuLaw8000Hz => "ulaw_8000",
+                OutputFormat::ALaw8000Hz => "alaw_8000",
+                OutputFormat::Opus48000Hz32kbps => "opus_48000_32",
+                OutputFormat::Opus48000Hz64kbps => "opus_48000_64",
+                OutputFormat::Opus48000Hz96kbps => "opus_48000_96",
+                OutputFormat::Opus48000Hz128kbps => "opus_48000_128",
+                OutputFormat::Opus48000Hz192kbps => "opus_48000_192",
+            }
+        }
+        pub fn codec(&self) -> AudioCodec {
+            match self.as_str().split('_').next() {
+                Some("mp3") => AudioCodec::Mp3,
+                Some("pcm") => AudioCodec::Pcm,
+                Some("ulaw") => AudioCodec::MuLaw,
+                Some("alaw") => AudioCodec::ALaw,
+                _ => AudioCodec::Opus,
+            }
+        }
+        /// In Hz
+        pub fn sample_rate(&self) -> u32 {
+            self.as_str()
+                .split('_')
+                .nth(1)
+                .and_then(|rate| rate.parse().ok())
+                .unwrap_or_default()
+        }
+        /// In kbps, only compressed formats have a fixed bitrate
+        pub fn bitrate(&self) -> Option<u32> {
+            self.as_str()
+                .split('_')
+                .nth(2)
+                .and_then(|bitrate| bitrate.parse().ok())
+        }
+    }
+
+    impl fmt::Display for OutputFormat {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(self.as_str())
+        }
+    }
+
+    impl FromStr for OutputFormat {
+        type Err = Error;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            OutputFormat::ALL
+                .into_iter()
+                .find(|format| format.as_str() == s)
+                .ok_or_else(|| Error::UnknownOutputFormat(s.to_string()))
+        }
+    }
+
+    /// The codec of an [OutputFormat]
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub enum AudioCodec {
+        Mp3,
+        /// 16-bit signed little endian mono samples, without a header
+        Pcm,
+        /// G.711 μ-law
+        MuLaw,
+        /// G.711 A-law
+        ALaw,
+        /// In an Ogg container
+        Opus,
+    }
+}
+
+pub mod upload {
+    use crate::client::Result;
+    use crate::error::Error;
+    use bytes::Bytes;
+    use futures_util::stream;
+    use reqwest::multipart::Part;
+    use reqwest::Body;
+    use std::fmt;
+    use std::path::{Path, PathBuf};
+    use std::sync::{Arc, Mutex};
+    use tokio::io::{AsyncRead, AsyncReadExt};
+
+    const CHUNK_SIZE: usize = 64 * 1024;
+
+    type BoxedReader = Box<dyn AsyncRead + Send + Unpin>;
+
+    /// A file to upload
+    ///
+    /// Files on disk and readers are streamed into the request rather than read into memory.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use elevenlabs_rs::*;
+    /// use elevenlabs_rs::endpoints::sts::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     let c = ElevenLabsClient::default()?;
+    ///     let file = tokio::fs::File::open("recording.wav").await?;
+    ///     let len = file.metadata().await?.len();
+    ///     let audio = FileSource::from_reader(file, "recording.wav").with_length(len);
+    ///     let body = SpeechToSpeechBody::new(audio);
+    ///     let speech = c.hit(SpeechToSpeech::new(PreMadeVoiceID::Sam, body)).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[derive(Clone)]
+    pub struct FileSource(Source);
+
+    #[derive(Clone)]
+    enum Source {
+        Path(PathBuf),
+        Bytes {
+            bytes: Bytes,
+            file_name: String,
+        },
+        Reader {
+            // Taken on the first request, so a reader can only be sent once
+            reader: Arc<Mutex<Option<BoxedReader>>>,
+            file_name: String,
+            length: Option<u64>,
+        },
+    }
+
+    impl FileSource {
+        pub fn from_path<P: AsRef<Path>>(path: P) -> Self {
+            FileSource(Source::Path(path.as_ref().to_path_buf()))
+        }
+        pub fn from_bytes<B: Into<Bytes>>(bytes: B, file_name: &str) -> Self {
+            FileSource(Source::Bytes {
+                bytes: bytes.into(),
+                file_name: file_name.to_string(),
+            })
+        }
+        /// The file name is used to infer the mime type of the upload.
+        ///
+        /// A reader can only be sent once, so requests with a reader are neither retried
+        /// nor sent again with another [api key](crate::ApiKeys).
+        pub fn from_reader<R>(reader: R, file_name: &str) -> Self
+        where
+            R: AsyncRead + Send + Unpin + 'static,
+        {
+            FileSource(Source::Reader {
+                reader: Arc::new(Mutex::new(Some(Box::new(reader)))),
+                file_name: file_name.to_string(),
+                length: None,
+            })
+        }
+        /// The length of a reader in bytes, when known it is sent as the part's content length
+        pub fn with_length(mut self, len: u64) -> Self {
+            if let Source::Reader { length, .. } = &mut self.0 {
+                *length = Some(len);
+            }
+            self
+        }
+
+        pub fn file_name(&self) -> Result<&str> {
+            match &self.0 {
+                Source::Path(path) => Ok(path.to_str().ok_or(Box::new(Error::PathNotValidUTF8))?),
+                Source::Bytes { file_name, .. } | Source::Reader { file_name, .. } => Ok(file_name),
+            }
+        }
+
+        pub fn extension(&self) -> Result<&str> {
+            let file_name = self.file_name()?;
+            Ok(Path::new(file_name)
+                .extension()
+                .ok_or(Box::new(Error::FileExtensionNotFound))?
+                .to_str()
+                .ok_or(Box::new(Error::FileExtensionNotValidUTF8))?)
+        }
+
+        /// The file as a part of a [multipart form](reqwest::multipart::Form),
+        /// e.g. for a custom [Endpoint](crate::endpoints::Endpoint)
+        pub fn to_part(&self) -> Result<Part> {
+            let file_name = self.file_name()?.to_string();
+            let part = match &self.0 {
+                Source::Path(path) => {
+                    let file = std::fs::File::open(path)?;
+                    let length = file.metadata()?.len();
+                    let body = reader_body(Box::new(tokio::fs::File::from_std(file)));
+                    Part::stream_with_length(body, length)
+                }
+                Source::Bytes { bytes, .. } => Part::bytes(bytes.to_vec()),
+                Source::Reader { reader, length, .. } => {
+                    let reader = reader
+                        .lock()
+                        .map_err(|_| "File source reader lock poisoned")?
+                        .take()
+                        .ok_or(Error::ReaderAlreadyConsumed)?;
+                    match length {
+                        Some(length) => Part::stream_with_length(reader_body(reader), *length),
+                        None => Part::stream(reader_body(reader)),
+                    }
+                }
+            };
+            Ok(part.file_name(file_name))
+        }
+    }
+
+    fn reader_body(reader: BoxedReader) -> Body {
+        let chunks = stream::try_unfold(reader, |mut reader| async move {
+            let mut buf = vec![0; CHUNK_SIZE];
+            let n = reader.read(&mut buf).await?;
+            if n == 0 {
+                return Ok::<_, std::io::Error>(None);
             }
+            buf.truncate(n);
+            Ok(Some((Bytes::from(buf), reader)))
+        });
+        Body::wrap_stream(chunks)
+    }
+
+    impl fmt::Debug for FileSource {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match &self.0 {
+                Source::Path(path) => f.debug_tuple("Path").field(path).finish(),
+                Source::Bytes { bytes, file_name } => f
+                    .debug_struct("Bytes")
+                    .field("file_name", file_name)
+                    .field("len", &bytes.len())
+                    .finish(),
+                Source::Reader {
+                    file_name, length, ..
+                } => f
+                    .debug_struct("Reader")
+                    .field("file_name", file_name)
+                    .field("length", length)
+                    .finish(),
+            }
+        }
+    }
+
+    impl From<&str> for FileSource {
+        fn from(path: &str) -> Self {
+            FileSource::from_path(path)
+        }
+    }
+
+    impl From<String> for FileSource {
+        fn from(path: String) -> Self {
+            FileSource(Source::Path(PathBuf::from(path)))
+        }
+    }
+
+    impl From<&String> for FileSource {
+        fn from(path: &String) -> Self {
+            FileSource::from_path(path)
+        }
+    }
+
+    impl From<PathBuf> for FileSource {
+        fn from(path: PathBuf) -> Self {
+            FileSource(Source::Path(path))
+        }
+    }
+
+    impl From<&Path> for FileSource {
+        fn from(path: &Path) -> Self {
+            FileSource::from_path(path)
         }
     }
 }
@@ -0,0 +1,70 @@
+//! Text to speech in one line, for scripts
+use crate::client::{ElevenLabsClient, Result};
+use crate::endpoints::tts::{TextToSpeech, TextToSpeechBody};
+use crate::endpoints::voice::GetVoiceIDByName;
+use bytes::Bytes;
+
+/// Speech generated with a voice looked up by its name
+///
+/// A shortcut over [GetVoiceIDByName] and [TextToSpeech] for when the defaults will do,
+/// use the endpoints for anything more.
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let speech = Speech::new("Hello there", "Sarah", Model::ElevenMultilingualV2).await?;
+///     speech.play()?;
+///     speech.save("hello.mp3")?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Speech {
+    audio: Bytes,
+}
+
+impl Speech {
+    /// Reads the api key from the `ELEVEN_API_KEY` environment variable
+    ///
+    /// Fails with [Error::VoiceNotFound](crate::error::Error::VoiceNotFound) if no voice
+    /// of the account is named `voice_name`.
+    pub async fn new<M: Into<String>>(text: &str, voice_name: &str, model: M) -> Result<Self> {
+        let client = ElevenLabsClient::default()?;
+        Self::with_client(&client, text, voice_name, model).await
+    }
+
+    /// Like [Speech::new] with a configured client
+    pub async fn with_client<M: Into<String>>(
+        client: &ElevenLabsClient,
+        text: &str,
+        voice_name: &str,
+        model: M,
+    ) -> Result<Self> {
+        let voice_id = client.hit(GetVoiceIDByName::new(voice_name)).await?;
+        let body = TextToSpeechBody::new(text, model);
+        let audio = client.hit(TextToSpeech::new(voice_id, body)).await?;
+        Ok(Speech { audio })
+    }
+
+    /// Plays the speech, blocking the current thread until it ends
+    #[cfg(feature = "playback")]
+    pub fn play(&self) -> Result<()> {
+        crate::utils::play(self.audio.clone())
+    }
+
+    /// Saves the speech as an MP3 file
+    pub fn save(&self, filename: &str) -> Result<()> {
+        crate::utils::save(filename, self.audio.clone())
+    }
+
+    pub fn bytes(&self) -> &Bytes {
+        &self.audio
+    }
+
+    pub fn into_bytes(self) -> Bytes {
+        self.audio
+    }
+}
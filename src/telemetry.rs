@@ -0,0 +1,120 @@
+//! Spans for requests and websocket connections
+//!
+//! With the `tracing` feature every request made by [ElevenLabsClient](crate::ElevenLabsClient)
+//! and every websocket handshake is recorded in a `tracing` span, with field names following
+//! the OpenTelemetry HTTP conventions. Without the feature the spans compile to nothing.
+#[cfg(not(feature = "tracing"))]
+pub(crate) use disabled::Span;
+#[cfg(feature = "tracing")]
+pub(crate) use enabled::Span;
+
+#[cfg(feature = "tracing")]
+mod enabled {
+    use http::{HeaderMap, Method, StatusCode};
+    use std::fmt::Display;
+    use std::future::Future;
+    use std::time::Instant;
+    use tracing::field::Empty;
+    use tracing::Instrument;
+
+    const REQUEST_ID_HEADER: &str = "request-id";
+
+    pub(crate) struct Span {
+        span: tracing::Span,
+        start: Instant,
+    }
+
+    impl Span {
+        pub(crate) fn request(method: &Method, path: &str) -> Self {
+            let span = tracing::info_span!(
+                "elevenlabs.request",
+                otel.kind = "client",
+                otel.status_code = Empty,
+                http.request.method = method.as_str(),
+                url.path = path,
+                http.response.status_code = Empty,
+                latency_ms = Empty,
+                request_id = Empty,
+                error = Empty,
+            );
+            Span {
+                span,
+                start: Instant::now(),
+            }
+        }
+
+        pub(crate) fn websocket(path: &str) -> Self {
+            let span = tracing::info_span!(
+                "elevenlabs.websocket",
+                otel.kind = "client",
+                otel.status_code = Empty,
+                url.path = path,
+                http.response.status_code = Empty,
+                latency_ms = Empty,
+                request_id = Empty,
+                error = Empty,
+            );
+            Span {
+                span,
+                start: Instant::now(),
+            }
+        }
+
+        pub(crate) fn instrument<F: Future>(&self, fut: F) -> impl Future<Output = F::Output> {
+            fut.instrument(self.span.clone())
+        }
+
+        pub(crate) fn record_response(&self, status: StatusCode, headers: &HeaderMap) {
+            self.record_latency();
+            self.span
+                .record("http.response.status_code", status.as_u16());
+            if let Some(id) = headers.get(REQUEST_ID_HEADER).and_then(|v| v.to_str().ok()) {
+                self.span.record("request_id", id);
+            }
+            if status.is_client_error() || status.is_server_error() {
+                self.span.record("otel.status_code", "ERROR");
+            }
+        }
+
+        pub(crate) fn record_error(&self, error: &dyn Display) {
+            self.record_latency();
+            self.span.record("otel.status_code", "ERROR");
+            self.span.record("error", tracing::field::display(error));
+        }
+
+        fn record_latency(&self) {
+            let latency = self.start.elapsed().as_millis() as u64;
+            self.span.record("latency_ms", latency);
+        }
+    }
+}
+
+#[cfg(not(feature = "tracing"))]
+mod disabled {
+    use http::{HeaderMap, Method, StatusCode};
+    use std::fmt::Display;
+    use std::future::Future;
+
+    pub(crate) struct Span;
+
+    impl Span {
+        pub(crate) fn request(_method: &Method, _path: &str) -> Self {
+            Span
+        }
+        pub(crate) fn websocket(_path: &str) -> Self {
+            Span
+        }
+        pub(crate) fn instrument<F: Future>(&self, fut: F) -> F {
+            fut
+        }
+        pub(crate) fn record_response(&self, _status: StatusCode, _headers: &HeaderMap) {}
+        pub(crate) fn record_error(&self, _error: &dyn Display) {}
+    }
+}
+
+/// The path of a websocket url, never its query which may hold a token
+pub(crate) fn ws_path(url: &str) -> String {
+    url.parse::<reqwest::Url>()
+        .map(|url| url.path().to_string())
+        .unwrap_or_default()
+}
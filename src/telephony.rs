@@ -0,0 +1,487 @@
+//! Phone calls with conversational AI agents over Twilio media streams
+//!
+//! A [TwilioBridge] connects the websocket of a bidirectional Twilio `<Stream>` to a
+//! [Conversation](crate::endpoints::convai::client::Conversation).
+//! The caller's μ-law 8kHz audio is converted to the agent's input format, the agent's speech
+//! is converted back and played to the caller, and Twilio's buffered audio is cleared
+//! when the caller interrupts the agent.
+//!
+//! The audio is only converted if needed, an agent whose input and output formats are
+//! `ulaw_8000` is bridged as is.
+//!
+//! See the [Twilio docs](https://www.twilio.com/docs/voice/media-streams/websocket-messages) for more information.
+//!
+//! # Example
+//! ```no_run
+//! use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+//! use axum::response::Response;
+//! use axum::routing::get;
+//! use axum::Router;
+//! use elevenlabs_rs::*;
+//! use elevenlabs_rs::endpoints::convai::client::Conversation;
+//! use elevenlabs_rs::telephony::TwilioBridge;
+//! use futures_util::SinkExt;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let app = Router::new().route("/call/connection", get(connection));
+//!     let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await.unwrap();
+//!     axum::serve(listener, app).await.unwrap();
+//! }
+//!
+//! async fn connection(ws: WebSocketUpgrade) -> Response {
+//!     ws.on_upgrade(|socket| async move {
+//!         if let Err(e) = bridge(socket).await {
+//!             eprintln!("call failed: {}", e);
+//!         }
+//!     })
+//! }
+//!
+//! async fn bridge(socket: WebSocket) -> Result<()> {
+//!     let c = ElevenLabsClient::default()?;
+//!     let (tx, rx) = socket.split();
+//!     let incoming = rx.filter_map(|msg| async move {
+//!         match msg {
+//!             Ok(Message::Text(text)) => Some(text),
+//!             _ => None,
+//!         }
+//!     });
+//!     let outgoing = tx.with(|text: String| async move { Ok::<_, axum::Error>(Message::Text(text)) });
+//!     TwilioBridge::new(&c, Conversation::builder("agent_id"))
+//!         .run_split(incoming, outgoing, &mut ())
+//!         .await
+//! }
+//! ```
+use crate::client::{ElevenLabsClient, Result};
+use crate::endpoints::convai::client::{dispatch, ConversationBuilder, ConversationHandler};
+use crate::endpoints::convai::server_messages::ServerMessage;
+use crate::error::TelephonyError;
+use crate::shared::query_params::OutputFormat;
+use crate::utils::Transcoder;
+use base64::prelude::{Engine, BASE64_STANDARD};
+use bytes::Bytes;
+use futures_util::{pin_mut, Sink, SinkExt, Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio_tungstenite::tungstenite::protocol::Message;
+use tokio_tungstenite::WebSocketStream;
+
+/// The format of the audio of a Twilio media stream
+pub const TWILIO_AUDIO_FORMAT: OutputFormat = OutputFormat::MuLaw8000Hz;
+/// The input format of agents that do not state one
+const DEFAULT_USER_INPUT_FORMAT: OutputFormat = OutputFormat::Pcm16000Hz;
+const INBOUND_TRACK: &str = "inbound";
+
+type StartHook = Box<dyn FnOnce(&StreamStart, ConversationBuilder) -> ConversationBuilder + Send>;
+
+/// Bridges a Twilio media stream to a conversation with an agent
+///
+/// The conversation is started once Twilio sends the `start` event of the stream,
+/// and ends when either the call or the conversation ends.
+pub struct TwilioBridge {
+    client: ElevenLabsClient,
+    builder: ConversationBuilder,
+    on_start: Option<StartHook>,
+}
+
+impl TwilioBridge {
+    pub fn new(client: &ElevenLabsClient, builder: ConversationBuilder) -> Self {
+        TwilioBridge {
+            client: client.clone(),
+            builder,
+            on_start: None,
+        }
+    }
+    /// Adjusts the conversation before it starts, e.g. to pass the caller's number
+    /// from the stream's [custom parameters](StreamStart::custom_parameters) as a dynamic variable
+    pub fn with_on_start<F>(mut self, on_start: F) -> Self
+    where
+        F: FnOnce(&StreamStart, ConversationBuilder) -> ConversationBuilder + Send + 'static,
+    {
+        self.on_start = Some(Box::new(on_start));
+        self
+    }
+
+    /// Bridges a media stream accepted with `tokio-tungstenite`
+    pub async fn run<S, H>(self, ws: WebSocketStream<S>, handler: &mut H) -> Result<()>
+    where
+        S: AsyncRead + AsyncWrite + Unpin,
+        H: ConversationHandler,
+    {
+        let (tx, rx) = ws.split();
+        let incoming = rx.filter_map(|msg| async move {
+            match msg {
+                Ok(Message::Text(text)) => Some(text),
+                _ => None,
+            }
+        });
+        let outgoing = tx.with(|text: String| async move {
+            Ok::<_, tokio_tungstenite::tungstenite::Error>(Message::Text(text))
+        });
+        self.run_split(incoming, outgoing, handler).await
+    }
+
+    /// Bridges a media stream given as its incoming and outgoing text frames
+    ///
+    /// Every message of the conversation is also passed to the handler.
+    pub async fn run_split<I, O, H>(self, incoming: I, outgoing: O, handler: &mut H) -> Result<()>
+    where
+        I: Stream<Item = String>,
+        O: Sink<String>,
+        O::Error: std::error::Error + Send + Sync + 'static,
+        H: ConversationHandler,
+    {
+        pin_mut!(incoming);
+        pin_mut!(outgoing);
+
+        let start = loop {
+            let Some(frame) = incoming.next().await else {
+                return Err(Box::new(TelephonyError::StreamEndedBeforeStart));
+            };
+            if let TwilioEvent::Start(start) = serde_json::from_str(&frame)? {
+                break start;
+            }
+        };
+        let builder = match self.on_start {
+            Some(on_start) => on_start(&start, self.builder),
+            None => self.builder,
+        };
+        let mut conversation = builder.connect(&self.client).await?;
+        let mut call = Call::new(start.stream_sid());
+
+        let result = loop {
+            tokio::select! {
+                frame = incoming.next() => {
+                    let Some(frame) = frame else { break Ok(()) };
+                    match serde_json::from_str(&frame)? {
+                        TwilioEvent::Media(media) if media.track() == INBOUND_TRACK => {
+                            if let Some(audio) = call.user_audio(media.audio_as_bytes()?) {
+                                conversation.send_audio(&audio)?;
+                            }
+                        }
+                        TwilioEvent::Mark(mark) => {
+                            call.pending_marks.remove(mark.name());
+                        }
+                        TwilioEvent::Stop(_) => break Ok(()),
+                        _ => {}
+                    }
+                }
+                msg = conversation.next() => {
+                    let Some(msg) = msg else { break Ok(()) };
+                    let msg = msg?;
+                    for reply in call.on_message(&msg)? {
+                        outgoing.send(reply.json()?).await?;
+                    }
+                    if let Some(audio) = call.flush_user_audio() {
+                        conversation.send_audio(&audio)?;
+                    }
+                    dispatch(handler, &msg);
+                }
+            }
+        };
+        conversation.close();
+        outgoing.close().await?;
+        result
+    }
+}
+
+impl fmt::Debug for TwilioBridge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TwilioBridge")
+            .field("builder", &self.builder)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The state of a bridged call
+struct Call {
+    stream_sid: String,
+    /// Created once the conversation's formats are known
+    converters: Option<Converters>,
+    /// The caller's audio received before that
+    buffered_user_audio: Vec<u8>,
+    /// Marks sent after the agent's audio that Twilio has not played yet
+    pending_marks: HashSet<String>,
+}
+
+struct Converters {
+    user: Option<Transcoder>,
+    agent: Option<Transcoder>,
+}
+
+impl Call {
+    fn new(stream_sid: &str) -> Self {
+        Call {
+            stream_sid: stream_sid.to_string(),
+            converters: None,
+            buffered_user_audio: Vec::new(),
+            pending_marks: HashSet::new(),
+        }
+    }
+
+    /// The caller's audio in the agent's input format, if it can be sent yet
+    fn user_audio(&mut self, audio: Bytes) -> Option<Bytes> {
+        match &mut self.converters {
+            Some(Converters {
+                user: Some(transcoder),
+                ..
+            }) => Some(transcoder.process(&audio)),
+            Some(_) => Some(audio),
+            None => {
+                self.buffered_user_audio.extend_from_slice(&audio);
+                None
+            }
+        }
+    }
+
+    /// The buffered audio of the caller, once it can be sent
+    fn flush_user_audio(&mut self) -> Option<Bytes> {
+        if self.converters.is_none() || self.buffered_user_audio.is_empty() {
+            return None;
+        }
+        let audio = std::mem::take(&mut self.buffered_user_audio);
+        self.user_audio(audio.into())
+    }
+
+    /// The messages to send to Twilio for a message of the agent
+    fn on_message(&mut self, msg: &ServerMessage) -> Result<Vec<TwilioMessage>> {
+        match msg {
+            ServerMessage::ConversationInitiationMetadata(metadata) => {
+                let agent_format = metadata.agent_output_format().ok_or_else(|| {
+                    TelephonyError::UnknownAgentAudioFormat(
+                        metadata.agent_output_audio_format().to_string(),
+                    )
+                })?;
+                let user_format = metadata
+                    .user_input_format()
+                    .unwrap_or(DEFAULT_USER_INPUT_FORMAT);
+                self.converters = Some(Converters {
+                    user: converter(TWILIO_AUDIO_FORMAT, user_format)?,
+                    agent: converter(agent_format, TWILIO_AUDIO_FORMAT)?,
+                });
+                Ok(Vec::new())
+            }
+            ServerMessage::Audio(audio) => {
+                let mut bytes = audio.audio_as_bytes()?;
+                if let Some(Converters {
+                    agent: Some(transcoder),
+                    ..
+                }) = &mut self.converters
+                {
+                    bytes = transcoder.process(&bytes);
+                }
+                let mark = format!("audio_{}", audio.event_id());
+                self.pending_marks.insert(mark.clone());
+                Ok(vec![
+                    TwilioMessage::media(&self.stream_sid, &bytes),
+                    TwilioMessage::mark(&self.stream_sid, mark),
+                ])
+            }
+            ServerMessage::Interruption(_) if !self.pending_marks.is_empty() => {
+                self.pending_marks.clear();
+                Ok(vec![TwilioMessage::clear(&self.stream_sid)])
+            }
+            _ => Ok(Vec::new()),
+        }
+    }
+}
+
+fn converter(from: OutputFormat, to: OutputFormat) -> Result<Option<Transcoder>> {
+    if from == to {
+        return Ok(None);
+    }
+    Transcoder::new(from, to).map(Some)
+}
+
+/// A message received from Twilio over a media stream
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum TwilioEvent {
+    Connected,
+    Start(StreamStart),
+    Media(StreamMedia),
+    /// Audio sent before the mark has finished playing, or was cleared
+    Mark(StreamMark),
+    Dtmf(StreamDtmf),
+    Stop(StreamStop),
+    /// An event this version of the crate does not know about
+    #[serde(other)]
+    Unknown,
+}
+
+/// The first message of a media stream, after `connected`
+#[derive(Clone, Debug, Deserialize)]
+pub struct StreamStart {
+    start: StartEvent,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StartEvent {
+    stream_sid: String,
+    account_sid: String,
+    call_sid: String,
+    #[serde(default)]
+    tracks: Vec<String>,
+    #[serde(default)]
+    custom_parameters: HashMap<String, String>,
+}
+
+impl StreamStart {
+    pub fn stream_sid(&self) -> &str {
+        &self.start.stream_sid
+    }
+    pub fn account_sid(&self) -> &str {
+        &self.start.account_sid
+    }
+    pub fn call_sid(&self) -> &str {
+        &self.start.call_sid
+    }
+    /// e.g. `inbound` and `outbound`
+    pub fn tracks(&self) -> &[String] {
+        &self.start.tracks
+    }
+    /// The `<Parameter>`s of the `<Stream>` in the call's TwiML
+    pub fn custom_parameters(&self) -> &HashMap<String, String> {
+        &self.start.custom_parameters
+    }
+}
+
+/// A chunk of the call's audio
+#[derive(Clone, Debug, Deserialize)]
+pub struct StreamMedia {
+    media: MediaEvent,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct MediaEvent {
+    #[serde(default)]
+    track: String,
+    payload: String,
+}
+
+impl StreamMedia {
+    /// `inbound` for the caller's audio
+    pub fn track(&self) -> &str {
+        &self.media.track
+    }
+    pub fn payload(&self) -> &str {
+        &self.media.payload
+    }
+    /// Decodes the audio, μ-law at 8kHz
+    pub fn audio_as_bytes(&self) -> Result<Bytes> {
+        Ok(Bytes::from(BASE64_STANDARD.decode(&self.media.payload)?))
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct StreamMark {
+    mark: MarkEvent,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct MarkEvent {
+    name: String,
+}
+
+impl StreamMark {
+    pub fn name(&self) -> &str {
+        &self.mark.name
+    }
+}
+
+/// A key the caller pressed
+#[derive(Clone, Debug, Deserialize)]
+pub struct StreamDtmf {
+    dtmf: DtmfEvent,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct DtmfEvent {
+    digit: String,
+}
+
+impl StreamDtmf {
+    pub fn digit(&self) -> &str {
+        &self.dtmf.digit
+    }
+}
+
+/// The stream ended, e.g. because the call was hung up
+#[derive(Clone, Debug, Deserialize)]
+pub struct StreamStop {
+    stop: StopEvent,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StopEvent {
+    call_sid: String,
+}
+
+impl StreamStop {
+    pub fn call_sid(&self) -> &str {
+        &self.stop.call_sid
+    }
+}
+
+/// A message sent to Twilio over a media stream
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum TwilioMessage {
+    /// Audio played to the caller
+    Media {
+        #[serde(rename = "streamSid")]
+        stream_sid: String,
+        media: OutboundMedia,
+    },
+    /// Sent back by Twilio once the audio sent before it has played
+    Mark {
+        #[serde(rename = "streamSid")]
+        stream_sid: String,
+        mark: OutboundMark,
+    },
+    /// Drops the audio that has not been played yet
+    Clear {
+        #[serde(rename = "streamSid")]
+        stream_sid: String,
+    },
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct OutboundMedia {
+    payload: String,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct OutboundMark {
+    name: String,
+}
+
+impl TwilioMessage {
+    /// μ-law audio at 8kHz
+    pub fn media<T: Into<String>>(stream_sid: T, audio: &[u8]) -> Self {
+        TwilioMessage::Media {
+            stream_sid: stream_sid.into(),
+            media: OutboundMedia {
+                payload: BASE64_STANDARD.encode(audio),
+            },
+        }
+    }
+    pub fn mark<T: Into<String>, N: Into<String>>(stream_sid: T, name: N) -> Self {
+        TwilioMessage::Mark {
+            stream_sid: stream_sid.into(),
+            mark: OutboundMark { name: name.into() },
+        }
+    }
+    pub fn clear<T: Into<String>>(stream_sid: T) -> Self {
+        TwilioMessage::Clear {
+            stream_sid: stream_sid.into(),
+        }
+    }
+    pub fn json(&self) -> Result<String> {
+        serde_json::to_string(self).map_err(Into::into)
+    }
+}
@@ -0,0 +1,64 @@
+//! Pluggable transport for the HTTP endpoints
+//!
+//! By default [ElevenLabsClient](crate::ElevenLabsClient) sends requests with its `reqwest::Client`.
+//! A [Transport] replaces that last step, so code calling `client.hit(...)`
+//! can be unit tested without any network calls.
+//!
+//! Websocket endpoints are not routed through the transport.
+//!
+//! # Example
+//! ```
+//! use elevenlabs_rs::*;
+//! use elevenlabs_rs::transport::{mock_response, Request};
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<()> {
+//!     let c = ElevenLabsClient::new("test_key").with_transport(|req: Request| async move {
+//!         assert_eq!(req.url().path(), "/v1/voices/settings/default");
+//!         Ok(mock_response(
+//!             200,
+//!             r#"{"similarity_boost": 0.75, "stability": 0.5}"#,
+//!         ))
+//!     });
+//!     let settings = c.hit(GetDefaultSettings).await?;
+//!     assert_eq!(settings.stability(), 0.5);
+//!     Ok(())
+//! }
+//! ```
+use crate::client::Result;
+use bytes::Bytes;
+use std::future::Future;
+use std::pin::Pin;
+
+pub use reqwest::{Request, Response};
+
+pub type TransportFuture<'a> = Pin<Box<dyn Future<Output = Result<Response>> + Send + 'a>>;
+
+/// Sends a fully built request and returns the raw response
+///
+/// Implemented for any `Fn(Request) -> impl Future<Output = Result<Response>>`.
+pub trait Transport: Send + Sync {
+    fn send(&self, request: Request) -> TransportFuture<'_>;
+}
+
+impl<F, Fut> Transport for F
+where
+    F: Fn(Request) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<Response>> + Send + 'static,
+{
+    fn send(&self, request: Request) -> TransportFuture<'_> {
+        Box::pin(self(request))
+    }
+}
+
+/// Builds a response with the given status code and body, for use in a mock [Transport]
+///
+/// # Panics
+/// If `status` is not a valid HTTP status code.
+pub fn mock_response<B: Into<Bytes>>(status: u16, body: B) -> Response {
+    let resp = http::Response::builder()
+        .status(status)
+        .body(body.into())
+        .expect("valid status code");
+    Response::from(resp)
+}
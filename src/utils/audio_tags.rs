@@ -0,0 +1,129 @@
+//! Audio tags directing the delivery of Eleven v3
+use crate::client::Result;
+use crate::error::Error;
+use std::borrow::Cow;
+use std::fmt;
+
+const MAX_TAG_CHARS: usize = 40;
+
+/// An audio tag, e.g. `[whispers]`, which Eleven v3 performs instead of reading aloud
+///
+/// Other tags than the listed ones can be made with [AudioTag::new], which checks that
+/// the tag is well formed. A malformed tag would be read aloud as text.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AudioTag(Cow<'static, str>);
+
+impl AudioTag {
+    pub const LAUGHS: AudioTag = AudioTag::known("laughs");
+    pub const LAUGHS_HARDER: AudioTag = AudioTag::known("laughs harder");
+    pub const STARTS_LAUGHING: AudioTag = AudioTag::known("starts laughing");
+    pub const CHUCKLES: AudioTag = AudioTag::known("chuckles");
+    pub const WHISPERS: AudioTag = AudioTag::known("whispers");
+    pub const SIGHS: AudioTag = AudioTag::known("sighs");
+    pub const EXHALES: AudioTag = AudioTag::known("exhales");
+    pub const CLEARS_THROAT: AudioTag = AudioTag::known("clears throat");
+    pub const CRYING: AudioTag = AudioTag::known("crying");
+    pub const SARCASTIC: AudioTag = AudioTag::known("sarcastic");
+    pub const CURIOUS: AudioTag = AudioTag::known("curious");
+    pub const EXCITED: AudioTag = AudioTag::known("excited");
+    pub const MISCHIEVOUSLY: AudioTag = AudioTag::known("mischievously");
+    pub const SHORT_PAUSE: AudioTag = AudioTag::known("short pause");
+    pub const LONG_PAUSE: AudioTag = AudioTag::known("long pause");
+
+    const fn known(name: &'static str) -> Self {
+        AudioTag(Cow::Borrowed(name))
+    }
+
+    /// Fails with [Error::InvalidAudioTag] unless the name is made of letters, spaces,
+    /// hyphens and apostrophes, up to 40 characters
+    pub fn new(name: &str) -> Result<Self> {
+        let name = name.trim();
+        let valid = !name.is_empty()
+            && name.chars().count() <= MAX_TAG_CHARS
+            && name
+                .chars()
+                .all(|c| c.is_alphabetic() || c == ' ' || c == '-' || c == '\'');
+        if !valid {
+            return Err(Box::new(Error::InvalidAudioTag(name.to_string())));
+        }
+        Ok(AudioTag(Cow::Owned(name.to_lowercase())))
+    }
+    /// The name of the tag, without brackets
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for AudioTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}]", self.0)
+    }
+}
+
+/// Text interleaved with audio tags, for [Eleven v3](crate::Model::ElevenV3)
+///
+/// Square brackets in the text are replaced with parentheses,
+/// so that no part of the text is taken for a tag.
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::utils::{AudioTag, TaggedText};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let text = TaggedText::new()
+///         .tag(AudioTag::WHISPERS)
+///         .text("I have a secret.")
+///         .tag(AudioTag::SHORT_PAUSE)
+///         .tag(AudioTag::new("giggles")?)
+///         .text("You'll never guess.");
+///     // [whispers] I have a secret. [short pause] [giggles] You'll never guess.
+///     let body = TextToSpeechBody::new(text.as_str(), Model::ElevenV3);
+///     let speech = c.hit(TextToSpeech::new(PreMadeVoiceID::Jessica, body)).await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TaggedText(String);
+
+impl TaggedText {
+    pub fn new() -> Self {
+        TaggedText::default()
+    }
+    pub fn text(mut self, text: &str) -> Self {
+        let text = text.trim().replace('[', "(").replace(']', ")");
+        self.push(&text);
+        self
+    }
+    pub fn tag(mut self, tag: AudioTag) -> Self {
+        self.push(&tag.to_string());
+        self
+    }
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    fn push(&mut self, part: &str) {
+        if part.is_empty() {
+            return;
+        }
+        if !self.0.is_empty() {
+            self.0.push(' ');
+        }
+        self.0.push_str(part);
+    }
+}
+
+impl fmt::Display for TaggedText {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<TaggedText> for String {
+    fn from(text: TaggedText) -> String {
+        text.0
+    }
+}
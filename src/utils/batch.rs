@@ -0,0 +1,100 @@
+//! Text to speech for many texts at once
+use crate::client::{ElevenLabsClient, Result, RetryPolicy};
+use crate::endpoints::tts::TextToSpeech;
+use bytes::Bytes;
+use futures_util::{stream, Stream, StreamExt};
+
+/// Generates speech for each request, at most `concurrency` at a time
+///
+/// Results are returned in the order of the requests, a failed request does not stop the others.
+/// Failed requests are retried according to the client's [RetryPolicy], or if it has none,
+/// the default policy extended to text to speech requests.
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::utils::{save, synthesize_all};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let prompts = ["Welcome back.", "Your order has shipped.", "Goodbye."];
+///     let requests = prompts.iter().map(|prompt| {
+///         let body = TextToSpeechBody::new(*prompt, Model::ElevenFlashV2Dot5);
+///         TextToSpeech::new(PreMadeVoiceID::Sarah, body)
+///     });
+///     for (i, speech) in synthesize_all(&c, requests, 4).await.into_iter().enumerate() {
+///         match speech {
+///             Ok(audio) => save(&format!("prompt_{i}.mp3"), audio)?,
+///             Err(e) => eprintln!("prompt {i} failed: {e}"),
+///         }
+///     }
+///     Ok(())
+/// }
+/// ```
+pub async fn synthesize_all<I>(
+    client: &ElevenLabsClient,
+    requests: I,
+    concurrency: usize,
+) -> Vec<Result<Bytes>>
+where
+    I: IntoIterator<Item = TextToSpeech>,
+{
+    let client = &with_retries(client);
+    stream::iter(requests)
+        .map(|request| client.hit(request))
+        .buffered(concurrency.max(1))
+        .collect()
+        .await
+}
+
+/// Like [synthesize_all], but yields each result as soon as it completes,
+/// along with the index of its request
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::utils::{save, synthesize_unordered};
+/// use futures_util::{pin_mut, StreamExt};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let prompts = std::fs::read_to_string("prompts.txt")?;
+///     let requests = prompts.lines().map(|prompt| {
+///         let body = TextToSpeechBody::new(prompt, Model::ElevenFlashV2Dot5);
+///         TextToSpeech::new(PreMadeVoiceID::Sarah, body)
+///     });
+///     let results = synthesize_unordered(&c, requests, 8);
+///     pin_mut!(results);
+///     while let Some((i, speech)) = results.next().await {
+///         save(&format!("prompt_{i}.mp3"), speech?)?;
+///     }
+///     Ok(())
+/// }
+/// ```
+pub fn synthesize_unordered<I>(
+    client: &ElevenLabsClient,
+    requests: I,
+    concurrency: usize,
+) -> impl Stream<Item = (usize, Result<Bytes>)>
+where
+    I: IntoIterator<Item = TextToSpeech>,
+{
+    let client = with_retries(client);
+    stream::iter(requests.into_iter().enumerate())
+        .map(move |(i, request)| {
+            let client = client.clone();
+            async move { (i, client.hit(request).await) }
+        })
+        .buffer_unordered(concurrency.max(1))
+}
+
+fn with_retries(client: &ElevenLabsClient) -> ElevenLabsClient {
+    if client.has_retry_policy() {
+        client.clone()
+    } else {
+        let policy = RetryPolicy::default().with_non_idempotent_requests();
+        client.clone().with_retry_policy(policy)
+    }
+}
@@ -0,0 +1,231 @@
+//! Conversions between the PCM, μ-law and A-law output formats
+//!
+//! Telephony, e.g. Twilio Media Streams, uses 8kHz μ-law, while conversational AI agents
+//! default to 16kHz PCM.
+use crate::client::Result;
+use crate::error::AudioInterfaceError;
+use crate::shared::query_params::{AudioCodec, OutputFormat};
+use bytes::Bytes;
+
+const MULAW_BIAS: i32 = 0x84;
+const MULAW_CLIP: i32 = 32635;
+const ALAW_SEGMENT_ENDS: [i32; 8] = [0x1F, 0x3F, 0x7F, 0xFF, 0x1FF, 0x3FF, 0x7FF, 0xFFF];
+
+/// Converts audio between PCM, μ-law and A-law formats, resampling if the sample rates differ
+///
+/// The converter keeps its state across chunks, so a stream can be converted chunk by chunk.
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::utils::Transcoder;
+///
+/// fn main() -> Result<()> {
+///     // Agent audio to a Twilio media stream
+///     let mut to_twilio = Transcoder::new(OutputFormat::Pcm16000Hz, OutputFormat::MuLaw8000Hz)?;
+///     let agent_audio = vec![0u8; 3200];
+///     let payload = to_twilio.process(&agent_audio);
+///     assert_eq!(payload.len(), 800);
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Transcoder {
+    from: OutputFormat,
+    to: OutputFormat,
+    resampler: Option<Resampler>,
+    /// The first byte of a PCM sample split across chunks
+    carry: Option<u8>,
+}
+
+impl Transcoder {
+    /// Returns an error if either format is MP3 or Opus
+    pub fn new(from: OutputFormat, to: OutputFormat) -> Result<Self> {
+        for format in [from, to] {
+            if matches!(format.codec(), AudioCodec::Mp3 | AudioCodec::Opus) {
+                return Err(Box::new(AudioInterfaceError::UnsupportedAudioFormat(
+                    format.to_string(),
+                )));
+            }
+        }
+        let resampler = (from.sample_rate() != to.sample_rate())
+            .then(|| Resampler::new(from.sample_rate(), to.sample_rate()));
+        Ok(Transcoder {
+            from,
+            to,
+            resampler,
+            carry: None,
+        })
+    }
+
+    /// Converts the next chunk
+    pub fn process(&mut self, data: &[u8]) -> Bytes {
+        let mut samples = match self.from.codec() {
+            AudioCodec::MuLaw => mulaw_to_pcm(data),
+            AudioCodec::ALaw => alaw_to_pcm(data),
+            _ => {
+                let mut bytes = Vec::with_capacity(data.len() + 1);
+                bytes.extend(self.carry.take());
+                bytes.extend_from_slice(data);
+                if bytes.len() % 2 == 1 {
+                    self.carry = bytes.pop();
+                }
+                pcm_from_bytes(&bytes)
+            }
+        };
+        if let Some(resampler) = &mut self.resampler {
+            samples = resampler.process_pcm(&samples);
+        }
+        match self.to.codec() {
+            AudioCodec::MuLaw => pcm_to_mulaw(&samples).into(),
+            AudioCodec::ALaw => pcm_to_alaw(&samples).into(),
+            _ => pcm_to_bytes(&samples).into(),
+        }
+    }
+}
+
+/// Converts a whole clip between PCM, μ-law and A-law formats, see [Transcoder]
+pub fn transcode(data: &[u8], from: OutputFormat, to: OutputFormat) -> Result<Bytes> {
+    Ok(Transcoder::new(from, to)?.process(data))
+}
+
+/// 16 bit little endian PCM, as returned by the API, to samples
+pub fn pcm_from_bytes(data: &[u8]) -> Vec<i16> {
+    data.chunks_exact(2)
+        .map(|b| i16::from_le_bytes([b[0], b[1]]))
+        .collect()
+}
+
+/// Samples to 16 bit little endian PCM, as expected by the API
+pub fn pcm_to_bytes(samples: &[i16]) -> Vec<u8> {
+    samples.iter().flat_map(|s| s.to_le_bytes()).collect()
+}
+
+/// Encodes 16 bit PCM samples as G.711 μ-law
+pub fn pcm_to_mulaw(samples: &[i16]) -> Vec<u8> {
+    samples.iter().map(|&s| linear_to_mulaw(s)).collect()
+}
+
+/// Decodes G.711 μ-law to 16 bit PCM samples
+pub fn mulaw_to_pcm(data: &[u8]) -> Vec<i16> {
+    data.iter().map(|&u| mulaw_to_linear(u)).collect()
+}
+
+/// Encodes 16 bit PCM samples as G.711 A-law
+pub fn pcm_to_alaw(samples: &[i16]) -> Vec<u8> {
+    samples.iter().map(|&s| linear_to_alaw(s)).collect()
+}
+
+/// Decodes G.711 A-law to 16 bit PCM samples
+pub fn alaw_to_pcm(data: &[u8]) -> Vec<i16> {
+    data.iter().map(|&a| alaw_to_linear(a)).collect()
+}
+
+/// Resamples a whole clip of PCM samples, see [Resampler]
+pub fn resample(samples: &[i16], from_rate: u32, to_rate: u32) -> Vec<i16> {
+    Resampler::new(from_rate, to_rate).process_pcm(samples)
+}
+
+fn linear_to_mulaw(sample: i16) -> u8 {
+    let mut s = sample as i32;
+    let sign = if s < 0 {
+        s = -s;
+        0x80
+    } else {
+        0
+    };
+    s = s.min(MULAW_CLIP) + MULAW_BIAS;
+    let exponent = (7 - (s << 17).leading_zeros().min(7)) as i32;
+    let mantissa = (s >> (exponent + 3)) & 0x0F;
+    !(sign | (exponent << 4) | mantissa) as u8
+}
+
+fn mulaw_to_linear(u: u8) -> i16 {
+    let u = !u as i32;
+    let exponent = (u >> 4) & 0x07;
+    let mantissa = u & 0x0F;
+    let s = (((mantissa << 3) + MULAW_BIAS) << exponent) - MULAW_BIAS;
+    (if u & 0x80 != 0 { -s } else { s }) as i16
+}
+
+fn linear_to_alaw(sample: i16) -> u8 {
+    let mut s = (sample as i32) >> 3;
+    let mask = if s >= 0 {
+        0xD5
+    } else {
+        s = -s - 1;
+        0x55
+    };
+    let Some(segment) = ALAW_SEGMENT_ENDS.iter().position(|&end| s <= end) else {
+        return (0x7F ^ mask) as u8;
+    };
+    let shift = if segment < 2 { 1 } else { segment };
+    let value = ((segment as i32) << 4) | ((s >> shift) & 0x0F);
+    (value ^ mask) as u8
+}
+
+fn alaw_to_linear(a: u8) -> i16 {
+    let a = (a ^ 0x55) as i32;
+    let segment = (a & 0x70) >> 4;
+    let mut s = (a & 0x0F) << 4;
+    s = match segment {
+        0 => s + 8,
+        1 => s + 0x108,
+        _ => (s + 0x108) << (segment - 1),
+    };
+    (if a & 0x80 != 0 { s } else { -s }) as i16
+}
+
+/// Linear interpolation between sample rates, keeping its position across chunks
+///
+/// Good enough for speech, but it does not filter, so downsampling music may alias.
+#[derive(Clone, Debug)]
+pub struct Resampler {
+    step: f64,
+    position: f64,
+    last: f32,
+}
+
+impl Resampler {
+    pub fn new(from_rate: u32, to_rate: u32) -> Self {
+        Self {
+            step: from_rate as f64 / to_rate as f64,
+            position: 0.0,
+            last: 0.0,
+        }
+    }
+
+    /// Resamples the next chunk of samples, appending them to the output
+    pub fn process(&mut self, input: &[f32], output: &mut Vec<f32>) {
+        let Some(&end) = input.last() else {
+            return;
+        };
+        // A position of -1 is the last sample of the previous chunk
+        let len = input.len() as f64;
+        while self.position < len - 1.0 {
+            let index = self.position.floor();
+            let frac = (self.position - index) as f32;
+            let a = if index < 0.0 {
+                self.last
+            } else {
+                input[index as usize]
+            };
+            let b = input[(index + 1.0) as usize];
+            output.push(a + (b - a) * frac);
+            self.position += self.step;
+        }
+        self.position -= len;
+        self.last = end;
+    }
+
+    /// Resamples the next chunk of 16 bit PCM samples
+    pub fn process_pcm(&mut self, input: &[i16]) -> Vec<i16> {
+        let input = input.iter().map(|&s| s as f32).collect::<Vec<_>>();
+        let mut output = Vec::with_capacity((input.len() as f64 / self.step) as usize + 1);
+        self.process(&input, &mut output);
+        output
+            .into_iter()
+            .map(|s| s.round().clamp(i16::MIN as f32, i16::MAX as f32) as i16)
+            .collect()
+    }
+}
@@ -0,0 +1,231 @@
+//! Aggregates of conversational AI conversations, e.g. for dashboards
+use crate::client::Result;
+use crate::endpoints::convai::conversations::{
+    ConversationSummary, GetConversationDetailsResponse,
+};
+use crate::webhooks::CallSuccessful;
+use futures_util::{pin_mut, Stream, StreamExt};
+use std::collections::BTreeMap;
+
+const SECS_PER_DAY: u64 = 86_400;
+
+/// A conversation that can be aggregated by [conversation_analytics]
+///
+/// Only [details](GetConversationDetailsResponse) carry the cost of a conversation.
+pub trait ConversationRecord {
+    fn agent_id(&self) -> &str;
+    fn start_time_unix_secs(&self) -> u64;
+    fn call_duration_secs(&self) -> u32;
+    fn message_count(&self) -> u32;
+    /// `None` if the conversation has not been evaluated
+    fn call_successful(&self) -> Option<&CallSuccessful>;
+    /// In credits
+    fn cost(&self) -> Option<u32>;
+}
+
+impl ConversationRecord for ConversationSummary {
+    fn agent_id(&self) -> &str {
+        self.agent_id()
+    }
+    fn start_time_unix_secs(&self) -> u64 {
+        self.start_time_unix_secs()
+    }
+    fn call_duration_secs(&self) -> u32 {
+        self.call_duration_secs()
+    }
+    fn message_count(&self) -> u32 {
+        self.message_count()
+    }
+    fn call_successful(&self) -> Option<&CallSuccessful> {
+        Some(self.call_successful())
+    }
+    fn cost(&self) -> Option<u32> {
+        None
+    }
+}
+
+impl ConversationRecord for GetConversationDetailsResponse {
+    fn agent_id(&self) -> &str {
+        self.agent_id()
+    }
+    fn start_time_unix_secs(&self) -> u64 {
+        self.metadata().start_time_unix_secs()
+    }
+    fn call_duration_secs(&self) -> u32 {
+        self.metadata().call_duration_secs()
+    }
+    fn message_count(&self) -> u32 {
+        self.transcript().len() as u32
+    }
+    fn call_successful(&self) -> Option<&CallSuccessful> {
+        self.analysis().map(|analysis| analysis.call_successful())
+    }
+    fn cost(&self) -> Option<u32> {
+        self.metadata().cost()
+    }
+}
+
+/// Aggregates of a set of conversations
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ConversationStats {
+    conversations: u64,
+    successful: u64,
+    failed: u64,
+    total_duration_secs: u64,
+    total_messages: u64,
+    total_cost: u64,
+    conversations_with_cost: u64,
+}
+
+impl ConversationStats {
+    fn add<T: ConversationRecord>(&mut self, conversation: &T) {
+        self.conversations += 1;
+        match conversation.call_successful() {
+            Some(CallSuccessful::Success) => self.successful += 1,
+            Some(CallSuccessful::Failure) => self.failed += 1,
+            _ => {}
+        }
+        self.total_duration_secs += u64::from(conversation.call_duration_secs());
+        self.total_messages += u64::from(conversation.message_count());
+        if let Some(cost) = conversation.cost() {
+            self.total_cost += u64::from(cost);
+            self.conversations_with_cost += 1;
+        }
+    }
+    pub fn conversations(&self) -> u64 {
+        self.conversations
+    }
+    pub fn successful(&self) -> u64 {
+        self.successful
+    }
+    pub fn failed(&self) -> u64 {
+        self.failed
+    }
+    /// The share of successful conversations among the evaluated ones,
+    /// `None` if none was evaluated
+    pub fn success_rate(&self) -> Option<f64> {
+        let evaluated = self.successful + self.failed;
+        (evaluated > 0).then(|| self.successful as f64 / evaluated as f64)
+    }
+    pub fn total_duration_secs(&self) -> u64 {
+        self.total_duration_secs
+    }
+    pub fn average_duration_secs(&self) -> Option<f64> {
+        (self.conversations > 0)
+            .then(|| self.total_duration_secs as f64 / self.conversations as f64)
+    }
+    pub fn total_messages(&self) -> u64 {
+        self.total_messages
+    }
+    pub fn average_messages(&self) -> Option<f64> {
+        (self.conversations > 0).then(|| self.total_messages as f64 / self.conversations as f64)
+    }
+    /// In credits, of the conversations whose cost is known
+    pub fn total_cost(&self) -> u64 {
+        self.total_cost
+    }
+    pub fn conversations_with_cost(&self) -> u64 {
+        self.conversations_with_cost
+    }
+}
+
+/// Aggregates of conversations, overall and grouped by agent and by UTC day
+///
+/// Days are formatted as `YYYY-MM-DD`, so the groups iterate in chronological order.
+#[derive(Clone, Debug, Default)]
+pub struct ConversationAnalytics {
+    total: ConversationStats,
+    by_agent: BTreeMap<String, ConversationStats>,
+    by_day: BTreeMap<String, ConversationStats>,
+    by_agent_and_day: BTreeMap<(String, String), ConversationStats>,
+}
+
+impl ConversationAnalytics {
+    /// Adds a conversation to the aggregates
+    pub fn add<T: ConversationRecord>(&mut self, conversation: &T) {
+        let agent_id = conversation.agent_id().to_string();
+        let day = utc_day(conversation.start_time_unix_secs());
+        self.total.add(conversation);
+        self.by_agent
+            .entry(agent_id.clone())
+            .or_default()
+            .add(conversation);
+        self.by_day
+            .entry(day.clone())
+            .or_default()
+            .add(conversation);
+        self.by_agent_and_day
+            .entry((agent_id, day))
+            .or_default()
+            .add(conversation);
+    }
+    pub fn total(&self) -> &ConversationStats {
+        &self.total
+    }
+    pub fn by_agent(&self) -> &BTreeMap<String, ConversationStats> {
+        &self.by_agent
+    }
+    pub fn by_day(&self) -> &BTreeMap<String, ConversationStats> {
+        &self.by_day
+    }
+    /// Keyed by agent id and day
+    pub fn by_agent_and_day(&self) -> &BTreeMap<(String, String), ConversationStats> {
+        &self.by_agent_and_day
+    }
+}
+
+/// Aggregates the conversations of a stream, such as the one returned by
+/// [paginate](crate::ElevenLabsClient::paginate)
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::endpoints::convai::conversations::*;
+/// use elevenlabs_rs::utils::conversation_analytics;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let query = ConversationsQuery::default().with_page_size(100);
+///     let conversations = c.paginate(ListConversations::with_query(query));
+///     let analytics = conversation_analytics(conversations).await?;
+///     for (day, stats) in analytics.by_day() {
+///         println!(
+///             "{}: {} conversations, {:?} success rate, {:?}s on average",
+///             day,
+///             stats.conversations(),
+///             stats.success_rate(),
+///             stats.average_duration_secs()
+///         );
+///     }
+///     Ok(())
+/// }
+/// ```
+pub async fn conversation_analytics<S, T>(conversations: S) -> Result<ConversationAnalytics>
+where
+    S: Stream<Item = Result<T>>,
+    T: ConversationRecord,
+{
+    pin_mut!(conversations);
+    let mut analytics = ConversationAnalytics::default();
+    while let Some(conversation) = conversations.next().await {
+        analytics.add(&conversation?);
+    }
+    Ok(analytics)
+}
+
+/// The UTC date of a unix timestamp, as `YYYY-MM-DD`
+fn utc_day(unix_secs: u64) -> String {
+    // Howard Hinnant's days_from_civil, inverted
+    let days = (unix_secs / SECS_PER_DAY) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
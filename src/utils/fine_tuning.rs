@@ -0,0 +1,120 @@
+//! Waiting for a professional voice clone to be trained
+use crate::client::{ElevenLabsClient, Result};
+use crate::endpoints::voice::{FineTuning, FineTuningState, GetVoice, VoiceResponseBody};
+use crate::error::Error;
+use crate::shared::identifiers::VoiceID;
+use crate::utils::poll::{poll_until, PollPolicy};
+use std::time::Duration;
+
+const DEFAULT_INTERVAL: Duration = Duration::from_secs(5);
+const DEFAULT_MAX_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Polls a voice with [GetVoice] until its fine tuning has finished
+///
+/// By default the interval between polls starts at five seconds and doubles up to a minute.
+/// Without a model id the voice is ready once any model is fine tuned.
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::utils::{FineTuningWait, PollPolicy};
+/// use std::time::Duration;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     c.hit(TrainPvcVoice::new("some_voice_id")).await?;
+///     let policy = PollPolicy::default().with_timeout(Duration::from_secs(6 * 60 * 60));
+///     let voice = FineTuningWait::new("some_voice_id")
+///         .with_poll_policy(policy)
+///         .wait_until_ready(&c, |fine_tuning| println!("{:?}", fine_tuning.progress()))
+///         .await?;
+///     println!("{} is ready", voice.get_name());
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct FineTuningWait {
+    voice_id: VoiceID,
+    model_id: Option<String>,
+    policy: PollPolicy,
+}
+
+impl FineTuningWait {
+    pub fn new<T: Into<VoiceID>>(voice_id: T) -> Self {
+        FineTuningWait {
+            voice_id: voice_id.into(),
+            model_id: None,
+            policy: PollPolicy::default()
+                .with_interval(DEFAULT_INTERVAL)
+                .with_max_interval(DEFAULT_MAX_INTERVAL),
+        }
+    }
+    /// Wait for the model the voice is [trained](crate::TrainPvcVoice::with_model_id) for
+    pub fn with_model_id<T: Into<String>>(mut self, model_id: T) -> Self {
+        self.model_id = Some(model_id.into());
+        self
+    }
+    pub fn with_poll_policy(mut self, policy: PollPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Returns the fine tuned voice, calling `on_progress` after each poll
+    ///
+    /// Fails with [Error::FineTuningFailed] and the API's message if training failed.
+    pub async fn wait_until_ready<F>(
+        self,
+        client: &ElevenLabsClient,
+        mut on_progress: F,
+    ) -> Result<VoiceResponseBody>
+    where
+        F: FnMut(&FineTuning),
+    {
+        let endpoint = || GetVoice::new(self.voice_id.clone());
+        let done = |voice: &VoiceResponseBody| -> Result<bool> {
+            let Some(fine_tuning) = voice.get_fine_tuning() else {
+                return Ok(false);
+            };
+            on_progress(fine_tuning);
+            match self.state(fine_tuning) {
+                Some((_, FineTuningState::FineTuned)) => Ok(true),
+                Some((model_id, FineTuningState::Failed)) => {
+                    let message = fine_tuning.message().get(model_id).cloned();
+                    let message = message.unwrap_or_else(|| model_id.to_string());
+                    Err(Error::FineTuningFailed(message).into())
+                }
+                _ => Ok(false),
+            }
+        };
+        poll_until(client, endpoint, done, &self.policy).await
+    }
+
+    /// The state of the awaited model, otherwise a fine tuned model,
+    /// or a failed one once no other model is still training
+    fn state<'a>(&self, fine_tuning: &'a FineTuning) -> Option<(&'a str, FineTuningState)> {
+        let mut states = fine_tuning
+            .state()
+            .iter()
+            .map(|(model_id, state)| (model_id.as_str(), *state));
+        if let Some(model_id) = &self.model_id {
+            return states.find(|(id, _)| id == model_id);
+        }
+        let training = |state: FineTuningState| {
+            matches!(
+                state,
+                FineTuningState::Queued | FineTuningState::FineTuning | FineTuningState::Delayed
+            )
+        };
+        if let Some(ready) = states
+            .clone()
+            .find(|(_, s)| *s == FineTuningState::FineTuned)
+        {
+            Some(ready)
+        } else if states.clone().any(|(_, s)| training(s)) {
+            None
+        } else {
+            states.find(|(_, s)| *s == FineTuningState::Failed)
+        }
+    }
+}
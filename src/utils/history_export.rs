@@ -0,0 +1,119 @@
+//! Exports of the generation history, e.g. for reporting
+use crate::client::Result;
+use crate::endpoints::history::HistoryItem;
+use futures_util::{pin_mut, Stream, StreamExt};
+use serde::Serialize;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+const CSV_HEADER: &str =
+    "history_item_id,voice_id,voice_name,model_id,character_cost,date_unix,text";
+
+/// The format of a history export
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Comma separated values with a header row
+    Csv,
+    /// One JSON object per line
+    JsonLines,
+}
+
+/// A row of a history export
+#[derive(Serialize)]
+struct ExportRow<'a> {
+    history_item_id: &'a str,
+    voice_id: &'a str,
+    voice_name: &'a str,
+    model_id: Option<&'a str>,
+    character_cost: u64,
+    date_unix: u64,
+    text: &'a str,
+}
+
+impl<'a> From<&'a HistoryItem> for ExportRow<'a> {
+    fn from(item: &'a HistoryItem) -> Self {
+        ExportRow {
+            history_item_id: item.history_item_id(),
+            voice_id: item.voice_id(),
+            voice_name: item.voice_name(),
+            model_id: item.model_id(),
+            character_cost: item
+                .character_count_change_to()
+                .saturating_sub(item.character_count_change_from()),
+            date_unix: item.date_unix(),
+            text: item.text(),
+        }
+    }
+}
+
+impl ExportRow<'_> {
+    fn to_csv(&self) -> String {
+        [
+            csv_field(self.history_item_id),
+            csv_field(self.voice_id),
+            csv_field(self.voice_name),
+            csv_field(self.model_id.unwrap_or_default()),
+            self.character_cost.to_string(),
+            self.date_unix.to_string(),
+            csv_field(self.text),
+        ]
+        .join(",")
+    }
+}
+
+/// Writes the history items of a stream, such as the one returned by
+/// [paginate](crate::ElevenLabsClient::paginate), returning the number of items written
+///
+/// Each row has the item id, voice, model, character cost, date and text of an item.
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::utils::{export_history, ExportFormat};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let query = HistoryQuery::default().with_page_size(1000);
+///     let items = c.paginate(GetGeneratedItems::new(query));
+///     let mut file = tokio::fs::File::create("history.csv").await?;
+///     let count = export_history(items, ExportFormat::Csv, &mut file).await?;
+///     println!("exported {} items", count);
+///     Ok(())
+/// }
+/// ```
+pub async fn export_history<S, W>(items: S, format: ExportFormat, writer: &mut W) -> Result<u64>
+where
+    S: Stream<Item = Result<HistoryItem>>,
+    W: AsyncWrite + Unpin,
+{
+    pin_mut!(items);
+    if format == ExportFormat::Csv {
+        writer
+            .write_all(format!("{}\n", CSV_HEADER).as_bytes())
+            .await?;
+    }
+
+    let mut count = 0;
+    while let Some(item) = items.next().await {
+        let item = item?;
+        let row = ExportRow::from(&item);
+        let mut line = match format {
+            ExportFormat::Csv => row.to_csv(),
+            ExportFormat::JsonLines => serde_json::to_string(&row)?,
+        };
+        line.push('\n');
+        writer.write_all(line.as_bytes()).await?;
+        count += 1;
+    }
+    writer.flush().await?;
+    Ok(count)
+}
+
+/// Quotes a field if it contains a separator, a quote or a line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
@@ -0,0 +1,263 @@
+//! Text to speech for texts too long for a single request
+use crate::client::{ElevenLabsClient, Result};
+use crate::endpoints::tts::{
+    PronunciationDictionaryLocator, SpeechQuery, TextToSpeechBody, TextToSpeechWithRequestID,
+};
+use crate::endpoints::voice::VoiceSettings;
+use crate::shared::identifiers::VoiceID;
+use crate::shared::query_params::{AudioCodec, OutputFormat};
+use bytes::{Bytes, BytesMut};
+
+const DEFAULT_MAX_CHUNK_CHARS: usize = 2_500;
+/// Only the last three requests are taken into account for stitching
+const MAX_PREVIOUS_REQUEST_IDS: usize = 3;
+const ID3V2_HEADER_LEN: usize = 10;
+const ID3V1_TAG_LEN: usize = 128;
+
+/// Generates speech for a long text, e.g. a chapter of an audiobook
+///
+/// The text is split at sentence boundaries into chunks, which are generated one after another,
+/// each [stitched](TextToSpeechBody::with_previous_request_ids) to the ones before it
+/// so that the prosody is continuous, then concatenated into one audio file.
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::utils::{save, LongFormSynthesizer};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let chapter = std::fs::read_to_string("chapter_01.txt")?;
+///     let model = Model::ElevenMultilingualV2;
+///     let synthesizer = LongFormSynthesizer::new(PreMadeVoiceID::George, model)
+///         .with_output_format(OutputFormat::Mp3_44100Hz192kbps);
+///     let audio = synthesizer.synthesize(&c, &chapter).await?;
+///     save("chapter_01.mp3", audio)?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct LongFormSynthesizer {
+    voice_id: VoiceID,
+    model_id: String,
+    voice_settings: Option<VoiceSettings>,
+    pronunciation_dictionary_locators: Vec<PronunciationDictionaryLocator>,
+    seed: Option<u64>,
+    output_format: Option<OutputFormat>,
+    max_chunk_chars: usize,
+}
+
+impl LongFormSynthesizer {
+    pub fn new<V: Into<VoiceID>, M: Into<String>>(voice_id: V, model_id: M) -> Self {
+        LongFormSynthesizer {
+            voice_id: voice_id.into(),
+            model_id: model_id.into(),
+            voice_settings: None,
+            pronunciation_dictionary_locators: Vec::new(),
+            seed: None,
+            output_format: None,
+            max_chunk_chars: DEFAULT_MAX_CHUNK_CHARS,
+        }
+    }
+    pub fn with_voice_settings(mut self, voice_settings: VoiceSettings) -> Self {
+        self.voice_settings = Some(voice_settings);
+        self
+    }
+    pub fn with_pronunciation_dictionary<L: Into<PronunciationDictionaryLocator>>(
+        mut self,
+        locator: L,
+    ) -> Self {
+        self.pronunciation_dictionary_locators.push(locator.into());
+        self
+    }
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+    /// Defaults to the API's default, `mp3_44100_128`
+    pub fn with_output_format(mut self, output_format: OutputFormat) -> Self {
+        self.output_format = Some(output_format);
+        self
+    }
+    /// The maximum number of characters sent in a single request, defaults to 2500
+    ///
+    /// Sentences longer than this are split between words.
+    pub fn with_max_chunk_chars(mut self, max_chunk_chars: usize) -> Self {
+        self.max_chunk_chars = max_chunk_chars.max(1);
+        self
+    }
+
+    /// Generates the whole text, returning the audio in the output format
+    pub async fn synthesize(&self, client: &ElevenLabsClient, text: &str) -> Result<Bytes> {
+        let chunks = self.split(text);
+        let mut request_ids: Vec<String> = Vec::new();
+        let mut audio = Vec::with_capacity(chunks.len());
+
+        for (i, chunk) in chunks.iter().enumerate() {
+            let mut body = TextToSpeechBody::new(chunk, self.model_id.as_str())
+                .with_pronunciation_dictionary_locators(
+                    self.pronunciation_dictionary_locators.clone(),
+                );
+            if let Some(voice_settings) = &self.voice_settings {
+                body = body.with_voice_settings(voice_settings.clone());
+            }
+            if let Some(seed) = self.seed {
+                body = body.with_seed(seed);
+            }
+            if !request_ids.is_empty() {
+                let from = request_ids.len().saturating_sub(MAX_PREVIOUS_REQUEST_IDS);
+                body = body.with_previous_request_ids(request_ids[from..].to_vec());
+            }
+            if let Some(next) = chunks.get(i + 1) {
+                body = body.with_next_text(next);
+            }
+
+            let mut endpoint = TextToSpeechWithRequestID::new(&self.voice_id, body);
+            if let Some(output_format) = &self.output_format {
+                endpoint = endpoint
+                    .with_query(SpeechQuery::default().with_output_format(*output_format));
+            }
+            let resp = client.hit(endpoint).await?;
+            request_ids.extend(resp.request_id().map(str::to_string));
+            audio.push(resp.into_audio());
+        }
+
+        Ok(self.concat(audio))
+    }
+
+    /// Splits a text into the chunks that [synthesize](Self::synthesize) generates
+    pub fn split(&self, text: &str) -> Vec<String> {
+        let mut chunks = Vec::new();
+        let mut chunk = String::new();
+        let mut chunk_chars = 0;
+
+        for piece in sentences(text)
+            .into_iter()
+            .flat_map(|sentence| split_long(sentence, self.max_chunk_chars))
+        {
+            let piece_chars = piece.chars().count();
+            if chunk_chars + piece_chars > self.max_chunk_chars && !chunk.trim().is_empty() {
+                chunks.push(chunk.trim().to_string());
+                chunk.clear();
+                chunk_chars = 0;
+            }
+            chunk.push_str(piece);
+            chunk_chars += piece_chars;
+        }
+        if !chunk.trim().is_empty() {
+            chunks.push(chunk.trim().to_string());
+        }
+        chunks
+    }
+
+    fn concat(&self, audio: Vec<Bytes>) -> Bytes {
+        let is_mp3 = self
+            .output_format
+            .as_ref()
+            .is_none_or(|format| format.codec() == AudioCodec::Mp3);
+        if !is_mp3 {
+            // PCM, μ-law and A-law are headerless and Opus streams can be chained
+            return audio.concat().into();
+        }
+
+        // Keep the ID3v2 tag of the first chunk only, and drop any ID3v1 tags,
+        // so that players do not stop at the end of the first chunk
+        let mut out = BytesMut::new();
+        for (i, chunk) in audio.iter().enumerate() {
+            let mut frames = &chunk[..];
+            if i > 0 {
+                frames = strip_id3v2(frames);
+            }
+            out.extend_from_slice(strip_id3v1(frames));
+        }
+        out.freeze()
+    }
+}
+
+/// Splits after sentence ending punctuation that is followed by whitespace, and after line breaks
+fn sentences(text: &str) -> Vec<&str> {
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        if c == '\n' {
+            sentences.push(&text[start..i + 1]);
+            start = i + 1;
+            continue;
+        }
+        if !matches!(c, '.' | '!' | '?' | '…' | '。' | '！' | '？') {
+            continue;
+        }
+        // Closing quotes and brackets belong to the sentence, e.g. `"Stop!" she said.`
+        let mut end = i + c.len_utf8();
+        while let Some(&(j, next)) = chars.peek() {
+            if !matches!(
+                next,
+                '.' | '!' | '?' | '"' | '\'' | ')' | ']' | '”' | '’' | '»'
+            ) {
+                break;
+            }
+            end = j + next.len_utf8();
+            chars.next();
+        }
+        // Not the end of a sentence if e.g. part of a number like 3.14
+        match chars.peek() {
+            Some(&(_, next)) if !next.is_whitespace() && !matches!(c, '。' | '！' | '？') => {
+                continue
+            }
+            _ => {}
+        }
+        sentences.push(&text[start..end]);
+        start = end;
+    }
+    if start < text.len() {
+        sentences.push(&text[start..]);
+    }
+    sentences
+}
+
+/// Splits a sentence longer than `max_chars` between words, or anywhere if a word is too long
+fn split_long(sentence: &str, max_chars: usize) -> Vec<&str> {
+    let mut pieces = Vec::new();
+    let mut rest = sentence;
+
+    while rest.chars().count() > max_chars {
+        let limit = rest
+            .char_indices()
+            .nth(max_chars)
+            .map_or(rest.len(), |(i, _)| i);
+        let split = rest[..limit]
+            .rfind(char::is_whitespace)
+            .filter(|&i| i > 0)
+            .unwrap_or(limit);
+        pieces.push(&rest[..split]);
+        rest = &rest[split..];
+    }
+    pieces.push(rest);
+    pieces
+}
+
+fn strip_id3v2(audio: &[u8]) -> &[u8] {
+    if audio.len() < ID3V2_HEADER_LEN || &audio[..3] != b"ID3" {
+        return audio;
+    }
+    // The size is stored as a 28 bit syncsafe integer and excludes the header and footer
+    let size = audio[6..10]
+        .iter()
+        .fold(0, |size, b| (size << 7) | (b & 0x7f) as usize);
+    let footer = if audio[5] & 0x10 != 0 {
+        ID3V2_HEADER_LEN
+    } else {
+        0
+    };
+    &audio[(ID3V2_HEADER_LEN + size + footer).min(audio.len())..]
+}
+
+fn strip_id3v1(audio: &[u8]) -> &[u8] {
+    match audio.len().checked_sub(ID3V1_TAG_LEN) {
+        Some(start) if &audio[start..start + 3] == b"TAG" => &audio[..start],
+        _ => audio,
+    }
+}
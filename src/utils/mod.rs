@@ -1,23 +1,93 @@
 use crate::client::Result;
+use crate::shared::query_params::{AudioCodec, OutputFormat};
 use async_stream::stream;
 use bytes::Bytes;
 use futures_util::{pin_mut, Stream, StreamExt};
 use std::sync::mpsc;
 use std::{fs::File, io::prelude::*};
 
+mod audio_tags;
+mod batch;
+mod codec;
+mod conversation_analytics;
+mod fine_tuning;
+mod history_export;
+mod long_form;
 #[cfg(feature = "playback")]
 mod playback;
+mod poll;
+mod quota_guard;
+#[cfg(feature = "playback")]
+mod stream_player;
+mod streaming_synthesizer;
+mod subtitles;
+mod tts_cache;
+mod voice_resolver;
+mod wav;
+
+pub use audio_tags::{AudioTag, TaggedText};
+pub use batch::{synthesize_all, synthesize_unordered};
+pub use codec::{
+    alaw_to_pcm, mulaw_to_pcm, pcm_from_bytes, pcm_to_alaw, pcm_to_bytes, pcm_to_mulaw,
+    resample, transcode, Resampler, Transcoder,
+};
+pub use conversation_analytics::{
+    conversation_analytics, ConversationAnalytics, ConversationRecord, ConversationStats,
+};
+pub use fine_tuning::FineTuningWait;
+pub use history_export::{export_history, ExportFormat};
+pub use long_form::LongFormSynthesizer;
+pub use poll::{poll_until, PollPolicy};
+pub use quota_guard::QuotaGuard;
+pub use streaming_synthesizer::StreamingSynthesizer;
+pub use subtitles::{Cue, Subtitles};
+pub use tts_cache::TtsCache;
+pub use voice_resolver::VoiceResolver;
+pub use wav::to_wav;
 
 #[cfg(feature = "playback")]
-pub use playback::{play, stream_audio};
+pub use playback::{play, play_async, stream_audio, Playback};
+#[cfg(feature = "playback")]
+pub use stream_player::StreamPlayer;
 
-/// Save audio to a file
+/// Save audio to a file as is
+///
+/// PCM, μ-law and A-law output is headerless, use [save_audio] to save it as a playable WAV file.
 pub fn save(filename: &str, data: Bytes) -> Result<()> {
     let mut file = File::create(filename)?;
     file.write_all(&data)?;
     Ok(())
 }
 
+/// Save audio in the container of its output format
+///
+/// MP3 and Opus are written as is, PCM, μ-law and A-law are wrapped in a [WAV](to_wav) header.
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::utils::save_audio;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let format = OutputFormat::Pcm24000Hz;
+///     let body = TextToSpeechBody::new("Saved as a proper WAV file.", Model::ElevenMultilingualV2);
+///     let endpoint = TextToSpeech::new(PreMadeVoiceID::Sarah, body)
+///         .with_query(SpeechQuery::default().with_output_format(format));
+///     let audio = c.hit(endpoint).await?;
+///     save_audio("speech.wav", audio, format)?;
+///     Ok(())
+/// }
+/// ```
+pub fn save_audio(filename: &str, data: Bytes, format: OutputFormat) -> Result<()> {
+    let data = match format.codec() {
+        AudioCodec::Mp3 | AudioCodec::Opus => data,
+        AudioCodec::Pcm | AudioCodec::MuLaw | AudioCodec::ALaw => to_wav(&data, format)?,
+    };
+    save(filename, data)
+}
+
 pub fn text_chunker<S>(text_stream: S) -> impl Stream<Item = String>
 where
     S: Stream<Item = String> + Send + 'static,
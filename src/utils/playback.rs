@@ -3,8 +3,12 @@ use bytes::Bytes;
 use bytes::{BufMut, BytesMut};
 use futures_util::{pin_mut, Stream, StreamExt};
 use rodio::{Decoder, OutputStream, Sink};
+use std::sync::Arc;
+use tokio::sync::{oneshot, watch};
 
-/// Play audio
+/// Play audio, blocking the current thread until it ends
+///
+/// In async code, prefer [play_async] which does not block the runtime.
 pub fn play(data: Bytes) -> Result<()> {
     let (_stream, stream_handle) = OutputStream::try_default()?;
     let source = Decoder::new(std::io::Cursor::new(data))?;
@@ -49,3 +53,118 @@ pub async fn stream_audio(data: impl Stream<Item = Result<Bytes>>) -> Result<()>
 
     Ok(())
 }
+
+/// Play audio without blocking the async runtime, returning a handle to control the playback
+///
+/// The audio is decoded and played on a dedicated thread.
+/// Dropping the handle stops the playback, so await [finished](Playback::finished) to play it to the end.
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::utils::play_async;
+/// use std::time::Duration;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let body = TextToSpeechBody::new("Hello there, how are you?", Model::ElevenFlashV2Dot5);
+///     let audio = c.hit(TextToSpeech::new(PreMadeVoiceID::Sarah, body)).await?;
+///     let playback = play_async(audio).await?;
+///     playback.set_volume(0.5);
+///     tokio::time::sleep(Duration::from_secs(1)).await;
+///     playback.pause();
+///     tokio::time::sleep(Duration::from_secs(1)).await;
+///     playback.resume();
+///     playback.finished().await;
+///     Ok(())
+/// }
+/// ```
+pub async fn play_async(data: Bytes) -> Result<Playback> {
+    let (sink_tx, sink_rx) = oneshot::channel();
+    let (done_tx, done_rx) = watch::channel(false);
+
+    std::thread::spawn(move || {
+        // The output stream is not Send, so it lives on this thread for the whole playback
+        match open_sink(data) {
+            Ok((_stream, sink)) => {
+                let _ = sink_tx.send(Ok(Arc::clone(&sink)));
+                sink.sleep_until_end();
+            }
+            Err(e) => {
+                let _ = sink_tx.send(Err(e));
+            }
+        }
+        let _ = done_tx.send(true);
+    });
+
+    let sink = sink_rx
+        .await
+        .map_err(|_| "Playback thread stopped unexpectedly")??;
+    Ok(Playback {
+        sink,
+        done: done_rx,
+    })
+}
+
+fn open_sink(data: Bytes) -> Result<(OutputStream, Arc<Sink>)> {
+    let (stream, handle) = OutputStream::try_default()?;
+    let sink = Sink::try_new(&handle)?;
+    sink.append(Decoder::new(std::io::Cursor::new(data))?);
+    Ok((stream, Arc::new(sink)))
+}
+
+/// A handle to audio played by [play_async]
+pub struct Playback {
+    sink: Arc<Sink>,
+    done: watch::Receiver<bool>,
+}
+
+impl Playback {
+    pub fn pause(&self) {
+        self.sink.pause();
+    }
+    pub fn resume(&self) {
+        self.sink.play();
+    }
+    pub fn is_paused(&self) -> bool {
+        self.sink.is_paused()
+    }
+    /// Stops the playback, it cannot be resumed
+    pub fn stop(&self) {
+        self.sink.stop();
+    }
+    /// 1.0 is the original volume
+    pub fn set_volume(&self, volume: f32) {
+        self.sink.set_volume(volume);
+    }
+    pub fn volume(&self) -> f32 {
+        self.sink.volume()
+    }
+    /// Whether the audio played to the end or was stopped
+    pub fn is_finished(&self) -> bool {
+        *self.done.borrow()
+    }
+    /// Completes once the audio played to the end or was stopped
+    pub async fn finished(&self) {
+        let mut done = self.done.clone();
+        // An error means the playback thread is gone, so the playback is over too
+        let _ = done.wait_for(|done| *done).await;
+    }
+}
+
+impl Drop for Playback {
+    fn drop(&mut self) {
+        self.sink.stop();
+    }
+}
+
+impl std::fmt::Debug for Playback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Playback")
+            .field("paused", &self.is_paused())
+            .field("volume", &self.volume())
+            .field("finished", &self.is_finished())
+            .finish()
+    }
+}
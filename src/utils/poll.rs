@@ -0,0 +1,121 @@
+//! Polling an endpoint until a background job is done
+use crate::client::{ElevenLabsClient, Result};
+use crate::endpoints::Endpoint;
+use crate::error::Error;
+use std::time::Duration;
+use tokio::time::{sleep, Instant};
+
+const MIN_INTERVAL: Duration = Duration::from_millis(100);
+
+/// How often and for how long to poll
+///
+/// The interval between polls starts at one second and doubles up to thirty seconds.
+/// By default polling goes on for as long as it takes.
+/// Intervals below 100 milliseconds are raised to it.
+#[derive(Clone, Debug)]
+pub struct PollPolicy {
+    interval: Duration,
+    max_interval: Duration,
+    multiplier: u32,
+    timeout: Option<Duration>,
+}
+
+impl Default for PollPolicy {
+    fn default() -> Self {
+        PollPolicy {
+            interval: Duration::from_secs(1),
+            max_interval: Duration::from_secs(30),
+            multiplier: 2,
+            timeout: None,
+        }
+    }
+}
+
+impl PollPolicy {
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval.max(MIN_INTERVAL);
+        self
+    }
+    pub fn with_max_interval(mut self, max_interval: Duration) -> Self {
+        self.max_interval = max_interval.max(MIN_INTERVAL);
+        self
+    }
+    /// Use `1` for a fixed interval, `0` is taken as `1`
+    pub fn with_multiplier(mut self, multiplier: u32) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+    /// Fails with [Error::Timeout] once elapsed
+    ///
+    /// A timeout too long to be added to the current time means polling without a deadline.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+}
+
+/// Hits the endpoint made by `endpoint` until `done` returns `true` for its response
+///
+/// Returns the final response. An error returned by `done`, e.g. for a failed job,
+/// ends polling and is returned as is.
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::utils::{poll_until, PollPolicy};
+/// use std::time::Duration;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let dubbing_id = "dubbing_id".to_string();
+///     let policy = PollPolicy::default().with_timeout(Duration::from_secs(600));
+///     let dubbing = poll_until(
+///         &c,
+///         || GetDubbingProjectMetadata::new(dubbing_id.clone().into()),
+///         |dubbing| match dubbing.status() {
+///             "dubbed" => Ok(true),
+///             "failed" => Err("dubbing failed".into()),
+///             _ => Ok(false),
+///         },
+///         &policy,
+///     )
+///     .await?;
+///     Ok(())
+/// }
+/// ```
+pub async fn poll_until<T, F, P>(
+    client: &ElevenLabsClient,
+    mut endpoint: F,
+    mut done: P,
+    policy: &PollPolicy,
+) -> Result<T::ResponseBody>
+where
+    T: Endpoint,
+    F: FnMut() -> T,
+    P: FnMut(&T::ResponseBody) -> Result<bool>,
+{
+    let deadline = policy
+        .timeout
+        .and_then(|timeout| Instant::now().checked_add(timeout));
+    let mut interval = policy.interval;
+    loop {
+        let resp = client.hit(endpoint()).await?;
+        if done(&resp)? {
+            return Ok(resp);
+        }
+        if let (Some(deadline), Some(timeout)) = (deadline, policy.timeout) {
+            if Instant::now()
+                .checked_add(interval)
+                .is_none_or(|next| next > deadline)
+            {
+                return Err(Box::new(Error::Timeout(timeout)));
+            }
+        }
+        sleep(interval).await;
+        interval = interval
+            .checked_mul(policy.multiplier.max(1))
+            .unwrap_or(policy.max_interval)
+            .min(policy.max_interval);
+    }
+}
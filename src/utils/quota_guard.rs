@@ -0,0 +1,107 @@
+//! Checking the character quota before generating
+use crate::client::{ElevenLabsClient, Result};
+use crate::endpoints::user::GetUserSubscriptionInfo;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+const DEFAULT_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Keeps track of the characters left in the subscription, so that batch jobs can stop
+/// before a request fails for lack of quota
+///
+/// The subscription is fetched with [GetUserSubscriptionInfo] on the first check,
+/// then again once older than the refresh interval. In between, characters recorded
+/// with [QuotaGuard::record_usage] are counted locally.
+///
+/// Characters are counted as the text's length, which is what most models bill.
+/// Some models bill at a different rate, so keep a margin when the quota is tight.
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::utils::QuotaGuard;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let quota = QuotaGuard::new(c.clone());
+///     for text in ["First chapter", "Second chapter"] {
+///         if !quota.can_generate(text).await? {
+///             println!("out of characters, stopping");
+///             break;
+///         }
+///         let body = TextToSpeechBody::new(text, Model::ElevenMultilingualV2);
+///         let speech = c.hit(TextToSpeech::new(PreMadeVoiceID::Rachel, body)).await?;
+///         quota.record_usage(text).await;
+///     }
+///     Ok(())
+/// }
+/// ```
+pub struct QuotaGuard {
+    client: ElevenLabsClient,
+    refresh_interval: Duration,
+    state: Mutex<Option<State>>,
+}
+
+struct State {
+    fetched_at: Instant,
+    remaining: u64,
+}
+
+impl QuotaGuard {
+    pub fn new(client: ElevenLabsClient) -> Self {
+        QuotaGuard {
+            client,
+            refresh_interval: DEFAULT_REFRESH_INTERVAL,
+            state: Mutex::new(None),
+        }
+    }
+    /// Defaults to a minute
+    pub fn with_refresh_interval(mut self, refresh_interval: Duration) -> Self {
+        self.refresh_interval = refresh_interval;
+        self
+    }
+
+    /// Fetches the subscription now
+    pub async fn refresh(&self) -> Result<()> {
+        let mut state = self.state.lock().await;
+        *state = Some(self.fetch().await?);
+        Ok(())
+    }
+
+    /// Characters left in the current period
+    pub async fn remaining_characters(&self) -> Result<u64> {
+        let mut state = self.state.lock().await;
+        if state
+            .as_ref()
+            .is_none_or(|s| s.fetched_at.elapsed() >= self.refresh_interval)
+        {
+            *state = Some(self.fetch().await?);
+        }
+        Ok(state.as_ref().expect("state is filled above").remaining)
+    }
+
+    /// Whether enough characters are left to generate `text`
+    pub async fn can_generate(&self, text: &str) -> Result<bool> {
+        Ok(self.remaining_characters().await? >= characters(text))
+    }
+
+    /// Counts `text` against the quota until the next refresh
+    pub async fn record_usage(&self, text: &str) {
+        if let Some(state) = self.state.lock().await.as_mut() {
+            state.remaining = state.remaining.saturating_sub(characters(text));
+        }
+    }
+
+    async fn fetch(&self) -> Result<State> {
+        let subscription = self.client.hit(GetUserSubscriptionInfo).await?;
+        Ok(State {
+            fetched_at: Instant::now(),
+            remaining: subscription.remaining_characters() as u64,
+        })
+    }
+}
+
+fn characters(text: &str) -> u64 {
+    text.chars().count() as u64
+}
@@ -0,0 +1,450 @@
+//! Playback of audio that arrives in chunks, e.g. from streaming text to speech
+use crate::client::Result;
+use crate::error::AudioInterfaceError;
+use crate::shared::query_params::{AudioCodec, OutputFormat};
+use bytes::Bytes;
+use futures_util::{pin_mut, Stream, StreamExt};
+use rodio::buffer::SamplesBuffer;
+use rodio::{Decoder, OutputStream, Sink, Source};
+use std::collections::VecDeque;
+use std::io::{Read, Seek, SeekFrom};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::watch;
+
+const DEFAULT_JITTER_BUFFER: Duration = Duration::from_millis(100);
+/// The length of the decoded pieces appended to the sink
+const PIECE_DURATION_MS: usize = 20;
+const DRAIN_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+enum Message {
+    Chunk(Bytes),
+    Clear,
+}
+
+/// Plays audio as its chunks arrive instead of waiting for the whole clip
+///
+/// Playback starts once the jitter buffer is filled, and pauses to refill it whenever
+/// the chunks arrive slower than they are played, so that a late chunk does not cause
+/// choppy audio. MP3 and PCM [output formats](OutputFormat) are supported.
+///
+/// The audio is decoded and played on a dedicated thread, started by the first chunk.
+/// Dropping the player stops the playback.
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::utils::StreamPlayer;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let body = TextToSpeechBody::new("Streaming makes this start sooner.", Model::ElevenFlashV2Dot5);
+///     let query = SpeechQuery::default().with_output_format(OutputFormat::Pcm24000Hz);
+///     let endpoint = TextToSpeechStream::new(PreMadeVoiceID::Sarah, body).with_query(query);
+///     let stream = c.hit(endpoint).await?;
+///
+///     let mut player = StreamPlayer::new(OutputFormat::Pcm24000Hz);
+///     player.play(stream).await?;
+///     Ok(())
+/// }
+/// ```
+pub struct StreamPlayer {
+    format: OutputFormat,
+    jitter_buffer: Duration,
+    running: Option<Running>,
+}
+
+struct Running {
+    tx: Option<mpsc::Sender<Message>>,
+    sink: Arc<Sink>,
+    stopped: Arc<AtomicBool>,
+    done: watch::Receiver<bool>,
+}
+
+impl StreamPlayer {
+    pub fn new(format: OutputFormat) -> Self {
+        StreamPlayer {
+            format,
+            jitter_buffer: DEFAULT_JITTER_BUFFER,
+            running: None,
+        }
+    }
+    /// How much audio is buffered before playback starts or resumes, defaults to 100ms
+    pub fn with_jitter_buffer(mut self, jitter_buffer: Duration) -> Self {
+        self.jitter_buffer = jitter_buffer;
+        self
+    }
+
+    /// Queues a chunk of audio, starting the player on the first chunk
+    ///
+    /// Empty chunks are skipped.
+    pub fn push(&mut self, chunk: Bytes) -> Result<()> {
+        if chunk.is_empty() {
+            return Ok(());
+        }
+        let running = match &mut self.running {
+            Some(running) => running,
+            None => self.running.insert(start(self.format, self.jitter_buffer)?),
+        };
+        running
+            .tx
+            .as_ref()
+            .ok_or("StreamPlayer input already finished")?
+            .send(Message::Chunk(chunk))
+            .map_err(|_| "StreamPlayer thread stopped unexpectedly")?;
+        Ok(())
+    }
+
+    /// Marks the end of the input, the queued audio is played to the end
+    pub fn finish(&mut self) {
+        if let Some(running) = &mut self.running {
+            running.tx = None;
+        }
+    }
+
+    /// Plays a stream of chunks, such as the one returned by a streaming endpoint, to the end
+    pub async fn play<S>(&mut self, stream: S) -> Result<()>
+    where
+        S: Stream<Item = Result<Bytes>>,
+    {
+        pin_mut!(stream);
+        while let Some(chunk) = stream.next().await {
+            self.push(chunk?)?;
+        }
+        self.finish();
+        self.finished().await;
+        Ok(())
+    }
+
+    /// Drops the queued audio and stops what is playing, e.g. when the user interrupts the agent
+    ///
+    /// Chunks pushed afterwards are played once the jitter buffer is filled again.
+    pub fn clear(&mut self) {
+        if let Some(running) = &self.running {
+            if let Some(tx) = &running.tx {
+                let _ = tx.send(Message::Clear);
+            }
+            running.sink.clear();
+        }
+    }
+
+    /// 1.0 is the original volume
+    pub fn set_volume(&self, volume: f32) {
+        if let Some(running) = &self.running {
+            running.sink.set_volume(volume);
+        }
+    }
+
+    /// Completes once the input is [finished](Self::finish) and all of it was played
+    pub async fn finished(&self) {
+        if let Some(running) = &self.running {
+            let mut done = running.done.clone();
+            // An error means the player thread is gone, so the playback is over too
+            let _ = done.wait_for(|done| *done).await;
+        }
+    }
+}
+
+impl Drop for StreamPlayer {
+    fn drop(&mut self) {
+        if let Some(running) = &mut self.running {
+            running.tx = None;
+            running.stopped.store(true, Ordering::SeqCst);
+            running.sink.stop();
+        }
+    }
+}
+
+impl std::fmt::Debug for StreamPlayer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StreamPlayer")
+            .field("format", &self.format)
+            .field("jitter_buffer", &self.jitter_buffer)
+            .field("started", &self.running.is_some())
+            .finish()
+    }
+}
+
+fn start(format: OutputFormat, jitter_buffer: Duration) -> Result<Running> {
+    match format.codec() {
+        AudioCodec::Mp3 | AudioCodec::Pcm => {}
+        _ => {
+            return Err(Box::new(AudioInterfaceError::UnsupportedAudioFormat(
+                format.to_string(),
+            )))
+        }
+    }
+    let (tx, rx) = mpsc::channel();
+    let (sink_tx, sink_rx) = mpsc::channel();
+    let (done_tx, done_rx) = watch::channel(false);
+    let stopped = Arc::new(AtomicBool::new(false));
+    let thread_stopped = Arc::clone(&stopped);
+
+    std::thread::spawn(move || {
+        // The output stream is not Send, so it lives on this thread for the whole playback
+        let (_stream, sink) = match open_sink() {
+            Ok(output) => output,
+            Err(e) => {
+                let _ = sink_tx.send(Err(e));
+                return;
+            }
+        };
+        let _ = sink_tx.send(Ok(Arc::clone(&sink)));
+
+        let mut queue = JitterQueue::new(&sink, &thread_stopped, jitter_buffer);
+        let reader = ChunkReader::new(rx);
+        if format.codec() == AudioCodec::Mp3 {
+            play_mp3(reader, &mut queue);
+        } else {
+            play_pcm(reader, format.sample_rate(), &mut queue);
+        }
+        queue.drain();
+        let _ = done_tx.send(true);
+    });
+
+    let sink = sink_rx
+        .recv()
+        .map_err(|_| "StreamPlayer thread stopped unexpectedly")??;
+    Ok(Running {
+        tx: Some(tx),
+        sink,
+        stopped,
+        done: done_rx,
+    })
+}
+
+fn open_sink() -> Result<(OutputStream, Arc<Sink>)> {
+    let (stream, handle) = OutputStream::try_default()?;
+    let sink = Sink::try_new(&handle)?;
+    sink.pause();
+    Ok((stream, Arc::new(sink)))
+}
+
+fn play_pcm(mut reader: ChunkReader, sample_rate: u32, queue: &mut JitterQueue) {
+    let mut carry: Option<u8> = None;
+    while let Some(message) = reader.next_message() {
+        if queue.is_stopped() {
+            break;
+        }
+        let chunk = match message {
+            Message::Chunk(chunk) => chunk,
+            Message::Clear => {
+                // A half sample left over belongs to the dropped audio
+                carry = None;
+                continue;
+            }
+        };
+        let mut bytes = chunk.iter().copied();
+        let mut samples = Vec::with_capacity(chunk.len() / 2 + 1);
+        if let Some(low) = carry.take() {
+            if let Some(high) = bytes.next() {
+                samples.push(i16::from_le_bytes([low, high]));
+            }
+        }
+        loop {
+            match (bytes.next(), bytes.next()) {
+                (Some(low), Some(high)) => samples.push(i16::from_le_bytes([low, high])),
+                (Some(low), None) => {
+                    carry = Some(low);
+                    break;
+                }
+                _ => break,
+            }
+        }
+        queue.push(SamplesBuffer::new(1, sample_rate, samples));
+    }
+}
+
+fn play_mp3(reader: ChunkReader, queue: &mut JitterQueue) {
+    let probed = Arc::clone(&reader.probed);
+    // A single decoder for the whole stream, so frames are decoded with the
+    // bit reservoir of the previous ones and chunk boundaries do not click
+    let Ok(mut decoder) = Decoder::new_mp3(reader) else {
+        return;
+    };
+    probed.store(true, Ordering::SeqCst);
+    loop {
+        let channels = decoder.channels();
+        let sample_rate = decoder.sample_rate();
+        let len = sample_rate as usize * channels as usize * PIECE_DURATION_MS / 1000;
+        let samples = decoder.by_ref().take(len).collect::<Vec<_>>();
+        if samples.is_empty() || queue.is_stopped() {
+            break;
+        }
+        queue.push(SamplesBuffer::new(channels, sample_rate, samples));
+    }
+}
+
+/// Appends decoded audio to the sink, pausing it until the jitter buffer is filled
+struct JitterQueue<'a> {
+    sink: &'a Sink,
+    stopped: &'a AtomicBool,
+    jitter_buffer: Duration,
+    buffering: bool,
+    buffered: Duration,
+}
+
+impl<'a> JitterQueue<'a> {
+    fn new(sink: &'a Sink, stopped: &'a AtomicBool, jitter_buffer: Duration) -> Self {
+        JitterQueue {
+            sink,
+            stopped,
+            jitter_buffer,
+            buffering: true,
+            buffered: Duration::ZERO,
+        }
+    }
+
+    /// Whether the player was dropped, appending would restart the stopped sink
+    fn is_stopped(&self) -> bool {
+        self.stopped.load(Ordering::SeqCst)
+    }
+
+    fn push(&mut self, samples: SamplesBuffer<i16>) {
+        // Ran dry, either because the chunks arrive too slowly or the player was cleared
+        if !self.buffering && self.sink.empty() {
+            self.sink.pause();
+            self.buffering = true;
+            self.buffered = Duration::ZERO;
+        }
+        let duration = samples.total_duration().unwrap_or_default();
+        self.sink.append(samples);
+        if self.buffering {
+            self.buffered += duration;
+            if self.buffered >= self.jitter_buffer {
+                self.sink.play();
+                self.buffering = false;
+            }
+        }
+    }
+
+    /// Plays whatever is left, returning once the sink is empty
+    fn drain(&mut self) {
+        self.sink.play();
+        while !self.sink.empty() && !self.is_stopped() {
+            std::thread::sleep(DRAIN_POLL_INTERVAL);
+        }
+    }
+}
+
+/// A reader over the chunks sent to the player, blocking until more arrive
+///
+/// The data is kept so that the decoder can seek back while probing the format,
+/// afterwards the decoder only reads forward and what it read is dropped.
+struct ChunkReader {
+    rx: Mutex<mpsc::Receiver<Message>>,
+    pending: VecDeque<Bytes>,
+    cleared: bool,
+    data: Vec<u8>,
+    /// Position in `data`
+    pos: usize,
+    /// Bytes dropped from the front of `data`
+    dropped: u64,
+    probed: Arc<AtomicBool>,
+}
+
+impl ChunkReader {
+    fn new(rx: mpsc::Receiver<Message>) -> Self {
+        ChunkReader {
+            rx: Mutex::new(rx),
+            pending: VecDeque::new(),
+            cleared: false,
+            data: Vec::new(),
+            pos: 0,
+            dropped: 0,
+            probed: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// The next chunk or clear, or `None` once the input is finished
+    fn next_message(&mut self) -> Option<Message> {
+        if self.pending.is_empty() && !self.cleared {
+            let message = self.rx.get_mut().ok()?.recv().ok()?;
+            self.queue(message);
+        }
+        // Look ahead, so that chunks queued before a clear are dropped rather than played
+        while let Ok(message) = self.rx.get_mut().ok()?.try_recv() {
+            self.queue(message);
+        }
+        if std::mem::take(&mut self.cleared) {
+            return Some(Message::Clear);
+        }
+        self.pending.pop_front().map(Message::Chunk)
+    }
+
+    fn queue(&mut self, message: Message) {
+        match message {
+            Message::Chunk(chunk) => self.pending.push_back(chunk),
+            Message::Clear => {
+                self.pending.clear();
+                self.cleared = true;
+            }
+        }
+    }
+
+    /// Blocks until there is data left to read, returns false once the input is finished
+    fn fill(&mut self) -> bool {
+        if self.probed.load(Ordering::SeqCst) {
+            self.data.drain(..self.pos);
+            self.dropped += self.pos as u64;
+            self.pos = 0;
+        }
+        let Ok(rx) = self.rx.get_mut() else {
+            return false;
+        };
+        // A clear leaves nothing to read, so keep waiting for the chunks pushed after it
+        while self.pos >= self.data.len() {
+            let Ok(mut message) = rx.recv() else {
+                return false;
+            };
+            loop {
+                match message {
+                    Message::Chunk(chunk) => self.data.extend_from_slice(&chunk),
+                    Message::Clear => {
+                        // Skip everything not decoded yet, the decoder resyncs on the next frame
+                        self.pos = self.data.len();
+                    }
+                }
+                match rx.try_recv() {
+                    Ok(next) => message = next,
+                    Err(_) => break,
+                }
+            }
+        }
+        true
+    }
+}
+
+impl Read for ChunkReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        while self.pos >= self.data.len() {
+            if !self.fill() {
+                return Ok(0);
+            }
+        }
+        let n = buf.len().min(self.data.len() - self.pos);
+        buf[..n].copy_from_slice(&self.data[self.pos..self.pos + n]);
+        self.pos += n;
+        Ok(n)
+    }
+}
+
+impl Seek for ChunkReader {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let pos = match pos {
+            SeekFrom::Start(pos) => pos as i64 - self.dropped as i64,
+            SeekFrom::Current(offset) => self.pos as i64 + offset,
+            SeekFrom::End(offset) => self.data.len() as i64 + offset,
+        };
+        if pos < 0 || pos as usize > self.data.len() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "seek outside of the received audio",
+            ));
+        }
+        self.pos = pos as usize;
+        Ok(self.dropped + self.pos as u64)
+    }
+}
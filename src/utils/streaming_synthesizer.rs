@@ -0,0 +1,193 @@
+//! Text to speech for text that is generated while it is spoken, e.g. by an LLM
+use crate::client::{ElevenLabsClient, Result};
+use crate::endpoints::tts::ws::{BOSMessage, TextChunk, WebSocketTTS, WebSocketTTSBody};
+use crate::endpoints::tts::SpeechQuery;
+use crate::endpoints::voice::VoiceSettings;
+use crate::shared::identifiers::VoiceID;
+use crate::shared::query_params::OutputFormat;
+use async_stream::stream;
+use bytes::Bytes;
+use futures_util::{pin_mut, Stream, StreamExt};
+
+const SENTENCE_ENDS: [char; 6] = ['.', '!', '?', ';', ':', '\n'];
+/// Full width punctuation, which is not followed by whitespace
+const FULL_WIDTH_SENTENCE_ENDS: [char; 3] = ['。', '！', '？'];
+/// Lowercase words that end with a period without ending a sentence
+const ABBREVIATIONS: [&str; 11] = [
+    "e.g", "i.e", "mr", "mrs", "ms", "dr", "prof", "st", "vs", "jr", "sr",
+];
+const CLAUSE_ENDS: [char; 4] = [',', '—', '–', ')'];
+const DEFAULT_MIN_CLAUSE_CHARS: usize = 60;
+
+/// Speaks a stream of tokens over the [websocket](WebSocketTTS) as they arrive
+///
+/// Tokens are buffered into chunks that end at sentence boundaries, or at clause boundaries
+/// once a chunk is long enough, so that every chunk is spoken with natural prosody.
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::utils::{stream_audio, StreamingSynthesizer};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     // e.g. the deltas of a streamed chat completion
+///     let tokens = futures_util::stream::iter(
+///         ["Sure", "!", " The", " weather", " today", " is", " sunny", "."].map(String::from),
+///     );
+///     let synthesizer = StreamingSynthesizer::new(PreMadeVoiceID::Sarah, Model::ElevenFlashV2Dot5)
+///         .with_chunk_length_schedule([50, 90, 120]);
+///     let audio = synthesizer.synthesize_stream(&c, tokens).await?;
+///     stream_audio(audio).await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct StreamingSynthesizer {
+    voice_id: VoiceID,
+    model_id: String,
+    voice_settings: Option<VoiceSettings>,
+    chunk_length_schedule: Option<Vec<usize>>,
+    output_format: Option<OutputFormat>,
+    min_clause_chars: usize,
+}
+
+impl StreamingSynthesizer {
+    pub fn new<V: Into<VoiceID>, M: Into<String>>(voice_id: V, model_id: M) -> Self {
+        StreamingSynthesizer {
+            voice_id: voice_id.into(),
+            model_id: model_id.into(),
+            voice_settings: None,
+            chunk_length_schedule: None,
+            output_format: None,
+            min_clause_chars: DEFAULT_MIN_CLAUSE_CHARS,
+        }
+    }
+    pub fn with_voice_settings(mut self, voice_settings: VoiceSettings) -> Self {
+        self.voice_settings = Some(voice_settings);
+        self
+    }
+    /// See [BOSMessage::with_chunk_length_schedule]
+    pub fn with_chunk_length_schedule<I: IntoIterator<Item = usize>>(
+        mut self,
+        schedule: I,
+    ) -> Self {
+        self.chunk_length_schedule = Some(schedule.into_iter().collect());
+        self
+    }
+    /// Defaults to the API's default, `mp3_44100_128`
+    pub fn with_output_format(mut self, output_format: OutputFormat) -> Self {
+        self.output_format = Some(output_format);
+        self
+    }
+    /// A chunk ends at a clause boundary, e.g. a comma, only once it has this many characters,
+    /// defaults to 60
+    pub fn with_min_clause_chars(mut self, min_clause_chars: usize) -> Self {
+        self.min_clause_chars = min_clause_chars;
+        self
+    }
+
+    /// Sends the tokens as they arrive, returning the audio chunks in the output format
+    pub async fn synthesize_stream<S>(
+        &self,
+        client: &ElevenLabsClient,
+        tokens: S,
+    ) -> Result<impl Stream<Item = Result<Bytes>>>
+    where
+        S: Stream<Item = String> + Send + 'static,
+    {
+        let mut bos_message = BOSMessage::default();
+        if let Some(voice_settings) = &self.voice_settings {
+            bos_message = bos_message.with_voice_settings(voice_settings.clone());
+        }
+        if let Some(schedule) = &self.chunk_length_schedule {
+            bos_message = bos_message.with_chunk_length_schedule(schedule.iter().copied());
+        }
+        let chunks = chunk_tokens(tokens, self.min_clause_chars);
+        let body = WebSocketTTSBody::new(bos_message, chunks);
+        let mut endpoint = WebSocketTTS::new(&self.voice_id, self.model_id.as_str(), body);
+        if let Some(output_format) = self.output_format {
+            endpoint =
+                endpoint.with_query(SpeechQuery::default().with_output_format(output_format));
+        }
+        let responses = client.hit_ws(endpoint).await?;
+        Ok(responses.filter_map(|resp| async move {
+            match resp.and_then(|resp| resp.audio_as_bytes()) {
+                Ok(audio) if audio.is_empty() => None,
+                result => Some(result),
+            }
+        }))
+    }
+}
+
+/// Buffers tokens into chunks that end at sentence or clause boundaries
+fn chunk_tokens<S>(tokens: S, min_clause_chars: usize) -> impl Stream<Item = TextChunk> + Send
+where
+    S: Stream<Item = String> + Send + 'static,
+{
+    stream! {
+        pin_mut!(tokens);
+        let mut buf = String::new();
+        while let Some(token) = tokens.next().await {
+            buf.push_str(&token);
+            while let Some(end) = chunk_end(&buf, min_clause_chars) {
+                let rest = buf.split_off(end);
+                yield TextChunk::from(ending_with_space(buf));
+                buf = rest.trim_start().to_string();
+            }
+        }
+        if !buf.trim().is_empty() {
+            yield TextChunk::from(ending_with_space(buf));
+        }
+    }
+}
+
+/// The end of the first chunk of the buffer, after the boundary and the whitespace following it
+///
+/// A boundary only counts once whitespace follows it, so `3.14` split across tokens is not cut,
+/// except for full width punctuation such as `。`. A period after an abbreviation such as `e.g.`
+/// or `Dr.`, or after an initial, is not a boundary.
+fn chunk_end(buf: &str, min_clause_chars: usize) -> Option<usize> {
+    let mut chars = buf.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        if FULL_WIDTH_SENTENCE_ENDS.contains(&c) {
+            return Some(i + c.len_utf8());
+        }
+        let is_boundary = (SENTENCE_ENDS.contains(&c) && !(c == '.' && is_abbreviation(&buf[..i])))
+            || (CLAUSE_ENDS.contains(&c) && buf[..i].chars().count() >= min_clause_chars);
+        if !is_boundary {
+            continue;
+        }
+        if c == '\n' {
+            return Some(i + 1);
+        }
+        match chars.peek() {
+            Some((next, whitespace)) if whitespace.is_whitespace() => return Some(*next),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Whether the last word of the text, which a period follows, is an abbreviation or an initial
+fn is_abbreviation(text: &str) -> bool {
+    let word = text
+        .rsplit(char::is_whitespace)
+        .next()
+        .unwrap_or_default()
+        .trim_start_matches(|c: char| !c.is_alphanumeric());
+    let mut chars = word.chars();
+    // e.g. `J. R. R. Tolkien`, but not the pronoun `I`
+    let is_initial =
+        matches!((chars.next(), chars.next()), (Some(c), None) if c.is_uppercase() && c != 'I');
+    is_initial || ABBREVIATIONS.contains(&word.to_lowercase().as_str())
+}
+
+/// Chunks should end with a space, so words are not joined across chunks
+fn ending_with_space(mut text: String) -> String {
+    if !text.ends_with(char::is_whitespace) {
+        text.push(' ');
+    }
+    text
+}
@@ -0,0 +1,190 @@
+//! SRT and WebVTT subtitles from word timestamps
+use crate::endpoints::stt::{Transcript, WordType};
+use crate::endpoints::tts::Alignment;
+use std::fmt::Write;
+use std::time::Duration;
+
+const DEFAULT_MAX_LINE_CHARS: usize = 42;
+const DEFAULT_MAX_LINES: usize = 2;
+const DEFAULT_MAX_DURATION: Duration = Duration::from_secs(7);
+
+/// Subtitles made of the words of a transcript or of generated speech
+///
+/// Words are laid out in lines of at most 42 characters, at most two lines per cue,
+/// and a cue lasts at most seven seconds. A word longer than a line gets a line of its own.
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::utils::Subtitles;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let body = SpeechToTextBody::new("interview.mp4", Model::ScribeV1);
+///     let resp = c.hit(SpeechToText::new(body)).await?;
+///     let subtitles = Subtitles::from_transcript(&resp.transcripts()[0]).with_max_line_chars(32);
+///     std::fs::write("interview.srt", subtitles.to_srt())?;
+///     std::fs::write("interview.vtt", subtitles.to_vtt())?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Subtitles {
+    words: Vec<TimedWord>,
+    max_line_chars: usize,
+    max_lines: usize,
+    max_duration: Duration,
+}
+
+#[derive(Clone, Debug)]
+struct TimedWord {
+    text: String,
+    start: f32,
+    end: f32,
+}
+
+/// A subtitle shown from its start to its end, in seconds
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cue {
+    start: f32,
+    end: f32,
+    lines: Vec<String>,
+}
+
+impl Cue {
+    pub fn start(&self) -> f32 {
+        self.start
+    }
+    pub fn end(&self) -> f32 {
+        self.end
+    }
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+}
+
+impl Subtitles {
+    /// The words of a transcript, leaving out audio events and words without timestamps
+    pub fn from_transcript(transcript: &Transcript) -> Self {
+        let words = transcript
+            .words()
+            .iter()
+            .filter(|word| word.word_type() == WordType::Word)
+            .filter_map(|word| {
+                Some(TimedWord {
+                    text: word.text().trim().to_string(),
+                    start: word.start()?,
+                    end: word.end()?,
+                })
+            });
+        Self::new(words.collect())
+    }
+    /// The words of speech generated [with timestamps](crate::TextToSpeechWithTimestamps)
+    pub fn from_alignment(alignment: &Alignment) -> Self {
+        let words = alignment.words().into_iter().map(|word| TimedWord {
+            text: word.word().to_string(),
+            start: word.start_seconds(),
+            end: word.end_seconds(),
+        });
+        Self::new(words.collect())
+    }
+    fn new(words: Vec<TimedWord>) -> Self {
+        Subtitles {
+            words,
+            max_line_chars: DEFAULT_MAX_LINE_CHARS,
+            max_lines: DEFAULT_MAX_LINES,
+            max_duration: DEFAULT_MAX_DURATION,
+        }
+    }
+    pub fn with_max_line_chars(mut self, max_line_chars: usize) -> Self {
+        self.max_line_chars = max_line_chars;
+        self
+    }
+    pub fn with_max_lines(mut self, max_lines: usize) -> Self {
+        self.max_lines = max_lines.max(1);
+        self
+    }
+    pub fn with_max_duration(mut self, max_duration: Duration) -> Self {
+        self.max_duration = max_duration;
+        self
+    }
+
+    pub fn cues(&self) -> Vec<Cue> {
+        let max_duration = self.max_duration.as_secs_f32();
+        let mut cues = Vec::new();
+        let mut current: Option<Cue> = None;
+
+        for word in self.words.iter().filter(|word| !word.text.is_empty()) {
+            if let Some(cue) = current.as_mut() {
+                let line = cue.lines.last().expect("a cue has a line");
+                let fits_line =
+                    line.chars().count() + 1 + word.text.chars().count() <= self.max_line_chars;
+                let fits_cue = fits_line || cue.lines.len() < self.max_lines;
+                if fits_cue && word.end - cue.start <= max_duration {
+                    if fits_line {
+                        let line = cue.lines.last_mut().expect("a cue has a line");
+                        line.push(' ');
+                        line.push_str(&word.text);
+                    } else {
+                        cue.lines.push(word.text.clone());
+                    }
+                    cue.end = word.end;
+                    continue;
+                }
+            }
+            cues.extend(current.take());
+            current = Some(Cue {
+                start: word.start,
+                end: word.end,
+                lines: vec![word.text.clone()],
+            });
+        }
+        cues.extend(current);
+        cues
+    }
+
+    /// SubRip subtitles
+    pub fn to_srt(&self) -> String {
+        let mut srt = String::new();
+        for (i, cue) in self.cues().iter().enumerate() {
+            let _ = write!(
+                srt,
+                "{}\n{} --> {}\n{}\n\n",
+                i + 1,
+                timestamp(cue.start, ','),
+                timestamp(cue.end, ','),
+                cue.lines.join("\n")
+            );
+        }
+        srt
+    }
+
+    /// WebVTT subtitles
+    pub fn to_vtt(&self) -> String {
+        let mut vtt = String::from("WEBVTT\n\n");
+        for cue in self.cues() {
+            let _ = write!(
+                vtt,
+                "{} --> {}\n{}\n\n",
+                timestamp(cue.start, '.'),
+                timestamp(cue.end, '.'),
+                cue.lines.join("\n")
+            );
+        }
+        vtt
+    }
+}
+
+/// `HH:MM:SS` and milliseconds after the separator, `,` for SRT and `.` for WebVTT
+fn timestamp(seconds: f32, separator: char) -> String {
+    let millis = (seconds.max(0.0) * 1000.0).round() as u64;
+    format!(
+        "{:02}:{:02}:{:02}{}{:03}",
+        millis / 3_600_000,
+        millis / 60_000 % 60,
+        millis / 1000 % 60,
+        separator,
+        millis % 1000
+    )
+}
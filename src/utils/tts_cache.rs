@@ -0,0 +1,121 @@
+//! Generated speech cached on disk
+use crate::client::Result;
+use crate::middleware::{Middleware, Next};
+use crate::transport::{Request, Response, TransportFuture};
+use bytes::Bytes;
+use http::Method;
+use sha2::{Digest, Sha256};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+const TTS_PATH: &str = "/v1/text-to-speech/";
+
+static TMP_FILE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// [Middleware] answering repeated text to speech requests with audio cached on disk
+///
+/// Requests are keyed by a hash of their voice id, query, e.g. the output format,
+/// and body, i.e. the text, model, voice settings and every other option, so a cached
+/// file is only returned for an identical request. Only successful responses are cached.
+///
+/// Streaming endpoints pass through uncached, so that audio is still played as it arrives.
+/// Cached responses have no headers, e.g. no request id to
+/// [stitch](crate::TextToSpeechBody::with_previous_request_ids) the next request to.
+/// A failed write to the cache does not fail the request.
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::utils::TtsCache;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?.with_middleware(TtsCache::new("tts_cache"));
+///     let body = TextToSpeechBody::new("Please hold.", Model::ElevenMultilingualV2);
+///     // Only the first run is charged
+///     let speech = c.hit(TextToSpeech::new(PreMadeVoiceID::Sarah, body)).await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct TtsCache {
+    dir: PathBuf,
+}
+
+impl TtsCache {
+    /// The directory is created on the first write
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Self {
+        TtsCache { dir: dir.into() }
+    }
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Removes every cached file
+    pub async fn clear(&self) -> Result<()> {
+        match tokio::fs::remove_dir_all(&self.dir).await {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+
+    async fn store(&self, path: &Path, audio: &[u8]) -> std::io::Result<()> {
+        tokio::fs::create_dir_all(&self.dir).await?;
+        // Written aside then renamed, so that a concurrent read never sees a partial file
+        let n = TMP_FILE_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let tmp = path.with_extension(format!("{}-{}.tmp", std::process::id(), n));
+        tokio::fs::write(&tmp, audio).await?;
+        tokio::fs::rename(&tmp, path).await
+    }
+}
+
+impl Middleware for TtsCache {
+    fn handle(&self, request: Request, next: Next) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let Some(key) = cache_key(&request) else {
+                return next.run(request).await;
+            };
+            let path = self.dir.join(key);
+            if let Ok(audio) = tokio::fs::read(&path).await {
+                return Ok(Response::from(http::Response::new(Bytes::from(audio))));
+            }
+
+            let resp = next.run(request).await?;
+            if !resp.status().is_success() {
+                return Ok(resp);
+            }
+            let mut cached = http::Response::builder().status(resp.status());
+            if let Some(headers) = cached.headers_mut() {
+                *headers = resp.headers().clone();
+            }
+            let audio = resp.bytes().await?;
+            let _ = self.store(&path, &audio).await;
+            Ok(Response::from(cached.body(audio)?))
+        })
+    }
+}
+
+/// Hex encoded SHA-256 of the path, query and body of a text to speech request
+fn cache_key(request: &Request) -> Option<String> {
+    let url = request.url();
+    if request.method() != Method::POST
+        || !url.path().starts_with(TTS_PATH)
+        || url.path().contains("/stream")
+    {
+        return None;
+    }
+    let body = request.body()?.as_bytes()?;
+
+    let mut hasher = Sha256::new();
+    hasher.update(url.path());
+    hasher.update([b'?']);
+    hasher.update(url.query().unwrap_or_default());
+    hasher.update([b'\n']);
+    hasher.update(body);
+    let mut key = String::with_capacity(64);
+    for byte in hasher.finalize() {
+        let _ = write!(key, "{:02x}", byte);
+    }
+    Some(key)
+}
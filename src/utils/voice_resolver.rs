@@ -0,0 +1,134 @@
+//! Voice ids looked up by name, with a cache of the account's voices
+use crate::client::{ElevenLabsClient, Result};
+use crate::endpoints::voice::GetVoices;
+use crate::error::Error;
+use crate::shared::identifiers::VoiceID;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+const DEFAULT_TTL: Duration = Duration::from_secs(300);
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Resolves voice names to voice ids
+///
+/// The voices of the account are fetched with [GetVoices] on the first lookup,
+/// then kept until the cache is older than its time to live.
+/// Names are matched exactly first, then ignoring case.
+///
+/// # Example
+/// ```no_run
+/// use elevenlabs_rs::*;
+/// use elevenlabs_rs::utils::VoiceResolver;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let c = ElevenLabsClient::default()?;
+///     let voices = VoiceResolver::new(c.clone());
+///     let body = TextToSpeechBody::new("Found by name", Model::ElevenMultilingualV2);
+///     let speech = c.hit(TextToSpeech::new(voices.resolve("Clyde").await?, body)).await?;
+///     Ok(())
+/// }
+/// ```
+pub struct VoiceResolver {
+    client: ElevenLabsClient,
+    ttl: Duration,
+    cache: Mutex<Option<Cache>>,
+}
+
+struct Cache {
+    fetched_at: Instant,
+    /// Names and ids, in the order the API lists them
+    voices: Vec<(String, VoiceID)>,
+}
+
+impl VoiceResolver {
+    pub fn new(client: ElevenLabsClient) -> Self {
+        VoiceResolver {
+            client,
+            ttl: DEFAULT_TTL,
+            cache: Mutex::new(None),
+        }
+    }
+    /// Defaults to five minutes
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Fails with [Error::UnknownVoiceName] listing the closest names if none matches
+    pub async fn resolve(&self, name: &str) -> Result<VoiceID> {
+        let mut cache = self.cache.lock().await;
+        if cache
+            .as_ref()
+            .is_none_or(|c| c.fetched_at.elapsed() >= self.ttl)
+        {
+            let resp = self.client.hit(GetVoices).await?;
+            let voices = resp
+                .get_voices()
+                .iter()
+                .map(|v| (v.get_name().clone(), VoiceID::from(v.get_voice_id())))
+                .collect();
+            *cache = Some(Cache {
+                fetched_at: Instant::now(),
+                voices,
+            });
+        }
+        let voices = &cache.as_ref().expect("cache is filled above").voices;
+
+        let found = voices
+            .iter()
+            .find(|(n, _)| n == name)
+            .or_else(|| voices.iter().find(|(n, _)| n.eq_ignore_ascii_case(name)));
+        match found {
+            Some((_, id)) => Ok(id.clone()),
+            None => Err(Box::new(Error::UnknownVoiceName {
+                name: name.to_string(),
+                suggestions: suggestions(name, voices.iter().map(|(n, _)| n.as_str())),
+            })),
+        }
+    }
+
+    /// Drops the cached voices, e.g. after adding a voice, so the next lookup fetches them again
+    pub async fn invalidate(&self) {
+        *self.cache.lock().await = None;
+    }
+}
+
+/// The names closest to `name` by edit distance, leaving out those too far off to be a typo
+fn suggestions<'a>(name: &str, names: impl Iterator<Item = &'a str>) -> Vec<String> {
+    let name = name.to_lowercase();
+    let max_distance = (name.chars().count() / 3).max(2);
+    let mut close = names
+        .filter_map(|n| {
+            let lower = n.to_lowercase();
+            let distance = if lower.contains(&name) || name.contains(&lower) {
+                0
+            } else {
+                levenshtein(&name, &lower)
+            };
+            (distance <= max_distance).then(|| (distance, n.to_string()))
+        })
+        .collect::<Vec<_>>();
+    close.sort();
+    close.dedup_by(|a, b| a.1 == b.1);
+    close
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, n)| n)
+        .collect()
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut row = (0..=b.len()).collect::<Vec<_>>();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != *cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
@@ -0,0 +1,52 @@
+//! WAV containers for the headerless PCM, μ-law and A-law output formats
+use crate::client::Result;
+use crate::error::AudioInterfaceError;
+use crate::shared::query_params::{AudioCodec, OutputFormat};
+use bytes::{BufMut, Bytes, BytesMut};
+
+const WAV_HEADER_LEN: usize = 44;
+const FORMAT_PCM: u16 = 1;
+const FORMAT_ALAW: u16 = 6;
+const FORMAT_MULAW: u16 = 7;
+
+/// Wraps raw audio in a WAV container
+///
+/// The API returns PCM as raw 16 bit little endian mono samples, and μ-law and A-law
+/// as raw 8 bit mono samples, which most players cannot open without a header.
+///
+/// Returns an error for MP3 and Opus, which already have a container.
+pub fn to_wav(data: &[u8], format: OutputFormat) -> Result<Bytes> {
+    let (format_tag, bits_per_sample) = match format.codec() {
+        AudioCodec::Pcm => (FORMAT_PCM, 16),
+        AudioCodec::MuLaw => (FORMAT_MULAW, 8),
+        AudioCodec::ALaw => (FORMAT_ALAW, 8),
+        AudioCodec::Mp3 | AudioCodec::Opus => {
+            return Err(Box::new(AudioInterfaceError::UnsupportedAudioFormat(
+                format.to_string(),
+            )))
+        }
+    };
+    let channels: u16 = 1;
+    let sample_rate = format.sample_rate();
+    let block_align = channels * bits_per_sample / 8;
+    // A trailing half sample would make the data length invalid
+    let data = &data[..data.len() - data.len() % block_align as usize];
+    let data_len = u32::try_from(data.len()).map_err(|_| "Audio too long for a WAV file")?;
+
+    let mut wav = BytesMut::with_capacity(WAV_HEADER_LEN + data.len());
+    wav.put_slice(b"RIFF");
+    wav.put_u32_le(36 + data_len);
+    wav.put_slice(b"WAVE");
+    wav.put_slice(b"fmt ");
+    wav.put_u32_le(16);
+    wav.put_u16_le(format_tag);
+    wav.put_u16_le(channels);
+    wav.put_u32_le(sample_rate);
+    wav.put_u32_le(sample_rate * block_align as u32);
+    wav.put_u16_le(block_align);
+    wav.put_u16_le(bits_per_sample);
+    wav.put_slice(b"data");
+    wav.put_u32_le(data_len);
+    wav.put_slice(data);
+    Ok(wav.freeze())
+}
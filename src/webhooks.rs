@@ -0,0 +1,573 @@
+//! Webhooks sent by ElevenLabs after a conversational AI call or an asynchronous transcription
+//!
+//! Requests carry an `ElevenLabs-Signature` header of the form `t=<timestamp>,v0=<hash>`,
+//! where the hash is a hex encoded HMAC-SHA256 of `<timestamp>.<body>` keyed with the webhook's secret.
+//!
+//! See the [ElevenLabs docs](https://elevenlabs.io/docs/conversational-ai/workflows/post-call-webhooks) for more information.
+//!
+//! # Example
+//! ```no_run
+//! use elevenlabs_rs::*;
+//! use elevenlabs_rs::webhooks::{construct_event, WebhookEvent};
+//!
+//! fn handle(signature_header: &str, body: &[u8]) -> Result<()> {
+//!     let secret = std::env::var("ELEVENLABS_WEBHOOK_SECRET")?;
+//!     match construct_event(&secret, signature_header, body)? {
+//!         WebhookEvent::PostCallTranscription { data, .. } => {
+//!             for turn in data.transcript() {
+//!                 println!("{:?}: {}", turn.role(), turn.message().unwrap_or_default());
+//!             }
+//!         }
+//!         WebhookEvent::PostCallAudio { data, .. } => {
+//!             std::fs::write("call.mp3", data.full_audio_as_bytes()?)?;
+//!         }
+//!         _ => {}
+//!     }
+//!     Ok(())
+//! }
+//! ```
+use crate::client::Result;
+use crate::endpoints::convai::{ConversationInitiationClientData, FeedbackScore};
+use crate::endpoints::stt::SpeechToTextResponse;
+use crate::error::WebhookError;
+#[cfg(feature = "chrono")]
+use crate::shared::time::utc;
+use base64::prelude::{Engine, BASE64_STANDARD};
+use bytes::Bytes;
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use sha2::Sha256;
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The header the signature is sent in
+pub const SIGNATURE_HEADER: &str = "ElevenLabs-Signature";
+/// How old a signed request may be before it is rejected
+pub const SIGNATURE_TOLERANCE: Duration = Duration::from_secs(30 * 60);
+
+const TIMESTAMP_PREFIX: &str = "t=";
+const SIGNATURE_PREFIX: &str = "v0=";
+
+/// Checks the `ElevenLabs-Signature` header of a webhook request against its raw body
+///
+/// Fails if the header is malformed, older than [SIGNATURE_TOLERANCE] or does not match.
+pub fn verify_signature(secret: &str, header: &str, body: &[u8]) -> Result<()> {
+    let mut timestamp = None;
+    let mut signature = None;
+    for part in header.split(',').map(str::trim) {
+        if let Some(t) = part.strip_prefix(TIMESTAMP_PREFIX) {
+            timestamp = Some(t);
+        } else if let Some(v0) = part.strip_prefix(SIGNATURE_PREFIX) {
+            signature = Some(v0);
+        }
+    }
+    let (Some(timestamp), Some(signature)) = (timestamp, signature) else {
+        return Err(Box::new(WebhookError::InvalidSignatureHeader));
+    };
+    let signed_at = timestamp
+        .parse::<u64>()
+        .map_err(|_| WebhookError::InvalidSignatureHeader)?;
+    let signature = decode_hex(signature).ok_or(WebhookError::InvalidSignatureHeader)?;
+
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    if now.saturating_sub(signed_at) > SIGNATURE_TOLERANCE.as_secs() {
+        return Err(Box::new(WebhookError::TimestampOutOfTolerance));
+    }
+
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())?;
+    mac.update(timestamp.as_bytes());
+    mac.update(b".");
+    mac.update(body);
+    mac.verify_slice(&signature)
+        .map_err(|_| WebhookError::SignatureMismatch)?;
+    Ok(())
+}
+
+/// Verifies the signature of a webhook request and deserializes its body
+pub fn construct_event(secret: &str, header: &str, body: &[u8]) -> Result<WebhookEvent> {
+    verify_signature(secret, header, body)?;
+    Ok(serde_json::from_slice(body)?)
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    let pairs = hex.as_bytes().chunks_exact(2);
+    if !pairs.remainder().is_empty() {
+        return None;
+    }
+    pairs
+        .map(|pair| u8::from_str_radix(std::str::from_utf8(pair).ok()?, 16).ok())
+        .collect()
+}
+
+/// A webhook request body
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum WebhookEvent {
+    /// The transcript and analysis of a finished call
+    PostCallTranscription {
+        event_timestamp: u64,
+        data: Box<PostCallTranscription>,
+    },
+    /// The recording of a finished call
+    PostCallAudio {
+        event_timestamp: u64,
+        data: PostCallAudio,
+    },
+    /// An outbound call that could not be placed
+    CallInitiationFailure {
+        event_timestamp: u64,
+        data: CallInitiationFailure,
+    },
+    /// The result of a [SpeechToTextWithWebhook](crate::SpeechToTextWithWebhook) request
+    SpeechToTextTranscription {
+        event_timestamp: u64,
+        data: Box<SpeechToTextTranscription>,
+    },
+    /// An event type this version of the crate does not know about
+    #[serde(other)]
+    Unknown,
+}
+
+impl WebhookEvent {
+    /// Unix seconds, `None` for [Unknown](WebhookEvent::Unknown) events
+    pub fn event_timestamp(&self) -> Option<u64> {
+        match self {
+            WebhookEvent::PostCallTranscription {
+                event_timestamp, ..
+            }
+            | WebhookEvent::PostCallAudio {
+                event_timestamp, ..
+            }
+            | WebhookEvent::CallInitiationFailure {
+                event_timestamp, ..
+            }
+            | WebhookEvent::SpeechToTextTranscription {
+                event_timestamp, ..
+            } => Some(*event_timestamp),
+            WebhookEvent::Unknown => None,
+        }
+    }
+}
+
+/// The data of a [post call transcription](WebhookEvent::PostCallTranscription) webhook
+#[derive(Clone, Debug, Deserialize)]
+pub struct PostCallTranscription {
+    agent_id: String,
+    conversation_id: String,
+    status: String,
+    user_id: Option<String>,
+    #[serde(default)]
+    transcript: Vec<TranscriptTurn>,
+    metadata: Option<CallMetadata>,
+    analysis: Option<CallAnalysis>,
+    conversation_initiation_client_data: Option<ConversationInitiationClientData>,
+}
+
+impl PostCallTranscription {
+    pub fn agent_id(&self) -> &str {
+        &self.agent_id
+    }
+    pub fn conversation_id(&self) -> &str {
+        &self.conversation_id
+    }
+    /// e.g. `done` or `failed`
+    pub fn status(&self) -> &str {
+        &self.status
+    }
+    pub fn user_id(&self) -> Option<&str> {
+        self.user_id.as_deref()
+    }
+    pub fn transcript(&self) -> &[TranscriptTurn] {
+        &self.transcript
+    }
+    pub fn metadata(&self) -> Option<&CallMetadata> {
+        self.metadata.as_ref()
+    }
+    pub fn analysis(&self) -> Option<&CallAnalysis> {
+        self.analysis.as_ref()
+    }
+    /// The overrides and dynamic variables the conversation was started with
+    pub fn conversation_initiation_client_data(&self) -> Option<&ConversationInitiationClientData> {
+        self.conversation_initiation_client_data.as_ref()
+    }
+}
+
+/// One turn of a call's transcript
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TranscriptTurn {
+    role: TranscriptRole,
+    message: Option<String>,
+    #[serde(default)]
+    time_in_call_secs: u32,
+    #[serde(default)]
+    tool_calls: Vec<Value>,
+    #[serde(default)]
+    tool_results: Vec<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    feedback: Option<TurnFeedback>,
+}
+
+impl TranscriptTurn {
+    /// A turn of the user, e.g. for the history of a simulated conversation
+    pub fn user<T: Into<String>>(message: T) -> Self {
+        Self::new(TranscriptRole::User, message)
+    }
+    pub fn agent<T: Into<String>>(message: T) -> Self {
+        Self::new(TranscriptRole::Agent, message)
+    }
+    fn new<T: Into<String>>(role: TranscriptRole, message: T) -> Self {
+        Self {
+            role,
+            message: Some(message.into()),
+            time_in_call_secs: 0,
+            tool_calls: Vec::new(),
+            tool_results: Vec::new(),
+            feedback: None,
+        }
+    }
+    pub fn role(&self) -> &TranscriptRole {
+        &self.role
+    }
+    /// `None` for turns that only call tools
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+    pub fn time_in_call_secs(&self) -> u32 {
+        self.time_in_call_secs
+    }
+    pub fn tool_calls(&self) -> &[Value] {
+        &self.tool_calls
+    }
+    pub fn tool_results(&self) -> &[Value] {
+        &self.tool_results
+    }
+    /// The user's rating of an agent turn, if they rated it during the call
+    pub fn feedback(&self) -> Option<&TurnFeedback> {
+        self.feedback.as_ref()
+    }
+}
+
+/// A user's rating of one of the agent's turns
+///
+/// Turns are rated during the call with a
+/// [feedback message](crate::endpoints::convai::client_messages::ClientMessage::Feedback).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TurnFeedback {
+    score: FeedbackScore,
+    #[serde(default)]
+    time_in_call_secs: u32,
+}
+
+impl TurnFeedback {
+    pub fn score(&self) -> &FeedbackScore {
+        &self.score
+    }
+    pub fn time_in_call_secs(&self) -> u32 {
+        self.time_in_call_secs
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TranscriptRole {
+    Agent,
+    User,
+}
+
+/// Call metadata
+#[derive(Clone, Debug, Deserialize)]
+pub struct CallMetadata {
+    start_time_unix_secs: u64,
+    call_duration_secs: u32,
+    cost: Option<u32>,
+    termination_reason: Option<String>,
+    phone_call: Option<Value>,
+    #[serde(flatten, deserialize_with = "crate::shared::extras::deserialize")]
+    extra: Map<String, Value>,
+}
+
+impl CallMetadata {
+    pub fn start_time_unix_secs(&self) -> u64 {
+        self.start_time_unix_secs
+    }
+    /// [start_time_unix_secs](Self::start_time_unix_secs) as a date
+    #[cfg(feature = "chrono")]
+    pub fn start_time(&self) -> DateTime<Utc> {
+        utc(self.start_time_unix_secs())
+    }
+    pub fn call_duration_secs(&self) -> u32 {
+        self.call_duration_secs
+    }
+    /// In credits
+    pub fn cost(&self) -> Option<u32> {
+        self.cost
+    }
+    pub fn termination_reason(&self) -> Option<&str> {
+        self.termination_reason.as_deref()
+    }
+    /// Details of the phone call, for calls over a phone number
+    pub fn phone_call(&self) -> Option<&Value> {
+        self.phone_call.as_ref()
+    }
+    /// Fields of the response this version of the crate does not know about yet
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+}
+
+/// The analysis of a call, run after it ended
+#[derive(Clone, Debug, Deserialize)]
+pub struct CallAnalysis {
+    call_successful: CallSuccessful,
+    transcript_summary: Option<String>,
+    #[serde(default)]
+    evaluation_criteria_results: HashMap<String, Value>,
+    #[serde(default)]
+    data_collection_results: HashMap<String, Value>,
+}
+
+impl CallAnalysis {
+    pub fn call_successful(&self) -> &CallSuccessful {
+        &self.call_successful
+    }
+    pub fn transcript_summary(&self) -> Option<&str> {
+        self.transcript_summary.as_deref()
+    }
+    /// The results of the agent's evaluation criteria, by criterion id
+    pub fn evaluation_criteria_results(&self) -> &HashMap<String, Value> {
+        &self.evaluation_criteria_results
+    }
+    /// The result of one evaluation criterion
+    pub fn evaluation_result(&self, criteria_id: &str) -> Option<EvaluationResult> {
+        serde_json::from_value(self.evaluation_criteria_results.get(criteria_id)?.clone()).ok()
+    }
+    /// The results of all evaluation criteria, sorted by criterion id
+    pub fn evaluation_results(&self) -> Vec<EvaluationResult> {
+        let mut results = self
+            .evaluation_criteria_results
+            .values()
+            .filter_map(|result| serde_json::from_value::<EvaluationResult>(result.clone()).ok())
+            .collect::<Vec<_>>();
+        results.sort_by(|a, b| a.criteria_id.cmp(&b.criteria_id));
+        results
+    }
+    /// The data collected by the agent, by data collection id
+    pub fn data_collection_results(&self) -> &HashMap<String, Value> {
+        &self.data_collection_results
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CallSuccessful {
+    Success,
+    Failure,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Whether a call met one of the agent's
+/// [evaluation criteria](crate::endpoints::convai::agents::EvaluationCriterion)
+#[derive(Clone, Debug, Deserialize)]
+pub struct EvaluationResult {
+    criteria_id: String,
+    result: CallSuccessful,
+    #[serde(default)]
+    rationale: String,
+}
+
+impl EvaluationResult {
+    pub fn criteria_id(&self) -> &str {
+        &self.criteria_id
+    }
+    pub fn result(&self) -> &CallSuccessful {
+        &self.result
+    }
+    /// Why the LLM judged the call as it did
+    pub fn rationale(&self) -> &str {
+        &self.rationale
+    }
+}
+
+/// The data of a [post call audio](WebhookEvent::PostCallAudio) webhook
+#[derive(Clone, Debug, Deserialize)]
+pub struct PostCallAudio {
+    agent_id: String,
+    conversation_id: String,
+    full_audio: String,
+}
+
+impl PostCallAudio {
+    pub fn agent_id(&self) -> &str {
+        &self.agent_id
+    }
+    pub fn conversation_id(&self) -> &str {
+        &self.conversation_id
+    }
+    /// Base64 encoded mp3 of the whole call
+    pub fn full_audio(&self) -> &str {
+        &self.full_audio
+    }
+    pub fn full_audio_as_bytes(&self) -> Result<Bytes> {
+        Ok(Bytes::from(BASE64_STANDARD.decode(&self.full_audio)?))
+    }
+}
+
+/// The data of a [call initiation failure](WebhookEvent::CallInitiationFailure) webhook
+#[derive(Clone, Debug, Deserialize)]
+pub struct CallInitiationFailure {
+    agent_id: String,
+    conversation_id: String,
+    failure_reason: String,
+    metadata: Option<Value>,
+}
+
+impl CallInitiationFailure {
+    pub fn agent_id(&self) -> &str {
+        &self.agent_id
+    }
+    pub fn conversation_id(&self) -> &str {
+        &self.conversation_id
+    }
+    /// e.g. `busy` or `no-answer`
+    pub fn failure_reason(&self) -> &str {
+        &self.failure_reason
+    }
+    /// Provider specific details, e.g. the Twilio or SIP status
+    pub fn metadata(&self) -> Option<&Value> {
+        self.metadata.as_ref()
+    }
+}
+
+/// The data of a [speech-to-text transcription](WebhookEvent::SpeechToTextTranscription) webhook
+#[derive(Clone, Debug, Deserialize)]
+pub struct SpeechToTextTranscription {
+    request_id: String,
+    transcription: SpeechToTextResponse,
+    webhook_metadata: Option<Value>,
+}
+
+impl SpeechToTextTranscription {
+    /// The [request id](crate::SpeechToTextWebhookResponse::request_id) returned on submission
+    pub fn request_id(&self) -> &str {
+        &self.request_id
+    }
+    pub fn transcription(&self) -> &SpeechToTextResponse {
+        &self.transcription
+    }
+    /// The [metadata](crate::SpeechToTextBody::with_webhook_metadata) sent with the request
+    pub fn webhook_metadata(&self) -> Option<&Value> {
+        self.webhook_metadata.as_ref()
+    }
+}
+
+/// An axum extractor for webhook requests
+///
+/// Requires the `axum` feature.
+///
+/// # Example
+/// ```no_run
+/// use axum::{routing::post, Router};
+/// use elevenlabs_rs::webhooks::axum::{VerifiedWebhook, WebhookSecret};
+/// use elevenlabs_rs::webhooks::WebhookEvent;
+///
+/// async fn webhook(VerifiedWebhook(event): VerifiedWebhook) {
+///     if let WebhookEvent::PostCallTranscription { data, .. } = event {
+///         println!("{} ended", data.conversation_id());
+///     }
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let secret = std::env::var("ELEVENLABS_WEBHOOK_SECRET").unwrap();
+///     let app = Router::new()
+///         .route("/webhooks/elevenlabs", post(webhook))
+///         .with_state(WebhookSecret::new(secret));
+///     let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await.unwrap();
+///     axum::serve(listener, app).await.unwrap();
+/// }
+/// ```
+#[cfg(feature = "axum")]
+pub mod axum {
+    use super::*;
+    use ::axum::async_trait;
+    use ::axum::body::Bytes;
+    use ::axum::extract::{FromRef, FromRequest, Request};
+    use ::axum::http::StatusCode;
+    use ::axum::response::{IntoResponse, Response};
+
+    /// The secret webhooks are signed with
+    ///
+    /// Taken from the router's state, implement `FromRef` to keep it in a larger state.
+    #[derive(Clone)]
+    pub struct WebhookSecret(String);
+
+    impl WebhookSecret {
+        pub fn new<T: Into<String>>(secret: T) -> Self {
+            Self(secret.into())
+        }
+    }
+
+    impl std::fmt::Debug for WebhookSecret {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str("WebhookSecret(..)")
+        }
+    }
+
+    /// A webhook whose signature was verified
+    #[derive(Clone, Debug)]
+    pub struct VerifiedWebhook(pub WebhookEvent);
+
+    #[async_trait]
+    impl<S> FromRequest<S> for VerifiedWebhook
+    where
+        WebhookSecret: FromRef<S>,
+        S: Send + Sync,
+    {
+        type Rejection = WebhookRejection;
+
+        async fn from_request(
+            req: Request,
+            state: &S,
+        ) -> std::result::Result<Self, Self::Rejection> {
+            let secret = WebhookSecret::from_ref(state);
+            let header = req
+                .headers()
+                .get(SIGNATURE_HEADER)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string)
+                .ok_or(WebhookRejection::InvalidSignature)?;
+            let body = Bytes::from_request(req, state)
+                .await
+                .map_err(|e| WebhookRejection::InvalidBody(e.body_text()))?;
+            verify_signature(&secret.0, &header, &body)
+                .map_err(|_| WebhookRejection::InvalidSignature)?;
+            let event = serde_json::from_slice(&body)
+                .map_err(|e| WebhookRejection::InvalidBody(e.to_string()))?;
+            Ok(VerifiedWebhook(event))
+        }
+    }
+
+    /// Why a webhook request was rejected
+    #[derive(Debug)]
+    pub enum WebhookRejection {
+        /// Answered with `401 Unauthorized`
+        InvalidSignature,
+        /// Answered with `400 Bad Request`
+        InvalidBody(String),
+    }
+
+    impl IntoResponse for WebhookRejection {
+        fn into_response(self) -> Response {
+            match self {
+                WebhookRejection::InvalidSignature => {
+                    (StatusCode::UNAUTHORIZED, "invalid webhook signature").into_response()
+                }
+                WebhookRejection::InvalidBody(e) => (StatusCode::BAD_REQUEST, e).into_response(),
+            }
+        }
+    }
+}